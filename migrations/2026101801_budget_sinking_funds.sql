@@ -0,0 +1,6 @@
+-- -----------------------------
+-- BUDGET ENVELOPES: sinking funds (irregular expenses saved for over several months)
+-- -----------------------------
+ALTER TABLE budget_envelopes
+    ADD COLUMN target_base_minor BIGINT NULL AFTER rollover_rule, -- montant à atteindre (NULL = enveloppe classique)
+    ADD COLUMN target_date       DATE NULL AFTER target_base_minor; -- échéance de la dépense
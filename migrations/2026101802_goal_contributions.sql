@@ -0,0 +1,67 @@
+-- -----------------------------
+-- GOAL CONTRIBUTION PLANS (virement "virtuel" planifié vers un objectif)
+-- -----------------------------
+CREATE TABLE goal_contribution_plans (
+    id                BINARY(16) PRIMARY KEY,
+    user_id           BINARY(16) NOT NULL,
+    goal_id           BINARY(16) NOT NULL,
+    amount_base_minor BIGINT NOT NULL,
+    day_of_month      TINYINT UNSIGNED NOT NULL DEFAULT 1, -- 1..31, ramené au dernier jour du mois si besoin
+
+    from_account_id   BINARY(16) NULL,
+    to_account_id     BINARY(16) NULL,
+
+    start_month       DATE NOT NULL, -- 1er du mois
+    end_month         DATE NULL,
+    active            TINYINT(1) NOT NULL DEFAULT 1,
+
+    note              TEXT NULL,
+    created_at        TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at        TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_goal_plans_user (user_id),
+    KEY idx_goal_plans_goal (goal_id),
+
+    CONSTRAINT fk_goal_plans_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_goal_plans_goal
+        FOREIGN KEY (goal_id) REFERENCES goals(id) ON DELETE CASCADE,
+    CONSTRAINT fk_goal_plans_from_account
+        FOREIGN KEY (from_account_id) REFERENCES accounts(id) ON DELETE SET NULL,
+    CONSTRAINT fk_goal_plans_to_account
+        FOREIGN KEY (to_account_id) REFERENCES accounts(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- -----------------------------
+-- GOAL CONTRIBUTIONS (occurrences attendues, marquées faites ou sautées)
+-- -----------------------------
+CREATE TABLE goal_contributions (
+    id                  BINARY(16) PRIMARY KEY,
+    user_id             BINARY(16) NOT NULL,
+    goal_id             BINARY(16) NOT NULL,
+    plan_id             BINARY(16) NULL, -- NULL = contribution ponctuelle
+    due_date            DATE NOT NULL,
+    planned_base_minor  BIGINT NOT NULL,
+    status              ENUM('planned','done','skipped') NOT NULL DEFAULT 'planned',
+
+    transaction_id      BINARY(16) NULL, -- transaction (ou jambe de virement) réelle
+    executed_base_minor BIGINT NULL,
+    executed_at         DATETIME(3) NULL,
+
+    note                TEXT NULL,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_goal_contrib_plan_due (plan_id, due_date),
+    KEY idx_goal_contrib_goal_due (goal_id, due_date),
+    KEY idx_goal_contrib_user_due (user_id, due_date),
+
+    CONSTRAINT fk_goal_contrib_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_goal_contrib_goal
+        FOREIGN KEY (goal_id) REFERENCES goals(id) ON DELETE CASCADE,
+    CONSTRAINT fk_goal_contrib_plan
+        FOREIGN KEY (plan_id) REFERENCES goal_contribution_plans(id) ON DELETE SET NULL,
+    CONSTRAINT fk_goal_contrib_tx
+        FOREIGN KEY (transaction_id) REFERENCES transactions(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,78 @@
+-- -----------------------------
+-- AUTOMATION RULES (déclencheur -> action, ex: "le jour de paie -> virement objectif X")
+-- -----------------------------
+CREATE TABLE automation_rules (
+    id                            BINARY(16) PRIMARY KEY,
+    user_id                       BINARY(16) NOT NULL,
+    name                          VARCHAR(120) NOT NULL,
+    enabled                       TINYINT(1) NOT NULL DEFAULT 1,
+
+    trigger_type                  ENUM('income_transaction','day_of_month') NOT NULL,
+    trigger_payee_id              BINARY(16) NULL,
+    trigger_category_id           BINARY(16) NULL,
+    trigger_min_amount_base_minor BIGINT NULL,
+    trigger_day_of_month          TINYINT UNSIGNED NULL,
+
+    action_type                   ENUM('transfer','goal_contribution','envelope_allocation','notification') NOT NULL,
+    action_amount_base_minor      BIGINT NULL,          -- montant fixe
+    action_percent                DECIMAL(5,2) NULL,    -- ou % du revenu déclencheur
+    action_from_account_id        BINARY(16) NULL,
+    action_to_account_id          BINARY(16) NULL,
+    action_goal_id                BINARY(16) NULL,
+    action_category_id            BINARY(16) NULL,      -- enveloppe de la catégorie pour le mois
+    action_message                TEXT NULL,
+
+    last_run_at                   DATETIME(3) NULL,
+    created_at                    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at                    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_automation_rules_user (user_id, enabled),
+
+    CONSTRAINT fk_automation_rules_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_automation_rules_payee
+        FOREIGN KEY (trigger_payee_id) REFERENCES payees(id) ON DELETE SET NULL,
+    CONSTRAINT fk_automation_rules_trigger_category
+        FOREIGN KEY (trigger_category_id) REFERENCES categories(id) ON DELETE SET NULL,
+    CONSTRAINT fk_automation_rules_from_account
+        FOREIGN KEY (action_from_account_id) REFERENCES accounts(id) ON DELETE SET NULL,
+    CONSTRAINT fk_automation_rules_to_account
+        FOREIGN KEY (action_to_account_id) REFERENCES accounts(id) ON DELETE SET NULL,
+    CONSTRAINT fk_automation_rules_goal
+        FOREIGN KEY (action_goal_id) REFERENCES goals(id) ON DELETE SET NULL,
+    CONSTRAINT fk_automation_rules_action_category
+        FOREIGN KEY (action_category_id) REFERENCES categories(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- -----------------------------
+-- AUTOMATION RUNS (journal d'exécution, les dry-runs ne sont pas journalisés)
+-- -----------------------------
+CREATE TABLE automation_runs (
+    id                     BINARY(16) PRIMARY KEY,
+    rule_id                BINARY(16) NOT NULL,
+    user_id                BINARY(16) NOT NULL,
+
+    trigger_transaction_id BINARY(16) NULL,
+    trigger_date           DATE NOT NULL,
+
+    status                 ENUM('success','failed') NOT NULL,
+    amount_base_minor      BIGINT NULL,
+    result_entity_type     VARCHAR(40) NULL, -- ex: "transaction", "goal_contribution", "budget_envelope"
+    result_entity_id       BINARY(16) NULL,
+    message                TEXT NULL,
+
+    created_at             TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    KEY idx_automation_runs_rule (rule_id, created_at),
+    KEY idx_automation_runs_trigger (rule_id, trigger_date, trigger_transaction_id),
+
+    CONSTRAINT fk_automation_runs_rule
+        FOREIGN KEY (rule_id) REFERENCES automation_rules(id) ON DELETE CASCADE,
+    CONSTRAINT fk_automation_runs_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_automation_runs_tx
+        FOREIGN KEY (trigger_transaction_id) REFERENCES transactions(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+INSERT INTO notification_types (id, code, name, severity, default_in_app, default_email)
+VALUES (UUID_TO_BIN(UUID()), 'AUTOMATION_RULE', 'Automation rule', 'INFO', 1, 0);
@@ -0,0 +1,21 @@
+-- -----------------------------
+-- PROJECT STATUS HISTORY (chaque transition de statut)
+-- -----------------------------
+CREATE TABLE project_status_history (
+    id          BINARY(16) PRIMARY KEY,
+    project_id  BINARY(16) NOT NULL,
+    user_id     BINARY(16) NOT NULL,
+    from_status ENUM('planned','active','paused','done','cancelled') NULL, -- NULL = création
+    to_status   ENUM('planned','active','paused','done','cancelled') NOT NULL,
+    note        TEXT NULL,
+    changed_at  DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+
+    KEY idx_project_status_history_project (project_id, changed_at),
+
+    CONSTRAINT fk_project_status_history_project
+        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    CONSTRAINT fk_project_status_history_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+CREATE INDEX idx_projects_user_status ON projects (user_id, status, priority);
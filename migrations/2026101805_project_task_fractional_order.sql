@@ -0,0 +1,7 @@
+-- -----------------------------
+-- PROJECT TASKS : ordre fractionnaire (un déplacement ne renumérote pas toute la colonne)
+-- -----------------------------
+ALTER TABLE project_tasks
+    MODIFY order_idx DOUBLE NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_tasks_project_status_order ON project_tasks (project_id, status, order_idx);
@@ -0,0 +1,18 @@
+-- -----------------------------
+-- PROJECT TASK DEPENDENCIES (task_id est bloquée tant que depends_on_task_id n'est pas terminée)
+-- -----------------------------
+CREATE TABLE project_task_dependencies (
+    task_id            BINARY(16) NOT NULL,
+    depends_on_task_id BINARY(16) NOT NULL,
+    created_at         TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (task_id, depends_on_task_id),
+    KEY idx_task_dependencies_depends_on (depends_on_task_id),
+
+    CONSTRAINT chk_task_dependencies_not_self CHECK (task_id <> depends_on_task_id),
+
+    CONSTRAINT fk_task_dependencies_task
+        FOREIGN KEY (task_id) REFERENCES project_tasks(id) ON DELETE CASCADE,
+    CONSTRAINT fk_task_dependencies_depends_on
+        FOREIGN KEY (depends_on_task_id) REFERENCES project_tasks(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,18 @@
+-- -----------------------------
+-- PROJECT MILESTONES : budget, tâches et transactions rattachées
+-- -----------------------------
+ALTER TABLE project_milestones
+    ADD COLUMN budget_base_minor BIGINT NULL AFTER status;
+
+ALTER TABLE project_tasks
+    ADD COLUMN milestone_id BINARY(16) NULL AFTER parent_task_id,
+    ADD KEY idx_tasks_milestone (milestone_id),
+    ADD CONSTRAINT fk_tasks_milestone
+        FOREIGN KEY (milestone_id) REFERENCES project_milestones(id) ON DELETE SET NULL;
+
+-- dépense d'un jalon = transactions du projet rattachées au jalon
+ALTER TABLE transactions
+    ADD COLUMN milestone_id BINARY(16) NULL AFTER project_id,
+    ADD KEY idx_tx_milestone (milestone_id),
+    ADD CONSTRAINT fk_tx_milestone
+        FOREIGN KEY (milestone_id) REFERENCES project_milestones(id) ON DELETE SET NULL;
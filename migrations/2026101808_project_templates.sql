@@ -0,0 +1,27 @@
+-- -----------------------------
+-- PROJECT TEMPLATES (projets récurrents : déménagement, voyage...)
+-- -----------------------------
+-- content : jalons, arbre de tâches et dépendances en JSON, dates en jours relatifs au début du projet
+CREATE TABLE project_templates (
+    id                BINARY(16) PRIMARY KEY,
+    user_id           BINARY(16) NOT NULL,
+    name              VARCHAR(120) NOT NULL,
+    description       TEXT NULL,
+    priority          INT NOT NULL DEFAULT 0,
+    budget_base_minor BIGINT NOT NULL DEFAULT 0,
+    due_offset_days   INT NULL,
+    source_project_id BINARY(16) NULL,
+    content           LONGTEXT NOT NULL,
+
+    created_at        TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at        TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_project_templates_user (user_id, name),
+
+    CONSTRAINT chk_project_templates_content CHECK (JSON_VALID(content)),
+
+    CONSTRAINT fk_project_templates_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_project_templates_source
+        FOREIGN KEY (source_project_id) REFERENCES projects(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,39 @@
+-- -----------------------------
+-- PROJECT TIME ENTRIES (chronomètres et saisies manuelles par tâche)
+-- -----------------------------
+-- ended_at NULL = chronomètre en cours ; un seul par utilisateur (running_user_id unique)
+-- project_tasks.actual_minutes = somme des minutes des entrées terminées de la tâche
+CREATE TABLE project_time_entries (
+    id              BINARY(16) PRIMARY KEY,
+    user_id         BINARY(16) NOT NULL,
+    task_id         BINARY(16) NOT NULL,
+    project_id      BINARY(16) NOT NULL,
+    person_id       BINARY(16) NULL,
+
+    started_at      DATETIME(3) NOT NULL,
+    ended_at        DATETIME(3) NULL,
+    minutes         INT NULL,
+    source          ENUM('timer','manual') NOT NULL,
+    note            TEXT NULL,
+
+    running_user_id BINARY(16) AS (IF(ended_at IS NULL, user_id, NULL)) STORED,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_time_entries_running (running_user_id),
+    KEY idx_time_entries_task (task_id, started_at),
+    KEY idx_time_entries_user_date (user_id, started_at),
+    KEY idx_time_entries_project_date (project_id, started_at),
+
+    CONSTRAINT chk_time_entries_minutes CHECK (minutes IS NULL OR minutes >= 0),
+
+    CONSTRAINT fk_time_entries_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_time_entries_task
+        FOREIGN KEY (task_id) REFERENCES project_tasks(id) ON DELETE CASCADE,
+    CONSTRAINT fk_time_entries_project
+        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    CONSTRAINT fk_time_entries_person
+        FOREIGN KEY (person_id) REFERENCES people(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
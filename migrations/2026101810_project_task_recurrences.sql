@@ -0,0 +1,49 @@
+-- -----------------------------
+-- PROJECT TASK RECURRENCES (tâches répétitives : entretien voiture, maison…)
+-- -----------------------------
+-- une règle par série ; chaque occurrence est une tâche liée par recurrence_id
+-- terminer une occurrence crée la suivante (échéance décalée), les occurrences terminées restent l'historique
+-- expense_amount_minor (devise du compte) : dépense attendue créée en transaction 'pending' sur le projet à chaque nouvelle occurrence
+CREATE TABLE project_task_recurrences (
+    id                  BINARY(16) PRIMARY KEY,
+    project_id          BINARY(16) NOT NULL,
+
+    frequency           ENUM('daily','weekly','monthly','yearly') NOT NULL,
+    interval_count      SMALLINT UNSIGNED NOT NULL DEFAULT 1,
+    from_completion     TINYINT(1) NOT NULL DEFAULT 0, -- 1 = prochaine échéance calculée depuis la date de fin, sinon depuis l'échéance
+    until_date          DATE NULL,
+    active              TINYINT(1) NOT NULL DEFAULT 1,
+
+    expense_amount_minor BIGINT NULL,
+    expense_account_id  BINARY(16) NULL,
+    expense_category_id BINARY(16) NULL,
+    expense_payee_id    BINARY(16) NULL,
+
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_task_recurrences_project (project_id),
+
+    CONSTRAINT chk_task_recurrences_interval CHECK (interval_count >= 1),
+    CONSTRAINT chk_task_recurrences_expense CHECK (expense_amount_minor IS NULL OR expense_account_id IS NOT NULL),
+
+    CONSTRAINT fk_task_recurrences_project
+        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    CONSTRAINT fk_task_recurrences_account
+        FOREIGN KEY (expense_account_id) REFERENCES accounts(id) ON DELETE SET NULL,
+    CONSTRAINT fk_task_recurrences_category
+        FOREIGN KEY (expense_category_id) REFERENCES categories(id) ON DELETE SET NULL,
+    CONSTRAINT fk_task_recurrences_payee
+        FOREIGN KEY (expense_payee_id) REFERENCES payees(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- completed_at : renseigné par proc_project_task_move à l'entrée dans la colonne done, vidé à la sortie
+ALTER TABLE project_tasks
+    ADD COLUMN recurrence_id BINARY(16) NULL AFTER milestone_id,
+    ADD COLUMN expense_transaction_id BINARY(16) NULL AFTER recurrence_id,
+    ADD COLUMN completed_at DATETIME(3) NULL AFTER note,
+    ADD KEY idx_tasks_recurrence (recurrence_id, due_date),
+    ADD CONSTRAINT fk_tasks_recurrence
+        FOREIGN KEY (recurrence_id) REFERENCES project_task_recurrences(id) ON DELETE SET NULL,
+    ADD CONSTRAINT fk_tasks_expense_tx
+        FOREIGN KEY (expense_transaction_id) REFERENCES transactions(id) ON DELETE SET NULL;
@@ -0,0 +1,34 @@
+-- -----------------------------
+-- PROJECT PURCHASES (dépenses idées : achats prévus d'un projet)
+-- -----------------------------
+-- idea -> approved -> bought ; les achats approuvés non achetés comptent dans le reste à financer du projet
+-- achat = transaction réelle rattachée (et rattachée au projet) ; actual_price_base_minor = montant de la transaction
+CREATE TABLE project_purchases (
+    id                         BINARY(16) PRIMARY KEY,
+    project_id                 BINARY(16) NOT NULL,
+    title                      VARCHAR(200) NOT NULL,
+
+    estimated_price_base_minor BIGINT NOT NULL,
+    priority                   INT NOT NULL DEFAULT 0,
+    planned_date               DATE NULL,
+    url                        VARCHAR(2048) NULL,
+    note                       TEXT NULL,
+    status                     ENUM('idea','approved','bought') NOT NULL DEFAULT 'idea',
+
+    transaction_id             BINARY(16) NULL,
+    actual_price_base_minor    BIGINT NULL,
+    bought_at                  DATETIME(3) NULL,
+
+    created_at                 TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at                 TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_purchases_project_status (project_id, status, priority),
+    KEY idx_purchases_tx (transaction_id),
+
+    CONSTRAINT chk_purchases_price CHECK (estimated_price_base_minor >= 0),
+
+    CONSTRAINT fk_purchases_project
+        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    CONSTRAINT fk_purchases_tx
+        FOREIGN KEY (transaction_id) REFERENCES transactions(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
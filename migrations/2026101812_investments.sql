@@ -0,0 +1,98 @@
+-- -----------------------------
+-- INVESTMENTS (portefeuilles, instruments, opérations)
+-- -----------------------------
+-- un portefeuille est rattaché à un compte de type 'broker' (vérifié par proc_portfolio_create / proc_portfolio_update)
+-- sa devise est celle du compte
+CREATE TABLE portfolios (
+    id          BINARY(16) PRIMARY KEY,
+    user_id     BINARY(16) NOT NULL,
+    account_id  BINARY(16) NOT NULL,
+    name        VARCHAR(120) NOT NULL,
+    note        TEXT NULL,
+    archived    TINYINT(1) NOT NULL DEFAULT 0,
+
+    created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_portfolios_user (user_id),
+    KEY idx_portfolios_account (account_id),
+
+    CONSTRAINT fk_portfolios_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_portfolios_account
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- instruments de l'utilisateur (action, ETF, fonds…) identifiés par ticker et/ou ISIN
+-- les prix sont exprimés dans la devise de l'instrument
+CREATE TABLE instruments (
+    id              BINARY(16) PRIMARY KEY,
+    user_id         BINARY(16) NOT NULL,
+    name            VARCHAR(200) NOT NULL,
+    symbol          VARCHAR(32) NULL,
+    isin            CHAR(12) NULL,
+    instrument_type ENUM('stock','etf','fund','bond','crypto','other') NOT NULL DEFAULT 'stock',
+    currency_code   CHAR(3) NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_instruments_symbol (user_id, symbol),
+    UNIQUE KEY uq_instruments_isin (user_id, isin),
+
+    CONSTRAINT fk_instruments_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_instruments_currency
+        FOREIGN KEY (currency_code) REFERENCES currencies(code)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- cours relevés (un par jour) ; la valeur de marché d'une ligne utilise le dernier cours connu
+CREATE TABLE instrument_prices (
+    id            BINARY(16) PRIMARY KEY,
+    instrument_id BINARY(16) NOT NULL,
+    price_date    DATE NOT NULL,
+    price         DECIMAL(24,8) NOT NULL,
+    source        VARCHAR(32) NOT NULL DEFAULT 'manual',
+    created_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_instrument_prices (instrument_id, price_date),
+
+    CONSTRAINT chk_instrument_prices_price CHECK (price >= 0),
+
+    CONSTRAINT fk_instrument_prices_instrument
+        FOREIGN KEY (instrument_id) REFERENCES instruments(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- opérations d'un portefeuille, rejouées par date pour calculer les lignes (quantité, prix de revient moyen)
+-- buy/sell : quantity, price (devise de l'instrument), fee_minor
+-- dividend : amount_minor (devise de l'instrument)
+-- fee      : amount_minor (devise de l'instrument, ou du compte si frais de portefeuille sans instrument)
+-- split    : split_ratio = nouvelles parts par ancienne part
+CREATE TABLE investment_trades (
+    id            BINARY(16) PRIMARY KEY,
+    portfolio_id  BINARY(16) NOT NULL,
+    instrument_id BINARY(16) NULL,
+    trade_type    ENUM('buy','sell','fee','dividend','split') NOT NULL,
+    trade_date    DATE NOT NULL,
+
+    quantity      DECIMAL(24,8) NULL,
+    price         DECIMAL(24,8) NULL,
+    amount_minor  BIGINT NULL,
+    fee_minor     BIGINT NOT NULL DEFAULT 0,
+    split_ratio   DECIMAL(16,8) NULL,
+    note          TEXT NULL,
+
+    created_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_trades_portfolio_date (portfolio_id, trade_date),
+    KEY idx_trades_instrument (instrument_id),
+
+    CONSTRAINT chk_trades_fee CHECK (fee_minor >= 0),
+    CONSTRAINT chk_trades_instrument CHECK (trade_type = 'fee' OR instrument_id IS NOT NULL),
+
+    CONSTRAINT fk_trades_portfolio
+        FOREIGN KEY (portfolio_id) REFERENCES portfolios(id) ON DELETE CASCADE,
+    CONSTRAINT fk_trades_instrument
+        FOREIGN KEY (instrument_id) REFERENCES instruments(id) ON DELETE RESTRICT
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
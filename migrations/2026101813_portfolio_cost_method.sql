@@ -0,0 +1,8 @@
+-- -----------------------------
+-- PORTFOLIO COST METHOD (lots fiscaux)
+-- -----------------------------
+-- méthode d'appariement des ventes avec les lots d'achat pour les plus-values réalisées
+-- fifo : premiers achetés, premiers vendus ; lifo : derniers achetés ; average : prix moyen pondéré
+-- les lots ne sont pas stockés : ils sont reconstruits en rejouant les opérations du portefeuille
+ALTER TABLE portfolios
+    ADD COLUMN cost_method ENUM('fifo','lifo','average') NOT NULL DEFAULT 'fifo' AFTER name;
@@ -0,0 +1,38 @@
+-- -----------------------------
+-- PORTFOLIO ALLOCATION TARGETS (allocation cible)
+-- -----------------------------
+-- tag : libellé libre regroupant des instruments (région, secteur…) pour l'allocation par tag
+ALTER TABLE instruments
+    ADD COLUMN tag VARCHAR(50) NULL AFTER currency_code,
+    ADD KEY idx_instruments_tag (user_id, tag);
+
+-- poids cibles d'un portefeuille, tous du même type (classe d'actifs, instrument ou tag)
+-- l'ensemble est remplacé en bloc (proc_allocation_target_delete_by_portfolio puis création) ; la somme des poids vaut 100
+-- une seule des colonnes asset_class / instrument_id / tag est renseignée, selon target_kind
+CREATE TABLE portfolio_allocation_targets (
+    id              BINARY(16) PRIMARY KEY,
+    portfolio_id    BINARY(16) NOT NULL,
+
+    target_kind     ENUM('asset_class','instrument','tag') NOT NULL,
+    asset_class     ENUM('stock','etf','fund','bond','crypto','other') NULL,
+    instrument_id   BINARY(16) NULL,
+    tag             VARCHAR(50) NULL,
+    weight_percent  DECIMAL(5,2) NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_allocation_targets_portfolio (portfolio_id),
+
+    CONSTRAINT chk_allocation_targets_weight CHECK (weight_percent > 0 AND weight_percent <= 100),
+    CONSTRAINT chk_allocation_targets_key CHECK (
+        (target_kind = 'asset_class' AND asset_class IS NOT NULL AND instrument_id IS NULL AND tag IS NULL)
+        OR (target_kind = 'instrument' AND instrument_id IS NOT NULL AND asset_class IS NULL AND tag IS NULL)
+        OR (target_kind = 'tag' AND tag IS NOT NULL AND asset_class IS NULL AND instrument_id IS NULL)
+    ),
+
+    CONSTRAINT fk_allocation_targets_portfolio
+        FOREIGN KEY (portfolio_id) REFERENCES portfolios(id) ON DELETE CASCADE,
+    CONSTRAINT fk_allocation_targets_instrument
+        FOREIGN KEY (instrument_id) REFERENCES instruments(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
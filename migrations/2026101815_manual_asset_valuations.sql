@@ -0,0 +1,48 @@
+-- -----------------------------
+-- MANUAL ASSETS (biens sans cotation : immobilier, épargne retraite…)
+-- -----------------------------
+-- valeur suivie par relevés mensuels (snapshot mensuel) ; la devise est celle du bien
+-- account_id : compte éventuellement associé (ex. prêt immobilier de type 'debt')
+CREATE TABLE manual_assets (
+    id              BINARY(16) PRIMARY KEY,
+    user_id         BINARY(16) NOT NULL,
+    account_id      BINARY(16) NULL,
+    name            VARCHAR(120) NOT NULL,
+    asset_kind      ENUM('real_estate','pension','vehicle','collectible','other') NOT NULL DEFAULT 'other',
+    currency_code   CHAR(3) NOT NULL,
+    note            TEXT NULL,
+    archived        TINYINT(1) NOT NULL DEFAULT 0,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_manual_assets_user (user_id),
+
+    CONSTRAINT fk_manual_assets_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_manual_assets_account
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE SET NULL,
+    CONSTRAINT fk_manual_assets_currency
+        FOREIGN KEY (currency_code) REFERENCES currencies(code)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- une valeur par bien et par mois (valuation_month = 1er du mois) ; un nouveau relevé du même mois remplace le précédent
+-- sans relevé, un mois reprend la dernière valeur connue
+CREATE TABLE asset_valuations (
+    id              BINARY(16) PRIMARY KEY,
+    asset_id        BINARY(16) NOT NULL,
+    valuation_month DATE NOT NULL,
+    value_minor     BIGINT NOT NULL,
+    source          VARCHAR(32) NOT NULL DEFAULT 'manual',
+    note            VARCHAR(255) NULL,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_asset_valuations_month (asset_id, valuation_month),
+
+    CONSTRAINT chk_asset_valuations_month CHECK (DAYOFMONTH(valuation_month) = 1),
+
+    CONSTRAINT fk_asset_valuations_asset
+        FOREIGN KEY (asset_id) REFERENCES manual_assets(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,26 @@
+-- -----------------------------
+-- NET WORTH SNAPSHOTS (patrimoine net : actifs/passifs)
+-- -----------------------------
+-- une ligne par mois et par poste : types de compte, portefeuilles ('investments') et biens manuels ('assets')
+-- valeurs en devise de base à la fin du mois ; les comptes 'debt' sont des passifs (valeur négative)
+-- un nouveau relevé du mois remplace le précédent (proc_net_worth_snapshot_delete_by_month puis création)
+CREATE TABLE net_worth_snapshots (
+    id                 BINARY(16) PRIMARY KEY,
+    user_id            BINARY(16) NOT NULL,
+    snapshot_month     DATE NOT NULL,
+    kind               ENUM('checking','savings','cash','broker','debt','investments','assets') NOT NULL,
+    value_base_minor   BIGINT NOT NULL,
+    base_currency_code CHAR(3) NOT NULL,
+
+    created_at         TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at         TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_net_worth_snapshots_kind (user_id, snapshot_month, kind),
+
+    CONSTRAINT chk_net_worth_snapshots_month CHECK (DAYOFMONTH(snapshot_month) = 1),
+
+    CONSTRAINT fk_net_worth_snapshots_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_net_worth_snapshots_currency
+        FOREIGN KEY (base_currency_code) REFERENCES currencies(code)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,40 @@
+-- -----------------------------
+-- RECURRING TRANSACTIONS (charges fixes et revenus réguliers)
+-- -----------------------------
+-- modèle d'opération répétée (loyer, salaire, abonnement…) utilisé par la prévision de trésorerie ; aucune transaction n'est créée
+-- amount_minor : signé dans la devise du compte (dépense négative, revenu positif)
+-- échéances : start_date puis tous les 7/14 jours ou tous les 1/3/12 mois (jour ramené à la fin du mois si besoin), jusqu'à end_date
+CREATE TABLE recurring_transactions (
+    id            BINARY(16) PRIMARY KEY,
+    user_id       BINARY(16) NOT NULL,
+    account_id    BINARY(16) NOT NULL,
+    label         VARCHAR(120) NOT NULL,
+    amount_minor  BIGINT NOT NULL,
+
+    category_id   BINARY(16) NULL,
+    payee_id      BINARY(16) NULL,
+
+    frequency     ENUM('weekly','biweekly','monthly','quarterly','yearly') NOT NULL DEFAULT 'monthly',
+    start_date    DATE NOT NULL,
+    end_date      DATE NULL,
+    active        TINYINT(1) NOT NULL DEFAULT 1,
+
+    note          TEXT NULL,
+    created_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_recurring_tx_user (user_id, active),
+    KEY idx_recurring_tx_account (account_id),
+
+    CONSTRAINT chk_recurring_tx_amount CHECK (amount_minor <> 0),
+    CONSTRAINT chk_recurring_tx_dates CHECK (end_date IS NULL OR end_date >= start_date),
+
+    CONSTRAINT fk_recurring_tx_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_recurring_tx_account
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE,
+    CONSTRAINT fk_recurring_tx_category
+        FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE SET NULL,
+    CONSTRAINT fk_recurring_tx_payee
+        FOREIGN KEY (payee_id) REFERENCES payees(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
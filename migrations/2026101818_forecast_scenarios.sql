@@ -0,0 +1,57 @@
+-- -----------------------------
+-- FORECAST SCENARIOS (scénarios « et si » sur la prévision de trésorerie)
+-- -----------------------------
+-- un scénario est une liste de modifications appliquées en mémoire à la prévision, jamais aux données réelles
+CREATE TABLE forecast_scenarios (
+    id          BINARY(16) PRIMARY KEY,
+    user_id     BINARY(16) NOT NULL,
+    name        VARCHAR(120) NOT NULL,
+    note        TEXT NULL,
+
+    created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_forecast_scenarios_user (user_id),
+
+    CONSTRAINT fk_forecast_scenarios_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- modifications d'un scénario, appliquées dans l'ordre de position ; l'ensemble est remplacé en bloc
+-- (proc_scenario_overlay_delete_by_scenario puis création)
+-- action 'add' : nouvelle opération récurrente (account_id, amount_minor signé en devise du compte, frequency),
+--   nouvelle contribution mensuelle à l'objectif target_id, nouvel achat ponctuel ou financement du projet target_id
+--   (montants des contributions et achats positifs en devise de base, à partir de start_date)
+-- action 'remove' / 'scale' : target_id désigne l'opération récurrente, l'objectif, l'achat, le projet ou la catégorie
+--   (enveloppes et opérations récurrentes de la catégorie) ; scale_percent = 80 garde 80 % des montants
+-- pas de clé étrangère sur target_id : la cible peut être supprimée sans casser le scénario
+CREATE TABLE forecast_scenario_overlays (
+    id             BINARY(16) PRIMARY KEY,
+    scenario_id    BINARY(16) NOT NULL,
+    position       INT NOT NULL,
+
+    overlay_action ENUM('add','remove','scale') NOT NULL,
+    target_kind    ENUM('recurring','goal_contribution','purchase','project','category') NOT NULL,
+    target_id      BINARY(16) NULL,
+
+    account_id     BINARY(16) NULL,
+    label          VARCHAR(120) NULL,
+    amount_minor   BIGINT NULL,
+    frequency      ENUM('weekly','biweekly','monthly','quarterly','yearly') NULL,
+    start_date     DATE NULL,
+    end_date       DATE NULL,
+    scale_percent  DECIMAL(7,2) NULL,
+
+    created_at     TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at     TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    KEY idx_scenario_overlays_scenario (scenario_id, position),
+
+    CONSTRAINT chk_scenario_overlays_scale CHECK (scale_percent IS NULL OR scale_percent >= 0),
+    CONSTRAINT chk_scenario_overlays_dates CHECK (end_date IS NULL OR start_date IS NULL OR end_date >= start_date),
+
+    CONSTRAINT fk_scenario_overlays_scenario
+        FOREIGN KEY (scenario_id) REFERENCES forecast_scenarios(id) ON DELETE CASCADE,
+    CONSTRAINT fk_scenario_overlays_account
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
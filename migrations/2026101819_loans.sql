@@ -0,0 +1,57 @@
+-- -----------------------------
+-- LOANS (paramètres d'emprunt des comptes de dette)
+-- -----------------------------
+-- un prêt par compte de type 'debt' ; montants dans la devise du compte
+-- annual_rate : taux nominal annuel en fraction (0.035000 = 3,5 %), intérêts mensuels sur le capital restant dû
+-- échéances : term_months mensualités constantes, la première le mois suivant start_date au jour payment_day
+--   (ramené à la fin du mois si besoin)
+-- les remboursements réels sont les transactions positives du compte, rapprochées du tableau d'amortissement
+CREATE TABLE loans (
+    id              BINARY(16) PRIMARY KEY,
+    user_id         BINARY(16) NOT NULL,
+    account_id      BINARY(16) NOT NULL,
+    principal_minor BIGINT NOT NULL,
+    annual_rate     DECIMAL(9,6) NOT NULL,
+    rate_type       ENUM('fixed','variable') NOT NULL DEFAULT 'fixed',
+    term_months     INT NOT NULL,
+    start_date      DATE NOT NULL,
+    payment_day     TINYINT UNSIGNED NOT NULL,
+    note            TEXT NULL,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_loans_account (account_id),
+    KEY idx_loans_user (user_id),
+
+    CONSTRAINT chk_loans_principal CHECK (principal_minor > 0),
+    CONSTRAINT chk_loans_rate CHECK (annual_rate >= 0),
+    CONSTRAINT chk_loans_term CHECK (term_months > 0),
+    CONSTRAINT chk_loans_payment_day CHECK (payment_day BETWEEN 1 AND 31),
+
+    CONSTRAINT fk_loans_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_loans_account
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+-- révisions d'un prêt à taux variable : le taux s'applique aux échéances à partir de effective_month (1er du mois)
+-- et la mensualité est recalculée sur la durée restante ; l'ensemble est remplacé en bloc
+-- (proc_loan_rate_change_delete_by_loan puis création)
+CREATE TABLE loan_rate_changes (
+    id              BINARY(16) PRIMARY KEY,
+    loan_id         BINARY(16) NOT NULL,
+    effective_month DATE NOT NULL,
+    annual_rate     DECIMAL(9,6) NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+    UNIQUE KEY uq_loan_rate_changes_month (loan_id, effective_month),
+
+    CONSTRAINT chk_loan_rate_changes_month CHECK (DAYOFMONTH(effective_month) = 1),
+    CONSTRAINT chk_loan_rate_changes_rate CHECK (annual_rate >= 0),
+
+    CONSTRAINT fk_loan_rate_changes_loan
+        FOREIGN KEY (loan_id) REFERENCES loans(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::shared::db::mysql::FromSqlRow;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum AccountType {
+    Checking,
+    Savings,
+    Cash,
+    Broker,
+    Debt,
+}
+
+
+/// Account row with its balance on a day, read only.
+/// The procedures sum the signed transaction amounts up to the end of that day, in the account currency;
+/// a debt account balance is negative while money is owed. The balance of a broker account is its cash,
+/// the portfolios attached to it are valued from their trades.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AccountBalance {
+    pub id: Vec<u8>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub account_type: AccountType,
+    pub currency_code: String,
+    pub archived: bool,
+
+    pub balance_minor: i64,
+    /// Minor unit of the account currency
+    pub minor_unit: u8,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for AccountBalance {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            account_type: row.try_get(index_map["account_type"])?,
+            currency_code: row.try_get(index_map["currency_code"])?,
+            archived: row.try_get(index_map["archived"])?,
+            balance_minor: row.try_get(index_map["balance_minor"])?,
+            minor_unit: row.try_get(index_map["minor_unit"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
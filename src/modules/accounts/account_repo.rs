@@ -0,0 +1,51 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::accounts::account_model::AccountBalance;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait AccountRepositoryInterface {
+
+    /// Every account of the user, archived ones included, with its balance at the end of `as_of`
+    async fn get_balances_by_user(&self, user_id: Uuid, as_of: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<AccountBalance>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AccountRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AccountRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AccountBalance> for AccountRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AccountRepositoryInterface for AccountRepository {
+    async fn get_balances_by_user(&self, user_id: Uuid, as_of: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<AccountBalance>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(as_of),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_account_balance_by_user", params).await
+    }
+}
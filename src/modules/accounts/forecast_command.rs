@@ -0,0 +1,26 @@
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::accounts::forecast_dto::ForecastQuery;
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ForecastCommand {
+    pub user_id: Uuid,
+    pub months: Option<u32>,
+    pub spending_account_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ForecastCommand {
+    pub fn new(query: ForecastQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            months: query.months,
+            spending_account_id: query.spending_account_id,
+            auth_user,
+        }
+    }
+}
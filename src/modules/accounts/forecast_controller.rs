@@ -0,0 +1,47 @@
+use axum::{extract::{Query, State}, http::StatusCode, routing::get, Json, Router};
+
+use crate::modules::accounts::{
+    forecast_command::ForecastCommand,
+    forecast_dto::*,
+    forecast_service::{ForecastService, ForecastServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/forecast", get(get_forecast))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/forecast",
+    params(
+        ForecastQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Projected daily balances of the active accounts, with the periods below zero", body = ForecastResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid horizon or spending account, or missing fx rate"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Forecast"
+)]
+pub async fn get_forecast(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<ForecastQuery>,
+) -> Result<Json<ForecastResponse>, StatusCode> {
+    let command = ForecastCommand::new(query, auth_user);
+    let forecast_service = ForecastService::from(&state);
+
+    let forecast = forecast_service.forecast(command).await;
+    match forecast {
+        Ok(forecast) => Ok(Json(forecast)),
+        Err(e) => Err(error_status(&e))
+    }
+}
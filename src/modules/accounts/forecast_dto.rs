@@ -0,0 +1,116 @@
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    account_model::AccountType,
+    forecast_model::{ForecastDay, ForecastEvent, ForecastSource},
+};
+
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ForecastQuery {
+    /// Months projected after today, 3 by default and 24 at most
+    pub months: Option<u32>,
+    /// Account paying the purchases and the envelope spending, and the goal contributions without a source account.
+    /// The first checking account by default.
+    pub spending_account_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ForecastDayResponse {
+    pub date: NaiveDate,
+    /// End of day
+    pub balance_minor: i64,
+    pub inflow_minor: i64,
+    pub outflow_minor: i64,
+}
+
+impl From<&ForecastDay> for ForecastDayResponse {
+    fn from(day: &ForecastDay) -> Self {
+        Self {
+            date: day.date,
+            balance_minor: day.balance_minor,
+            inflow_minor: day.inflow_minor,
+            outflow_minor: day.outflow_minor,
+        }
+    }
+}
+
+/// Amounts in the account currency
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ForecastAccountResponse {
+    pub account_id: Uuid,
+    pub account_name: String,
+    pub account_type: AccountType,
+    pub currency_code: String,
+
+    /// Balance at the end of today
+    pub opening_balance_minor: i64,
+    pub closing_balance_minor: i64,
+    pub lowest_balance_minor: i64,
+    pub lowest_balance_on: Option<NaiveDate>,
+    /// None for debt accounts, which are expected to stay below zero
+    pub first_negative_on: Option<NaiveDate>,
+
+    pub days: Vec<ForecastDayResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ForecastEventResponse {
+    pub date: NaiveDate,
+    pub account_id: Uuid,
+    /// Signed in the account currency
+    pub amount_minor: i64,
+    pub source: ForecastSource,
+    pub label: String,
+}
+
+impl From<&ForecastEvent> for ForecastEventResponse {
+    fn from(event: &ForecastEvent) -> Self {
+        Self {
+            date: event.date,
+            account_id: event.account_id,
+            amount_minor: event.amount_minor,
+            source: event.source,
+            label: event.label.clone(),
+        }
+    }
+}
+
+/// Envelope spending of a month, spread evenly over its forecast days on the spending account
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ForecastEnvelopeMonthResponse {
+    pub month: NaiveDate,
+    pub budget_month: NaiveDate,
+    /// What is left to spend for the current month, the planned amount for the next ones
+    pub spending_base_minor: i64,
+    pub envelope_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ForecastNegativePeriodResponse {
+    pub account_id: Uuid,
+    pub account_name: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub lowest_balance_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ForecastResponse {
+    /// First projected day, tomorrow
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub base_currency_code: String,
+    pub spending_account_id: Uuid,
+
+    /// Active accounts
+    pub accounts: Vec<ForecastAccountResponse>,
+    /// Dated movements but the envelope spending, by date
+    pub events: Vec<ForecastEventResponse>,
+    pub envelope_months: Vec<ForecastEnvelopeMonthResponse>,
+    /// Periods where an account other than a debt one ends the day below zero, by start date
+    pub negative_periods: Vec<ForecastNegativePeriodResponse>,
+}
@@ -0,0 +1,116 @@
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+
+/// What a forecast movement comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ForecastSource {
+    Recurring,
+    GoalContribution,
+    Purchase,
+    Envelope,
+}
+
+
+/// Expected movement on an account, signed in the account currency
+#[derive(Debug, Clone)]
+pub struct ForecastEvent {
+    pub date: NaiveDate,
+    pub account_id: Uuid,
+    pub amount_minor: i64,
+    pub source: ForecastSource,
+    pub label: String,
+    /// Recurring transaction, goal or purchase the movement comes from
+    pub item_id: Option<Uuid>,
+    /// Category of a recurring transaction or an envelope, project of a purchase
+    pub group_id: Option<Uuid>,
+}
+
+impl ForecastEvent {
+    /// Same event, traced back to what it comes from
+    pub fn of_item(self, item_id: Option<Uuid>, group_id: Option<Uuid>) -> Self {
+        Self { item_id, group_id, ..self }
+    }
+}
+
+/// Projected end of day of an account
+#[derive(Debug, Clone, Copy)]
+pub struct ForecastDay {
+    pub date: NaiveDate,
+    pub balance_minor: i64,
+    pub inflow_minor: i64,
+    /// Positive when money goes out
+    pub outflow_minor: i64,
+}
+
+/// Consecutive days below zero
+#[derive(Debug, Clone, Copy)]
+pub struct NegativePeriod {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub lowest_balance_minor: i64,
+}
+
+
+/// Daily balances of an account from `from` to `to` included, starting from its balance at the end of the day before.
+/// Events dated before `from` are counted on `from`, later ones are ignored.
+pub fn project_account(account_id: Uuid, opening_minor: i64, events: &[ForecastEvent], from: NaiveDate, to: NaiveDate) -> Vec<ForecastDay> {
+    let mut days: Vec<ForecastDay> = Vec::new();
+    let mut date = from;
+    while date <= to {
+        days.push(ForecastDay { date, balance_minor: 0, inflow_minor: 0, outflow_minor: 0 });
+        date = date + Days::new(1);
+    }
+    if days.is_empty() {
+        return days;
+    }
+
+    for event in events.iter().filter(|event| event.account_id == account_id && event.date <= to) {
+        let index = (event.date.max(from) - from).num_days() as usize;
+        if event.amount_minor >= 0 {
+            days[index].inflow_minor += event.amount_minor;
+        } else {
+            days[index].outflow_minor -= event.amount_minor;
+        }
+    }
+
+    let mut balance = opening_minor;
+    for day in days.iter_mut() {
+        balance += day.inflow_minor - day.outflow_minor;
+        day.balance_minor = balance;
+    }
+    days
+}
+
+/// Runs of days ending below zero, in date order
+pub fn negative_periods(days: &[ForecastDay]) -> Vec<NegativePeriod> {
+    let mut periods: Vec<NegativePeriod> = Vec::new();
+    let mut previous: Option<NaiveDate> = None;
+    for day in days.iter().filter(|day| day.balance_minor < 0) {
+        match periods.last_mut() {
+            Some(period) if previous.is_some_and(|previous| previous + Days::new(1) == day.date) => {
+                period.to = day.date;
+                period.lowest_balance_minor = period.lowest_balance_minor.min(day.balance_minor);
+            },
+            _ => periods.push(NegativePeriod { from: day.date, to: day.date, lowest_balance_minor: day.balance_minor }),
+        }
+        previous = Some(day.date);
+    }
+    periods
+}
+
+/// `amount_minor` split evenly over the days from `from` to `to` included, the remainder going to the first days
+pub fn spread(amount_minor: i64, from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, i64)> {
+    if to < from {
+        return Vec::new();
+    }
+    let count = (to - from).num_days() + 1;
+    let (share, remainder) = (amount_minor / count, amount_minor % count);
+    (0..count)
+        .map(|n| (from + Days::new(n as u64), share + if n < remainder.abs() { remainder.signum() } else { 0 }))
+        .filter(|(_, amount)| *amount != 0)
+        .collect()
+}
@@ -0,0 +1,459 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    account_model::{AccountBalance, AccountType},
+    account_repo::{AccountRepository, AccountRepositoryInterface},
+    forecast_command::ForecastCommand,
+    forecast_dto::*,
+    forecast_model::{negative_periods, project_account, spread, ForecastEvent, ForecastSource},
+};
+use crate::modules::budgets::{
+    budget_model::BudgetEnvelope,
+    budget_repo::{BudgetEnvelopeRepository, BudgetEnvelopeRepositoryInterface, BudgetRepository, BudgetRepositoryInterface},
+};
+use crate::modules::currencies::{
+    currency_model::FxTable,
+    currency_repo::{CurrencyRepository, CurrencyRepositoryInterface, FxRateRepository, FxRateRepositoryInterface},
+};
+use crate::modules::goals::{
+    goal_model::{ContributionStatus, Goal, GoalContributionPlan},
+    goal_repo::{
+        GoalContributionPlanRepository, GoalContributionPlanRepositoryInterface, GoalContributionRepository,
+        GoalContributionRepositoryInterface, GoalRepository, GoalRepositoryInterface,
+    },
+};
+use crate::modules::projects::{
+    project_command::ProjectListByUserCommand,
+    project_model::ProjectStatus,
+    project_service::{ProjectService, ProjectServiceInterface},
+    purchase_model::PurchaseStatus,
+    purchase_repo::{PurchaseRepository, PurchaseRepositoryInterface},
+};
+use crate::modules::transactions::recurring_repo::{RecurringTransactionRepository, RecurringTransactionRepositoryInterface};
+use crate::modules::users::user::user_repo::{UserRepository, UserRepositoryInterface};
+use crate::shared::auth::jwt::AuthUser;
+use crate::shared::dates::{add_months, day_in_month, month_start, months_between};
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::bu;
+
+
+/// Months projected when none are asked for, and at most
+const DEFAULT_FORECAST_MONTHS: u32 = 3;
+const MAX_FORECAST_MONTHS: u32 = 24;
+
+#[async_trait]
+pub trait ForecastServiceInterface {
+
+    /// Daily balances of the active accounts from tomorrow to N months ahead
+    async fn forecast(&self, command: ForecastCommand) -> Result<ForecastResponse, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct ForecastService {
+    user_repo: UserRepository,
+    currency_repo: CurrencyRepository,
+    fx_rate_repo: FxRateRepository,
+    account_repo: AccountRepository,
+    recurring_repo: RecurringTransactionRepository,
+    goal_repo: GoalRepository,
+    plan_repo: GoalContributionPlanRepository,
+    contribution_repo: GoalContributionRepository,
+    project_service: ProjectService,
+    purchase_repo: PurchaseRepository,
+    budget_repo: BudgetRepository,
+    envelope_repo: BudgetEnvelopeRepository,
+}
+
+impl From<&AppState> for ForecastService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            user_repo: UserRepository::from(app_state),
+            currency_repo: CurrencyRepository::from(app_state),
+            fx_rate_repo: FxRateRepository::from(app_state),
+            account_repo: AccountRepository::from(app_state),
+            recurring_repo: RecurringTransactionRepository::from(app_state),
+            goal_repo: GoalRepository::from(app_state),
+            plan_repo: GoalContributionPlanRepository::from(app_state),
+            contribution_repo: GoalContributionRepository::from(app_state),
+            project_service: ProjectService::from(app_state),
+            purchase_repo: PurchaseRepository::from(app_state),
+            budget_repo: BudgetRepository::from(app_state),
+            envelope_repo: BudgetEnvelopeRepository::from(app_state),
+        }
+    }
+}
+
+/// Opening balances and expected movements of a forecast, before the daily projection
+#[derive(Clone)]
+pub struct ForecastPlan {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub fx: FxTable,
+    pub base_scale: Decimal,
+    pub spending_account_id: Uuid,
+    /// Active accounts with their balance at the end of today
+    pub accounts: Vec<AccountBalance>,
+    pub events: Vec<ForecastEvent>,
+    pub envelope_months: Vec<ForecastEnvelopeMonthResponse>,
+}
+
+impl ForecastPlan {
+    pub fn account(&self, account_id: Uuid) -> Option<&AccountBalance> {
+        self.accounts.iter().find(|account| bu(&account.id) == account_id)
+    }
+
+    /// Event of `amount_base_minor` converted to the account currency with the rate of the day
+    pub fn base_event(&self, account: &AccountBalance, amount_base_minor: i64, date: NaiveDate, source: ForecastSource, label: &str) -> Result<ForecastEvent, Error> {
+        let Some(rate) = self.fx.rate_on(&account.currency_code, date) else {
+            return Err(AppError::BadRequest(format!("missing fx rate {}/{}", self.fx.base_code, account.currency_code)).into());
+        };
+        let amount = Decimal::from(amount_base_minor) / self.base_scale * rate * scale_factor(account.minor_unit);
+        Ok(ForecastEvent {
+            date,
+            account_id: bu(&account.id),
+            amount_minor: amount.round().to_i64().unwrap_or(0),
+            source,
+            label: label.to_string(),
+            item_id: None,
+            group_id: None,
+        })
+    }
+
+    /// Contribution to the goal `goal_id`: outflow from one account and inflow into the other, a missing side being left out
+    pub fn push_contribution(&mut self, goal_id: Uuid, from_account_id: Option<Uuid>, to_account_id: Option<Uuid>, amount_base_minor: i64, date: NaiveDate, label: &str) -> Result<(), Error> {
+        if from_account_id.is_some() && from_account_id == to_account_id {
+            return Ok(());
+        }
+        let mut events = Vec::new();
+        if let Some(account) = from_account_id.and_then(|account_id| self.account(account_id)) {
+            events.push(self.base_event(account, -amount_base_minor, date, ForecastSource::GoalContribution, label)?.of_item(Some(goal_id), None));
+        }
+        if let Some(account) = to_account_id.and_then(|account_id| self.account(account_id)) {
+            events.push(self.base_event(account, amount_base_minor, date, ForecastSource::GoalContribution, label)?.of_item(Some(goal_id), None));
+        }
+        self.events.extend(events);
+        Ok(())
+    }
+
+    /// Spending of `amount_base_minor` spread over the days from `from` to `to` on the spending account
+    fn push_spread(&mut self, amount_base_minor: i64, from: NaiveDate, to: NaiveDate, source: ForecastSource, label: &str, group_id: Option<Uuid>) -> Result<(), Error> {
+        let Some(account) = self.account(self.spending_account_id) else {
+            return Ok(());
+        };
+        let mut events = Vec::new();
+        for (date, amount) in spread(amount_base_minor, from, to) {
+            events.push(self.base_event(account, -amount, date, source, label)?.of_item(None, group_id));
+        }
+        self.events.extend(events);
+        Ok(())
+    }
+}
+
+impl ForecastService {
+    /// The asked account, else the first checking account, else the first one that is not a debt
+    fn spending_account(accounts: &[AccountBalance], spending_account_id: Option<Uuid>) -> Result<Uuid, Error> {
+        if let Some(spending_account_id) = spending_account_id {
+            return match accounts.iter().any(|account| bu(&account.id) == spending_account_id) {
+                true => Ok(spending_account_id),
+                false => Err(AppError::BadRequest("spending account must be an active account".into()).into()),
+            };
+        }
+        accounts.iter()
+            .find(|account| account.account_type == AccountType::Checking)
+            .or_else(|| accounts.iter().find(|account| account.account_type != AccountType::Debt))
+            .map(|account| bu(&account.id))
+            .ok_or_else(|| AppError::BadRequest("no account to spend from".into()).into())
+    }
+
+    /// Months starting from the current one up to the one containing `to`
+    fn months_of(today: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        (0..=months_between(today, to)).map(|n| add_months(month_start(today), n)).collect()
+    }
+
+    async fn push_recurring(&self, plan: &mut ForecastPlan, user_id: Uuid, meta_user: Option<Uuid>) -> Result<HashSet<Vec<u8>>, Error> {
+        let recurrings = match self.recurring_repo.get_by_user(user_id, meta_user).await {
+            Ok(recurrings) => recurrings,
+            Err(_) => return Err(Error::msg("Error getting recurring transactions")),
+        };
+
+        let mut categories = HashSet::new();
+        for recurring in recurrings.iter().filter(|recurring| recurring.active) {
+            if let Some(category_id) = &recurring.category_id {
+                categories.insert(category_id.clone());
+            }
+            if plan.account(bu(&recurring.account_id)).is_none() {
+                continue;
+            }
+            for date in recurring.occurrences(plan.from, plan.to) {
+                plan.events.push(ForecastEvent {
+                    date,
+                    account_id: bu(&recurring.account_id),
+                    amount_minor: recurring.amount_minor,
+                    source: ForecastSource::Recurring,
+                    label: recurring.label.clone(),
+                    item_id: recurring.id.as_deref().map(bu),
+                    group_id: recurring.category_id.as_deref().map(bu),
+                });
+            }
+        }
+        Ok(categories)
+    }
+
+    /// Planned contributions from the current month, overdue ones landing on the first day, then the months
+    /// of the active plans not generated yet
+    async fn push_goal_contributions(&self, plan: &mut ForecastPlan, user_id: Uuid, today: NaiveDate, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let goals: HashMap<Vec<u8>, Goal> = match self.goal_repo.get_by_user(user_id, meta_user).await {
+            Ok(goals) => goals.into_iter().filter_map(|goal| goal.id.clone().map(|goal_id| (goal_id, goal))).collect(),
+            Err(_) => return Err(Error::msg("Error getting goals")),
+        };
+        let contribution_plans: HashMap<Vec<u8>, GoalContributionPlan> = match self.plan_repo.get_active_by_user(user_id, meta_user).await {
+            Ok(contribution_plans) => contribution_plans.into_iter().filter_map(|contribution_plan| contribution_plan.id.clone().map(|plan_id| (plan_id, contribution_plan))).collect(),
+            Err(_) => return Err(Error::msg("Error getting contribution plans")),
+        };
+        let accounts_of = |goal: &Goal, contribution_plan: Option<&GoalContributionPlan>, spending_account_id: Uuid| {
+            let from_account_id = contribution_plan.and_then(|contribution_plan| contribution_plan.from_account_id.as_deref()).map(bu).unwrap_or(spending_account_id);
+            let to_account_id = contribution_plan.and_then(|contribution_plan| contribution_plan.to_account_id.as_deref())
+                .or(goal.linked_account_id.as_deref())
+                .map(bu);
+            (Some(from_account_id), to_account_id)
+        };
+
+        let mut generated: HashSet<(Vec<u8>, NaiveDate)> = HashSet::new();
+        for (goal_id, goal) in &goals {
+            let contributions = match self.contribution_repo.get_by_goal(bu(goal_id), Some(month_start(today)), Some(plan.to), meta_user).await {
+                Ok(contributions) => contributions,
+                Err(_) => return Err(Error::msg("Error getting contributions")),
+            };
+            for contribution in contributions {
+                if let Some(plan_id) = &contribution.plan_id {
+                    generated.insert((plan_id.clone(), month_start(contribution.due_date)));
+                }
+                if contribution.status != ContributionStatus::Planned || contribution.due_date > plan.to {
+                    continue;
+                }
+                let contribution_plan = contribution.plan_id.as_ref().and_then(|plan_id| contribution_plans.get(plan_id));
+                let (from_account_id, to_account_id) = accounts_of(goal, contribution_plan, plan.spending_account_id);
+                plan.push_contribution(bu(goal_id), from_account_id, to_account_id, contribution.planned_base_minor, contribution.due_date.max(plan.from), &goal.name)?;
+            }
+        }
+
+        for (plan_id, contribution_plan) in &contribution_plans {
+            let Some(goal) = goals.get(&contribution_plan.goal_id) else {
+                continue;
+            };
+            for month in Self::months_of(today, plan.to) {
+                if !contribution_plan.covers(month) || generated.contains(&(plan_id.clone(), month)) {
+                    continue;
+                }
+                let due_date = day_in_month(month, contribution_plan.day_of_month as u32);
+                if due_date < plan.from || due_date > plan.to {
+                    continue;
+                }
+                let (from_account_id, to_account_id) = accounts_of(goal, Some(contribution_plan), plan.spending_account_id);
+                plan.push_contribution(bu(&contribution_plan.goal_id), from_account_id, to_account_id, contribution_plan.amount_base_minor, due_date, &goal.name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Approved purchases of the planned and active projects with a planned date, overdue ones landing on the first day
+    async fn push_purchases(&self, plan: &mut ForecastPlan, auth_user: &AuthUser) -> Result<(), Error> {
+        let projects = self.project_service.get_by_user(ProjectListByUserCommand {
+            user_id: auth_user.user_id,
+            status: None,
+            priority: None,
+            person_id: None,
+            location_id: None,
+            auth_user: auth_user.clone(),
+        }).await?;
+
+        for project in projects.iter().filter(|project| matches!(project.project_status, ProjectStatus::Planned | ProjectStatus::Active)) {
+            let purchases = match self.purchase_repo.get_by_project(project.project_id, Some(auth_user.user_id)).await {
+                Ok(purchases) => purchases,
+                Err(_) => return Err(Error::msg("Error getting purchases")),
+            };
+            for purchase in purchases.iter().filter(|purchase| purchase.status == PurchaseStatus::Approved) {
+                let Some(planned_date) = purchase.planned_date.filter(|planned_date| *planned_date <= plan.to) else {
+                    continue;
+                };
+                let label = format!("{}: {}", project.project_name, purchase.title);
+                if let Some(account) = plan.account(plan.spending_account_id) {
+                    let event = plan.base_event(account, -purchase.estimated_price_base_minor, planned_date.max(plan.from), ForecastSource::Purchase, &label)?
+                        .of_item(purchase.id.as_deref().map(bu), Some(project.project_id));
+                    plan.events.push(event);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Envelope spending of each month from the household budget of that month, or the latest one before.
+    /// The current month only counts what is left to spend. Sinking funds, whose money stays on the account,
+    /// and categories with a recurring transaction, already projected, are left out.
+    async fn push_envelopes(&self, plan: &mut ForecastPlan, user_id: Uuid, today: NaiveDate, recurring_categories: &HashSet<Vec<u8>>, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let mut budgets = match self.budget_repo.get_by_user(user_id, meta_user).await {
+            Ok(budgets) => budgets,
+            Err(_) => return Err(Error::msg("Error getting budgets")),
+        };
+        budgets.retain(|budget| budget.person_id.is_none());
+        budgets.sort_by_key(|budget| budget.month);
+
+        let mut envelopes_by_budget: HashMap<Vec<u8>, Vec<BudgetEnvelope>> = HashMap::new();
+        for month in Self::months_of(today, plan.to) {
+            let Some(budget) = budgets.iter().rev().find(|budget| budget.month <= month) else {
+                continue;
+            };
+            let budget_id = budget.id.clone().unwrap_or_default();
+            if !envelopes_by_budget.contains_key(&budget_id) {
+                let envelopes = match self.envelope_repo.get_by_budget(bu(&budget_id), meta_user).await {
+                    Ok(envelopes) => envelopes,
+                    Err(_) => return Err(Error::msg("Error getting envelopes")),
+                };
+                envelopes_by_budget.insert(budget_id.clone(), envelopes);
+            }
+            let envelopes: Vec<&BudgetEnvelope> = envelopes_by_budget[&budget_id].iter()
+                .filter(|envelope| !envelope.is_sinking_fund() && !recurring_categories.contains(&envelope.category_id))
+                .collect();
+
+            let month_end = add_months(month, 1) - Days::new(1);
+            let (from, to) = (month.max(plan.from), month_end.min(plan.to));
+            if from > to {
+                continue;
+            }
+            let (days_left, days) = ((to - from).num_days() + 1, (month_end - month).num_days() + 1);
+            let mut spending = 0;
+            for envelope in &envelopes {
+                let planned = envelope.planned_base_minor.max(0);
+                let envelope_spending = if month > month_start(today) {
+                    planned
+                } else if budget.month == month {
+                    (planned - envelope.spent_base_minor).max(0)
+                } else {
+                    planned * days_left / days
+                };
+                plan.push_spread(envelope_spending, from, to, ForecastSource::Envelope, "Envelopes", Some(bu(&envelope.category_id)))?;
+                spending += envelope_spending;
+            }
+            plan.envelope_months.push(ForecastEnvelopeMonthResponse {
+                month,
+                budget_month: budget.month,
+                spending_base_minor: spending,
+                envelope_count: envelopes.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Opening balances and every expected movement from tomorrow to `months` ahead
+    pub async fn plan(&self, months: Option<u32>, spending_account_id: Option<Uuid>, auth_user: &AuthUser) -> Result<ForecastPlan, Error> {
+        let months = months.unwrap_or(DEFAULT_FORECAST_MONTHS);
+        if months == 0 || months > MAX_FORECAST_MONTHS {
+            return Err(AppError::BadRequest(format!("months must be between 1 and {}", MAX_FORECAST_MONTHS)).into());
+        }
+        let user_id = auth_user.user_id;
+        let meta_user = Some(auth_user.user_id);
+        let today = Utc::now().date_naive();
+
+        let base_code = match self.user_repo.get(user_id, meta_user).await {
+            Ok(Some(user)) => user.base_currency_code,
+            Ok(None) => return Err(Error::msg("User not found")),
+            Err(_) => return Err(Error::msg("Error getting user")),
+        };
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let fx_rates = match self.fx_rate_repo.get_by_base_code(base_code.clone(), meta_user).await {
+            Ok(fx_rates) => fx_rates,
+            Err(_) => return Err(Error::msg("Error getting fx rates")),
+        };
+        let accounts: Vec<AccountBalance> = match self.account_repo.get_balances_by_user(user_id, today, meta_user).await {
+            Ok(balances) => balances.into_iter().filter(|account| !account.archived).collect(),
+            Err(_) => return Err(Error::msg("Error getting account balances")),
+        };
+
+        let mut plan = ForecastPlan {
+            from: today + Days::new(1),
+            to: add_months(today, months as i32),
+            fx: FxTable::new(&base_code, &fx_rates),
+            base_scale: scale_factor(minor_units.get(&base_code).copied().unwrap_or(2)),
+            spending_account_id: Self::spending_account(&accounts, spending_account_id)?,
+            accounts,
+            events: Vec::new(),
+            envelope_months: Vec::new(),
+        };
+
+        let recurring_categories = self.push_recurring(&mut plan, user_id, meta_user).await?;
+        self.push_goal_contributions(&mut plan, user_id, today, meta_user).await?;
+        self.push_purchases(&mut plan, auth_user).await?;
+        self.push_envelopes(&mut plan, user_id, today, &recurring_categories, meta_user).await?;
+        plan.events.sort_by_key(|event| event.date);
+        Ok(plan)
+    }
+
+    /// Daily projection of every account of the plan
+    pub fn project(plan: &ForecastPlan) -> ForecastResponse {
+        let mut accounts = Vec::new();
+        let mut periods = Vec::new();
+        for account in &plan.accounts {
+            let account_id = bu(&account.id);
+            let days = project_account(account_id, account.balance_minor, &plan.events, plan.from, plan.to);
+            let lowest = days.iter().min_by_key(|day| day.balance_minor);
+            let alerted = account.account_type != AccountType::Debt;
+
+            if alerted {
+                periods.extend(negative_periods(&days).into_iter().map(|period| ForecastNegativePeriodResponse {
+                    account_id,
+                    account_name: account.name.clone(),
+                    from: period.from,
+                    to: period.to,
+                    lowest_balance_minor: period.lowest_balance_minor,
+                }));
+            }
+            accounts.push(ForecastAccountResponse {
+                account_id,
+                account_name: account.name.clone(),
+                account_type: account.account_type,
+                currency_code: account.currency_code.clone(),
+                opening_balance_minor: account.balance_minor,
+                closing_balance_minor: days.last().map_or(account.balance_minor, |day| day.balance_minor),
+                lowest_balance_minor: lowest.map_or(account.balance_minor, |day| day.balance_minor),
+                lowest_balance_on: lowest.map(|day| day.date),
+                first_negative_on: days.iter().find(|day| alerted && day.balance_minor < 0).map(|day| day.date),
+                days: days.iter().map(ForecastDayResponse::from).collect(),
+            });
+        }
+        periods.sort_by_key(|period| period.from);
+
+        ForecastResponse {
+            from: plan.from,
+            to: plan.to,
+            base_currency_code: plan.fx.base_code.clone(),
+            spending_account_id: plan.spending_account_id,
+            accounts,
+            events: plan.events.iter()
+                .filter(|event| event.source != ForecastSource::Envelope)
+                .map(ForecastEventResponse::from)
+                .collect(),
+            envelope_months: plan.envelope_months.clone(),
+            negative_periods: periods,
+        }
+    }
+}
+
+#[async_trait]
+impl ForecastServiceInterface for ForecastService {
+    async fn forecast(&self, command: ForecastCommand) -> Result<ForecastResponse, Error> {
+        let plan = self.plan(command.months, command.spending_account_id, &command.auth_user).await?;
+        Ok(Self::project(&plan))
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::accounts::{
+    loan_dto::{LoanCreateRequest, LoanExtraPaymentRequest, LoanOneOffPaymentRequest, LoanRateChangeRequest, LoanUpdateRequest},
+    loan_model::{LoanExtraPaymentEffect, LoanRateType},
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoanGetCommand {
+    pub loan_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl LoanGetCommand {
+    pub fn new(loan_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { loan_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoanCreateCommand {
+    pub user_id: Uuid,
+    pub account_id: Uuid,
+    pub loan_principal_minor: i64,
+    pub loan_annual_rate: Decimal,
+    pub loan_rate_type: LoanRateType,
+    pub loan_term_months: i32,
+    pub loan_start_date: NaiveDate,
+    pub loan_payment_day: u8,
+    pub loan_note: Option<String>,
+    pub loan_rate_changes: Vec<LoanRateChangeRequest>,
+
+    pub auth_user: AuthUser,
+}
+
+impl LoanCreateCommand {
+    pub fn new(request: LoanCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            account_id: request.account_id,
+            loan_principal_minor: request.loan_principal_minor,
+            loan_annual_rate: request.loan_annual_rate,
+            loan_rate_type: request.loan_rate_type.unwrap_or(LoanRateType::Fixed),
+            loan_term_months: request.loan_term_months,
+            loan_start_date: request.loan_start_date,
+            loan_payment_day: request.loan_payment_day,
+            loan_note: request.loan_note,
+            loan_rate_changes: request.loan_rate_changes.unwrap_or_default(),
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoanUpdateCommand {
+    pub loan_id: Uuid,
+    pub loan_principal_minor: i64,
+    pub loan_annual_rate: Decimal,
+    pub loan_rate_type: LoanRateType,
+    pub loan_term_months: i32,
+    pub loan_start_date: NaiveDate,
+    pub loan_payment_day: u8,
+    pub loan_note: Option<String>,
+    pub loan_rate_changes: Vec<LoanRateChangeRequest>,
+
+    pub auth_user: AuthUser,
+}
+
+impl LoanUpdateCommand {
+    pub fn new(loan_id: Uuid, request: LoanUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            loan_id,
+            loan_principal_minor: request.loan_principal_minor,
+            loan_annual_rate: request.loan_annual_rate,
+            loan_rate_type: request.loan_rate_type.unwrap_or(LoanRateType::Fixed),
+            loan_term_months: request.loan_term_months,
+            loan_start_date: request.loan_start_date,
+            loan_payment_day: request.loan_payment_day,
+            loan_note: request.loan_note,
+            loan_rate_changes: request.loan_rate_changes.unwrap_or_default(),
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoanDeleteCommand {
+    pub loan_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl LoanDeleteCommand {
+    pub fn new(loan_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { loan_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoanListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl LoanListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoanExtraPaymentCommand {
+    pub loan_id: Uuid,
+    pub monthly_extra_minor: i64,
+    pub monthly_extra_from: Option<NaiveDate>,
+    pub one_off_payments: Vec<LoanOneOffPaymentRequest>,
+    pub extra_effect: LoanExtraPaymentEffect,
+
+    pub auth_user: AuthUser,
+}
+
+impl LoanExtraPaymentCommand {
+    pub fn new(loan_id: Uuid, request: LoanExtraPaymentRequest, auth_user: AuthUser) -> Self {
+        Self {
+            loan_id,
+            monthly_extra_minor: request.monthly_extra_minor.unwrap_or(0),
+            monthly_extra_from: request.monthly_extra_from,
+            one_off_payments: request.one_off_payments.unwrap_or_default(),
+            extra_effect: request.extra_effect.unwrap_or(LoanExtraPaymentEffect::ReduceTerm),
+            auth_user,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    loan_command::*,
+    loan_dto::*,
+    loan_service::{LoanService, LoanServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/loans", get(get_loans).post(post_loan))
+        .route("/loans/{loan_id}", get(get_loan).put(put_loan).delete(delete_loan))
+        .route("/loans/{loan_id}/schedule", get(get_loan_schedule))
+        .route("/loans/{loan_id}/extra-payments", post(post_loan_extra_payments))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/loans",
+    responses(
+        (status = StatusCode::OK, description = "Loans of current user", body = Vec<LoanResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn get_loans(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<LoanResponse>>, StatusCode> {
+    let command = LoanListByUserCommand::new(auth_user.user_id, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let loans = loan_service.get_by_user(command).await;
+    match loans {
+        Ok(loans) => Ok(Json(loans)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/accounts/loans",
+    responses(
+        (status = StatusCode::OK, description = "Loan created successfully", body = LoanResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid loan, account not a debt account or already with a loan"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn post_loan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(loan_create_request): Json<LoanCreateRequest>
+) -> Result<Json<LoanResponse>, StatusCode> {
+    let command = LoanCreateCommand::new(loan_create_request, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let loan = loan_service.create(command).await;
+    match loan {
+        Ok(loan) => Ok(Json(loan)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/loans/{loan_id}",
+    params(
+        ("loan_id", description = "loan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Loan with its rate changes", body = LoanResponse),
+        (status = StatusCode::NOT_FOUND, description = "Loan not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn get_loan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(loan_id): Path<Uuid>,
+) -> Result<Json<LoanResponse>, StatusCode> {
+    let command = LoanGetCommand::new(loan_id, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let loan = loan_service.get_by_id(command).await;
+    match loan {
+        Ok(loan) => {
+            match loan {
+                Some(loan) => Ok(Json(loan)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/accounts/loans/{loan_id}",
+    params(
+        ("loan_id", description = "loan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Loan updated successfully, its rate changes replaced", body = LoanResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid loan"),
+        (status = StatusCode::NOT_FOUND, description = "Loan not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn put_loan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(loan_id): Path<Uuid>,
+    Json(loan_update_request): Json<LoanUpdateRequest>
+) -> Result<Json<LoanResponse>, StatusCode> {
+    let command = LoanUpdateCommand::new(loan_id, loan_update_request, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let loan = loan_service.update(command).await;
+    match loan {
+        Ok(loan) => {
+            match loan {
+                Some(loan) => Ok(Json(loan)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/accounts/loans/{loan_id}",
+    params(
+        ("loan_id", description = "loan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Loan deleted successfully, the account and its transactions are kept"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn delete_loan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(loan_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = LoanDeleteCommand::new(loan_id, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let response = loan_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/loans/{loan_id}/schedule",
+    params(
+        ("loan_id", description = "loan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Amortization schedule reconciled with the payments, remaining interest", body = LoanScheduleResponse),
+        (status = StatusCode::NOT_FOUND, description = "Loan not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn get_loan_schedule(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(loan_id): Path<Uuid>,
+) -> Result<Json<LoanScheduleResponse>, StatusCode> {
+    let command = LoanGetCommand::new(loan_id, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let schedule = loan_service.schedule(command).await;
+    match schedule {
+        Ok(schedule) => {
+            match schedule {
+                Some(schedule) => Ok(Json(schedule)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/accounts/loans/{loan_id}/extra-payments",
+    params(
+        ("loan_id", description = "loan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Payoff with and without the extra payments, nothing is saved", body = LoanExtraPaymentResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Extra payments not positive or not dated after today"),
+        (status = StatusCode::NOT_FOUND, description = "Loan not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Loan"
+)]
+pub async fn post_loan_extra_payments(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(loan_id): Path<Uuid>,
+    Json(loan_extra_payment_request): Json<LoanExtraPaymentRequest>
+) -> Result<Json<LoanExtraPaymentResponse>, StatusCode> {
+    let command = LoanExtraPaymentCommand::new(loan_id, loan_extra_payment_request, auth_user);
+    let loan_service = LoanService::from(&state);
+
+    let extra_payments = loan_service.extra_payments(command).await;
+    match extra_payments {
+        Ok(extra_payments) => {
+            match extra_payments {
+                Some(extra_payments) => Ok(Json(extra_payments)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
@@ -0,0 +1,240 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::accounts::loan_model::{
+    annuity, Loan, LoanExtraPaymentEffect, LoanInstallment, LoanInstallmentStatus, LoanRateChange, LoanRateType
+};
+use crate::shared::utils::bu;
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanRateChangeResponse {
+    pub rate_change_id: Uuid,
+    pub effective_month: NaiveDate,
+    pub annual_rate: Decimal,
+}
+
+impl From<&LoanRateChange> for LoanRateChangeResponse {
+    fn from(change: &LoanRateChange) -> Self {
+        Self {
+            rate_change_id: bu(change.id.clone().unwrap().as_slice()),
+            effective_month: change.effective_month,
+            annual_rate: change.annual_rate,
+        }
+    }
+}
+
+/// Amounts in the currency of the debt account
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanResponse {
+    pub loan_id: Uuid,
+    pub user_id: Uuid,
+    pub account_id: Uuid,
+    pub loan_principal_minor: i64,
+    pub loan_annual_rate: Decimal,
+    pub loan_rate_type: LoanRateType,
+    pub loan_term_months: i32,
+    pub loan_start_date: NaiveDate,
+    pub loan_payment_day: u8,
+    pub loan_note: Option<String>,
+    /// Payment at the rate of the first installment
+    pub loan_monthly_payment_minor: i64,
+    pub loan_first_payment_date: NaiveDate,
+    pub loan_last_payment_date: NaiveDate,
+    /// Ordered by month, variable loans only
+    pub loan_rate_changes: Vec<LoanRateChangeResponse>,
+
+    pub loan_created_at: Option<DateTime<Utc>>,
+    pub loan_updated_at: Option<DateTime<Utc>>,
+}
+
+impl LoanResponse {
+    pub fn of(loan: &Loan, changes: &[LoanRateChange]) -> Self {
+        let first_payment_date = loan.due_date(1);
+        Self {
+            loan_id: bu(loan.id.clone().unwrap().as_slice()),
+            user_id: bu(loan.user_id.as_slice()),
+            account_id: bu(loan.account_id.as_slice()),
+            loan_principal_minor: loan.principal_minor,
+            loan_annual_rate: loan.annual_rate,
+            loan_rate_type: loan.rate_type,
+            loan_term_months: loan.term_months,
+            loan_start_date: loan.start_date,
+            loan_payment_day: loan.payment_day,
+            loan_note: loan.note.clone(),
+            loan_monthly_payment_minor: annuity(loan.principal_minor, loan.rate_on(changes, first_payment_date), loan.term_months),
+            loan_first_payment_date: first_payment_date,
+            loan_last_payment_date: loan.due_date(loan.term_months),
+            loan_rate_changes: changes.iter().map(LoanRateChangeResponse::from).collect(),
+            loan_created_at: loan.created_at,
+            loan_updated_at: loan.updated_at,
+        }
+    }
+}
+
+/// Yearly rate as a fraction from `effective_month` (any day of the month)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanRateChangeRequest {
+    pub effective_month: NaiveDate,
+    pub annual_rate: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LoanCreateRequest {
+    /// Debt account of the loan, one loan per account
+    pub account_id: Uuid,
+    pub loan_principal_minor: i64,
+    /// Nominal yearly rate as a fraction (0.035 = 3.5%)
+    pub loan_annual_rate: Decimal,
+    /// Fixed by default
+    pub loan_rate_type: Option<LoanRateType>,
+    pub loan_term_months: i32,
+    /// Day the money was lent; the first payment is due the month after
+    pub loan_start_date: NaiveDate,
+    /// Day of month of the payments, the last day of shorter months
+    pub loan_payment_day: u8,
+    pub loan_note: Option<String>,
+    /// Variable loans only
+    pub loan_rate_changes: Option<Vec<LoanRateChangeRequest>>,
+}
+
+/// Replaces the parameters and the whole set of rate changes
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LoanUpdateRequest {
+    pub loan_principal_minor: i64,
+    pub loan_annual_rate: Decimal,
+    pub loan_rate_type: Option<LoanRateType>,
+    pub loan_term_months: i32,
+    pub loan_start_date: NaiveDate,
+    pub loan_payment_day: u8,
+    pub loan_note: Option<String>,
+    pub loan_rate_changes: Option<Vec<LoanRateChangeRequest>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanInstallmentResponse {
+    pub installment_number: i32,
+    pub due_date: NaiveDate,
+    pub annual_rate: Decimal,
+    /// Interest plus principal, extra payment excluded
+    pub payment_minor: i64,
+    pub interest_minor: i64,
+    pub principal_minor: i64,
+    pub extra_minor: i64,
+    /// Outstanding after the installment
+    pub balance_minor: i64,
+    /// Payments counted for the installment, None in projections
+    pub paid_minor: Option<i64>,
+    pub status: Option<LoanInstallmentStatus>,
+}
+
+impl From<&LoanInstallment> for LoanInstallmentResponse {
+    fn from(installment: &LoanInstallment) -> Self {
+        Self {
+            installment_number: installment.number,
+            due_date: installment.due_date,
+            annual_rate: installment.annual_rate,
+            payment_minor: installment.payment_minor,
+            interest_minor: installment.interest_minor,
+            principal_minor: installment.principal_minor,
+            extra_minor: installment.extra_minor,
+            balance_minor: installment.balance_minor,
+            paid_minor: None,
+            status: None,
+        }
+    }
+}
+
+/// Contractual schedule reconciled with the payments made to the account.
+/// A payment counts for an installment up to 7 days after its due date.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanScheduleResponse {
+    pub loan_id: Uuid,
+    pub account_id: Uuid,
+    pub account_name: String,
+    pub currency_code: String,
+    pub as_of: NaiveDate,
+
+    pub scheduled_total_interest_minor: i64,
+    /// Outstanding after the installments due so far, if every one was paid on time
+    pub scheduled_balance_minor: i64,
+    /// Contractual interest of the installments not due yet
+    pub scheduled_remaining_interest_minor: i64,
+
+    pub paid_minor: i64,
+    /// Interest charged so far on the actual outstanding
+    pub interest_charged_minor: i64,
+    /// Principal and interest charged, minus the payments
+    pub actual_balance_minor: i64,
+    /// Due but not paid once the grace days are over
+    pub arrears_minor: i64,
+    /// Interest left from the actual outstanding, keeping the contractual payment
+    pub remaining_interest_minor: i64,
+    pub next_due_date: Option<NaiveDate>,
+    /// None once repaid
+    pub projected_payoff_date: Option<NaiveDate>,
+
+    pub installments: Vec<LoanInstallmentResponse>,
+}
+
+/// Paid on top of the installments, from the day after today
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanOneOffPaymentRequest {
+    pub payment_date: NaiveDate,
+    pub amount_minor: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LoanExtraPaymentRequest {
+    /// Added to every installment
+    pub monthly_extra_minor: Option<i64>,
+    /// First installment due on or after this day gets the monthly extra, the next one by default
+    pub monthly_extra_from: Option<NaiveDate>,
+    pub one_off_payments: Option<Vec<LoanOneOffPaymentRequest>>,
+    /// Reduce term by default
+    pub extra_effect: Option<LoanExtraPaymentEffect>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanPayoffResponse {
+    pub installment_count: i32,
+    pub payoff_date: Option<NaiveDate>,
+    /// Payment of the next installment
+    pub monthly_payment_minor: i64,
+    pub total_interest_minor: i64,
+    /// Installments and extra payments
+    pub total_paid_minor: i64,
+}
+
+impl LoanPayoffResponse {
+    pub fn of(installments: &[LoanInstallment]) -> Self {
+        Self {
+            installment_count: installments.len() as i32,
+            payoff_date: installments.last().map(|installment| installment.due_date),
+            monthly_payment_minor: installments.first().map_or(0, |installment| installment.payment_minor),
+            total_interest_minor: installments.iter().map(|installment| installment.interest_minor).sum(),
+            total_paid_minor: installments.iter().map(|installment| installment.payment_minor + installment.extra_minor).sum(),
+        }
+    }
+}
+
+/// Both projections start from the actual outstanding of the loan
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoanExtraPaymentResponse {
+    pub loan_id: Uuid,
+    pub currency_code: String,
+    pub as_of: NaiveDate,
+    pub balance_minor: i64,
+    pub extra_effect: LoanExtraPaymentEffect,
+
+    pub baseline: LoanPayoffResponse,
+    pub with_extra: LoanPayoffResponse,
+    pub interest_saved_minor: i64,
+    pub installments_saved: i32,
+
+    /// Projection with the extra payments
+    pub installments: Vec<LoanInstallmentResponse>,
+}
@@ -0,0 +1,488 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::accounts::loan_command::LoanCreateCommand;
+use crate::shared::dates::{add_months, day_in_month, month_start};
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::ub;
+
+
+/// Days after a due date during which a payment still counts for that installment
+pub const PAYMENT_GRACE_DAYS: u64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum LoanRateType {
+    Fixed,
+    /// Follows the rate changes of the loan
+    Variable,
+}
+
+impl LoanRateType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoanRateType::Fixed => "fixed",
+            LoanRateType::Variable => "variable",
+        }
+    }
+}
+
+/// What an extra payment shortens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoanExtraPaymentEffect {
+    /// Same payment, the loan ends earlier
+    ReduceTerm,
+    /// Same end, the payment is recomputed after each extra payment
+    ReducePayment,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoanInstallmentStatus {
+    /// Everything due up to this installment has been paid
+    Paid,
+    /// Some money came in for it, not enough to cover everything due so far
+    Partial,
+    /// Nothing came in for it once the grace days were over
+    Missed,
+    /// Past its due date, still within the grace days
+    Due,
+    Upcoming,
+}
+
+
+/// Loan parameters of a debt account, amounts in the account currency.
+/// Payments are monthly and constant, the first one the month after `start_date` on `payment_day`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Loan {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub account_id: Vec<u8>,
+    pub principal_minor: i64,
+    /// Nominal yearly rate as a fraction (0.035 = 3.5%)
+    pub annual_rate: Decimal,
+    pub rate_type: LoanRateType,
+    pub term_months: i32,
+    pub start_date: NaiveDate,
+    pub payment_day: u8,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Loan {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            account_id: row.try_get(index_map["account_id"])?,
+            principal_minor: row.try_get(index_map["principal_minor"])?,
+            annual_rate: row.try_get(index_map["annual_rate"])?,
+            rate_type: row.try_get(index_map["rate_type"])?,
+            term_months: row.try_get(index_map["term_months"])?,
+            start_date: row.try_get(index_map["start_date"])?,
+            payment_day: row.try_get(index_map["payment_day"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<&LoanCreateCommand> for Loan {
+    fn from(command: &LoanCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            account_id: ub(command.account_id),
+            principal_minor: command.loan_principal_minor,
+            annual_rate: command.loan_annual_rate,
+            rate_type: command.loan_rate_type,
+            term_months: command.loan_term_months,
+            start_date: command.loan_start_date,
+            payment_day: command.loan_payment_day,
+            note: command.loan_note.clone(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl Loan {
+    /// Due date of installment `number`, counted from 1
+    pub fn due_date(&self, number: i32) -> NaiveDate {
+        day_in_month(add_months(month_start(self.start_date), number), self.payment_day as u32)
+    }
+
+    /// Rate of the installment due on `due_date`: the latest change in effect for a variable loan
+    pub fn rate_on(&self, changes: &[LoanRateChange], due_date: NaiveDate) -> Decimal {
+        if self.rate_type == LoanRateType::Fixed {
+            return self.annual_rate;
+        }
+        changes.iter()
+            .filter(|change| change.effective_month <= due_date)
+            .max_by_key(|change| change.effective_month)
+            .map_or(self.annual_rate, |change| change.annual_rate)
+    }
+
+    /// Payments dated in this window count for installment `number`
+    fn payment_window(&self, number: i32) -> (NaiveDate, NaiveDate) {
+        let from = if number == 1 {
+            self.start_date
+        } else {
+            self.due_date(number - 1) + Days::new(PAYMENT_GRACE_DAYS + 1)
+        };
+        (from, self.due_date(number) + Days::new(PAYMENT_GRACE_DAYS))
+    }
+}
+
+
+/// New rate of a variable loan from `effective_month` (first day of the month)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LoanRateChange {
+    pub id: Option<Vec<u8>>,
+
+    pub loan_id: Vec<u8>,
+    pub effective_month: NaiveDate,
+    pub annual_rate: Decimal,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for LoanRateChange {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            loan_id: row.try_get(index_map["loan_id"])?,
+            effective_month: row.try_get(index_map["effective_month"])?,
+            annual_rate: row.try_get(index_map["annual_rate"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl LoanRateChange {
+    pub fn of_loan(loan_id: Uuid, effective_month: NaiveDate, annual_rate: Decimal) -> Self {
+        Self {
+            id: None,
+            loan_id: ub(loan_id),
+            effective_month: month_start(effective_month),
+            annual_rate,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Transaction paying money into the loan account, read only.
+/// A debt account balance is negative while money is owed, so payments are the positive amounts.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LoanPayment {
+    pub transaction_id: Vec<u8>,
+    pub paid_on: NaiveDate,
+    pub amount_minor: i64,
+}
+
+impl FromSqlRow for LoanPayment {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            transaction_id: row.try_get(index_map["transaction_id"])?,
+            paid_on: row.try_get(index_map["paid_on"])?,
+            amount_minor: row.try_get(index_map["amount_minor"])?,
+        })
+    }
+}
+
+
+/// One line of an amortization schedule; `payment_minor` is interest plus principal, extra payment excluded
+#[derive(Debug, Clone, Copy)]
+pub struct LoanInstallment {
+    pub number: i32,
+    pub due_date: NaiveDate,
+    pub annual_rate: Decimal,
+    pub payment_minor: i64,
+    pub interest_minor: i64,
+    pub principal_minor: i64,
+    pub extra_minor: i64,
+    /// Outstanding after the installment and the extra payment
+    pub balance_minor: i64,
+}
+
+/// Where an amortization starts from
+#[derive(Debug, Clone, Copy)]
+pub struct LoanPosition {
+    /// Next installment to pay
+    pub number: i32,
+    /// Outstanding before that installment
+    pub balance_minor: i64,
+    /// Payment kept until the rate changes; the annuity of the balance over the remaining installments when None
+    pub payment_minor: Option<i64>,
+}
+
+/// Extra principal repaid with the first installment due on or after `date`
+#[derive(Debug, Clone, Copy)]
+pub struct LoanExtraPayment {
+    pub date: NaiveDate,
+    pub amount_minor: i64,
+}
+
+/// Interest of one month on `balance_minor`
+pub fn monthly_interest(balance_minor: i64, annual_rate: Decimal) -> i64 {
+    (Decimal::from(balance_minor) * annual_rate / Decimal::from(12)).round().to_i64().unwrap_or(0)
+}
+
+/// Constant payment repaying `balance_minor` in `installments` months
+pub fn annuity(balance_minor: i64, annual_rate: Decimal, installments: i32) -> i64 {
+    let rate = (annual_rate / Decimal::from(12)).to_f64().unwrap_or(0.0);
+    let payment = if rate.abs() < f64::EPSILON {
+        balance_minor as f64 / installments as f64
+    } else {
+        balance_minor as f64 * rate / (1.0 - (1.0 + rate).powi(-installments))
+    };
+    payment.round() as i64
+}
+
+/// Installments from `position` until the balance is repaid, the last one of the term settling what is left.
+/// The payment is recomputed over the remaining installments whenever the rate changes and,
+/// with `ReducePayment`, after each extra payment.
+pub fn amortize(loan: &Loan, changes: &[LoanRateChange], position: LoanPosition, extras: &[LoanExtraPayment], effect: LoanExtraPaymentEffect) -> Vec<LoanInstallment> {
+    let mut installments = Vec::new();
+    let mut balance = position.balance_minor;
+    let mut payment = position.payment_minor.unwrap_or(0);
+    let mut rate = position.payment_minor.map(|_| loan.rate_on(changes, loan.due_date(position.number)));
+    let mut previous_due = if position.number <= 1 { loan.start_date } else { loan.due_date(position.number - 1) };
+
+    for number in position.number.max(1)..=loan.term_months {
+        if balance <= 0 {
+            break;
+        }
+        let due_date = loan.due_date(number);
+        let annual_rate = loan.rate_on(changes, due_date);
+        if rate != Some(annual_rate) {
+            payment = annuity(balance, annual_rate, loan.term_months - number + 1);
+            rate = Some(annual_rate);
+        }
+
+        let interest = monthly_interest(balance, annual_rate);
+        let principal = if number == loan.term_months { balance } else { (payment - interest).clamp(0, balance) };
+        balance -= principal;
+
+        let extra = extras.iter()
+            .filter(|extra| previous_due < extra.date && extra.date <= due_date)
+            .map(|extra| extra.amount_minor)
+            .sum::<i64>()
+            .min(balance);
+        balance -= extra;
+        if extra > 0 && effect == LoanExtraPaymentEffect::ReducePayment {
+            rate = None;
+        }
+
+        installments.push(LoanInstallment {
+            number,
+            due_date,
+            annual_rate,
+            payment_minor: interest + principal,
+            interest_minor: interest,
+            principal_minor: principal,
+            extra_minor: extra,
+            balance_minor: balance,
+        });
+        previous_due = due_date;
+    }
+    installments
+}
+
+
+/// Actual payments replayed against a schedule up to a day
+#[derive(Debug, Clone)]
+pub struct LoanReconciliation {
+    /// Paid and status of each installment of the schedule, in the same order
+    pub installments: Vec<(i64, LoanInstallmentStatus)>,
+    pub paid_minor: i64,
+    /// Interest charged on the actual outstanding so far
+    pub interest_minor: i64,
+    /// Actual outstanding: principal plus interest charged, minus payments
+    pub balance_minor: i64,
+    /// Scheduled but not paid once the grace days are over
+    pub arrears_minor: i64,
+    /// First installment not due yet
+    pub next_number: i32,
+}
+
+/// Each payment counts for the installment whose window holds it, from the day after the grace days of the
+/// previous installment to the end of its own grace days. Interest of an installment is charged on the actual
+/// outstanding after the payments of the previous one.
+pub fn reconcile(loan: &Loan, changes: &[LoanRateChange], schedule: &[LoanInstallment], payments: &[LoanPayment], today: NaiveDate) -> LoanReconciliation {
+    let paid_in = |from: NaiveDate, to: NaiveDate| -> i64 {
+        payments.iter()
+            .filter(|payment| from <= payment.paid_on && payment.paid_on <= to.min(today))
+            .map(|payment| payment.amount_minor)
+            .sum()
+    };
+
+    let mut reconciliation = LoanReconciliation {
+        installments: Vec::with_capacity(schedule.len()),
+        paid_minor: payments.iter().filter(|payment| payment.paid_on <= today).map(|payment| payment.amount_minor).sum(),
+        interest_minor: 0,
+        balance_minor: loan.principal_minor,
+        arrears_minor: 0,
+        next_number: loan.term_months + 1,
+    };
+    let mut scheduled_total = 0;
+    let mut paid_total = 0;
+
+    for installment in schedule {
+        let (from, to) = loan.payment_window(installment.number);
+        let paid = paid_in(from, to);
+        if installment.due_date > today {
+            // Paid ahead of its due date
+            reconciliation.balance_minor -= paid;
+            reconciliation.next_number = reconciliation.next_number.min(installment.number);
+            reconciliation.installments.push((paid, LoanInstallmentStatus::Upcoming));
+            continue;
+        }
+
+        let interest = monthly_interest(reconciliation.balance_minor.max(0), loan.rate_on(changes, installment.due_date));
+        reconciliation.interest_minor += interest;
+        reconciliation.balance_minor += interest - paid;
+
+        scheduled_total += installment.payment_minor;
+        paid_total += paid;
+        let status = if paid_total >= scheduled_total {
+            LoanInstallmentStatus::Paid
+        } else if to > today {
+            LoanInstallmentStatus::Due
+        } else if paid > 0 {
+            LoanInstallmentStatus::Partial
+        } else {
+            LoanInstallmentStatus::Missed
+        };
+        if to <= today {
+            reconciliation.arrears_minor = (scheduled_total - paid_total).max(0);
+        }
+        reconciliation.installments.push((paid, status));
+    }
+
+    // Payments made after the window of the last installment
+    if let Some(last) = schedule.last() {
+        reconciliation.balance_minor -= paid_in(loan.payment_window(last.number).1 + Days::new(1), today);
+    }
+    reconciliation.balance_minor = reconciliation.balance_minor.max(0);
+    reconciliation
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn loan(principal_minor: i64, annual_rate: Decimal, term_months: i32, payment_day: u8) -> Loan {
+        Loan {
+            id: None,
+            user_id: vec![],
+            account_id: vec![],
+            principal_minor,
+            annual_rate,
+            rate_type: LoanRateType::Fixed,
+            term_months,
+            start_date: d(2024, 1, 15),
+            payment_day,
+            note: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn schedule(loan: &Loan, extras: &[LoanExtraPayment], effect: LoanExtraPaymentEffect) -> Vec<LoanInstallment> {
+        let position = LoanPosition { number: 1, balance_minor: loan.principal_minor, payment_minor: None };
+        amortize(loan, &[], position, extras, effect)
+    }
+
+    #[test]
+    fn zero_rate_annuity_splits_the_principal() {
+        assert_eq!(annuity(120_000, Decimal::ZERO, 12), 10_000);
+
+        let loan = loan(100_000, Decimal::ZERO, 12, 5);
+        let installments = schedule(&loan, &[], LoanExtraPaymentEffect::ReduceTerm);
+
+        assert_eq!(installments.len(), 12);
+        assert!(installments.iter().all(|installment| installment.interest_minor == 0));
+        assert!(installments[..11].iter().all(|installment| installment.payment_minor == 8_333));
+        assert_eq!(installments[11].payment_minor, 100_000 - 11 * 8_333);
+        assert_eq!(installments[11].balance_minor, 0);
+    }
+
+    #[test]
+    fn last_installment_settles_the_balance() {
+        let loan = loan(10_000_000, Decimal::new(36, 3), 240, 31);
+        let installments = schedule(&loan, &[], LoanExtraPaymentEffect::ReduceTerm);
+
+        assert_eq!(installments.len(), 240);
+        assert_eq!(installments.last().unwrap().balance_minor, 0);
+        assert_eq!(installments.iter().map(|installment| installment.principal_minor).sum::<i64>(), loan.principal_minor);
+        // rounding of the constant payment only shows on the last installment
+        let payment = installments[0].payment_minor;
+        assert!(installments[..239].iter().all(|installment| installment.payment_minor == payment));
+        assert!((installments[239].payment_minor - payment).abs() < 240);
+    }
+
+    #[test]
+    fn late_payment_counts_only_inside_the_grace_days() {
+        let loan = loan(1_200_000, Decimal::new(36, 3), 12, 5);
+        let installments = schedule(&loan, &[], LoanExtraPaymentEffect::ReduceTerm);
+        let payment = installments[0].payment_minor;
+        assert_eq!(installments[0].due_date, d(2024, 2, 5));
+        let paid_on = |paid_on: NaiveDate| [LoanPayment { transaction_id: vec![], paid_on, amount_minor: payment }];
+
+        let inside = reconcile(&loan, &[], &installments, &paid_on(d(2024, 2, 12)), d(2024, 2, 20));
+        assert_eq!(inside.installments[0], (payment, LoanInstallmentStatus::Paid));
+        assert_eq!(inside.arrears_minor, 0);
+
+        let outside = reconcile(&loan, &[], &installments, &paid_on(d(2024, 2, 13)), d(2024, 2, 20));
+        assert_eq!(outside.installments[0], (0, LoanInstallmentStatus::Missed));
+        assert_eq!(outside.installments[1], (payment, LoanInstallmentStatus::Upcoming));
+        assert_eq!(outside.arrears_minor, payment);
+        assert_eq!(outside.next_number, 2);
+    }
+
+    #[test]
+    fn extra_payment_reduces_the_term_or_the_payment() {
+        let loan = loan(10_000_000, Decimal::new(36, 3), 240, 31);
+        let extras = [LoanExtraPayment { date: d(2025, 1, 15), amount_minor: 1_000_000 }];
+        let base = schedule(&loan, &[], LoanExtraPaymentEffect::ReduceTerm);
+        let reduce_term = schedule(&loan, &extras, LoanExtraPaymentEffect::ReduceTerm);
+        let reduce_payment = schedule(&loan, &extras, LoanExtraPaymentEffect::ReducePayment);
+
+        assert_eq!(reduce_term[11].extra_minor, 1_000_000);
+        assert_eq!(reduce_payment[11].extra_minor, 1_000_000);
+
+        // same payment, paid off earlier
+        assert!(reduce_term.len() < base.len());
+        assert_eq!(reduce_term[12].payment_minor, base[12].payment_minor);
+
+        // same term, lower payment
+        assert_eq!(reduce_payment.len(), base.len());
+        assert!(reduce_payment[12].payment_minor < base[12].payment_minor);
+
+        let interest = |installments: &[LoanInstallment]| installments.iter().map(|installment| installment.interest_minor).sum::<i64>();
+        assert!(interest(&reduce_term) < interest(&reduce_payment));
+        assert!(interest(&reduce_payment) < interest(&base));
+    }
+}
@@ -0,0 +1,227 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::accounts::loan_model::{Loan, LoanPayment, LoanRateChange};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait LoanRepositoryInterface {
+
+    async fn get(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Loan>, Error>;
+
+    async fn get_by_account(&self, account_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Loan>, Error>;
+
+    async fn create(&self, loan: Loan, meta_user: Option<Uuid>) -> Result<Loan, Error>;
+
+    /// The account of a loan never changes
+    async fn update(&self, loan: Loan, meta_user: Option<Uuid>) -> Result<Option<Loan>, Error>;
+
+    /// Removes the rate changes with the loan
+    async fn delete(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by start date
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Loan>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct LoanRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for LoanRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Loan> for LoanRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl LoanRepositoryInterface for LoanRepository {
+    async fn get(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Loan>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(loan_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_loan_get_by_id", params).await
+    }
+
+    async fn get_by_account(&self, account_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Loan>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(account_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_loan_get_by_account", params).await
+    }
+
+    async fn create(&self, loan: Loan, meta_user: Option<Uuid>) -> Result<Loan, Error> {
+        let params = vec![
+            MySqlParam::from(loan.user_id),
+            MySqlParam::from(loan.account_id),
+            MySqlParam::from(loan.principal_minor),
+            MySqlParam::from(loan.annual_rate),
+            MySqlParam::from(loan.rate_type.as_str()),
+            MySqlParam::from(loan.term_months),
+            MySqlParam::from(loan.start_date),
+            MySqlParam::from(loan.payment_day),
+            MySqlParam::from(loan.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_loan_create", params).await
+    }
+
+    async fn update(&self, loan: Loan, meta_user: Option<Uuid>) -> Result<Option<Loan>, Error> {
+        let params = vec![
+            MySqlParam::from(loan.id),
+            MySqlParam::from(loan.principal_minor),
+            MySqlParam::from(loan.annual_rate),
+            MySqlParam::from(loan.rate_type.as_str()),
+            MySqlParam::from(loan.term_months),
+            MySqlParam::from(loan.start_date),
+            MySqlParam::from(loan.payment_day),
+            MySqlParam::from(loan.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_loan_update", params).await
+    }
+
+    async fn delete(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(loan_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_loan_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Loan>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_loan_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait LoanRateChangeRepositoryInterface {
+
+    async fn create(&self, change: LoanRateChange, meta_user: Option<Uuid>) -> Result<LoanRateChange, Error>;
+
+    /// Removes every rate change of the loan before a new set is created
+    async fn delete_by_loan(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by month
+    async fn get_by_loan(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<LoanRateChange>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct LoanRateChangeRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for LoanRateChangeRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<LoanRateChange> for LoanRateChangeRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl LoanRateChangeRepositoryInterface for LoanRateChangeRepository {
+    async fn create(&self, change: LoanRateChange, meta_user: Option<Uuid>) -> Result<LoanRateChange, Error> {
+        let params = vec![
+            MySqlParam::from(change.loan_id),
+            MySqlParam::from(change.effective_month),
+            MySqlParam::from(change.annual_rate),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_loan_rate_change_create", params).await
+    }
+
+    async fn delete_by_loan(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(loan_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_loan_rate_change_delete_by_loan", params).await
+    }
+
+    async fn get_by_loan(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<LoanRateChange>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(loan_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_loan_rate_change_by_loan", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait LoanPaymentRepositoryInterface {
+
+    /// Positive transactions of the account from `from` to `to` included, ordered by date
+    async fn get_by_account(&self, account_id: Uuid, from: NaiveDate, to: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<LoanPayment>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct LoanPaymentRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for LoanPaymentRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<LoanPayment> for LoanPaymentRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl LoanPaymentRepositoryInterface for LoanPaymentRepository {
+    async fn get_by_account(&self, account_id: Uuid, from: NaiveDate, to: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<LoanPayment>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(account_id)),
+            MySqlParam::from(from),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_loan_payments_by_account", params).await
+    }
+}
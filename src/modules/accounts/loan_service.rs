@@ -0,0 +1,355 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    account_model::{AccountBalance, AccountType},
+    account_repo::{AccountRepository, AccountRepositoryInterface},
+    loan_command::*,
+    loan_dto::*,
+    loan_model::{
+        amortize, reconcile, Loan, LoanExtraPayment, LoanExtraPaymentEffect, LoanInstallment, LoanPosition,
+        LoanRateChange, LoanRateType, LoanReconciliation
+    },
+    loan_repo::{
+        LoanPaymentRepository, LoanPaymentRepositoryInterface, LoanRateChangeRepository,
+        LoanRateChangeRepositoryInterface, LoanRepository, LoanRepositoryInterface
+    },
+};
+use crate::shared::dates::month_start;
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::bu;
+
+
+/// Longest loan term we amortize (50 years)
+const MAX_LOAN_TERM_MONTHS: i32 = 600;
+
+#[async_trait]
+pub trait LoanServiceInterface {
+
+    async fn get_by_id(&self, command: LoanGetCommand) -> Result<Option<LoanResponse>, Error>;
+
+    async fn create(&self, command: LoanCreateCommand) -> Result<LoanResponse, Error>;
+
+    async fn update(&self, command: LoanUpdateCommand) -> Result<Option<LoanResponse>, Error>;
+
+    async fn delete(&self, command: LoanDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: LoanListByUserCommand) -> Result<Vec<LoanResponse>, Error>;
+
+    /// Amortization schedule reconciled with the payments made so far
+    async fn schedule(&self, command: LoanGetCommand) -> Result<Option<LoanScheduleResponse>, Error>;
+
+    /// Payoff with and without extra payments, from the actual outstanding. Nothing is saved.
+    async fn extra_payments(&self, command: LoanExtraPaymentCommand) -> Result<Option<LoanExtraPaymentResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct LoanService {
+    loan_repo: LoanRepository,
+    rate_change_repo: LoanRateChangeRepository,
+    payment_repo: LoanPaymentRepository,
+    account_repo: AccountRepository,
+}
+
+impl From<&AppState> for LoanService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            loan_repo: LoanRepository::from(app_state),
+            rate_change_repo: LoanRateChangeRepository::from(app_state),
+            payment_repo: LoanPaymentRepository::from(app_state),
+            account_repo: AccountRepository::from(app_state),
+        }
+    }
+}
+
+/// Loan with its contractual schedule and the payments replayed against it
+struct LoanState {
+    loan: Loan,
+    changes: Vec<LoanRateChange>,
+    account: AccountBalance,
+    schedule: Vec<LoanInstallment>,
+    reconciliation: LoanReconciliation,
+}
+
+impl LoanState {
+    /// Next installment from the actual outstanding, keeping the contractual payment
+    fn position(&self) -> LoanPosition {
+        let number = self.reconciliation.next_number;
+        LoanPosition {
+            number,
+            balance_minor: self.reconciliation.balance_minor,
+            payment_minor: self.schedule.iter().find(|installment| installment.number == number).map(|installment| installment.payment_minor),
+        }
+    }
+}
+
+impl LoanService {
+    fn validate(
+        principal_minor: i64,
+        annual_rate: Decimal,
+        rate_type: LoanRateType,
+        term_months: i32,
+        payment_day: u8,
+        rate_changes: &[LoanRateChangeRequest],
+    ) -> Result<(), Error> {
+        let message = if principal_minor <= 0 {
+            "loan principal must be positive"
+        } else if annual_rate < Decimal::ZERO || rate_changes.iter().any(|change| change.annual_rate < Decimal::ZERO) {
+            "loan rate cannot be negative"
+        } else if term_months <= 0 || term_months > MAX_LOAN_TERM_MONTHS {
+            "loan term must be between 1 and 600 months"
+        } else if !(1..=31).contains(&payment_day) {
+            "payment day must be between 1 and 31"
+        } else if rate_type == LoanRateType::Fixed && !rate_changes.is_empty() {
+            "only a variable loan has rate changes"
+        } else if rate_changes.iter().map(|change| month_start(change.effective_month)).collect::<HashSet<_>>().len() != rate_changes.len() {
+            "a month has more than one rate change"
+        } else {
+            return Ok(());
+        };
+        Err(AppError::BadRequest(message.into()).into())
+    }
+
+    async fn rate_changes(&self, loan_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<LoanRateChange>, Error> {
+        match self.rate_change_repo.get_by_loan(loan_id, meta_user).await {
+            Ok(changes) => Ok(changes),
+            Err(_) => Err(Error::msg("Error getting loan rate changes")),
+        }
+    }
+
+    /// Replaces the rate changes of the loan
+    async fn set_rate_changes(&self, loan_id: Uuid, requests: &[LoanRateChangeRequest], meta_user: Option<Uuid>) -> Result<Vec<LoanRateChange>, Error> {
+        if self.rate_change_repo.delete_by_loan(loan_id, meta_user).await.is_err() {
+            return Err(Error::msg("Error deleting loan rate changes"));
+        }
+        let mut changes = Vec::new();
+        for request in requests {
+            match self.rate_change_repo.create(LoanRateChange::of_loan(loan_id, request.effective_month, request.annual_rate), meta_user).await {
+                Ok(change) => changes.push(change),
+                Err(_) => return Err(Error::msg("Error creating loan rate change")),
+            }
+        }
+        changes.sort_by_key(|change| change.effective_month);
+        Ok(changes)
+    }
+
+    async fn account(&self, user_id: Uuid, account_id: Uuid, today: NaiveDate) -> Result<Option<AccountBalance>, Error> {
+        match self.account_repo.get_balances_by_user(user_id, today, Some(user_id)).await {
+            Ok(accounts) => Ok(accounts.into_iter().find(|account| bu(account.id.as_slice()) == account_id)),
+            Err(_) => Err(Error::msg("Error getting account balances")),
+        }
+    }
+
+    async fn state(&self, loan_id: Uuid, user_id: Uuid, today: NaiveDate) -> Result<Option<LoanState>, Error> {
+        let meta_user = Some(user_id);
+        let loan = match self.loan_repo.get(loan_id, meta_user).await {
+            Ok(Some(loan)) => loan,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting loan")),
+        };
+        let account_id = bu(loan.account_id.as_slice());
+        let Some(account) = self.account(user_id, account_id, today).await? else {
+            return Ok(None);
+        };
+        let changes = self.rate_changes(loan_id, meta_user).await?;
+        let payments = match self.payment_repo.get_by_account(account_id, loan.start_date, today, meta_user).await {
+            Ok(payments) => payments,
+            Err(_) => return Err(Error::msg("Error getting loan payments")),
+        };
+
+        let start = LoanPosition { number: 1, balance_minor: loan.principal_minor, payment_minor: None };
+        let schedule = amortize(&loan, &changes, start, &[], LoanExtraPaymentEffect::ReduceTerm);
+        let reconciliation = reconcile(&loan, &changes, &schedule, &payments, today);
+        Ok(Some(LoanState { loan, changes, account, schedule, reconciliation }))
+    }
+}
+
+#[async_trait]
+impl LoanServiceInterface for LoanService {
+    async fn get_by_id(&self, command: LoanGetCommand) -> Result<Option<LoanResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let loan = match self.loan_repo.get(command.loan_id, meta_user).await {
+            Ok(Some(loan)) => loan,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting loan")),
+        };
+        let changes = self.rate_changes(command.loan_id, meta_user).await?;
+        Ok(Some(LoanResponse::of(&loan, &changes)))
+    }
+
+    async fn create(&self, command: LoanCreateCommand) -> Result<LoanResponse, Error> {
+        Self::validate(
+            command.loan_principal_minor,
+            command.loan_annual_rate,
+            command.loan_rate_type,
+            command.loan_term_months,
+            command.loan_payment_day,
+            &command.loan_rate_changes,
+        )?;
+        let meta_user = Some(command.auth_user.user_id);
+
+        let today = Utc::now().date_naive();
+        match self.account(command.user_id, command.account_id, today).await? {
+            Some(account) if account.account_type == AccountType::Debt => {}
+            _ => return Err(AppError::BadRequest("a loan needs a debt account of the user".into()).into()),
+        }
+        match self.loan_repo.get_by_account(command.account_id, meta_user).await {
+            Ok(None) => {}
+            Ok(Some(_)) => return Err(AppError::BadRequest("the account already has a loan".into()).into()),
+            Err(_) => return Err(Error::msg("Error getting loan")),
+        }
+
+        let loan = match self.loan_repo.create(Loan::from(&command), meta_user).await {
+            Ok(loan) => loan,
+            Err(_) => return Err(Error::msg("Error creating loan")),
+        };
+        let changes = self.set_rate_changes(bu(loan.id.as_deref().unwrap()), &command.loan_rate_changes, meta_user).await?;
+        Ok(LoanResponse::of(&loan, &changes))
+    }
+
+    async fn update(&self, command: LoanUpdateCommand) -> Result<Option<LoanResponse>, Error> {
+        Self::validate(
+            command.loan_principal_minor,
+            command.loan_annual_rate,
+            command.loan_rate_type,
+            command.loan_term_months,
+            command.loan_payment_day,
+            &command.loan_rate_changes,
+        )?;
+        let meta_user = Some(command.auth_user.user_id);
+
+        let mut loan = match self.loan_repo.get(command.loan_id, meta_user).await {
+            Ok(Some(loan)) => loan,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting loan")),
+        };
+        loan.principal_minor = command.loan_principal_minor;
+        loan.annual_rate = command.loan_annual_rate;
+        loan.rate_type = command.loan_rate_type;
+        loan.term_months = command.loan_term_months;
+        loan.start_date = command.loan_start_date;
+        loan.payment_day = command.loan_payment_day;
+        loan.note = command.loan_note;
+
+        let loan = match self.loan_repo.update(loan, meta_user).await {
+            Ok(Some(loan)) => loan,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error updating loan")),
+        };
+        let changes = self.set_rate_changes(command.loan_id, &command.loan_rate_changes, meta_user).await?;
+        Ok(Some(LoanResponse::of(&loan, &changes)))
+    }
+
+    async fn delete(&self, command: LoanDeleteCommand) -> Result<(), Error> {
+        match self.loan_repo.delete(command.loan_id, Some(command.auth_user.user_id)).await {
+            Ok(()) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting loan")),
+        }
+    }
+
+    async fn get_by_user(&self, command: LoanListByUserCommand) -> Result<Vec<LoanResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let loans = match self.loan_repo.get_by_user(command.user_id, meta_user).await {
+            Ok(loans) => loans,
+            Err(_) => return Err(Error::msg("Error getting loans")),
+        };
+
+        let mut responses = Vec::new();
+        for loan in &loans {
+            let changes = self.rate_changes(bu(loan.id.as_deref().unwrap()), meta_user).await?;
+            responses.push(LoanResponse::of(loan, &changes));
+        }
+        Ok(responses)
+    }
+
+    async fn schedule(&self, command: LoanGetCommand) -> Result<Option<LoanScheduleResponse>, Error> {
+        let today = Utc::now().date_naive();
+        let Some(state) = self.state(command.loan_id, command.auth_user.user_id, today).await? else {
+            return Ok(None);
+        };
+        let LoanState { loan, changes, account, schedule, reconciliation } = &state;
+
+        let projection = amortize(loan, changes, state.position(), &[], LoanExtraPaymentEffect::ReduceTerm);
+        let installments = schedule.iter()
+            .zip(&reconciliation.installments)
+            .map(|(installment, (paid, status))| LoanInstallmentResponse {
+                paid_minor: Some(*paid),
+                status: Some(*status),
+                ..LoanInstallmentResponse::from(installment)
+            })
+            .collect();
+
+        Ok(Some(LoanScheduleResponse {
+            loan_id: command.loan_id,
+            account_id: bu(account.id.as_slice()),
+            account_name: account.name.clone(),
+            currency_code: account.currency_code.clone(),
+            as_of: today,
+            scheduled_total_interest_minor: schedule.iter().map(|installment| installment.interest_minor).sum(),
+            scheduled_balance_minor: schedule.iter().rev()
+                .find(|installment| installment.due_date <= today)
+                .map_or(loan.principal_minor, |installment| installment.balance_minor),
+            scheduled_remaining_interest_minor: schedule.iter()
+                .filter(|installment| installment.due_date > today)
+                .map(|installment| installment.interest_minor)
+                .sum(),
+            paid_minor: reconciliation.paid_minor,
+            interest_charged_minor: reconciliation.interest_minor,
+            actual_balance_minor: reconciliation.balance_minor,
+            arrears_minor: reconciliation.arrears_minor,
+            remaining_interest_minor: projection.iter().map(|installment| installment.interest_minor).sum(),
+            next_due_date: projection.first().map(|installment| installment.due_date),
+            projected_payoff_date: projection.last().map(|installment| installment.due_date),
+            installments,
+        }))
+    }
+
+    async fn extra_payments(&self, command: LoanExtraPaymentCommand) -> Result<Option<LoanExtraPaymentResponse>, Error> {
+        let today = Utc::now().date_naive();
+        if command.monthly_extra_minor < 0 || command.one_off_payments.iter().any(|payment| payment.amount_minor <= 0) {
+            return Err(AppError::BadRequest("extra payments must be positive".into()).into());
+        }
+        if command.one_off_payments.iter().any(|payment| payment.payment_date <= today) {
+            return Err(AppError::BadRequest("extra payments must be dated after today".into()).into());
+        }
+
+        let Some(state) = self.state(command.loan_id, command.auth_user.user_id, today).await? else {
+            return Ok(None);
+        };
+        let position = state.position();
+
+        let monthly_from = command.monthly_extra_from.unwrap_or(today);
+        let mut extras: Vec<LoanExtraPayment> = command.one_off_payments.iter()
+            .map(|payment| LoanExtraPayment { date: payment.payment_date, amount_minor: payment.amount_minor })
+            .collect();
+        if command.monthly_extra_minor > 0 {
+            extras.extend((position.number..=state.loan.term_months)
+                .map(|number| state.loan.due_date(number))
+                .filter(|due_date| *due_date >= monthly_from)
+                .map(|due_date| LoanExtraPayment { date: due_date, amount_minor: command.monthly_extra_minor }));
+        }
+
+        let baseline = amortize(&state.loan, &state.changes, position, &[], LoanExtraPaymentEffect::ReduceTerm);
+        let with_extra = amortize(&state.loan, &state.changes, position, &extras, command.extra_effect);
+        let (baseline, with_extra_payoff) = (LoanPayoffResponse::of(&baseline), LoanPayoffResponse::of(&with_extra));
+
+        Ok(Some(LoanExtraPaymentResponse {
+            loan_id: command.loan_id,
+            currency_code: state.account.currency_code.clone(),
+            as_of: today,
+            balance_minor: position.balance_minor,
+            extra_effect: command.extra_effect,
+            interest_saved_minor: baseline.total_interest_minor - with_extra_payoff.total_interest_minor,
+            installments_saved: baseline.installment_count - with_extra_payoff.installment_count,
+            baseline,
+            with_extra: with_extra_payoff,
+            installments: with_extra.iter().map(LoanInstallmentResponse::from).collect(),
+        }))
+    }
+}
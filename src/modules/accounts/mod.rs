@@ -0,0 +1,25 @@
+pub mod account_model;
+pub mod account_repo;
+mod networth_model;
+mod networth_repo;
+mod networth_command;
+mod networth_service;
+pub mod networth_dto;
+pub mod networth_controller;
+mod forecast_model;
+mod forecast_command;
+mod forecast_service;
+pub mod forecast_dto;
+pub mod forecast_controller;
+mod scenario_model;
+mod scenario_repo;
+mod scenario_command;
+mod scenario_service;
+pub mod scenario_dto;
+pub mod scenario_controller;
+mod loan_model;
+mod loan_repo;
+mod loan_command;
+mod loan_service;
+pub mod loan_dto;
+pub mod loan_controller;
\ No newline at end of file
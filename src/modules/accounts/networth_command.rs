@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::accounts::networth_dto::{NetWorthHistoryQuery, NetWorthQuery, NetWorthSnapshotRequest};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NetWorthCommand {
+    pub user_id: Uuid,
+    pub as_of: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl NetWorthCommand {
+    pub fn new(query: NetWorthQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            as_of: query.as_of,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NetWorthSnapshotCommand {
+    pub user_id: Uuid,
+    pub from_month: Option<NaiveDate>,
+    pub to_month: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl NetWorthSnapshotCommand {
+    pub fn new(request: NetWorthSnapshotRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            from_month: request.from_month,
+            to_month: request.to_month,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NetWorthHistoryCommand {
+    pub user_id: Uuid,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl NetWorthHistoryCommand {
+    pub fn new(query: NetWorthHistoryQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            from: query.from,
+            to: query.to,
+            auth_user,
+        }
+    }
+}
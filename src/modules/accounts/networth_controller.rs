@@ -0,0 +1,105 @@
+use axum::{extract::State, http::StatusCode, routing::{get, post}, Json, Router};
+use axum::extract::Query;
+
+use crate::modules::accounts::{
+    networth_command::*,
+    networth_dto::*,
+    networth_service::{NetWorthService, NetWorthServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/net-worth", get(get_net_worth))
+        .route("/net-worth/snapshots", post(post_net_worth_snapshots))
+        .route("/net-worth/history", get(get_net_worth_history))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/net-worth",
+    params(
+        NetWorthQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Accounts, portfolios and manual assets of current user in the base currency", body = NetWorthResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Missing fx rate"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Net Worth"
+)]
+pub async fn get_net_worth(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<NetWorthQuery>,
+) -> Result<Json<NetWorthResponse>, StatusCode> {
+    let command = NetWorthCommand::new(query, auth_user);
+    let net_worth_service = NetWorthService::from(&state);
+
+    let net_worth = net_worth_service.net_worth(command).await;
+    match net_worth {
+        Ok(net_worth) => Ok(Json(net_worth)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/accounts/net-worth/snapshots",
+    responses(
+        (status = StatusCode::OK, description = "Net worth recorded for each month", body = Vec<NetWorthMonthResponse>),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid months or missing fx rate"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Net Worth"
+)]
+pub async fn post_net_worth_snapshots(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(net_worth_snapshot_request): Json<NetWorthSnapshotRequest>
+) -> Result<Json<Vec<NetWorthMonthResponse>>, StatusCode> {
+    let command = NetWorthSnapshotCommand::new(net_worth_snapshot_request, auth_user);
+    let net_worth_service = NetWorthService::from(&state);
+
+    let months = net_worth_service.record_snapshots(command).await;
+    match months {
+        Ok(months) => Ok(Json(months)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/net-worth/history",
+    params(
+        NetWorthHistoryQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recorded net worth by month with the change by account type", body = NetWorthHistoryResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid period"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Net Worth"
+)]
+pub async fn get_net_worth_history(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<NetWorthHistoryQuery>,
+) -> Result<Json<NetWorthHistoryResponse>, StatusCode> {
+    let command = NetWorthHistoryCommand::new(query, auth_user);
+    let net_worth_service = NetWorthService::from(&state);
+
+    let history = net_worth_service.history(command).await;
+    match history {
+        Ok(history) => Ok(Json(history)),
+        Err(e) => Err(error_status(&e))
+    }
+}
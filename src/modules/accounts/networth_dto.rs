@@ -0,0 +1,98 @@
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    account_model::AccountType,
+    networth_model::NetWorthKind,
+};
+use crate::modules::investments::asset_dto::AssetSnapshotLineResponse;
+
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct NetWorthQuery {
+    /// Today by default
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthAccountResponse {
+    pub account_id: Uuid,
+    pub account_name: String,
+    pub account_type: AccountType,
+    pub currency_code: String,
+    /// In the account currency, negative for money owed
+    pub balance_minor: i64,
+    pub balance_base_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthPortfolioResponse {
+    pub portfolio_id: Uuid,
+    pub portfolio_name: String,
+    /// Market value of the units held, the cash being the broker account balance
+    pub value_base_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthKindResponse {
+    pub kind: NetWorthKind,
+    pub value_base_minor: i64,
+    /// Against the previous recorded month in the history, None otherwise
+    pub change_base_minor: Option<i64>,
+}
+
+/// Assets are every line but the debt accounts, whose balance makes the liabilities
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthResponse {
+    pub as_of: NaiveDate,
+    pub base_currency_code: String,
+
+    pub assets_base_minor: i64,
+    /// Positive while money is owed
+    pub liabilities_base_minor: i64,
+    pub net_worth_base_minor: i64,
+
+    pub by_kind: Vec<NetWorthKindResponse>,
+    /// Accounts with a balance, archived ones included
+    pub accounts: Vec<NetWorthAccountResponse>,
+    pub portfolios: Vec<NetWorthPortfolioResponse>,
+    pub manual_assets: Vec<AssetSnapshotLineResponse>,
+}
+
+// --- Monthly snapshots ---
+
+/// Months whose net worth is recorded, replacing what was recorded for them
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthSnapshotRequest {
+    /// Any day of the first month, current month by default
+    pub from_month: Option<NaiveDate>,
+    /// Any day of the last month, `from_month` by default
+    pub to_month: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct NetWorthHistoryQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+/// Net worth recorded for a month: at the end of the month, or on the day it was recorded for the current month
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthMonthResponse {
+    pub month: NaiveDate,
+    pub assets_base_minor: i64,
+    pub liabilities_base_minor: i64,
+    pub net_worth_base_minor: i64,
+    /// Against the previous recorded month, None for the first one
+    pub change_base_minor: Option<i64>,
+    pub by_kind: Vec<NetWorthKindResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NetWorthHistoryResponse {
+    /// Months recorded in another base currency are left out
+    pub base_currency_code: String,
+    pub months: Vec<NetWorthMonthResponse>,
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::accounts::account_model::AccountType;
+use crate::shared::db::mysql::FromSqlRow;
+
+
+/// Line of the net worth: an account type, the portfolios or the manual assets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum NetWorthKind {
+    Checking,
+    Savings,
+    Cash,
+    Broker,
+    Debt,
+    Investments,
+    Assets,
+}
+
+impl NetWorthKind {
+    pub const ALL: [NetWorthKind; 7] = [
+        NetWorthKind::Checking,
+        NetWorthKind::Savings,
+        NetWorthKind::Cash,
+        NetWorthKind::Broker,
+        NetWorthKind::Debt,
+        NetWorthKind::Investments,
+        NetWorthKind::Assets,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetWorthKind::Checking => "checking",
+            NetWorthKind::Savings => "savings",
+            NetWorthKind::Cash => "cash",
+            NetWorthKind::Broker => "broker",
+            NetWorthKind::Debt => "debt",
+            NetWorthKind::Investments => "investments",
+            NetWorthKind::Assets => "assets",
+        }
+    }
+
+    pub fn of(account_type: AccountType) -> Self {
+        match account_type {
+            AccountType::Checking => NetWorthKind::Checking,
+            AccountType::Savings => NetWorthKind::Savings,
+            AccountType::Cash => NetWorthKind::Cash,
+            AccountType::Broker => NetWorthKind::Broker,
+            AccountType::Debt => NetWorthKind::Debt,
+        }
+    }
+}
+
+
+/// Value of one line of the net worth for a month (first day of the month), in minor units of the base currency
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NetWorthSnapshot {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub snapshot_month: NaiveDate,
+    pub kind: NetWorthKind,
+    pub value_base_minor: i64,
+    pub base_currency_code: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for NetWorthSnapshot {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            snapshot_month: row.try_get(index_map["snapshot_month"])?,
+            kind: row.try_get(index_map["kind"])?,
+            value_base_minor: row.try_get(index_map["value_base_minor"])?,
+            base_currency_code: row.try_get(index_map["base_currency_code"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl NetWorthSnapshot {
+    pub fn of_month(user_id: Vec<u8>, snapshot_month: NaiveDate, kind: NetWorthKind, value_base_minor: i64, base_currency_code: &str) -> Self {
+        Self {
+            id: None,
+            user_id,
+            snapshot_month,
+            kind,
+            value_base_minor,
+            base_currency_code: base_currency_code.to_string(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
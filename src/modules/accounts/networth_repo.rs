@@ -0,0 +1,80 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::accounts::networth_model::NetWorthSnapshot;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait NetWorthSnapshotRepositoryInterface {
+
+    async fn create(&self, snapshot: NetWorthSnapshot, meta_user: Option<Uuid>) -> Result<NetWorthSnapshot, Error>;
+
+    /// Removes every line of the month before the month is recorded again
+    async fn delete_by_month(&self, user_id: Uuid, snapshot_month: NaiveDate, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by month
+    async fn get_by_user(&self, user_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<NetWorthSnapshot>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct NetWorthSnapshotRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for NetWorthSnapshotRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<NetWorthSnapshot> for NetWorthSnapshotRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl NetWorthSnapshotRepositoryInterface for NetWorthSnapshotRepository {
+    async fn create(&self, snapshot: NetWorthSnapshot, meta_user: Option<Uuid>) -> Result<NetWorthSnapshot, Error> {
+        let params = vec![
+            MySqlParam::from(snapshot.user_id),
+            MySqlParam::from(snapshot.snapshot_month),
+            MySqlParam::from(snapshot.kind.as_str()),
+            MySqlParam::from(snapshot.value_base_minor),
+            MySqlParam::from(snapshot.base_currency_code),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_net_worth_snapshot_create", params).await
+    }
+
+    async fn delete_by_month(&self, user_id: Uuid, snapshot_month: NaiveDate, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(snapshot_month),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_net_worth_snapshot_delete_by_month", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<NetWorthSnapshot>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(from),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_net_worth_snapshot_by_user", params).await
+    }
+}
@@ -0,0 +1,357 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    account_model::AccountBalance,
+    account_repo::{AccountRepository, AccountRepositoryInterface},
+    networth_command::*,
+    networth_dto::*,
+    networth_model::{NetWorthKind, NetWorthSnapshot},
+    networth_repo::{NetWorthSnapshotRepository, NetWorthSnapshotRepositoryInterface},
+};
+use crate::modules::currencies::{
+    currency_model::FxTable,
+    currency_repo::{CurrencyRepository, CurrencyRepositoryInterface, FxRateRepository, FxRateRepositoryInterface},
+};
+use crate::modules::investments::{
+    asset_model::{Asset, AssetValuation},
+    asset_repo::{AssetRepository, AssetRepositoryInterface, AssetValuationRepository, AssetValuationRepositoryInterface},
+    asset_service::AssetService,
+    instrument_repo::{InstrumentPriceRepository, InstrumentPriceRepositoryInterface, InstrumentRepository, InstrumentRepositoryInterface},
+    performance_model::MarketData,
+    portfolio_model::Portfolio,
+    portfolio_repo::{PortfolioRepository, PortfolioRepositoryInterface},
+    trade_model::Trade,
+    trade_repo::{TradeRepository, TradeRepositoryInterface},
+};
+use crate::modules::users::user::user_repo::{UserRepository, UserRepositoryInterface};
+use crate::shared::dates::{add_months, month_start, months_between};
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, ub};
+
+
+/// Months recorded by a single snapshot request, at most
+const MAX_SNAPSHOT_MONTHS: i32 = 120;
+
+#[async_trait]
+pub trait NetWorthServiceInterface {
+
+    /// Accounts, portfolios and manual assets valued in the base currency at the end of a day
+    async fn net_worth(&self, command: NetWorthCommand) -> Result<NetWorthResponse, Error>;
+
+    /// Records the net worth of each month of the range, replacing what was recorded for those months
+    async fn record_snapshots(&self, command: NetWorthSnapshotCommand) -> Result<Vec<NetWorthMonthResponse>, Error>;
+
+    /// Recorded months with the change of each line against the previous recorded month
+    async fn history(&self, command: NetWorthHistoryCommand) -> Result<NetWorthHistoryResponse, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct NetWorthService {
+    user_repo: UserRepository,
+    currency_repo: CurrencyRepository,
+    fx_rate_repo: FxRateRepository,
+    account_repo: AccountRepository,
+    portfolio_repo: PortfolioRepository,
+    instrument_repo: InstrumentRepository,
+    price_repo: InstrumentPriceRepository,
+    trade_repo: TradeRepository,
+    asset_repo: AssetRepository,
+    valuation_repo: AssetValuationRepository,
+    snapshot_repo: NetWorthSnapshotRepository,
+}
+
+impl From<&AppState> for NetWorthService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            user_repo: UserRepository::from(app_state),
+            currency_repo: CurrencyRepository::from(app_state),
+            fx_rate_repo: FxRateRepository::from(app_state),
+            account_repo: AccountRepository::from(app_state),
+            portfolio_repo: PortfolioRepository::from(app_state),
+            instrument_repo: InstrumentRepository::from(app_state),
+            price_repo: InstrumentPriceRepository::from(app_state),
+            trade_repo: TradeRepository::from(app_state),
+            asset_repo: AssetRepository::from(app_state),
+            valuation_repo: AssetValuationRepository::from(app_state),
+            snapshot_repo: NetWorthSnapshotRepository::from(app_state),
+        }
+    }
+}
+
+/// Everything but the account balances needed to value the net worth on any day up to the loaded one
+struct NetWorthContext {
+    base_scale: Decimal,
+    fx: FxTable,
+    market: MarketData,
+    portfolios: Vec<Portfolio>,
+    trades: Vec<Trade>,
+    assets: Vec<Asset>,
+    valuations: Vec<AssetValuation>,
+}
+
+impl NetWorthService {
+    fn base_minor(value: Decimal) -> i64 {
+        value.round().to_i64().unwrap_or(0)
+    }
+
+    /// Liabilities are the debt line, turned positive; assets every other line
+    fn totals(by_kind: &[NetWorthKindResponse]) -> (i64, i64) {
+        by_kind.iter().fold((0, 0), |(assets, liabilities), line| match line.kind {
+            NetWorthKind::Debt => (assets, liabilities - line.value_base_minor),
+            _ => (assets + line.value_base_minor, liabilities),
+        })
+    }
+
+    /// Net worth at the end of `on` from the account balances of that day.
+    /// Err with the currency code missing an fx rate.
+    fn net_worth_of(on: NaiveDate, balances: &[AccountBalance], context: &NetWorthContext) -> Result<NetWorthResponse, String> {
+        let mut values: HashMap<NetWorthKind, i64> = HashMap::new();
+
+        let mut accounts = Vec::new();
+        for account in balances.iter().filter(|account| account.balance_minor != 0) {
+            let balance = Decimal::from(account.balance_minor) / scale_factor(account.minor_unit);
+            let Some(balance_base) = context.fx.to_base(balance, &account.currency_code, on) else {
+                return Err(account.currency_code.clone());
+            };
+            let balance_base_minor = Self::base_minor(balance_base * context.base_scale);
+            *values.entry(NetWorthKind::of(account.account_type)).or_default() += balance_base_minor;
+            accounts.push(NetWorthAccountResponse {
+                account_id: bu(&account.id),
+                account_name: account.name.clone(),
+                account_type: account.account_type,
+                currency_code: account.currency_code.clone(),
+                balance_minor: account.balance_minor,
+                balance_base_minor,
+            });
+        }
+
+        let mut portfolios = Vec::new();
+        for portfolio in &context.portfolios {
+            let trades: Vec<&Trade> = context.trades.iter()
+                .filter(|trade| Some(&trade.portfolio_id) == portfolio.id.as_ref() && trade.trade_date <= on)
+                .collect();
+            if trades.is_empty() {
+                continue;
+            }
+            let value_base_minor = Self::base_minor(context.market.value_on(&trades, on)? * context.base_scale);
+            *values.entry(NetWorthKind::Investments).or_default() += value_base_minor;
+            portfolios.push(NetWorthPortfolioResponse {
+                portfolio_id: bu(portfolio.id.as_deref().unwrap()),
+                portfolio_name: portfolio.name.clone(),
+                value_base_minor,
+            });
+        }
+
+        let assets = AssetService::snapshot_of(on, &context.assets, &context.valuations, &context.fx, context.base_scale)?;
+        values.insert(NetWorthKind::Assets, assets.total_base_minor);
+
+        let by_kind: Vec<NetWorthKindResponse> = NetWorthKind::ALL.iter()
+            .map(|kind| NetWorthKindResponse {
+                kind: *kind,
+                value_base_minor: values.get(kind).copied().unwrap_or(0),
+                change_base_minor: None,
+            })
+            .collect();
+        let (assets_base_minor, liabilities_base_minor) = Self::totals(&by_kind);
+
+        Ok(NetWorthResponse {
+            as_of: on,
+            base_currency_code: context.fx.base_code.clone(),
+            assets_base_minor,
+            liabilities_base_minor,
+            net_worth_base_minor: assets_base_minor - liabilities_base_minor,
+            by_kind,
+            accounts,
+            portfolios,
+            manual_assets: assets.assets,
+        })
+    }
+
+    /// Months of the history, each line compared with the previous month of the list
+    fn months_of(snapshots: &[NetWorthSnapshot]) -> Vec<NetWorthMonthResponse> {
+        let mut by_month: BTreeMap<NaiveDate, HashMap<NetWorthKind, i64>> = BTreeMap::new();
+        for snapshot in snapshots {
+            *by_month.entry(snapshot.snapshot_month).or_default().entry(snapshot.kind).or_default() += snapshot.value_base_minor;
+        }
+
+        let mut months: Vec<NetWorthMonthResponse> = Vec::new();
+        for (month, values) in by_month {
+            let previous = months.last();
+            let by_kind: Vec<NetWorthKindResponse> = NetWorthKind::ALL.iter()
+                .map(|kind| {
+                    let value_base_minor = values.get(kind).copied().unwrap_or(0);
+                    let change_base_minor = previous
+                        .and_then(|previous| previous.by_kind.iter().find(|line| line.kind == *kind))
+                        .map(|line| value_base_minor - line.value_base_minor);
+                    NetWorthKindResponse { kind: *kind, value_base_minor, change_base_minor }
+                })
+                .collect();
+            let (assets_base_minor, liabilities_base_minor) = Self::totals(&by_kind);
+            let net_worth_base_minor = assets_base_minor - liabilities_base_minor;
+            months.push(NetWorthMonthResponse {
+                month,
+                assets_base_minor,
+                liabilities_base_minor,
+                net_worth_base_minor,
+                change_base_minor: previous.map(|previous| net_worth_base_minor - previous.net_worth_base_minor),
+                by_kind,
+            });
+        }
+        months
+    }
+
+    async fn base_code(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<String, Error> {
+        match self.user_repo.get(user_id, meta_user).await {
+            Ok(Some(user)) => Ok(user.base_currency_code),
+            Ok(None) => Err(Error::msg("User not found")),
+            Err(_) => Err(Error::msg("Error getting user")),
+        }
+    }
+
+    /// Loads the portfolios, prices and manual assets of the user up to `to`
+    async fn context(&self, user_id: Uuid, to: NaiveDate, meta_user: Option<Uuid>) -> Result<NetWorthContext, Error> {
+        let base_code = self.base_code(user_id, meta_user).await?;
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let fx_rates = match self.fx_rate_repo.get_by_base_code(base_code.clone(), meta_user).await {
+            Ok(fx_rates) => fx_rates,
+            Err(_) => return Err(Error::msg("Error getting fx rates")),
+        };
+        let fx = FxTable::new(&base_code, &fx_rates);
+        let base_scale = scale_factor(minor_units.get(&base_code).copied().unwrap_or(2));
+
+        let portfolios = match self.portfolio_repo.get_by_user(user_id, meta_user).await {
+            Ok(portfolios) => portfolios,
+            Err(_) => return Err(Error::msg("Error getting portfolios")),
+        };
+        let mut trades: Vec<Trade> = Vec::new();
+        for portfolio in &portfolios {
+            match self.trade_repo.get_by_portfolio(bu(portfolio.id.as_deref().unwrap()), meta_user).await {
+                Ok(portfolio_trades) => trades.extend(portfolio_trades.into_iter().filter(|trade| trade.trade_date <= to)),
+                Err(_) => return Err(Error::msg("Error getting portfolio trades")),
+            }
+        }
+        let instruments = match self.instrument_repo.get_by_user(user_id, meta_user).await {
+            Ok(instruments) => instruments,
+            Err(_) => return Err(Error::msg("Error getting instruments")),
+        };
+        let traded: BTreeSet<Vec<u8>> = trades.iter().filter_map(|trade| trade.instrument_id.clone()).collect();
+        let mut prices = Vec::new();
+        for instrument_id in &traded {
+            match self.price_repo.get_by_instrument(bu(instrument_id), None, Some(to), meta_user).await {
+                Ok(instrument_prices) => prices.extend(instrument_prices),
+                Err(_) => return Err(Error::msg("Error getting instrument prices")),
+            }
+        }
+        let portfolio_currencies = portfolios.iter()
+            .map(|portfolio| (portfolio.id.clone().unwrap(), portfolio.currency_code.clone()))
+            .collect();
+        let market = MarketData::new(&trades, instruments, &prices, portfolio_currencies, minor_units, fx.clone());
+
+        let assets = match self.asset_repo.get_by_user(user_id, meta_user).await {
+            Ok(assets) => assets,
+            Err(_) => return Err(Error::msg("Error getting assets")),
+        };
+        let valuations = match self.valuation_repo.get_by_user(user_id, to, meta_user).await {
+            Ok(valuations) => valuations,
+            Err(_) => return Err(Error::msg("Error getting asset valuations")),
+        };
+
+        Ok(NetWorthContext { base_scale, fx, market, portfolios, trades, assets, valuations })
+    }
+
+    async fn net_worth_on(&self, user_id: Uuid, on: NaiveDate, context: &NetWorthContext, meta_user: Option<Uuid>) -> Result<NetWorthResponse, Error> {
+        let balances = match self.account_repo.get_balances_by_user(user_id, on, meta_user).await {
+            Ok(balances) => balances,
+            Err(_) => return Err(Error::msg("Error getting account balances")),
+        };
+        Self::net_worth_of(on, &balances, context)
+            .map_err(|currency_code| AppError::BadRequest(format!("missing fx rate {}/{}", context.fx.base_code, currency_code)).into())
+    }
+}
+
+#[async_trait]
+impl NetWorthServiceInterface for NetWorthService {
+    async fn net_worth(&self, command: NetWorthCommand) -> Result<NetWorthResponse, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let as_of = command.as_of.unwrap_or(Utc::now().date_naive());
+
+        let context = self.context(command.user_id, as_of, meta_user).await?;
+        self.net_worth_on(command.user_id, as_of, &context, meta_user).await
+    }
+
+    async fn record_snapshots(&self, command: NetWorthSnapshotCommand) -> Result<Vec<NetWorthMonthResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let today = Utc::now().date_naive();
+        let from = month_start(command.from_month.unwrap_or(today));
+        let to = month_start(command.to_month.unwrap_or(from));
+        if from > to {
+            return Err(AppError::BadRequest("first month must be before the last one".into()).into());
+        }
+        if to > month_start(today) {
+            return Err(AppError::BadRequest("future months cannot be recorded".into()).into());
+        }
+        if months_between(from, to) >= MAX_SNAPSHOT_MONTHS {
+            return Err(AppError::BadRequest(format!("at most {} months can be recorded at once", MAX_SNAPSHOT_MONTHS)).into());
+        }
+
+        // a month is valued at its end, the current month on the day it is recorded
+        let end_of = |month: NaiveDate| (add_months(month, 1) - Days::new(1)).min(today);
+        let context = self.context(command.user_id, end_of(to), meta_user).await?;
+
+        let mut snapshots = Vec::new();
+        let mut month = from;
+        while month <= to {
+            let net_worth = self.net_worth_on(command.user_id, end_of(month), &context, meta_user).await?;
+            if self.snapshot_repo.delete_by_month(command.user_id, month, meta_user).await.is_err() {
+                return Err(Error::msg("Error deleting net worth snapshot"));
+            }
+            for line in &net_worth.by_kind {
+                let snapshot = NetWorthSnapshot::of_month(ub(command.user_id), month, line.kind, line.value_base_minor, &net_worth.base_currency_code);
+                match self.snapshot_repo.create(snapshot, meta_user).await {
+                    Ok(snapshot) => snapshots.push(snapshot),
+                    Err(_) => return Err(Error::msg("Error creating net worth snapshot")),
+                }
+            }
+            month = add_months(month, 1);
+        }
+
+        Ok(Self::months_of(&snapshots))
+    }
+
+    async fn history(&self, command: NetWorthHistoryCommand) -> Result<NetWorthHistoryResponse, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let from = command.from.map(month_start);
+        if let (Some(from), Some(to)) = (from, command.to) && from > to {
+            return Err(AppError::BadRequest("period start must be before its end".into()).into());
+        }
+        let base_code = self.base_code(command.user_id, meta_user).await?;
+
+        // the month before the period gives the change of its first month
+        let snapshots = match self.snapshot_repo.get_by_user(command.user_id, from.map(|from| add_months(from, -1)), command.to, meta_user).await {
+            Ok(snapshots) => snapshots,
+            Err(_) => return Err(Error::msg("Error getting net worth snapshots")),
+        };
+        let snapshots: Vec<NetWorthSnapshot> = snapshots.into_iter()
+            .filter(|snapshot| snapshot.base_currency_code == base_code)
+            .collect();
+
+        let mut months = Self::months_of(&snapshots);
+        if let Some(from) = from {
+            months.retain(|month| month.month >= from);
+        }
+
+        Ok(NetWorthHistoryResponse { base_currency_code: base_code, months })
+    }
+}
@@ -0,0 +1,111 @@
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::accounts::scenario_dto::{ScenarioCompareRequest, ScenarioCreateRequest, ScenarioOverlayRequest, ScenarioUpdateRequest};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioGetCommand {
+    pub scenario_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ScenarioGetCommand {
+    pub fn new(scenario_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { scenario_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioCreateCommand {
+    pub user_id: Uuid,
+    pub scenario_name: String,
+    pub scenario_note: Option<String>,
+    pub scenario_overlays: Vec<ScenarioOverlayRequest>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ScenarioCreateCommand {
+    pub fn new(request: ScenarioCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            scenario_name: request.scenario_name,
+            scenario_note: request.scenario_note,
+            scenario_overlays: request.scenario_overlays,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioUpdateCommand {
+    pub scenario_id: Uuid,
+    pub scenario_name: String,
+    pub scenario_note: Option<String>,
+    pub scenario_overlays: Vec<ScenarioOverlayRequest>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ScenarioUpdateCommand {
+    pub fn new(scenario_id: Uuid, request: ScenarioUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            scenario_id,
+            scenario_name: request.scenario_name,
+            scenario_note: request.scenario_note,
+            scenario_overlays: request.scenario_overlays,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioDeleteCommand {
+    pub scenario_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ScenarioDeleteCommand {
+    pub fn new(scenario_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { scenario_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ScenarioListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScenarioCompareCommand {
+    pub scenario_ids: Vec<Uuid>,
+    pub months: Option<u32>,
+    pub spending_account_id: Option<Uuid>,
+    pub include_days: bool,
+
+    pub auth_user: AuthUser,
+}
+
+impl ScenarioCompareCommand {
+    pub fn new(request: ScenarioCompareRequest, auth_user: AuthUser) -> Self {
+        Self {
+            scenario_ids: request.scenario_ids,
+            months: request.months,
+            spending_account_id: request.spending_account_id,
+            include_days: request.include_days.unwrap_or(false),
+            auth_user,
+        }
+    }
+}
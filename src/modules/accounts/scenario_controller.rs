@@ -0,0 +1,201 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    scenario_command::*,
+    scenario_dto::*,
+    scenario_service::{ScenarioService, ScenarioServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/forecast/scenarios", get(get_scenarios).post(post_scenario))
+        .route("/forecast/scenarios/compare", post(post_scenario_compare))
+        .route("/forecast/scenarios/{scenario_id}", get(get_scenario).put(put_scenario).delete(delete_scenario))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/forecast/scenarios",
+    responses(
+        (status = StatusCode::OK, description = "Forecast scenarios of current user", body = Vec<ScenarioResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Scenario"
+)]
+pub async fn get_scenarios(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<ScenarioResponse>>, StatusCode> {
+    let command = ScenarioListByUserCommand::new(auth_user.user_id, auth_user);
+    let scenario_service = ScenarioService::from(&state);
+
+    let scenarios = scenario_service.get_by_user(command).await;
+    match scenarios {
+        Ok(scenarios) => Ok(Json(scenarios)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/accounts/forecast/scenarios",
+    responses(
+        (status = StatusCode::OK, description = "Scenario created successfully", body = ScenarioResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid scenario"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Scenario"
+)]
+pub async fn post_scenario(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(scenario_create_request): Json<ScenarioCreateRequest>
+) -> Result<Json<ScenarioResponse>, StatusCode> {
+    let command = ScenarioCreateCommand::new(scenario_create_request, auth_user);
+    let scenario_service = ScenarioService::from(&state);
+
+    let scenario = scenario_service.create(command).await;
+    match scenario {
+        Ok(scenario) => Ok(Json(scenario)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/accounts/forecast/scenarios/compare",
+    responses(
+        (status = StatusCode::OK, description = "Baseline forecast and scenario forecasts side by side, real data left untouched", body = ScenarioComparisonResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid comparison or overlay, or missing fx rate"),
+        (status = StatusCode::NOT_FOUND, description = "Scenario not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Scenario"
+)]
+pub async fn post_scenario_compare(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(scenario_compare_request): Json<ScenarioCompareRequest>
+) -> Result<Json<ScenarioComparisonResponse>, StatusCode> {
+    let command = ScenarioCompareCommand::new(scenario_compare_request, auth_user);
+    let scenario_service = ScenarioService::from(&state);
+
+    let comparison = scenario_service.compare(command).await;
+    match comparison {
+        Ok(comparison) => {
+            match comparison {
+                Some(comparison) => Ok(Json(comparison)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/accounts/forecast/scenarios/{scenario_id}",
+    params(
+        ("scenario_id", description = "scenario identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Scenario with its overlays", body = ScenarioResponse),
+        (status = StatusCode::NOT_FOUND, description = "Scenario not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Scenario"
+)]
+pub async fn get_scenario(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(scenario_id): Path<Uuid>,
+) -> Result<Json<ScenarioResponse>, StatusCode> {
+    let command = ScenarioGetCommand::new(scenario_id, auth_user);
+    let scenario_service = ScenarioService::from(&state);
+
+    let scenario = scenario_service.get_by_id(command).await;
+    match scenario {
+        Ok(scenario) => {
+            match scenario {
+                Some(scenario) => Ok(Json(scenario)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/accounts/forecast/scenarios/{scenario_id}",
+    params(
+        ("scenario_id", description = "scenario identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Scenario updated successfully, its overlays replaced", body = ScenarioResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid scenario"),
+        (status = StatusCode::NOT_FOUND, description = "Scenario not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Scenario"
+)]
+pub async fn put_scenario(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(scenario_id): Path<Uuid>,
+    Json(scenario_update_request): Json<ScenarioUpdateRequest>
+) -> Result<Json<ScenarioResponse>, StatusCode> {
+    let command = ScenarioUpdateCommand::new(scenario_id, scenario_update_request, auth_user);
+    let scenario_service = ScenarioService::from(&state);
+
+    let scenario = scenario_service.update(command).await;
+    match scenario {
+        Ok(scenario) => {
+            match scenario {
+                Some(scenario) => Ok(Json(scenario)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/accounts/forecast/scenarios/{scenario_id}",
+    params(
+        ("scenario_id", description = "scenario identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Scenario deleted successfully, with its overlays"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Cashflow Scenario"
+)]
+pub async fn delete_scenario(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(scenario_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = ScenarioDeleteCommand::new(scenario_id, auth_user);
+    let scenario_service = ScenarioService::from(&state);
+
+    let response = scenario_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
@@ -0,0 +1,166 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    forecast_dto::{ForecastNegativePeriodResponse, ForecastResponse},
+    scenario_model::{Scenario, ScenarioOverlay, ScenarioOverlayAction, ScenarioOverlayTarget},
+};
+use crate::modules::transactions::recurring_model::RecurringFrequency;
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioOverlayResponse {
+    pub overlay_id: Uuid,
+    pub overlay_action: ScenarioOverlayAction,
+    pub overlay_target: ScenarioOverlayTarget,
+    pub target_id: Option<Uuid>,
+    pub account_id: Option<Uuid>,
+    pub overlay_label: Option<String>,
+    pub amount_minor: Option<i64>,
+    pub frequency: Option<RecurringFrequency>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub scale_percent: Option<Decimal>,
+}
+
+impl From<&ScenarioOverlay> for ScenarioOverlayResponse {
+    fn from(overlay: &ScenarioOverlay) -> Self {
+        Self {
+            overlay_id: bu(overlay.id.clone().unwrap().as_slice()),
+            overlay_action: overlay.action,
+            overlay_target: overlay.target_kind,
+            target_id: obu(overlay.target_id.as_deref()),
+            account_id: obu(overlay.account_id.as_deref()),
+            overlay_label: overlay.label.clone(),
+            amount_minor: overlay.amount_minor,
+            frequency: overlay.frequency,
+            start_date: overlay.start_date,
+            end_date: overlay.end_date,
+            scale_percent: overlay.scale_percent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioResponse {
+    pub scenario_id: Uuid,
+    pub user_id: Uuid,
+    pub scenario_name: String,
+    pub scenario_note: Option<String>,
+    /// In the order they are applied
+    pub scenario_overlays: Vec<ScenarioOverlayResponse>,
+
+    pub scenario_created_at: Option<DateTime<Utc>>,
+    pub scenario_updated_at: Option<DateTime<Utc>>,
+}
+
+impl ScenarioResponse {
+    pub fn of(scenario: &Scenario, overlays: &[ScenarioOverlay]) -> Self {
+        Self {
+            scenario_id: bu(scenario.id.clone().unwrap().as_slice()),
+            user_id: bu(scenario.user_id.as_slice()),
+            scenario_name: scenario.name.clone(),
+            scenario_note: scenario.note.clone(),
+            scenario_overlays: overlays.iter().map(ScenarioOverlayResponse::from).collect(),
+            scenario_created_at: scenario.created_at,
+            scenario_updated_at: scenario.updated_at,
+        }
+    }
+}
+
+/// Change applied to the forecast.
+/// - `add`: a recurring transaction (`account_id`, `amount_minor` signed in the account currency, `frequency`),
+///   a monthly contribution to the goal `target_id`, a one-off purchase, or the funding of every purchase of the
+///   project `target_id` not bought yet. Contributions and purchases are positive amounts in the base currency,
+///   taken from `account_id` or the spending account from `start_date`.
+/// - `remove` / `scale`: the recurring transaction, goal, purchase, project or category `target_id`;
+///   a category covers its envelopes and its recurring transactions.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioOverlayRequest {
+    pub overlay_action: ScenarioOverlayAction,
+    pub overlay_target: ScenarioOverlayTarget,
+    pub target_id: Option<Uuid>,
+    pub account_id: Option<Uuid>,
+    pub overlay_label: Option<String>,
+    pub amount_minor: Option<i64>,
+    /// Added recurring transactions, monthly by default
+    pub frequency: Option<RecurringFrequency>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    /// 80 keeps 80% of the amounts, 120 raises them by 20%
+    pub scale_percent: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioCreateRequest {
+    pub scenario_name: String,
+    pub scenario_note: Option<String>,
+    pub scenario_overlays: Vec<ScenarioOverlayRequest>,
+}
+
+/// Replaces the name, the note and the whole set of overlays
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioUpdateRequest {
+    pub scenario_name: String,
+    pub scenario_note: Option<String>,
+    pub scenario_overlays: Vec<ScenarioOverlayRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioCompareRequest {
+    /// Compared in this order against the baseline
+    pub scenario_ids: Vec<Uuid>,
+    /// Months projected after today, 3 by default and 24 at most
+    pub months: Option<u32>,
+    pub spending_account_id: Option<Uuid>,
+    /// Adds the full daily forecast to each outcome
+    pub include_days: Option<bool>,
+}
+
+/// Amounts in the account currency, changes against the baseline
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioAccountOutcomeResponse {
+    pub account_id: Uuid,
+    pub account_name: String,
+    pub currency_code: String,
+    pub closing_balance_minor: i64,
+    pub closing_change_minor: i64,
+    pub lowest_balance_minor: i64,
+    pub lowest_change_minor: i64,
+    pub first_negative_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioOutcomeResponse {
+    /// None for the baseline
+    pub scenario_id: Option<Uuid>,
+    pub scenario_name: String,
+
+    /// Closing balances of the accounts in the base currency, at the rates of the last day
+    pub closing_total_base_minor: i64,
+    /// Against the baseline
+    pub closing_change_base_minor: i64,
+    /// Days where at least one account other than a debt one ends below zero
+    pub negative_day_count: usize,
+    pub first_negative_on: Option<NaiveDate>,
+
+    pub accounts: Vec<ScenarioAccountOutcomeResponse>,
+    pub negative_periods: Vec<ForecastNegativePeriodResponse>,
+    /// Only when asked for
+    pub forecast: Option<ForecastResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioComparisonResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub base_currency_code: String,
+    pub spending_account_id: Uuid,
+
+    pub baseline: ScenarioOutcomeResponse,
+    pub scenarios: Vec<ScenarioOutcomeResponse>,
+}
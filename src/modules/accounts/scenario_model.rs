@@ -0,0 +1,182 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    forecast_model::{ForecastEvent, ForecastSource},
+    scenario_command::ScenarioCreateCommand,
+    scenario_dto::ScenarioOverlayRequest,
+};
+use crate::modules::transactions::recurring_model::RecurringFrequency;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum ScenarioOverlayAction {
+    Add,
+    Remove,
+    Scale,
+}
+
+impl ScenarioOverlayAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScenarioOverlayAction::Add => "add",
+            ScenarioOverlayAction::Remove => "remove",
+            ScenarioOverlayAction::Scale => "scale",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum ScenarioOverlayTarget {
+    Recurring,
+    GoalContribution,
+    Purchase,
+    Project,
+    Category,
+}
+
+impl ScenarioOverlayTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScenarioOverlayTarget::Recurring => "recurring",
+            ScenarioOverlayTarget::GoalContribution => "goal_contribution",
+            ScenarioOverlayTarget::Purchase => "purchase",
+            ScenarioOverlayTarget::Project => "project",
+            ScenarioOverlayTarget::Category => "category",
+        }
+    }
+
+    /// Whether the forecast movement belongs to the target `target_id` of this kind.
+    /// A category covers its envelopes and its recurring transactions, a project its purchases.
+    pub fn matches(&self, target_id: Uuid, event: &ForecastEvent) -> bool {
+        match self {
+            ScenarioOverlayTarget::Recurring => event.source == ForecastSource::Recurring && event.item_id == Some(target_id),
+            ScenarioOverlayTarget::GoalContribution => event.source == ForecastSource::GoalContribution && event.item_id == Some(target_id),
+            ScenarioOverlayTarget::Purchase => event.source == ForecastSource::Purchase && event.item_id == Some(target_id),
+            ScenarioOverlayTarget::Project => event.source == ForecastSource::Purchase && event.group_id == Some(target_id),
+            ScenarioOverlayTarget::Category => matches!(event.source, ForecastSource::Recurring | ForecastSource::Envelope) && event.group_id == Some(target_id),
+        }
+    }
+}
+
+
+/// Named set of changes applied to the cashflow forecast, never to the real data
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Scenario {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Scenario {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<&ScenarioCreateCommand> for Scenario {
+    fn from(command: &ScenarioCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            name: command.scenario_name.trim().to_string(),
+            note: command.scenario_note.clone(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Change of a scenario, applied in `position` order.
+/// Added recurring transactions are signed in the account currency; added contributions and purchases
+/// are positive amounts in the base currency.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ScenarioOverlay {
+    pub id: Option<Vec<u8>>,
+
+    pub scenario_id: Vec<u8>,
+    pub position: i32,
+    pub action: ScenarioOverlayAction,
+    pub target_kind: ScenarioOverlayTarget,
+    pub target_id: Option<Vec<u8>>,
+
+    pub account_id: Option<Vec<u8>>,
+    pub label: Option<String>,
+    pub amount_minor: Option<i64>,
+    pub frequency: Option<RecurringFrequency>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    /// 80 keeps 80% of the amounts
+    pub scale_percent: Option<Decimal>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for ScenarioOverlay {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            scenario_id: row.try_get(index_map["scenario_id"])?,
+            position: row.try_get(index_map["position"])?,
+            action: row.try_get(index_map["overlay_action"])?,
+            target_kind: row.try_get(index_map["target_kind"])?,
+            target_id: row.try_get(index_map["target_id"])?,
+            account_id: row.try_get(index_map["account_id"])?,
+            label: row.try_get(index_map["label"])?,
+            amount_minor: row.try_get(index_map["amount_minor"])?,
+            frequency: row.try_get(index_map["frequency"])?,
+            start_date: row.try_get(index_map["start_date"])?,
+            end_date: row.try_get(index_map["end_date"])?,
+            scale_percent: row.try_get(index_map["scale_percent"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl ScenarioOverlay {
+    pub fn of_scenario(request: &ScenarioOverlayRequest, scenario_id: Uuid, position: i32) -> Self {
+        Self {
+            id: None,
+            scenario_id: ub(scenario_id),
+            position,
+            action: request.overlay_action,
+            target_kind: request.overlay_target,
+            target_id: oub(request.target_id),
+            account_id: oub(request.account_id),
+            label: request.overlay_label.as_ref().map(|label| label.trim().to_string()),
+            amount_minor: request.amount_minor,
+            frequency: request.frequency,
+            start_date: request.start_date,
+            end_date: request.end_date,
+            scale_percent: request.scale_percent,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::accounts::scenario_model::{Scenario, ScenarioOverlay};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait ScenarioRepositoryInterface {
+
+    async fn get(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Scenario>, Error>;
+
+    async fn create(&self, scenario: Scenario, meta_user: Option<Uuid>) -> Result<Scenario, Error>;
+
+    async fn update(&self, scenario: Scenario, meta_user: Option<Uuid>) -> Result<Option<Scenario>, Error>;
+
+    /// Removes the overlays with the scenario
+    async fn delete(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by name
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Scenario>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct ScenarioRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for ScenarioRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Scenario> for ScenarioRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ScenarioRepositoryInterface for ScenarioRepository {
+    async fn get(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Scenario>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(scenario_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_scenario_get_by_id", params).await
+    }
+
+    async fn create(&self, scenario: Scenario, meta_user: Option<Uuid>) -> Result<Scenario, Error> {
+        let params = vec![
+            MySqlParam::from(scenario.user_id),
+            MySqlParam::from(scenario.name),
+            MySqlParam::from(scenario.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_scenario_create", params).await
+    }
+
+    async fn update(&self, scenario: Scenario, meta_user: Option<Uuid>) -> Result<Option<Scenario>, Error> {
+        let params = vec![
+            MySqlParam::from(scenario.id),
+            MySqlParam::from(scenario.name),
+            MySqlParam::from(scenario.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_scenario_update", params).await
+    }
+
+    async fn delete(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(scenario_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_scenario_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Scenario>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_scenario_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait ScenarioOverlayRepositoryInterface {
+
+    async fn create(&self, overlay: ScenarioOverlay, meta_user: Option<Uuid>) -> Result<ScenarioOverlay, Error>;
+
+    /// Removes every overlay of the scenario before a new set is created
+    async fn delete_by_scenario(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by position
+    async fn get_by_scenario(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ScenarioOverlay>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct ScenarioOverlayRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for ScenarioOverlayRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<ScenarioOverlay> for ScenarioOverlayRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ScenarioOverlayRepositoryInterface for ScenarioOverlayRepository {
+    async fn create(&self, overlay: ScenarioOverlay, meta_user: Option<Uuid>) -> Result<ScenarioOverlay, Error> {
+        let params = vec![
+            MySqlParam::from(overlay.scenario_id),
+            MySqlParam::from(overlay.position),
+            MySqlParam::from(overlay.action.as_str()),
+            MySqlParam::from(overlay.target_kind.as_str()),
+            MySqlParam::from(overlay.target_id),
+            MySqlParam::from(overlay.account_id),
+            MySqlParam::from(overlay.label),
+            MySqlParam::from(overlay.amount_minor),
+            MySqlParam::from(overlay.frequency.map(|frequency| frequency.as_str())),
+            MySqlParam::from(overlay.start_date),
+            MySqlParam::from(overlay.end_date),
+            MySqlParam::from(overlay.scale_percent),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_scenario_overlay_create", params).await
+    }
+
+    async fn delete_by_scenario(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(scenario_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_scenario_overlay_delete_by_scenario", params).await
+    }
+
+    async fn get_by_scenario(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ScenarioOverlay>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(scenario_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_scenario_overlay_by_scenario", params).await
+    }
+}
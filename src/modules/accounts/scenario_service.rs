@@ -0,0 +1,378 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+use crate::modules::accounts::{
+    account_model::AccountType,
+    forecast_dto::ForecastResponse,
+    forecast_model::{ForecastEvent, ForecastSource},
+    forecast_service::{ForecastPlan, ForecastService},
+    scenario_command::*,
+    scenario_dto::*,
+    scenario_model::{Scenario, ScenarioOverlay, ScenarioOverlayAction, ScenarioOverlayTarget},
+    scenario_repo::{ScenarioOverlayRepository, ScenarioOverlayRepositoryInterface, ScenarioRepository, ScenarioRepositoryInterface},
+};
+use crate::modules::goals::goal_repo::{GoalRepository, GoalRepositoryInterface};
+use crate::modules::projects::{
+    purchase_model::PurchaseStatus,
+    purchase_repo::{PurchaseRepository, PurchaseRepositoryInterface},
+};
+use crate::modules::transactions::recurring_model::RecurringFrequency;
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::bu;
+
+
+/// Scenarios compared against the baseline by a single request, at most
+const MAX_COMPARED_SCENARIOS: usize = 5;
+
+#[async_trait]
+pub trait ScenarioServiceInterface {
+
+    async fn get_by_id(&self, command: ScenarioGetCommand) -> Result<Option<ScenarioResponse>, Error>;
+
+    async fn create(&self, command: ScenarioCreateCommand) -> Result<ScenarioResponse, Error>;
+
+    async fn update(&self, command: ScenarioUpdateCommand) -> Result<Option<ScenarioResponse>, Error>;
+
+    async fn delete(&self, command: ScenarioDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: ScenarioListByUserCommand) -> Result<Vec<ScenarioResponse>, Error>;
+
+    /// Baseline forecast and the forecast of each scenario, side by side.
+    /// None when one of the scenarios does not exist.
+    async fn compare(&self, command: ScenarioCompareCommand) -> Result<Option<ScenarioComparisonResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct ScenarioService {
+    scenario_repo: ScenarioRepository,
+    overlay_repo: ScenarioOverlayRepository,
+    forecast_service: ForecastService,
+    goal_repo: GoalRepository,
+    purchase_repo: PurchaseRepository,
+}
+
+impl From<&AppState> for ScenarioService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            scenario_repo: ScenarioRepository::from(app_state),
+            overlay_repo: ScenarioOverlayRepository::from(app_state),
+            forecast_service: ForecastService::from(app_state),
+            goal_repo: GoalRepository::from(app_state),
+            purchase_repo: PurchaseRepository::from(app_state),
+        }
+    }
+}
+
+impl ScenarioService {
+    fn validate(name: &str, overlays: &[ScenarioOverlayRequest]) -> Result<(), Error> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest("scenario name is required".into()).into());
+        }
+        for overlay in overlays {
+            if overlay.end_date.is_some_and(|end_date| overlay.start_date.is_some_and(|start_date| end_date < start_date)) {
+                return Err(AppError::BadRequest("overlay end date must be after its start date".into()).into());
+            }
+            let amount = overlay.amount_minor.unwrap_or(0);
+            let message = match (overlay.overlay_action, overlay.overlay_target) {
+                (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Recurring) if overlay.account_id.is_none() || amount == 0 || overlay.start_date.is_none() =>
+                    "an added recurring transaction needs an account, a non zero amount and a start date",
+                (ScenarioOverlayAction::Add, ScenarioOverlayTarget::GoalContribution) if overlay.target_id.is_none() || amount <= 0 || overlay.start_date.is_none() =>
+                    "an added contribution needs a goal, a positive amount and a start date",
+                (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Purchase) if amount <= 0 || overlay.start_date.is_none() =>
+                    "an added purchase needs a positive amount and a date",
+                (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Project) if overlay.target_id.is_none() =>
+                    "a funded project needs its project",
+                (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Category) =>
+                    "a category can only be removed or scaled",
+                (ScenarioOverlayAction::Remove | ScenarioOverlayAction::Scale, _) if overlay.target_id.is_none() =>
+                    "a removed or scaled overlay needs its target",
+                (ScenarioOverlayAction::Scale, _) if overlay.scale_percent.is_none_or(|scale_percent| scale_percent < Decimal::ZERO) =>
+                    "a scaled overlay needs a percentage of at least 0",
+                _ => continue,
+            };
+            return Err(AppError::BadRequest(message.into()).into());
+        }
+        Ok(())
+    }
+
+    async fn overlays(&self, scenario_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ScenarioOverlay>, Error> {
+        match self.overlay_repo.get_by_scenario(scenario_id, meta_user).await {
+            Ok(overlays) => Ok(overlays),
+            Err(_) => Err(Error::msg("Error getting scenario overlays")),
+        }
+    }
+
+    /// Replaces the overlays of the scenario, keeping the order of the request
+    async fn set_overlays(&self, scenario_id: Uuid, requests: &[ScenarioOverlayRequest], meta_user: Option<Uuid>) -> Result<Vec<ScenarioOverlay>, Error> {
+        if self.overlay_repo.delete_by_scenario(scenario_id, meta_user).await.is_err() {
+            return Err(Error::msg("Error deleting scenario overlays"));
+        }
+        let mut overlays = Vec::new();
+        for (position, request) in requests.iter().enumerate() {
+            match self.overlay_repo.create(ScenarioOverlay::of_scenario(request, scenario_id, position as i32), meta_user).await {
+                Ok(overlay) => overlays.push(overlay),
+                Err(_) => return Err(Error::msg("Error creating scenario overlay")),
+            }
+        }
+        Ok(overlays)
+    }
+
+    fn scaled(amount_minor: i64, scale_percent: Decimal) -> i64 {
+        (Decimal::from(amount_minor) * scale_percent / Decimal::ONE_HUNDRED).round().to_i64().unwrap_or(0)
+    }
+
+    /// Account of an added movement: the one of the overlay, else the spending account
+    fn account_of(plan: &ForecastPlan, overlay: &ScenarioOverlay) -> Result<Uuid, Error> {
+        let account_id = overlay.account_id.as_deref().map(bu).unwrap_or(plan.spending_account_id);
+        match plan.account(account_id) {
+            Some(_) => Ok(account_id),
+            None => Err(AppError::BadRequest("overlay account must be an active account".into()).into()),
+        }
+    }
+
+    /// Applies one overlay to the movements of the plan
+    async fn apply(&self, plan: &mut ForecastPlan, overlay: &ScenarioOverlay, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let target_id = overlay.target_id.as_deref().map(bu);
+        let matches = |event: &ForecastEvent| target_id.is_some_and(|target_id| overlay.target_kind.matches(target_id, event));
+        let start_date = overlay.start_date.unwrap_or(plan.from);
+        let amount_minor = overlay.amount_minor.unwrap_or(0);
+
+        match (overlay.action, overlay.target_kind) {
+            (ScenarioOverlayAction::Remove, _) => plan.events.retain(|event| !matches(event)),
+            (ScenarioOverlayAction::Scale, _) => {
+                let scale_percent = overlay.scale_percent.unwrap_or(Decimal::ONE_HUNDRED);
+                for event in plan.events.iter_mut().filter(|event| matches(event)) {
+                    event.amount_minor = Self::scaled(event.amount_minor, scale_percent);
+                }
+            },
+            (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Recurring) => {
+                let account_id = Self::account_of(plan, overlay)?;
+                let frequency = overlay.frequency.unwrap_or(RecurringFrequency::Monthly);
+                for date in frequency.dates(start_date, overlay.end_date, plan.from, plan.to) {
+                    plan.events.push(ForecastEvent {
+                        date,
+                        account_id,
+                        amount_minor,
+                        source: ForecastSource::Recurring,
+                        label: overlay.label.clone().unwrap_or_default(),
+                        item_id: None,
+                        group_id: None,
+                    });
+                }
+            },
+            (ScenarioOverlayAction::Add, ScenarioOverlayTarget::GoalContribution) => {
+                let Some(goal_id) = target_id else {
+                    return Ok(());
+                };
+                let goal = match self.goal_repo.get(goal_id, meta_user).await {
+                    Ok(Some(goal)) => goal,
+                    Ok(None) => return Err(AppError::BadRequest("overlay goal not found".into()).into()),
+                    Err(_) => return Err(Error::msg("Error getting goal")),
+                };
+                let from_account_id = Self::account_of(plan, overlay)?;
+                let label = overlay.label.clone().unwrap_or(goal.name);
+                for date in RecurringFrequency::Monthly.dates(start_date, overlay.end_date, plan.from, plan.to) {
+                    plan.push_contribution(goal_id, Some(from_account_id), goal.linked_account_id.as_deref().map(bu), amount_minor, date, &label)?;
+                }
+            },
+            (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Purchase) => {
+                let account_id = Self::account_of(plan, overlay)?;
+                if start_date <= plan.to && let Some(account) = plan.account(account_id) {
+                    let label = overlay.label.clone().unwrap_or_default();
+                    let event = plan.base_event(account, -amount_minor, start_date.max(plan.from), ForecastSource::Purchase, &label)?;
+                    plan.events.push(event);
+                }
+            },
+            (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Project) => {
+                let Some(project_id) = target_id else {
+                    return Ok(());
+                };
+                let purchases = match self.purchase_repo.get_by_project(project_id, meta_user).await {
+                    Ok(purchases) => purchases,
+                    Err(_) => return Err(Error::msg("Error getting purchases")),
+                };
+                let account_id = Self::account_of(plan, overlay)?;
+                for purchase in purchases.iter().filter(|purchase| purchase.status != PurchaseStatus::Bought) {
+                    let purchase_id = purchase.id.as_deref().map(bu);
+                    let date = purchase.planned_date.unwrap_or(start_date).max(plan.from);
+                    let planned = plan.events.iter().any(|event| event.source == ForecastSource::Purchase && event.item_id == purchase_id);
+                    if planned || date > plan.to {
+                        continue;
+                    }
+                    if let Some(account) = plan.account(account_id) {
+                        let event = plan.base_event(account, -purchase.estimated_price_base_minor, date, ForecastSource::Purchase, &purchase.title)?
+                            .of_item(purchase_id, Some(project_id));
+                        plan.events.push(event);
+                    }
+                }
+            },
+            (ScenarioOverlayAction::Add, ScenarioOverlayTarget::Category) => {},
+        }
+        Ok(())
+    }
+
+    /// Outcome of a forecast, with its changes against the baseline when there is one
+    fn outcome(plan: &ForecastPlan, forecast: ForecastResponse, scenario: Option<&Scenario>, baseline: Option<&ScenarioOutcomeResponse>, include_days: bool) -> Result<ScenarioOutcomeResponse, Error> {
+        let baseline_accounts: HashMap<Uuid, &ScenarioAccountOutcomeResponse> = baseline
+            .map(|baseline| baseline.accounts.iter().map(|account| (account.account_id, account)).collect())
+            .unwrap_or_default();
+
+        let mut closing_total = Decimal::ZERO;
+        let mut negative_days = BTreeSet::new();
+        let mut accounts = Vec::new();
+        for (account, projection) in plan.accounts.iter().zip(&forecast.accounts) {
+            let closing = Decimal::from(projection.closing_balance_minor) / scale_factor(account.minor_unit);
+            let Some(closing_base) = plan.fx.to_base(closing, &account.currency_code, plan.to) else {
+                return Err(AppError::BadRequest(format!("missing fx rate {}/{}", plan.fx.base_code, account.currency_code)).into());
+            };
+            closing_total += closing_base;
+            if account.account_type != AccountType::Debt {
+                negative_days.extend(projection.days.iter().filter(|day| day.balance_minor < 0).map(|day| day.date));
+            }
+
+            let baseline_account = baseline_accounts.get(&projection.account_id);
+            accounts.push(ScenarioAccountOutcomeResponse {
+                account_id: projection.account_id,
+                account_name: projection.account_name.clone(),
+                currency_code: projection.currency_code.clone(),
+                closing_balance_minor: projection.closing_balance_minor,
+                closing_change_minor: baseline_account.map_or(0, |baseline_account| projection.closing_balance_minor - baseline_account.closing_balance_minor),
+                lowest_balance_minor: projection.lowest_balance_minor,
+                lowest_change_minor: baseline_account.map_or(0, |baseline_account| projection.lowest_balance_minor - baseline_account.lowest_balance_minor),
+                first_negative_on: projection.first_negative_on,
+            });
+        }
+        let closing_total_base_minor = (closing_total * plan.base_scale).round().to_i64().unwrap_or(0);
+        let first_negative_on: Option<NaiveDate> = negative_days.first().copied();
+
+        Ok(ScenarioOutcomeResponse {
+            scenario_id: scenario.and_then(|scenario| scenario.id.as_deref()).map(bu),
+            scenario_name: scenario.map_or_else(|| "Baseline".to_string(), |scenario| scenario.name.clone()),
+            closing_total_base_minor,
+            closing_change_base_minor: baseline.map_or(0, |baseline| closing_total_base_minor - baseline.closing_total_base_minor),
+            negative_day_count: negative_days.len(),
+            first_negative_on,
+            accounts,
+            negative_periods: forecast.negative_periods.clone(),
+            forecast: include_days.then_some(forecast),
+        })
+    }
+}
+
+#[async_trait]
+impl ScenarioServiceInterface for ScenarioService {
+    async fn get_by_id(&self, command: ScenarioGetCommand) -> Result<Option<ScenarioResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let scenario = match self.scenario_repo.get(command.scenario_id, meta_user).await {
+            Ok(Some(scenario)) => scenario,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting scenario")),
+        };
+        let overlays = self.overlays(command.scenario_id, meta_user).await?;
+        Ok(Some(ScenarioResponse::of(&scenario, &overlays)))
+    }
+
+    async fn create(&self, command: ScenarioCreateCommand) -> Result<ScenarioResponse, Error> {
+        Self::validate(&command.scenario_name, &command.scenario_overlays)?;
+        let meta_user = Some(command.auth_user.user_id);
+
+        let scenario = match self.scenario_repo.create(Scenario::from(&command), meta_user).await {
+            Ok(scenario) => scenario,
+            Err(_) => return Err(Error::msg("Error creating scenario")),
+        };
+        let overlays = self.set_overlays(bu(scenario.id.as_deref().unwrap()), &command.scenario_overlays, meta_user).await?;
+        Ok(ScenarioResponse::of(&scenario, &overlays))
+    }
+
+    async fn update(&self, command: ScenarioUpdateCommand) -> Result<Option<ScenarioResponse>, Error> {
+        Self::validate(&command.scenario_name, &command.scenario_overlays)?;
+        let meta_user = Some(command.auth_user.user_id);
+
+        let mut scenario = match self.scenario_repo.get(command.scenario_id, meta_user).await {
+            Ok(Some(scenario)) => scenario,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting scenario")),
+        };
+        scenario.name = command.scenario_name.trim().to_string();
+        scenario.note = command.scenario_note;
+
+        let scenario = match self.scenario_repo.update(scenario, meta_user).await {
+            Ok(Some(scenario)) => scenario,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error updating scenario")),
+        };
+        let overlays = self.set_overlays(command.scenario_id, &command.scenario_overlays, meta_user).await?;
+        Ok(Some(ScenarioResponse::of(&scenario, &overlays)))
+    }
+
+    async fn delete(&self, command: ScenarioDeleteCommand) -> Result<(), Error> {
+        match self.scenario_repo.delete(command.scenario_id, Some(command.auth_user.user_id)).await {
+            Ok(()) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting scenario")),
+        }
+    }
+
+    async fn get_by_user(&self, command: ScenarioListByUserCommand) -> Result<Vec<ScenarioResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let scenarios = match self.scenario_repo.get_by_user(command.user_id, meta_user).await {
+            Ok(scenarios) => scenarios,
+            Err(_) => return Err(Error::msg("Error getting scenarios")),
+        };
+
+        let mut responses = Vec::new();
+        for scenario in &scenarios {
+            let overlays = self.overlays(bu(scenario.id.as_deref().unwrap()), meta_user).await?;
+            responses.push(ScenarioResponse::of(scenario, &overlays));
+        }
+        Ok(responses)
+    }
+
+    async fn compare(&self, command: ScenarioCompareCommand) -> Result<Option<ScenarioComparisonResponse>, Error> {
+        if command.scenario_ids.len() > MAX_COMPARED_SCENARIOS {
+            return Err(AppError::BadRequest(format!("at most {} scenarios can be compared", MAX_COMPARED_SCENARIOS)).into());
+        }
+        let meta_user = Some(command.auth_user.user_id);
+
+        let mut scenarios = Vec::new();
+        for scenario_id in &command.scenario_ids {
+            let scenario = match self.scenario_repo.get(*scenario_id, meta_user).await {
+                Ok(Some(scenario)) => scenario,
+                Ok(None) => return Ok(None),
+                Err(_) => return Err(Error::msg("Error getting scenario")),
+            };
+            let overlays = self.overlays(*scenario_id, meta_user).await?;
+            scenarios.push((scenario, overlays));
+        }
+
+        let plan = self.forecast_service.plan(command.months, command.spending_account_id, &command.auth_user).await?;
+        let baseline = Self::outcome(&plan, ForecastService::project(&plan), None, None, command.include_days)?;
+
+        let mut outcomes = Vec::new();
+        for (scenario, overlays) in &scenarios {
+            let mut scenario_plan = plan.clone();
+            for overlay in overlays {
+                self.apply(&mut scenario_plan, overlay, meta_user).await?;
+            }
+            scenario_plan.events.sort_by_key(|event| event.date);
+            let forecast = ForecastService::project(&scenario_plan);
+            outcomes.push(Self::outcome(&scenario_plan, forecast, Some(scenario), Some(&baseline), command.include_days)?);
+        }
+
+        Ok(Some(ScenarioComparisonResponse {
+            from: plan.from,
+            to: plan.to,
+            base_currency_code: plan.fx.base_code.clone(),
+            spending_account_id: plan.spending_account_id,
+            baseline,
+            scenarios: outcomes,
+        }))
+    }
+}
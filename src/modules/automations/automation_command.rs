@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::automations::automation_dto::{
+    AutomationAction, AutomationRuleCreateRequest, AutomationRuleUpdateRequest, AutomationTrigger
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+// --- Rule ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRuleGetCommand {
+    pub rule_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRuleGetCommand {
+    pub fn new(rule_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { rule_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRuleCreateCommand {
+    pub user_id: Uuid,
+    pub automation_name: String,
+    pub automation_enabled: bool,
+    pub automation_trigger: AutomationTrigger,
+    pub automation_action: AutomationAction,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRuleCreateCommand {
+    pub fn new(request: AutomationRuleCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            automation_name: request.automation_name,
+            automation_enabled: request.automation_enabled.unwrap_or(true),
+            automation_trigger: request.automation_trigger,
+            automation_action: request.automation_action,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRuleUpdateCommand {
+    pub rule_id: Uuid,
+
+    pub automation_name: String,
+    pub automation_enabled: bool,
+    pub automation_trigger: AutomationTrigger,
+    pub automation_action: AutomationAction,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRuleUpdateCommand {
+    pub fn new(rule_id: Uuid, request: AutomationRuleUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            rule_id,
+            automation_name: request.automation_name,
+            automation_enabled: request.automation_enabled,
+            automation_trigger: request.automation_trigger,
+            automation_action: request.automation_action,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRuleDeleteCommand {
+    pub rule_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRuleDeleteCommand {
+    pub fn new(rule_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { rule_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRuleListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRuleListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+
+// --- Runs ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRunCommand {
+    pub user_id: Uuid,
+    /// None runs every enabled rule of the user
+    pub rule_id: Option<Uuid>,
+    pub date: NaiveDate,
+    pub dry_run: bool,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRunCommand {
+    pub fn new(rule_id: Option<Uuid>, date: NaiveDate, dry_run: bool, auth_user: AuthUser) -> Self {
+        Self { user_id: auth_user.user_id, rule_id, date, dry_run, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomationRunListCommand {
+    pub rule_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AutomationRunListCommand {
+    pub fn new(rule_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { rule_id, auth_user }
+    }
+}
@@ -0,0 +1,267 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::automations::{
+    automation_command::*,
+    automation_dto::*,
+    automation_service::{AutomationService, AutomationServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_automations).post(post_automation))
+        .route("/run", post(post_automations_run))
+        .route("/{rule_id}", get(get_automation).put(put_automation).delete(delete_automation))
+        .route("/{rule_id}/run", post(post_automation_run))
+        .route("/{rule_id}/runs", get(get_automation_runs))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/automations",
+    responses(
+        (status = StatusCode::OK, description = "Automation rules of current user", body = Vec<AutomationRuleResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn get_automations(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<AutomationRuleResponse>>, StatusCode> {
+    let command = AutomationRuleListByUserCommand::new(auth_user.user_id, auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let rules = automation_service.get_by_user(command).await;
+    match rules {
+        Ok(rules) => Ok(Json(rules)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/automations",
+    responses(
+        (status = StatusCode::OK, description = "Automation rule successfully created", body = AutomationRuleResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Incomplete trigger or action"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn post_automation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(rule_create_request): Json<AutomationRuleCreateRequest>
+) -> Result<Json<AutomationRuleResponse>, StatusCode> {
+    let command = AutomationRuleCreateCommand::new(rule_create_request, auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let rule = automation_service.create(command).await;
+    match rule {
+        Ok(rule) => Ok(Json(rule)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/automations/{rule_id}",
+    params(
+        ("rule_id", description = "automation rule identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Automation rule found successfully", body = AutomationRuleResponse),
+        (status = StatusCode::NOT_FOUND, description = "Automation rule not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn get_automation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(rule_id): Path<Uuid>,
+) -> Result<Json<AutomationRuleResponse>, StatusCode> {
+    let command = AutomationRuleGetCommand::new(rule_id, auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let rule = automation_service.get(command).await;
+    match rule {
+        Ok(rule) => {
+            match rule {
+                Some(rule) => Ok(Json(rule)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/automations/{rule_id}",
+    params(
+        ("rule_id", description = "automation rule identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Automation rule successfully updated", body = AutomationRuleResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Incomplete trigger or action"),
+        (status = StatusCode::NOT_FOUND, description = "Automation rule not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn put_automation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(rule_id): Path<Uuid>,
+    Json(rule_update_request): Json<AutomationRuleUpdateRequest>
+) -> Result<Json<AutomationRuleResponse>, StatusCode> {
+    let command = AutomationRuleUpdateCommand::new(rule_id, rule_update_request, auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let rule = automation_service.update(command).await;
+    match rule {
+        Ok(rule) => {
+            match rule {
+                Some(rule) => Ok(Json(rule)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/automations/{rule_id}",
+    params(
+        ("rule_id", description = "automation rule identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Automation rule deleted with its execution log"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn delete_automation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(rule_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = AutomationRuleDeleteCommand::new(rule_id, auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let response = automation_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/automations/run",
+    responses(
+        (status = StatusCode::OK, description = "Outcome of every enabled rule triggered on the date", body = Vec<AutomationRunResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn post_automations_run(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(run_request): Json<AutomationRunRequest>
+) -> Result<Json<Vec<AutomationRunResponse>>, StatusCode> {
+    let date = run_request.date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let command = AutomationRunCommand::new(None, date, run_request.dry_run.unwrap_or(false), auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let runs = automation_service.run(command).await;
+    match runs {
+        Ok(runs) => {
+            match runs {
+                Some(runs) => Ok(Json(runs)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/automations/{rule_id}/run",
+    params(
+        ("rule_id", description = "automation rule identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Outcome of the rule for each of its triggers on the date", body = Vec<AutomationRunResponse>),
+        (status = StatusCode::BAD_REQUEST, description = "Rule disabled and not a dry run"),
+        (status = StatusCode::NOT_FOUND, description = "Automation rule not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn post_automation_run(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(rule_id): Path<Uuid>,
+    Json(run_request): Json<AutomationRunRequest>
+) -> Result<Json<Vec<AutomationRunResponse>>, StatusCode> {
+    let date = run_request.date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let command = AutomationRunCommand::new(Some(rule_id), date, run_request.dry_run.unwrap_or(false), auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let runs = automation_service.run(command).await;
+    match runs {
+        Ok(runs) => {
+            match runs {
+                Some(runs) => Ok(Json(runs)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/automations/{rule_id}/runs",
+    params(
+        ("rule_id", description = "automation rule identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Execution log of the rule, latest first", body = Vec<AutomationRunResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Automation"
+)]
+pub async fn get_automation_runs(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(rule_id): Path<Uuid>,
+) -> Result<Json<Vec<AutomationRunResponse>>, StatusCode> {
+    let command = AutomationRunListCommand::new(rule_id, auth_user);
+    let automation_service = AutomationService::from(&state);
+
+    let runs = automation_service.list_runs(command).await;
+    match runs {
+        Ok(runs) => Ok(Json(runs)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
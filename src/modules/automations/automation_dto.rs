@@ -0,0 +1,154 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::automations::automation_model::{
+    AutomationActionType, AutomationRule, AutomationRun, AutomationRunStatus, AutomationTriggerType
+};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AutomationTrigger {
+    pub trigger_type: AutomationTriggerType,
+
+    /// Income trigger: payee of the transaction
+    pub trigger_payee_id: Option<Uuid>,
+    /// Income trigger: category of the transaction
+    pub trigger_category_id: Option<Uuid>,
+    /// Income trigger: ignore smaller incomes
+    pub trigger_min_amount_base_minor: Option<i64>,
+    /// Day-of-month trigger: 1..31, clamped to the end of short months
+    pub trigger_day_of_month: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AutomationAction {
+    pub action_type: AutomationActionType,
+
+    /// Fixed amount
+    pub action_amount_base_minor: Option<i64>,
+    /// Or a percentage (0-100) of the triggering income
+    pub action_percent: Option<Decimal>,
+
+    /// Transfer: source and destination accounts
+    pub action_from_account_id: Option<Uuid>,
+    pub action_to_account_id: Option<Uuid>,
+    /// Goal contribution: goal receiving the planned contribution
+    pub action_goal_id: Option<Uuid>,
+    /// Envelope allocation: category whose envelope of the month is topped up
+    pub action_category_id: Option<Uuid>,
+    /// Notification text, also used as note on created transfers and contributions
+    pub action_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AutomationRuleResponse {
+    pub automation_id: Uuid,
+    pub user_id: Uuid,
+    pub automation_name: String,
+    pub automation_enabled: bool,
+
+    pub automation_trigger: AutomationTrigger,
+    pub automation_action: AutomationAction,
+
+    pub automation_last_run_at: Option<DateTime<Utc>>,
+    pub automation_created_at: Option<DateTime<Utc>>,
+    pub automation_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&AutomationRule> for AutomationRuleResponse {
+    fn from(rule: &AutomationRule) -> Self {
+        Self {
+            automation_id: bu(rule.id.clone().unwrap().as_slice()),
+            user_id: bu(rule.user_id.as_slice()),
+            automation_name: rule.name.clone(),
+            automation_enabled: rule.enabled,
+            automation_trigger: AutomationTrigger {
+                trigger_type: rule.trigger_type,
+                trigger_payee_id: obu(rule.trigger_payee_id.as_deref()),
+                trigger_category_id: obu(rule.trigger_category_id.as_deref()),
+                trigger_min_amount_base_minor: rule.trigger_min_amount_base_minor,
+                trigger_day_of_month: rule.trigger_day_of_month,
+            },
+            automation_action: AutomationAction {
+                action_type: rule.action_type,
+                action_amount_base_minor: rule.action_amount_base_minor,
+                action_percent: rule.action_percent,
+                action_from_account_id: obu(rule.action_from_account_id.as_deref()),
+                action_to_account_id: obu(rule.action_to_account_id.as_deref()),
+                action_goal_id: obu(rule.action_goal_id.as_deref()),
+                action_category_id: obu(rule.action_category_id.as_deref()),
+                action_message: rule.action_message.clone(),
+            },
+            automation_last_run_at: rule.last_run_at,
+            automation_created_at: rule.created_at,
+            automation_updated_at: rule.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AutomationRuleCreateRequest {
+    pub automation_name: String,
+    pub automation_trigger: AutomationTrigger,
+    pub automation_action: AutomationAction,
+
+    /// Enabled by default
+    pub automation_enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AutomationRuleUpdateRequest {
+    pub automation_name: String,
+    pub automation_enabled: bool,
+    pub automation_trigger: AutomationTrigger,
+    pub automation_action: AutomationAction,
+}
+
+
+// --- Runs ---
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AutomationRunRequest {
+    /// Day to evaluate (today by default)
+    pub date: Option<NaiveDate>,
+    /// Report what would be done without executing nor logging anything
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AutomationRunResponse {
+    /// None for dry runs and skipped triggers, which are not logged
+    pub run_id: Option<Uuid>,
+    pub automation_id: Uuid,
+
+    pub run_trigger_transaction_id: Option<Uuid>,
+    pub run_trigger_date: NaiveDate,
+    pub run_status: AutomationRunStatus,
+    pub run_amount_base_minor: Option<i64>,
+    pub run_result_entity_type: Option<String>,
+    pub run_result_entity_id: Option<Uuid>,
+    pub run_message: Option<String>,
+
+    pub run_created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&AutomationRun> for AutomationRunResponse {
+    fn from(run: &AutomationRun) -> Self {
+        Self {
+            run_id: obu(run.id.as_deref()),
+            automation_id: bu(run.rule_id.as_slice()),
+            run_trigger_transaction_id: obu(run.trigger_transaction_id.as_deref()),
+            run_trigger_date: run.trigger_date,
+            run_status: run.status,
+            run_amount_base_minor: run.amount_base_minor,
+            run_result_entity_type: run.result_entity_type.clone(),
+            run_result_entity_id: obu(run.result_entity_id.as_deref()),
+            run_message: run.message.clone(),
+            run_created_at: run.created_at,
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::automations::{
+    automation_command::{AutomationRuleCreateCommand, AutomationRuleUpdateCommand},
+    automation_dto::{AutomationAction, AutomationTrigger},
+};
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AutomationTriggerType {
+    /// An income transaction matching a payee and/or a category
+    IncomeTransaction,
+    /// A fixed day of every month
+    DayOfMonth,
+}
+
+impl AutomationTriggerType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutomationTriggerType::IncomeTransaction => "income_transaction",
+            AutomationTriggerType::DayOfMonth => "day_of_month",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AutomationActionType {
+    Transfer,
+    GoalContribution,
+    EnvelopeAllocation,
+    Notification,
+}
+
+impl AutomationActionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutomationActionType::Transfer => "transfer",
+            AutomationActionType::GoalContribution => "goal_contribution",
+            AutomationActionType::EnvelopeAllocation => "envelope_allocation",
+            AutomationActionType::Notification => "notification",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AutomationRunStatus {
+    Success,
+    Failed,
+    /// Already executed for the same trigger
+    Skipped,
+    /// Dry run: what would have been done, nothing saved
+    DryRun,
+}
+
+impl AutomationRunStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutomationRunStatus::Success => "success",
+            AutomationRunStatus::Failed => "failed",
+            AutomationRunStatus::Skipped => "skipped",
+            AutomationRunStatus::DryRun => "dry_run",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AutomationRule {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub enabled: bool,
+
+    pub trigger_type: AutomationTriggerType,
+    pub trigger_payee_id: Option<Vec<u8>>,
+    pub trigger_category_id: Option<Vec<u8>>,
+    pub trigger_min_amount_base_minor: Option<i64>,
+    pub trigger_day_of_month: Option<u8>,
+
+    pub action_type: AutomationActionType,
+    pub action_amount_base_minor: Option<i64>,
+    pub action_percent: Option<Decimal>,
+    pub action_from_account_id: Option<Vec<u8>>,
+    pub action_to_account_id: Option<Vec<u8>>,
+    pub action_goal_id: Option<Vec<u8>>,
+    pub action_category_id: Option<Vec<u8>>,
+    pub action_message: Option<String>,
+
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for AutomationRule {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            enabled: row.try_get(index_map["enabled"])?,
+            trigger_type: row.try_get(index_map["trigger_type"])?,
+            trigger_payee_id: row.try_get(index_map["trigger_payee_id"])?,
+            trigger_category_id: row.try_get(index_map["trigger_category_id"])?,
+            trigger_min_amount_base_minor: row.try_get(index_map["trigger_min_amount_base_minor"])?,
+            trigger_day_of_month: row.try_get(index_map["trigger_day_of_month"])?,
+            action_type: row.try_get(index_map["action_type"])?,
+            action_amount_base_minor: row.try_get(index_map["action_amount_base_minor"])?,
+            action_percent: row.try_get(index_map["action_percent"])?,
+            action_from_account_id: row.try_get(index_map["action_from_account_id"])?,
+            action_to_account_id: row.try_get(index_map["action_to_account_id"])?,
+            action_goal_id: row.try_get(index_map["action_goal_id"])?,
+            action_category_id: row.try_get(index_map["action_category_id"])?,
+            action_message: row.try_get(index_map["action_message"])?,
+            last_run_at: row.try_get(index_map["last_run_at"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl AutomationRule {
+    fn from_parts(user_id: Uuid, name: String, enabled: bool, trigger: AutomationTrigger, action: AutomationAction) -> Self {
+        Self {
+            id: None,
+            user_id: ub(user_id),
+            name,
+            enabled,
+            trigger_type: trigger.trigger_type,
+            trigger_payee_id: oub(trigger.trigger_payee_id),
+            trigger_category_id: oub(trigger.trigger_category_id),
+            trigger_min_amount_base_minor: trigger.trigger_min_amount_base_minor,
+            trigger_day_of_month: trigger.trigger_day_of_month,
+            action_type: action.action_type,
+            action_amount_base_minor: action.action_amount_base_minor,
+            action_percent: action.action_percent,
+            action_from_account_id: oub(action.action_from_account_id),
+            action_to_account_id: oub(action.action_to_account_id),
+            action_goal_id: oub(action.action_goal_id),
+            action_category_id: oub(action.action_category_id),
+            action_message: action.action_message,
+            last_run_at: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl From<AutomationRuleCreateCommand> for AutomationRule {
+    fn from(command: AutomationRuleCreateCommand) -> Self {
+        Self::from_parts(
+            command.user_id,
+            command.automation_name,
+            command.automation_enabled,
+            command.automation_trigger,
+            command.automation_action
+        )
+    }
+}
+
+impl From<AutomationRuleUpdateCommand> for AutomationRule {
+    fn from(command: AutomationRuleUpdateCommand) -> Self {
+        Self {
+            id: Some(ub(command.rule_id)),
+            ..Self::from_parts(
+                command.auth_user.user_id,
+                command.automation_name,
+                command.automation_enabled,
+                command.automation_trigger,
+                command.automation_action
+            )
+        }
+    }
+}
+
+
+/// Execution log entry of a rule (only real runs are logged)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AutomationRun {
+    pub id: Option<Vec<u8>>,
+
+    pub rule_id: Vec<u8>,
+    pub user_id: Vec<u8>,
+    pub trigger_transaction_id: Option<Vec<u8>>,
+    pub trigger_date: NaiveDate,
+
+    pub status: AutomationRunStatus,
+    pub amount_base_minor: Option<i64>,
+    pub result_entity_type: Option<String>,
+    pub result_entity_id: Option<Vec<u8>>,
+    pub message: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for AutomationRun {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            rule_id: row.try_get(index_map["rule_id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            trigger_transaction_id: row.try_get(index_map["trigger_transaction_id"])?,
+            trigger_date: row.try_get(index_map["trigger_date"])?,
+            status: row.try_get(index_map["status"])?,
+            amount_base_minor: row.try_get(index_map["amount_base_minor"])?,
+            result_entity_type: row.try_get(index_map["result_entity_type"])?,
+            result_entity_id: row.try_get(index_map["result_entity_id"])?,
+            message: row.try_get(index_map["message"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+        })
+    }
+}
+
+
+/// Income transaction candidate for `income_transaction` triggers (positive base amount)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AutomationTriggerTransaction {
+    pub id: Vec<u8>,
+
+    pub account_id: Vec<u8>,
+    pub occurred_at: DateTime<Utc>,
+    pub base_amount_minor: i64,
+    pub payee_id: Option<Vec<u8>>,
+    pub category_id: Option<Vec<u8>>,
+}
+
+impl FromSqlRow for AutomationTriggerTransaction {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            account_id: row.try_get(index_map["account_id"])?,
+            occurred_at: row.try_get(index_map["occurred_at"])?,
+            base_amount_minor: row.try_get(index_map["base_amount_minor"])?,
+            payee_id: row.try_get(index_map["payee_id"])?,
+            category_id: row.try_get(index_map["category_id"])?,
+        })
+    }
+}
+
+
+/// Transfer created by an action: both legs (outflow on `from`, inflow on `to`) in base currency.
+/// The leg ids are filled by the procedure.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AutomationTransfer {
+    pub user_id: Vec<u8>,
+    pub from_account_id: Vec<u8>,
+    pub to_account_id: Vec<u8>,
+    pub amount_base_minor: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub note: Option<String>,
+
+    pub out_transaction_id: Option<Vec<u8>>,
+    pub in_transaction_id: Option<Vec<u8>>,
+}
+
+impl FromSqlRow for AutomationTransfer {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            user_id: row.try_get(index_map["user_id"])?,
+            from_account_id: row.try_get(index_map["from_account_id"])?,
+            to_account_id: row.try_get(index_map["to_account_id"])?,
+            amount_base_minor: row.try_get(index_map["amount_base_minor"])?,
+            occurred_at: row.try_get(index_map["occurred_at"])?,
+            note: row.try_get(index_map["note"])?,
+            out_transaction_id: row.try_get(index_map["out_transaction_id"])?,
+            in_transaction_id: row.try_get(index_map["in_transaction_id"])?,
+        })
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::automations::automation_model::{
+    AutomationRule, AutomationRun, AutomationTransfer, AutomationTriggerTransaction
+};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait AutomationRuleRepositoryInterface {
+
+    async fn get(&self, rule_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<AutomationRule>, Error>;
+
+    async fn create(&self, rule: AutomationRule, meta_user: Option<Uuid>) -> Result<AutomationRule, Error>;
+
+    async fn update(&self, rule: AutomationRule, meta_user: Option<Uuid>) -> Result<Option<AutomationRule>, Error>;
+
+    async fn delete(&self, rule_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AutomationRule>, Error>;
+
+    async fn touch_last_run(&self, rule_id: Uuid, last_run_at: DateTime<Utc>, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AutomationRuleRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AutomationRuleRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AutomationRule> for AutomationRuleRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+impl AutomationRuleRepository {
+    fn rule_params(rule: AutomationRule) -> Vec<MySqlParam> {
+        vec![
+            MySqlParam::from(rule.name),
+            MySqlParam::from(rule.enabled),
+            MySqlParam::from(rule.trigger_type.as_str()),
+            MySqlParam::from(rule.trigger_payee_id),
+            MySqlParam::from(rule.trigger_category_id),
+            MySqlParam::from(rule.trigger_min_amount_base_minor),
+            MySqlParam::from(rule.trigger_day_of_month),
+            MySqlParam::from(rule.action_type.as_str()),
+            MySqlParam::from(rule.action_amount_base_minor),
+            MySqlParam::from(rule.action_percent),
+            MySqlParam::from(rule.action_from_account_id),
+            MySqlParam::from(rule.action_to_account_id),
+            MySqlParam::from(rule.action_goal_id),
+            MySqlParam::from(rule.action_category_id),
+            MySqlParam::from(rule.action_message),
+        ]
+    }
+}
+
+#[async_trait]
+impl AutomationRuleRepositoryInterface for AutomationRuleRepository {
+    async fn get(&self, rule_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<AutomationRule>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(rule_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_automation_rule_get_by_id", params).await
+    }
+
+    async fn create(&self, rule: AutomationRule, meta_user: Option<Uuid>) -> Result<AutomationRule, Error> {
+        let mut params = vec![MySqlParam::from(rule.user_id.clone())];
+        params.extend(Self::rule_params(rule));
+        params.push(MySqlParam::from(oub(meta_user)));
+
+        self.call_procedure_for_one("proc_automation_rule_create", params).await
+    }
+
+    async fn update(&self, rule: AutomationRule, meta_user: Option<Uuid>) -> Result<Option<AutomationRule>, Error> {
+        let mut params = vec![MySqlParam::from(rule.id.clone())];
+        params.extend(Self::rule_params(rule));
+        params.push(MySqlParam::from(oub(meta_user)));
+
+        self.call_procedure_for_optional("proc_automation_rule_update", params).await
+    }
+
+    async fn delete(&self, rule_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(rule_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_automation_rule_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AutomationRule>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_automation_rule_by_user", params).await
+    }
+
+    async fn touch_last_run(&self, rule_id: Uuid, last_run_at: DateTime<Utc>, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(rule_id)),
+            MySqlParam::from(last_run_at),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_automation_rule_touch_last_run", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait AutomationRunRepositoryInterface {
+
+    async fn create(&self, run: AutomationRun, meta_user: Option<Uuid>) -> Result<AutomationRun, Error>;
+
+    async fn get_by_rule(&self, rule_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AutomationRun>, Error>;
+
+    /// Successful run of the rule for the same trigger (transaction, or date when there is none)
+    async fn find_success_by_trigger(&self, rule_id: Uuid, trigger_transaction_id: Option<Uuid>, trigger_date: NaiveDate, meta_user: Option<Uuid>) -> Result<Option<AutomationRun>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AutomationRunRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AutomationRunRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AutomationRun> for AutomationRunRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AutomationRunRepositoryInterface for AutomationRunRepository {
+    async fn create(&self, run: AutomationRun, meta_user: Option<Uuid>) -> Result<AutomationRun, Error> {
+        let params = vec![
+            MySqlParam::from(run.rule_id),
+            MySqlParam::from(run.user_id),
+            MySqlParam::from(run.trigger_transaction_id),
+            MySqlParam::from(run.trigger_date),
+            MySqlParam::from(run.status.as_str()),
+            MySqlParam::from(run.amount_base_minor),
+            MySqlParam::from(run.result_entity_type),
+            MySqlParam::from(run.result_entity_id),
+            MySqlParam::from(run.message),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_automation_run_create", params).await
+    }
+
+    async fn get_by_rule(&self, rule_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AutomationRun>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(rule_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_automation_run_by_rule", params).await
+    }
+
+    async fn find_success_by_trigger(&self, rule_id: Uuid, trigger_transaction_id: Option<Uuid>, trigger_date: NaiveDate, meta_user: Option<Uuid>) -> Result<Option<AutomationRun>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(rule_id)),
+            MySqlParam::from(oub(trigger_transaction_id)),
+            MySqlParam::from(trigger_date),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_automation_run_find_success_by_trigger", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait AutomationTriggerTransactionRepositoryInterface {
+
+    async fn get_income_by_user_date(&self, user_id: Uuid, date: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<AutomationTriggerTransaction>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AutomationTriggerTransactionRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AutomationTriggerTransactionRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AutomationTriggerTransaction> for AutomationTriggerTransactionRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AutomationTriggerTransactionRepositoryInterface for AutomationTriggerTransactionRepository {
+    async fn get_income_by_user_date(&self, user_id: Uuid, date: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<AutomationTriggerTransaction>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(date),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_automation_income_transactions_by_user_date", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait AutomationActionRepositoryInterface {
+
+    async fn create_transfer(&self, transfer: AutomationTransfer, meta_user: Option<Uuid>) -> Result<AutomationTransfer, Error>;
+
+    /// In-app notification of type `AUTOMATION_RULE`, linked to the rule
+    async fn create_notification(&self, user_id: Uuid, rule_id: Uuid, title: String, body: Option<String>, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AutomationActionRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AutomationActionRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AutomationTransfer> for AutomationActionRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AutomationActionRepositoryInterface for AutomationActionRepository {
+    async fn create_transfer(&self, transfer: AutomationTransfer, meta_user: Option<Uuid>) -> Result<AutomationTransfer, Error> {
+        let params = vec![
+            MySqlParam::from(transfer.user_id),
+            MySqlParam::from(transfer.from_account_id),
+            MySqlParam::from(transfer.to_account_id),
+            MySqlParam::from(transfer.amount_base_minor),
+            MySqlParam::from(transfer.occurred_at),
+            MySqlParam::from(transfer.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_automation_transfer_create", params).await
+    }
+
+    async fn create_notification(&self, user_id: Uuid, rule_id: Uuid, title: String, body: Option<String>, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(ub(rule_id)),
+            MySqlParam::from(title),
+            MySqlParam::from(body),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_automation_notification_create", params).await
+    }
+}
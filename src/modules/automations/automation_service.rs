@@ -0,0 +1,466 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use uuid::Uuid;
+
+use crate::modules::automations::{
+    automation_command::*,
+    automation_dto::*,
+    automation_model::{
+        AutomationActionType, AutomationRule, AutomationRun, AutomationRunStatus,
+        AutomationTransfer, AutomationTriggerTransaction, AutomationTriggerType
+    },
+    automation_repo::{
+        AutomationRuleRepository, AutomationRuleRepositoryInterface,
+        AutomationRunRepository, AutomationRunRepositoryInterface,
+        AutomationTriggerTransactionRepository, AutomationTriggerTransactionRepositoryInterface,
+        AutomationActionRepository, AutomationActionRepositoryInterface
+    },
+};
+use crate::modules::budgets::budget_repo::{BudgetEnvelopeRepository, BudgetEnvelopeRepositoryInterface};
+use crate::modules::goals::goal_model::{ContributionStatus, GoalContribution};
+use crate::modules::goals::goal_repo::{GoalContributionRepository, GoalContributionRepositoryInterface};
+use crate::shared::dates::{day_in_month, month_start};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu, oub, ub};
+
+
+#[async_trait]
+pub trait AutomationServiceInterface {
+
+    // --- Rule ---
+
+    async fn get(&self, command: AutomationRuleGetCommand) -> Result<Option<AutomationRuleResponse>, Error>;
+
+    async fn create(&self, command: AutomationRuleCreateCommand) -> Result<AutomationRuleResponse, Error>;
+
+    async fn update(&self, command: AutomationRuleUpdateCommand) -> Result<Option<AutomationRuleResponse>, Error>;
+
+    async fn delete(&self, command: AutomationRuleDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: AutomationRuleListByUserCommand) -> Result<Vec<AutomationRuleResponse>, Error>;
+
+
+    // --- Runs ---
+
+    async fn run(&self, command: AutomationRunCommand) -> Result<Option<Vec<AutomationRunResponse>>, Error>;
+
+    async fn list_runs(&self, command: AutomationRunListCommand) -> Result<Vec<AutomationRunResponse>, Error>;
+
+}
+
+/// What an executed action produced, for the execution log
+struct ActionOutcome {
+    entity_type: &'static str,
+    entity_id: Option<Vec<u8>>,
+    message: String,
+}
+
+#[derive(Clone)]
+pub struct AutomationService {
+    rule_repo: AutomationRuleRepository,
+    run_repo: AutomationRunRepository,
+    trigger_repo: AutomationTriggerTransactionRepository,
+    action_repo: AutomationActionRepository,
+    contribution_repo: GoalContributionRepository,
+    envelope_repo: BudgetEnvelopeRepository,
+}
+
+impl From<&AppState> for AutomationService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            rule_repo: AutomationRuleRepository::from(app_state),
+            run_repo: AutomationRunRepository::from(app_state),
+            trigger_repo: AutomationTriggerTransactionRepository::from(app_state),
+            action_repo: AutomationActionRepository::from(app_state),
+            contribution_repo: GoalContributionRepository::from(app_state),
+            envelope_repo: BudgetEnvelopeRepository::from(app_state),
+        }
+    }
+}
+
+impl AutomationService {
+    fn validate_rule(name: &str, trigger: &AutomationTrigger, action: &AutomationAction) -> Result<(), Error> {
+        let bad_request = |message: &str| -> Result<(), Error> { Err(AppError::BadRequest(message.into()).into()) };
+
+        if name.trim().is_empty() {
+            return bad_request("automation name is required");
+        }
+
+        match trigger.trigger_type {
+            AutomationTriggerType::IncomeTransaction => {
+                if trigger.trigger_payee_id.is_none() && trigger.trigger_category_id.is_none() {
+                    return bad_request("income trigger needs a payee or a category");
+                }
+                if trigger.trigger_min_amount_base_minor.is_some_and(|amount| amount < 0) {
+                    return bad_request("minimum income cannot be negative");
+                }
+            },
+            AutomationTriggerType::DayOfMonth => {
+                if !trigger.trigger_day_of_month.is_some_and(|day| (1..=31).contains(&day)) {
+                    return bad_request("day of month must be between 1 and 31");
+                }
+                if action.action_percent.is_some() {
+                    return bad_request("a percentage needs an income trigger");
+                }
+            },
+        }
+
+        if action.action_amount_base_minor.is_some_and(|amount| amount <= 0) {
+            return bad_request("action amount must be positive");
+        }
+        if action.action_percent.is_some_and(|percent| percent <= Decimal::ZERO || percent > Decimal::from(100)) {
+            return bad_request("action percentage must be above 0 and at most 100");
+        }
+        let has_amount = action.action_amount_base_minor.is_some() || action.action_percent.is_some();
+        if action.action_amount_base_minor.is_some() && action.action_percent.is_some() {
+            return bad_request("action takes either a fixed amount or a percentage, not both");
+        }
+
+        match action.action_type {
+            AutomationActionType::Transfer => {
+                match (action.action_from_account_id, action.action_to_account_id) {
+                    (Some(from), Some(to)) if from != to => {},
+                    (Some(_), Some(_)) => return bad_request("transfer accounts must differ"),
+                    _ => return bad_request("transfer needs a source and a destination account"),
+                }
+            },
+            AutomationActionType::GoalContribution if action.action_goal_id.is_none() => {
+                return bad_request("goal contribution needs a goal");
+            },
+            AutomationActionType::EnvelopeAllocation if action.action_category_id.is_none() => {
+                return bad_request("envelope allocation needs a category");
+            },
+            AutomationActionType::Notification if action.action_message.as_deref().is_none_or(|message| message.trim().is_empty()) => {
+                return bad_request("notification needs a message");
+            },
+            _ => {},
+        }
+        if action.action_type != AutomationActionType::Notification && !has_amount {
+            return bad_request("action needs a fixed amount or a percentage");
+        }
+
+        Ok(())
+    }
+
+    fn matches_income(rule: &AutomationRule, transaction: &AutomationTriggerTransaction) -> bool {
+        transaction.base_amount_minor > 0
+            && rule.trigger_payee_id.as_ref().is_none_or(|payee_id| transaction.payee_id.as_ref() == Some(payee_id))
+            && rule.trigger_category_id.as_ref().is_none_or(|category_id| transaction.category_id.as_ref() == Some(category_id))
+            && rule.trigger_min_amount_base_minor.is_none_or(|min_amount| transaction.base_amount_minor >= min_amount)
+    }
+
+    /// Triggers of the rule on `date`: one per matching income, or a single date trigger
+    fn triggers<'a>(rule: &AutomationRule, date: NaiveDate, incomes: &'a [AutomationTriggerTransaction]) -> Vec<Option<&'a AutomationTriggerTransaction>> {
+        match rule.trigger_type {
+            AutomationTriggerType::IncomeTransaction => incomes.iter()
+                .filter(|transaction| Self::matches_income(rule, transaction))
+                .map(Some)
+                .collect(),
+            AutomationTriggerType::DayOfMonth => match rule.trigger_day_of_month {
+                Some(day) if day_in_month(date, day as u32) == date => vec![None],
+                _ => Vec::new(),
+            },
+        }
+    }
+
+    /// Fixed amount, or the percentage of the triggering income
+    fn action_amount(rule: &AutomationRule, trigger: Option<&AutomationTriggerTransaction>) -> Option<i64> {
+        match (rule.action_amount_base_minor, rule.action_percent, trigger) {
+            (Some(amount), _, _) => Some(amount),
+            (None, Some(percent), Some(transaction)) => {
+                (Decimal::from(transaction.base_amount_minor) * percent / Decimal::from(100)).round().to_i64()
+            },
+            _ => None,
+        }
+    }
+
+    fn describe(rule: &AutomationRule, amount: Option<i64>) -> String {
+        let amount = amount.unwrap_or(0);
+        match rule.action_type {
+            AutomationActionType::Transfer => format!(
+                "transfer {} from account {} to account {}",
+                amount,
+                obu(rule.action_from_account_id.as_deref()).map(|id| id.to_string()).unwrap_or_default(),
+                obu(rule.action_to_account_id.as_deref()).map(|id| id.to_string()).unwrap_or_default()
+            ),
+            AutomationActionType::GoalContribution => format!(
+                "plan a contribution of {} to goal {}",
+                amount,
+                obu(rule.action_goal_id.as_deref()).map(|id| id.to_string()).unwrap_or_default()
+            ),
+            AutomationActionType::EnvelopeAllocation => format!(
+                "allocate {} to the envelope of category {}",
+                amount,
+                obu(rule.action_category_id.as_deref()).map(|id| id.to_string()).unwrap_or_default()
+            ),
+            AutomationActionType::Notification => format!(
+                "notify \"{}\"", rule.action_message.clone().unwrap_or_default()
+            ),
+        }
+    }
+
+    fn note(rule: &AutomationRule) -> Option<String> {
+        rule.action_message.clone().or_else(|| Some(format!("Automation: {}", rule.name)))
+    }
+
+    async fn execute_action(
+        &self,
+        rule: &AutomationRule,
+        trigger: Option<&AutomationTriggerTransaction>,
+        date: NaiveDate,
+        amount: Option<i64>,
+        meta_user: Option<Uuid>,
+    ) -> Result<ActionOutcome, String> {
+        let rule_id = bu(rule.id.clone().unwrap().as_slice());
+        let description = Self::describe(rule, amount);
+
+        let amount = match (rule.action_type, amount) {
+            (AutomationActionType::Notification, _) => amount.unwrap_or(0),
+            (_, Some(amount)) if amount > 0 => amount,
+            _ => return Err("no amount to apply".to_string()),
+        };
+
+        match rule.action_type {
+            AutomationActionType::Transfer => {
+                let transfer_create = AutomationTransfer {
+                    user_id: rule.user_id.clone(),
+                    from_account_id: rule.action_from_account_id.clone().unwrap_or_default(),
+                    to_account_id: rule.action_to_account_id.clone().unwrap_or_default(),
+                    amount_base_minor: amount,
+                    occurred_at: trigger.map(|transaction| transaction.occurred_at).unwrap_or_else(Utc::now),
+                    note: Self::note(rule),
+                    out_transaction_id: None,
+                    in_transaction_id: None,
+                };
+                match self.action_repo.create_transfer(transfer_create, meta_user).await {
+                    Ok(transfer) => Ok(ActionOutcome { entity_type: "transaction", entity_id: transfer.out_transaction_id, message: description }),
+                    Err(_) => Err("Error creating transfer".to_string()),
+                }
+            },
+            AutomationActionType::GoalContribution => {
+                let contribution_create = GoalContribution {
+                    id: None,
+                    user_id: rule.user_id.clone(),
+                    goal_id: rule.action_goal_id.clone().unwrap_or_default(),
+                    plan_id: None,
+                    due_date: date,
+                    planned_base_minor: amount,
+                    status: ContributionStatus::Planned,
+                    transaction_id: None,
+                    executed_base_minor: None,
+                    executed_at: None,
+                    note: Self::note(rule),
+                    created_at: None,
+                    updated_at: None,
+                };
+                match self.contribution_repo.create(contribution_create, meta_user).await {
+                    Ok(contribution) => Ok(ActionOutcome { entity_type: "goal_contribution", entity_id: contribution.id, message: description }),
+                    Err(_) => Err("Error creating goal contribution".to_string()),
+                }
+            },
+            AutomationActionType::EnvelopeAllocation => {
+                let category_id = obu(rule.action_category_id.as_deref()).unwrap_or_default();
+                let month = month_start(date);
+                match self.envelope_repo.allocate(bu(rule.user_id.as_slice()), category_id, month, amount, meta_user).await {
+                    Ok(Some(envelope)) => Ok(ActionOutcome { entity_type: "budget_envelope", entity_id: envelope.id, message: description }),
+                    Ok(None) => Err(format!("no envelope for category {} in the budget of {}", category_id, month)),
+                    Err(_) => Err("Error allocating envelope".to_string()),
+                }
+            },
+            AutomationActionType::Notification => {
+                let body = rule.action_message.clone();
+                match self.action_repo.create_notification(bu(rule.user_id.as_slice()), rule_id, rule.name.clone(), body, meta_user).await {
+                    Ok(_) => Ok(ActionOutcome { entity_type: "notification", entity_id: None, message: description }),
+                    Err(_) => Err("Error creating notification".to_string()),
+                }
+            },
+        }
+    }
+
+    /// Runs one rule for one trigger.
+    /// A trigger already executed successfully is skipped, so the same day can be run again safely.
+    /// Dry runs stop before the action and are not logged.
+    async fn execute(
+        &self,
+        rule: &AutomationRule,
+        trigger: Option<&AutomationTriggerTransaction>,
+        date: NaiveDate,
+        dry_run: bool,
+        meta_user: Option<Uuid>,
+    ) -> Result<AutomationRunResponse, Error> {
+        let rule_id = bu(rule.id.clone().unwrap().as_slice());
+        let trigger_transaction_id = trigger.map(|transaction| bu(transaction.id.as_slice()));
+        let amount = Self::action_amount(rule, trigger);
+
+        let response = |status: AutomationRunStatus, message: String| AutomationRunResponse {
+            run_id: None,
+            automation_id: rule_id,
+            run_trigger_transaction_id: trigger_transaction_id,
+            run_trigger_date: date,
+            run_status: status,
+            run_amount_base_minor: amount,
+            run_result_entity_type: None,
+            run_result_entity_id: None,
+            run_message: Some(message),
+            run_created_at: None,
+        };
+
+        match self.run_repo.find_success_by_trigger(rule_id, trigger_transaction_id, date, meta_user).await {
+            Ok(Some(run)) => return Ok(response(
+                AutomationRunStatus::Skipped,
+                format!("already executed on {}", run.created_at.map(|created_at| created_at.to_rfc3339()).unwrap_or_default())
+            )),
+            Ok(None) => {},
+            Err(_) => return Err(Error::msg("Error getting automation runs")),
+        }
+
+        if dry_run {
+            return Ok(response(AutomationRunStatus::DryRun, format!("would {}", Self::describe(rule, amount))));
+        }
+
+        let outcome = self.execute_action(rule, trigger, date, amount, meta_user).await;
+        let run_create = match outcome {
+            Ok(outcome) => AutomationRun {
+                id: None,
+                rule_id: ub(rule_id),
+                user_id: rule.user_id.clone(),
+                trigger_transaction_id: oub(trigger_transaction_id),
+                trigger_date: date,
+                status: AutomationRunStatus::Success,
+                amount_base_minor: amount,
+                result_entity_type: Some(outcome.entity_type.to_string()),
+                result_entity_id: outcome.entity_id,
+                message: Some(outcome.message),
+                created_at: None,
+            },
+            Err(message) => AutomationRun {
+                id: None,
+                rule_id: ub(rule_id),
+                user_id: rule.user_id.clone(),
+                trigger_transaction_id: oub(trigger_transaction_id),
+                trigger_date: date,
+                status: AutomationRunStatus::Failed,
+                amount_base_minor: amount,
+                result_entity_type: None,
+                result_entity_id: None,
+                message: Some(message),
+                created_at: None,
+            },
+        };
+        let succeeded = run_create.status == AutomationRunStatus::Success;
+
+        let run = match self.run_repo.create(run_create, meta_user).await {
+            Ok(run) => run,
+            Err(_) => return Err(Error::msg("Error logging automation run")),
+        };
+        if succeeded && self.rule_repo.touch_last_run(rule_id, Utc::now(), meta_user).await.is_err() {
+            return Err(Error::msg("Error updating automation rule"));
+        }
+
+        Ok(AutomationRunResponse::from(&run))
+    }
+}
+
+#[async_trait]
+impl AutomationServiceInterface for AutomationService {
+    async fn get(&self, command: AutomationRuleGetCommand) -> Result<Option<AutomationRuleResponse>, Error> {
+        let rule = self.rule_repo.get(command.rule_id, Some(command.auth_user.user_id)).await;
+        match rule {
+            Ok(rule) => Ok(rule.as_ref().map(AutomationRuleResponse::from)),
+            Err(_) => Err(Error::msg("Error getting automation rule")),
+        }
+    }
+
+    async fn create(&self, command: AutomationRuleCreateCommand) -> Result<AutomationRuleResponse, Error> {
+        Self::validate_rule(&command.automation_name, &command.automation_trigger, &command.automation_action)?;
+
+        let meta_user = command.auth_user.user_id;
+        let rule_create = AutomationRule::from(command);
+
+        let rule = self.rule_repo.create(rule_create, Some(meta_user)).await;
+        match rule {
+            Ok(rule) => Ok(AutomationRuleResponse::from(&rule)),
+            Err(_) => Err(Error::msg("Error creating automation rule")),
+        }
+    }
+
+    async fn update(&self, command: AutomationRuleUpdateCommand) -> Result<Option<AutomationRuleResponse>, Error> {
+        Self::validate_rule(&command.automation_name, &command.automation_trigger, &command.automation_action)?;
+
+        let meta_user = command.auth_user.user_id;
+        let rule_update = AutomationRule::from(command);
+
+        let rule = self.rule_repo.update(rule_update, Some(meta_user)).await;
+        match rule {
+            Ok(rule) => Ok(rule.as_ref().map(AutomationRuleResponse::from)),
+            Err(_) => Err(Error::msg("Error updating automation rule")),
+        }
+    }
+
+    async fn delete(&self, command: AutomationRuleDeleteCommand) -> Result<(), Error> {
+        let result = self.rule_repo.delete(command.rule_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting automation rule")),
+        }
+    }
+
+    async fn get_by_user(&self, command: AutomationRuleListByUserCommand) -> Result<Vec<AutomationRuleResponse>, Error> {
+        let rules = self.rule_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await;
+        match rules {
+            Ok(rules) => Ok(rules.iter().map(AutomationRuleResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting automation rules")),
+        }
+    }
+
+    /// Evaluates the rules on `date`: day-of-month rules due that day, and income rules
+    /// against the income transactions of that day. A single disabled rule can only be dry-run.
+    async fn run(&self, command: AutomationRunCommand) -> Result<Option<Vec<AutomationRunResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+
+        let rules = match command.rule_id {
+            Some(rule_id) => match self.rule_repo.get(rule_id, meta_user).await {
+                Ok(Some(rule)) if !rule.enabled && !command.dry_run => {
+                    return Err(AppError::BadRequest("automation rule is disabled, only a dry run is possible".into()).into());
+                },
+                Ok(Some(rule)) => vec![rule],
+                Ok(None) => return Ok(None),
+                Err(_) => return Err(Error::msg("Error getting automation rule")),
+            },
+            None => match self.rule_repo.get_by_user(command.user_id, meta_user).await {
+                Ok(rules) => rules.into_iter().filter(|rule| rule.enabled).collect(),
+                Err(_) => return Err(Error::msg("Error getting automation rules")),
+            },
+        };
+
+        let incomes = if rules.iter().any(|rule| rule.trigger_type == AutomationTriggerType::IncomeTransaction) {
+            match self.trigger_repo.get_income_by_user_date(command.user_id, command.date, meta_user).await {
+                Ok(incomes) => incomes,
+                Err(_) => return Err(Error::msg("Error getting income transactions")),
+            }
+        } else {
+            Vec::new()
+        };
+
+        let mut runs = Vec::new();
+        for rule in &rules {
+            for trigger in Self::triggers(rule, command.date, &incomes) {
+                runs.push(self.execute(rule, trigger, command.date, command.dry_run, meta_user).await?);
+            }
+        }
+
+        Ok(Some(runs))
+    }
+
+    async fn list_runs(&self, command: AutomationRunListCommand) -> Result<Vec<AutomationRunResponse>, Error> {
+        let runs = self.run_repo.get_by_rule(command.rule_id, Some(command.auth_user.user_id)).await;
+        match runs {
+            Ok(runs) => Ok(runs.iter().map(AutomationRunResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting automation runs")),
+        }
+    }
+}
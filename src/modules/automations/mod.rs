@@ -0,0 +1,6 @@
+mod automation_model;
+mod automation_repo;
+mod automation_command;
+mod automation_service;
+pub mod automation_dto;
+pub mod automation_controller;
\ No newline at end of file
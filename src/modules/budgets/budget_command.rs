@@ -0,0 +1,161 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::budgets::{
+    budget_dto::{BudgetCreateRequest, BudgetEnvelopeCreateRequest, SinkingFundUpdateRequest},
+    budget_model::{BudgetStatus, RolloverRule},
+};
+use crate::shared::auth::jwt::AuthUser;
+use crate::shared::dates::month_start;
+
+
+// --- Budget ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetGetCommand {
+    pub budget_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl BudgetGetCommand {
+    pub fn new(budget_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { budget_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetCreateCommand {
+    pub user_id: Uuid,
+    pub budget_month: NaiveDate,
+    pub budget_base_currency_code: String,
+
+    pub budget_person_id: Option<Uuid>,
+    pub budget_status: Option<BudgetStatus>,
+
+    pub auth_user: AuthUser,
+}
+
+impl BudgetCreateCommand {
+    pub fn new(request: BudgetCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            budget_month: month_start(request.budget_month),
+            budget_base_currency_code: request.budget_base_currency_code,
+            budget_person_id: request.budget_person_id,
+            budget_status: request.budget_status,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl BudgetListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+
+// --- Envelope ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetEnvelopeGetCommand {
+    pub envelope_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl BudgetEnvelopeGetCommand {
+    pub fn new(envelope_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { envelope_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetEnvelopeCreateCommand {
+    pub budget_id: Uuid,
+    pub envelope_category_id: Uuid,
+    pub envelope_planned_base_minor: i64,
+
+    pub envelope_carryover_base_minor: Option<i64>,
+    pub envelope_rollover_rule: Option<RolloverRule>,
+
+    pub envelope_target_base_minor: Option<i64>,
+    pub envelope_target_date: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl BudgetEnvelopeCreateCommand {
+    pub fn new(budget_id: Uuid, request: BudgetEnvelopeCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            budget_id,
+            envelope_category_id: request.envelope_category_id,
+            envelope_planned_base_minor: request.envelope_planned_base_minor,
+            envelope_carryover_base_minor: request.envelope_carryover_base_minor,
+            envelope_rollover_rule: request.envelope_rollover_rule,
+            envelope_target_base_minor: request.envelope_target_base_minor,
+            envelope_target_date: request.envelope_target_date,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetEnvelopeListCommand {
+    pub budget_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl BudgetEnvelopeListCommand {
+    pub fn new(budget_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { budget_id, auth_user }
+    }
+}
+
+
+// --- Sinking fund ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SinkingFundUpdateCommand {
+    pub envelope_id: Uuid,
+
+    pub envelope_target_base_minor: Option<i64>,
+    pub envelope_target_date: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl SinkingFundUpdateCommand {
+    pub fn new(envelope_id: Uuid, request: SinkingFundUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            envelope_id,
+            envelope_target_base_minor: request.envelope_target_base_minor,
+            envelope_target_date: request.envelope_target_date,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SinkingFundListCommand {
+    pub user_id: Uuid,
+    pub month: NaiveDate,
+
+    pub auth_user: AuthUser,
+}
+
+impl SinkingFundListCommand {
+    pub fn new(user_id: Uuid, month: NaiveDate, auth_user: AuthUser) -> Self {
+        Self { user_id, month: month_start(month), auth_user }
+    }
+}
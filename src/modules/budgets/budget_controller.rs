@@ -0,0 +1,299 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::get, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::budgets::{
+    budget_command::*,
+    budget_dto::*,
+    budget_service::{BudgetService, BudgetServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_budgets).post(post_budget))
+        .route("/sinking-funds", get(get_sinking_funds))
+        .route("/envelopes/{envelope_id}", get(get_envelope))
+        .route("/envelopes/{envelope_id}/sinking-fund", get(get_sinking_fund).put(put_sinking_fund))
+        .route("/{budget_id}", get(get_budget))
+        .route("/{budget_id}/envelopes", get(get_envelopes).post(post_envelope))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/budgets",
+    responses(
+        (status = StatusCode::OK, description = "List of budgets for current user", body = Vec<BudgetResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn get_budgets(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<BudgetResponse>>, StatusCode> {
+    let command = BudgetListByUserCommand::new(auth_user.user_id, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let budgets = budget_service.list_budgets_by_user(command).await;
+    match budgets {
+        Ok(budgets) => Ok(Json(budgets)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/budgets",
+    responses(
+        (status = StatusCode::OK, description = "Budget successfully created", body = BudgetResponse),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn post_budget(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(budget_create_request): Json<BudgetCreateRequest>
+) -> Result<Json<BudgetResponse>, StatusCode> {
+    let command = BudgetCreateCommand::new(budget_create_request, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let budget = budget_service.create_budget(command).await;
+    match budget {
+        Ok(budget) => Ok(Json(budget)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/budgets/{budget_id}",
+    params(
+        ("budget_id", description = "budget identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Budget found successfully", body = BudgetResponse),
+        (status = StatusCode::NOT_FOUND, description = "Budget not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn get_budget(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(budget_id): Path<Uuid>,
+) -> Result<Json<BudgetResponse>, StatusCode> {
+    let command = BudgetGetCommand::new(budget_id, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let budget = budget_service.get_budget(command).await;
+    match budget {
+        Ok(budget) => {
+            match budget {
+                Some(budget) => Ok(Json(budget)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/budgets/{budget_id}/envelopes",
+    params(
+        ("budget_id", description = "budget identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Envelopes of the budget, with sinking fund progress", body = Vec<BudgetEnvelopeResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn get_envelopes(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(budget_id): Path<Uuid>,
+) -> Result<Json<Vec<BudgetEnvelopeResponse>>, StatusCode> {
+    let command = BudgetEnvelopeListCommand::new(budget_id, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let envelopes = budget_service.list_envelopes(command).await;
+    match envelopes {
+        Ok(envelopes) => Ok(Json(envelopes)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/budgets/{budget_id}/envelopes",
+    params(
+        ("budget_id", description = "budget identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Envelope successfully created", body = BudgetEnvelopeResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Incomplete sinking fund target"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn post_envelope(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(budget_id): Path<Uuid>,
+    Json(envelope_create_request): Json<BudgetEnvelopeCreateRequest>
+) -> Result<Json<BudgetEnvelopeResponse>, StatusCode> {
+    let command = BudgetEnvelopeCreateCommand::new(budget_id, envelope_create_request, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let envelope = budget_service.create_envelope(command).await;
+    match envelope {
+        Ok(envelope) => Ok(Json(envelope)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/budgets/envelopes/{envelope_id}",
+    params(
+        ("envelope_id", description = "envelope identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Envelope found successfully", body = BudgetEnvelopeResponse),
+        (status = StatusCode::NOT_FOUND, description = "Envelope not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn get_envelope(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(envelope_id): Path<Uuid>,
+) -> Result<Json<BudgetEnvelopeResponse>, StatusCode> {
+    let command = BudgetEnvelopeGetCommand::new(envelope_id, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let envelope = budget_service.get_envelope(command).await;
+    match envelope {
+        Ok(envelope) => {
+            match envelope {
+                Some(envelope) => Ok(Json(envelope)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/budgets/envelopes/{envelope_id}/sinking-fund",
+    params(
+        ("envelope_id", description = "envelope identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Sinking fund progress of the envelope", body = SinkingFundResponse),
+        (status = StatusCode::NOT_FOUND, description = "Envelope not found or not a sinking fund"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn get_sinking_fund(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(envelope_id): Path<Uuid>,
+) -> Result<Json<SinkingFundResponse>, StatusCode> {
+    let command = BudgetEnvelopeGetCommand::new(envelope_id, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let envelope = budget_service.get_envelope(command).await;
+    match envelope {
+        Ok(envelope) => {
+            match envelope.and_then(|envelope| envelope.envelope_sinking_fund) {
+                Some(sinking_fund) => Ok(Json(sinking_fund)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/budgets/envelopes/{envelope_id}/sinking-fund",
+    params(
+        ("envelope_id", description = "envelope identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Sinking fund target updated", body = BudgetEnvelopeResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Incomplete sinking fund target"),
+        (status = StatusCode::NOT_FOUND, description = "Envelope not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn put_sinking_fund(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(envelope_id): Path<Uuid>,
+    Json(sinking_fund_update_request): Json<SinkingFundUpdateRequest>
+) -> Result<Json<BudgetEnvelopeResponse>, StatusCode> {
+    let command = SinkingFundUpdateCommand::new(envelope_id, sinking_fund_update_request, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let envelope = budget_service.update_sinking_fund(command).await;
+    match envelope {
+        Ok(envelope) => {
+            match envelope {
+                Some(envelope) => Ok(Json(envelope)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/budgets/sinking-funds",
+    params(
+        SinkingFundListQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Sinking funds of the month, flagged when behind schedule", body = Vec<SinkingFundResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Budget"
+)]
+pub async fn get_sinking_funds(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<SinkingFundListQuery>,
+) -> Result<Json<Vec<SinkingFundResponse>>, StatusCode> {
+    let month = query.month.unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let command = SinkingFundListCommand::new(auth_user.user_id, month, auth_user);
+    let budget_service = BudgetService::from(&state);
+
+    let sinking_funds = budget_service.list_sinking_funds(command).await;
+    match sinking_funds {
+        Ok(sinking_funds) => Ok(Json(sinking_funds)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::budgets::budget_model::{Budget, BudgetEnvelope, BudgetStatus, RolloverRule};
+use crate::shared::utils::{bu, obu};
+
+
+// --- Budget ---
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BudgetResponse {
+    pub budget_id: Uuid,
+    pub user_id: Uuid,
+    pub budget_month: NaiveDate,
+    pub budget_base_currency_code: String,
+    pub budget_person_id: Option<Uuid>,
+    pub budget_status: BudgetStatus,
+
+    pub budget_created_at: Option<DateTime<Utc>>,
+    pub budget_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<Budget> for BudgetResponse {
+    fn from(budget: Budget) -> Self {
+        Self {
+            budget_id: bu(budget.id.unwrap().as_slice()),
+            user_id: bu(budget.user_id.as_slice()),
+            budget_month: budget.month,
+            budget_base_currency_code: budget.base_currency_code,
+            budget_person_id: obu(budget.person_id.as_deref()),
+            budget_status: budget.status,
+            budget_created_at: budget.created_at,
+            budget_updated_at: budget.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BudgetCreateRequest {
+    /// Any day of the month, normalized to the 1st
+    pub budget_month: NaiveDate,
+    pub budget_base_currency_code: String,
+
+    pub budget_person_id: Option<Uuid>,
+    pub budget_status: Option<BudgetStatus>,
+}
+
+
+// --- Envelope ---
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BudgetEnvelopeResponse {
+    pub envelope_id: Uuid,
+    pub budget_id: Uuid,
+    pub envelope_category_id: Uuid,
+
+    pub envelope_planned_base_minor: i64,
+    pub envelope_carryover_base_minor: i64,
+    pub envelope_rollover_rule: RolloverRule,
+
+    pub envelope_spent_base_minor: i64,
+    /// carryover + planned - spent
+    pub envelope_available_base_minor: i64,
+
+    pub envelope_sinking_fund: Option<SinkingFundResponse>,
+
+    pub envelope_created_at: Option<DateTime<Utc>>,
+    pub envelope_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&BudgetEnvelope> for BudgetEnvelopeResponse {
+    fn from(envelope: &BudgetEnvelope) -> Self {
+        Self {
+            envelope_id: bu(envelope.id.clone().unwrap().as_slice()),
+            budget_id: bu(envelope.budget_id.as_slice()),
+            envelope_category_id: bu(envelope.category_id.as_slice()),
+            envelope_planned_base_minor: envelope.planned_base_minor,
+            envelope_carryover_base_minor: envelope.carryover_base_minor,
+            envelope_rollover_rule: envelope.rollover_rule,
+            envelope_spent_base_minor: envelope.spent_base_minor,
+            envelope_available_base_minor: envelope.carryover_base_minor + envelope.planned_base_minor - envelope.spent_base_minor,
+            envelope_sinking_fund: None,
+            envelope_created_at: envelope.created_at,
+            envelope_updated_at: envelope.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BudgetEnvelopeCreateRequest {
+    pub envelope_category_id: Uuid,
+    pub envelope_planned_base_minor: i64,
+
+    /// Left over by the category's envelope of the previous month when empty
+    pub envelope_carryover_base_minor: Option<i64>,
+    pub envelope_rollover_rule: Option<RolloverRule>,
+
+    /// Set both target fields to make the envelope a sinking fund
+    pub envelope_target_base_minor: Option<i64>,
+    pub envelope_target_date: Option<NaiveDate>,
+}
+
+
+// --- Sinking fund ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SinkingFundStatus {
+    Funded,
+    OnTrack,
+    Behind,
+    Overdue,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SinkingFundResponse {
+    pub envelope_id: Uuid,
+    pub envelope_category_id: Uuid,
+    pub month: NaiveDate,
+
+    pub target_base_minor: i64,
+    pub target_date: NaiveDate,
+
+    /// Amount set aside so far (carryover + this month's contribution - spending)
+    pub funded_base_minor: i64,
+    pub funded_percent: Decimal,
+    pub remaining_base_minor: i64,
+
+    /// Months left including the current one
+    pub months_remaining: i32,
+    /// Contribution needed every month from now on to reach the target on time
+    pub required_monthly_base_minor: i64,
+    pub planned_base_minor: i64,
+
+    pub status: SinkingFundStatus,
+    pub behind_schedule: bool,
+    pub warning: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SinkingFundUpdateRequest {
+    /// Leave both fields empty to turn the envelope back into a regular one
+    pub envelope_target_base_minor: Option<i64>,
+    pub envelope_target_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct SinkingFundListQuery {
+    /// Defaults to the current month
+    #[param(example = "2026-10-01")]
+    pub month: Option<NaiveDate>,
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::budgets::budget_command::{BudgetCreateCommand, BudgetEnvelopeCreateCommand};
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum BudgetStatus {
+    Draft,
+    Active,
+    Closed,
+}
+
+impl BudgetStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetStatus::Draft => "draft",
+            BudgetStatus::Active => "active",
+            BudgetStatus::Closed => "closed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum RolloverRule {
+    None,
+    Full,
+    Partial,
+}
+
+impl RolloverRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RolloverRule::None => "none",
+            RolloverRule::Full => "full",
+            RolloverRule::Partial => "partial",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Budget {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub month: NaiveDate,
+    pub base_currency_code: String,
+    pub person_id: Option<Vec<u8>>,
+    pub status: BudgetStatus,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Budget {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            month: row.try_get(index_map["month"])?,
+            base_currency_code: row.try_get(index_map["base_currency_code"])?,
+            person_id: row.try_get(index_map["person_id"])?,
+            status: row.try_get(index_map["status"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<BudgetCreateCommand> for Budget {
+    fn from(command: BudgetCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            month: command.budget_month,
+            base_currency_code: command.budget_base_currency_code,
+            person_id: oub(command.budget_person_id),
+            status: command.budget_status.unwrap_or(BudgetStatus::Active),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Budget envelope row.
+/// `month` and `spent_base_minor` are not stored on the envelope: the procedures join them
+/// from the parent budget and from the month's transactions of the envelope category
+/// (`spent_base_minor` is positive when money went out).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct BudgetEnvelope {
+    pub id: Option<Vec<u8>>,
+
+    pub budget_id: Vec<u8>,
+    pub category_id: Vec<u8>,
+    pub month: Option<NaiveDate>,
+
+    pub planned_base_minor: i64,
+    pub carryover_base_minor: i64,
+    pub rollover_rule: RolloverRule,
+
+    pub target_base_minor: Option<i64>,
+    pub target_date: Option<NaiveDate>,
+
+    pub spent_base_minor: i64,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl BudgetEnvelope {
+    pub fn is_sinking_fund(&self) -> bool {
+        self.target_base_minor.is_some() && self.target_date.is_some()
+    }
+}
+
+impl FromSqlRow for BudgetEnvelope {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            budget_id: row.try_get(index_map["budget_id"])?,
+            category_id: row.try_get(index_map["category_id"])?,
+            month: row.try_get(index_map["month"])?,
+            planned_base_minor: row.try_get(index_map["planned_base_minor"])?,
+            carryover_base_minor: row.try_get(index_map["carryover_base_minor"])?,
+            rollover_rule: row.try_get(index_map["rollover_rule"])?,
+            target_base_minor: row.try_get(index_map["target_base_minor"])?,
+            target_date: row.try_get(index_map["target_date"])?,
+            spent_base_minor: row.try_get(index_map["spent_base_minor"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<BudgetEnvelopeCreateCommand> for BudgetEnvelope {
+    fn from(command: BudgetEnvelopeCreateCommand) -> Self {
+        Self {
+            id: None,
+            budget_id: ub(command.budget_id),
+            category_id: ub(command.envelope_category_id),
+            month: None,
+            planned_base_minor: command.envelope_planned_base_minor,
+            carryover_base_minor: command.envelope_carryover_base_minor.unwrap_or(0),
+            rollover_rule: command.envelope_rollover_rule.unwrap_or(RolloverRule::Full),
+            target_base_minor: command.envelope_target_base_minor,
+            target_date: command.envelope_target_date,
+            spent_base_minor: 0,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
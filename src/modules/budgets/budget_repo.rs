@@ -0,0 +1,181 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::budgets::budget_model::{Budget, BudgetEnvelope};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait BudgetRepositoryInterface {
+
+    async fn get(&self, budget_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Budget>, Error>;
+
+    async fn create(&self, budget: Budget, meta_user: Option<Uuid>) -> Result<Budget, Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Budget>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct BudgetRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for BudgetRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Budget> for BudgetRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl BudgetRepositoryInterface for BudgetRepository {
+    async fn get(&self, budget_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Budget>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(budget_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_budget_get_by_id", params).await
+    }
+
+    async fn create(&self, budget: Budget, meta_user: Option<Uuid>) -> Result<Budget, Error> {
+        let params = vec![
+            MySqlParam::from(budget.user_id),
+            MySqlParam::from(budget.month),
+            MySqlParam::from(budget.base_currency_code),
+            MySqlParam::from(budget.person_id),
+            MySqlParam::from(budget.status.as_str()),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_budget_create", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Budget>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_budget_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait BudgetEnvelopeRepositoryInterface {
+
+    async fn get(&self, envelope_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<BudgetEnvelope>, Error>;
+
+    async fn create(&self, envelope: BudgetEnvelope, meta_user: Option<Uuid>) -> Result<BudgetEnvelope, Error>;
+
+    async fn get_by_budget(&self, budget_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<BudgetEnvelope>, Error>;
+
+    async fn update_sinking_fund(&self, envelope_id: Uuid, target_base_minor: Option<i64>, target_date: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Option<BudgetEnvelope>, Error>;
+
+    async fn get_sinking_funds_by_user_month(&self, user_id: Uuid, month: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<BudgetEnvelope>, Error>;
+
+    /// Adds `amount_base_minor` to the planned amount of the category envelope in the user's budget of the month
+    async fn allocate(&self, user_id: Uuid, category_id: Uuid, month: NaiveDate, amount_base_minor: i64, meta_user: Option<Uuid>) -> Result<Option<BudgetEnvelope>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct BudgetEnvelopeRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for BudgetEnvelopeRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<BudgetEnvelope> for BudgetEnvelopeRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl BudgetEnvelopeRepositoryInterface for BudgetEnvelopeRepository {
+    async fn get(&self, envelope_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<BudgetEnvelope>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(envelope_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_budget_envelope_get_by_id", params).await
+    }
+
+    async fn create(&self, envelope: BudgetEnvelope, meta_user: Option<Uuid>) -> Result<BudgetEnvelope, Error> {
+        let params = vec![
+            MySqlParam::from(envelope.budget_id),
+            MySqlParam::from(envelope.category_id),
+            MySqlParam::from(envelope.planned_base_minor),
+            MySqlParam::from(envelope.carryover_base_minor),
+            MySqlParam::from(envelope.rollover_rule.as_str()),
+            MySqlParam::from(envelope.target_base_minor),
+            MySqlParam::from(envelope.target_date),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_budget_envelope_create", params).await
+    }
+
+    async fn get_by_budget(&self, budget_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<BudgetEnvelope>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(budget_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_budget_envelope_by_budget", params).await
+    }
+
+    async fn update_sinking_fund(&self, envelope_id: Uuid, target_base_minor: Option<i64>, target_date: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Option<BudgetEnvelope>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(envelope_id)),
+            MySqlParam::from(target_base_minor),
+            MySqlParam::from(target_date),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_budget_envelope_update_sinking_fund", params).await
+    }
+
+    async fn get_sinking_funds_by_user_month(&self, user_id: Uuid, month: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<BudgetEnvelope>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(month),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_budget_envelope_sinking_funds_by_user_month", params).await
+    }
+
+    async fn allocate(&self, user_id: Uuid, category_id: Uuid, month: NaiveDate, amount_base_minor: i64, meta_user: Option<Uuid>) -> Result<Option<BudgetEnvelope>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(ub(category_id)),
+            MySqlParam::from(month),
+            MySqlParam::from(amount_base_minor),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_budget_envelope_allocate", params).await
+    }
+}
@@ -0,0 +1,316 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::modules::budgets::{
+    budget_command::*,
+    budget_dto::*,
+    budget_model::{Budget, BudgetEnvelope, RolloverRule},
+    budget_repo::{
+        BudgetRepository, BudgetRepositoryInterface,
+        BudgetEnvelopeRepository, BudgetEnvelopeRepositoryInterface
+    },
+};
+use crate::shared::dates::{add_months, month_start, months_between};
+use crate::shared::db::redis::{get_key, set_key};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, ub};
+
+
+#[async_trait]
+pub trait BudgetServiceInterface {
+
+    // --- Budget ---
+
+    async fn get_budget(&self, command: BudgetGetCommand) -> Result<Option<BudgetResponse>, Error>;
+
+    async fn create_budget(&self, command: BudgetCreateCommand) -> Result<BudgetResponse, Error>;
+
+    async fn list_budgets_by_user(&self, command: BudgetListByUserCommand) -> Result<Vec<BudgetResponse>, Error>;
+
+
+    // --- Envelope ---
+
+    async fn get_envelope(&self, command: BudgetEnvelopeGetCommand) -> Result<Option<BudgetEnvelopeResponse>, Error>;
+
+    async fn create_envelope(&self, command: BudgetEnvelopeCreateCommand) -> Result<BudgetEnvelopeResponse, Error>;
+
+    async fn list_envelopes(&self, command: BudgetEnvelopeListCommand) -> Result<Vec<BudgetEnvelopeResponse>, Error>;
+
+
+    // --- Sinking fund ---
+
+    async fn update_sinking_fund(&self, command: SinkingFundUpdateCommand) -> Result<Option<BudgetEnvelopeResponse>, Error>;
+
+    async fn list_sinking_funds(&self, command: SinkingFundListCommand) -> Result<Vec<SinkingFundResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct BudgetService {
+    budget_repo: BudgetRepository,
+    envelope_repo: BudgetEnvelopeRepository,
+    redis_pool: Option<Pool<RedisConnectionManager>>,
+}
+
+impl From<&AppState> for BudgetService {
+    fn from(app_state: &AppState) -> Self {
+        let budget_repo = BudgetRepository::from(app_state);
+        let envelope_repo = BudgetEnvelopeRepository::from(app_state);
+        Self { budget_repo, envelope_repo, redis_pool: Option::from(app_state.redis_pool.clone()) }
+    }
+}
+
+impl BudgetService {
+    fn redis_key_ttl(&self) -> Option<u64> {
+        Some(60*60)
+    }
+
+    fn form_redis_key_budget(&self, key: &Uuid) -> String {
+        format!("budget:{}", key)
+    }
+
+    async fn cache_budget(&self, budget: &BudgetResponse) -> Result<(), Error> {
+        if let Some(redis_pool) = &self.redis_pool {
+            let _: () = set_key(
+                redis_pool,
+                self.form_redis_key_budget(&budget.budget_id).as_str(),
+                &budget,
+                self.redis_key_ttl()
+            ).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_cache_budget(&self, key: &Uuid) -> Result<Option<BudgetResponse>, Error> {
+        if let Some(redis_pool) = &self.redis_pool {
+            return get_key(redis_pool, self.form_redis_key_budget(key).as_str()).await;
+        }
+        Ok(None)
+    }
+
+    /// Envelope response, with the sinking fund progress when the envelope carries a target.
+    /// Envelopes are not cached: their spending moves with every transaction of the category.
+    fn envelope_response(envelope: &BudgetEnvelope, today: NaiveDate) -> BudgetEnvelopeResponse {
+        let mut response = BudgetEnvelopeResponse::from(envelope);
+        response.envelope_sinking_fund = Self::sinking_fund_progress(envelope, today);
+        response
+    }
+
+    /// Progress of a sinking fund for the envelope's month.
+    ///
+    /// Money already set aside at the start of the month is the carryover minus this month's spending;
+    /// the gap to the target is then spread over the months left until the target date
+    /// (current month included) to get the required monthly contribution.
+    fn sinking_fund_progress(envelope: &BudgetEnvelope, today: NaiveDate) -> Option<SinkingFundResponse> {
+        let (target, target_date) = match (envelope.target_base_minor, envelope.target_date) {
+            (Some(target), Some(target_date)) => (target.max(0), target_date),
+            _ => return None,
+        };
+        let month = envelope.month.unwrap_or_else(|| month_start(today));
+
+        let funded = envelope.carryover_base_minor + envelope.planned_base_minor - envelope.spent_base_minor;
+        let already_set_aside = envelope.carryover_base_minor - envelope.spent_base_minor;
+        let remaining = (target - funded).max(0);
+        let months_remaining = months_between(month, target_date) + 1;
+
+        let required_monthly = if months_remaining > 0 {
+            let gap = (target - already_set_aside).max(0);
+            (gap + months_remaining as i64 - 1) / months_remaining as i64
+        } else {
+            remaining
+        };
+
+        let funded_percent = if target > 0 {
+            (Decimal::from(funded.max(0)) * Decimal::from(100) / Decimal::from(target)).round_dp(2)
+        } else {
+            Decimal::from(100)
+        };
+
+        let status = if funded >= target {
+            SinkingFundStatus::Funded
+        } else if months_remaining <= 0 {
+            SinkingFundStatus::Overdue
+        } else if envelope.planned_base_minor < required_monthly {
+            SinkingFundStatus::Behind
+        } else {
+            SinkingFundStatus::OnTrack
+        };
+
+        let warning = match status {
+            SinkingFundStatus::Behind => Some(format!(
+                "Behind schedule: {} planned this month, {} needed per month until {}",
+                envelope.planned_base_minor, required_monthly, target_date
+            )),
+            SinkingFundStatus::Overdue => Some(format!(
+                "Target date {} passed with {} still missing", target_date, remaining
+            )),
+            _ => None,
+        };
+
+        Some(SinkingFundResponse {
+            envelope_id: bu(envelope.id.clone().unwrap().as_slice()),
+            envelope_category_id: bu(envelope.category_id.as_slice()),
+            month,
+            target_base_minor: target,
+            target_date,
+            funded_base_minor: funded,
+            funded_percent,
+            remaining_base_minor: remaining,
+            months_remaining: months_remaining.max(0),
+            required_monthly_base_minor: required_monthly,
+            planned_base_minor: envelope.planned_base_minor,
+            behind_schedule: matches!(status, SinkingFundStatus::Behind | SinkingFundStatus::Overdue),
+            status,
+            warning,
+        })
+    }
+
+    /// What the envelope of the same category in the previous month's budget leaves over
+    /// (carryover + planned - spent), 0 without such an envelope or when it does not roll over
+    async fn previous_carryover(&self, budget_id: Uuid, category_id: Uuid, meta_user: Option<Uuid>) -> Result<i64, Error> {
+        let budget = match self.budget_repo.get(budget_id, meta_user).await {
+            Ok(Some(budget)) => budget,
+            Ok(None) => return Ok(0),
+            Err(_) => return Err(Error::msg("Error getting budget")),
+        };
+        let budgets = match self.budget_repo.get_by_user(bu(budget.user_id.as_slice()), meta_user).await {
+            Ok(budgets) => budgets,
+            Err(_) => return Err(Error::msg("Error getting budgets")),
+        };
+        let previous_month = add_months(month_start(budget.month), -1);
+        let Some(previous) = budgets.iter()
+            .find(|previous| month_start(previous.month) == previous_month && previous.person_id == budget.person_id) else {
+            return Ok(0);
+        };
+
+        let envelopes = match self.envelope_repo.get_by_budget(bu(previous.id.as_deref().unwrap()), meta_user).await {
+            Ok(envelopes) => envelopes,
+            Err(_) => return Err(Error::msg("Error getting envelopes")),
+        };
+        match envelopes.iter().find(|envelope| envelope.category_id == ub(category_id)) {
+            Some(envelope) if envelope.rollover_rule != RolloverRule::None => {
+                Ok(envelope.carryover_base_minor + envelope.planned_base_minor - envelope.spent_base_minor)
+            },
+            _ => Ok(0),
+        }
+    }
+
+    fn validate_target(target_base_minor: Option<i64>, target_date: Option<NaiveDate>) -> Result<(), Error> {
+        match (target_base_minor, target_date) {
+            (Some(target), Some(_)) if target <= 0 => Err(AppError::BadRequest("sinking fund target must be positive".into()).into()),
+            (Some(_), None) | (None, Some(_)) => Err(AppError::BadRequest("sinking fund needs both a target amount and a target date".into()).into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl BudgetServiceInterface for BudgetService {
+    async fn get_budget(&self, command: BudgetGetCommand) -> Result<Option<BudgetResponse>, Error> {
+        let budget_cache = self.get_cache_budget(&command.budget_id).await?;
+        if let Some(budget) = budget_cache {
+            return Ok(Some(budget));
+        }
+
+        let budget = self.budget_repo.get(command.budget_id, Some(command.auth_user.user_id)).await;
+        match budget {
+            Ok(Some(budget)) => {
+                let budget_response = BudgetResponse::from(budget);
+                self.cache_budget(&budget_response).await?;
+                Ok(Some(budget_response))
+            },
+            Ok(None) => Ok(None),
+            Err(_) => Err(Error::msg("Error getting budget")),
+        }
+    }
+
+    async fn create_budget(&self, command: BudgetCreateCommand) -> Result<BudgetResponse, Error> {
+        let meta_user = command.auth_user.user_id;
+        let budget_create = Budget::from(command);
+
+        let budget = self.budget_repo.create(budget_create, Some(meta_user)).await;
+        match budget {
+            Ok(budget) => {
+                let budget_response = BudgetResponse::from(budget);
+                self.cache_budget(&budget_response).await?;
+                Ok(budget_response)
+            },
+            Err(_) => Err(Error::msg("Error creating budget")),
+        }
+    }
+
+    async fn list_budgets_by_user(&self, command: BudgetListByUserCommand) -> Result<Vec<BudgetResponse>, Error> {
+        let budgets = self.budget_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await;
+        match budgets {
+            Ok(budgets) => Ok(budgets.into_iter().map(BudgetResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting budgets")),
+        }
+    }
+
+    async fn get_envelope(&self, command: BudgetEnvelopeGetCommand) -> Result<Option<BudgetEnvelopeResponse>, Error> {
+        let envelope = self.envelope_repo.get(command.envelope_id, Some(command.auth_user.user_id)).await;
+        match envelope {
+            Ok(envelope) => Ok(envelope.map(|envelope| Self::envelope_response(&envelope, chrono::Utc::now().date_naive()))),
+            Err(_) => Err(Error::msg("Error getting envelope")),
+        }
+    }
+
+    async fn create_envelope(&self, mut command: BudgetEnvelopeCreateCommand) -> Result<BudgetEnvelopeResponse, Error> {
+        Self::validate_target(command.envelope_target_base_minor, command.envelope_target_date)?;
+
+        let meta_user = command.auth_user.user_id;
+        if command.envelope_carryover_base_minor.is_none() {
+            let carryover = self.previous_carryover(command.budget_id, command.envelope_category_id, Some(meta_user)).await?;
+            command.envelope_carryover_base_minor = Some(carryover);
+        }
+        let envelope_create = BudgetEnvelope::from(command);
+
+        let envelope = self.envelope_repo.create(envelope_create, Some(meta_user)).await;
+        match envelope {
+            Ok(envelope) => Ok(Self::envelope_response(&envelope, chrono::Utc::now().date_naive())),
+            Err(_) => Err(Error::msg("Error creating envelope")),
+        }
+    }
+
+    async fn list_envelopes(&self, command: BudgetEnvelopeListCommand) -> Result<Vec<BudgetEnvelopeResponse>, Error> {
+        let today = chrono::Utc::now().date_naive();
+
+        let envelopes = self.envelope_repo.get_by_budget(command.budget_id, Some(command.auth_user.user_id)).await;
+        match envelopes {
+            Ok(envelopes) => Ok(envelopes.iter().map(|envelope| Self::envelope_response(envelope, today)).collect()),
+            Err(_) => Err(Error::msg("Error getting envelopes")),
+        }
+    }
+
+    async fn update_sinking_fund(&self, command: SinkingFundUpdateCommand) -> Result<Option<BudgetEnvelopeResponse>, Error> {
+        Self::validate_target(command.envelope_target_base_minor, command.envelope_target_date)?;
+
+        let envelope = self.envelope_repo.update_sinking_fund(
+            command.envelope_id,
+            command.envelope_target_base_minor,
+            command.envelope_target_date,
+            Some(command.auth_user.user_id)
+        ).await;
+        match envelope {
+            Ok(envelope) => Ok(envelope.map(|envelope| Self::envelope_response(&envelope, chrono::Utc::now().date_naive()))),
+            Err(_) => Err(Error::msg("Error updating sinking fund")),
+        }
+    }
+
+    async fn list_sinking_funds(&self, command: SinkingFundListCommand) -> Result<Vec<SinkingFundResponse>, Error> {
+        let envelopes = self.envelope_repo.get_sinking_funds_by_user_month(command.user_id, command.month, Some(command.auth_user.user_id)).await;
+        match envelopes {
+            Ok(envelopes) => Ok(envelopes.iter()
+                .filter(|envelope| envelope.is_sinking_fund())
+                .filter_map(|envelope| Self::sinking_fund_progress(envelope, command.month))
+                .collect()),
+            Err(_) => Err(Error::msg("Error getting sinking funds")),
+        }
+    }
+}
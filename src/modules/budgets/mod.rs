@@ -0,0 +1,6 @@
+pub mod budget_model;
+pub mod budget_repo;
+mod budget_command;
+mod budget_service;
+pub mod budget_dto;
+pub mod budget_controller;
\ No newline at end of file
@@ -78,3 +78,44 @@ impl From<FxRateCreateCommand> for FxRate {
         }
     }
 }
+
+
+/// Rates quoted against one base currency, to bring amounts into that base on a given day
+#[derive(Debug, Clone)]
+pub struct FxTable {
+    pub base_code: String,
+    /// By quote code, ordered by date
+    rates: HashMap<String, Vec<(NaiveDate, Decimal)>>,
+}
+
+impl FxTable {
+    /// Rates whose base is not `base_code` are ignored
+    pub fn new(base_code: &str, fx_rates: &[FxRate]) -> Self {
+        let mut rates: HashMap<String, Vec<(NaiveDate, Decimal)>> = HashMap::new();
+        for fx_rate in fx_rates.iter().filter(|fx_rate| fx_rate.base_code == base_code && fx_rate.rate > Decimal::ZERO) {
+            rates.entry(fx_rate.quote_code.clone()).or_default().push((fx_rate.as_of_date, fx_rate.rate));
+        }
+        for series in rates.values_mut() {
+            series.sort_by_key(|(as_of_date, _)| *as_of_date);
+        }
+        Self { base_code: base_code.to_string(), rates }
+    }
+
+    /// 1 base = rate quote: latest rate on or before `date`, or the first one when the pair is only quoted later
+    pub fn rate_on(&self, quote_code: &str, date: NaiveDate) -> Option<Decimal> {
+        if quote_code == self.base_code {
+            return Some(Decimal::ONE);
+        }
+        let series = self.rates.get(quote_code)?;
+        series.iter()
+            .rev()
+            .find(|(as_of_date, _)| *as_of_date <= date)
+            .or(series.first())
+            .map(|(_, rate)| *rate)
+    }
+
+    /// `amount` in major units of `quote_code`, converted to major units of the base currency
+    pub fn to_base(&self, amount: Decimal, quote_code: &str, date: NaiveDate) -> Option<Decimal> {
+        self.rate_on(quote_code, date).map(|rate| amount / rate)
+    }
+}
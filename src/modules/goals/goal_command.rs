@@ -0,0 +1,365 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::goals::{
+    goal_dto::{
+        GoalContributionCreateRequest, GoalContributionDoneRequest, GoalContributionPlanCreateRequest,
+        GoalContributionPlanUpdateRequest, GoalContributionSkipRequest, GoalCreateRequest,
+        GoalMonteCarloContributionStep, GoalMonteCarloRequest, GoalSimulationRequest, GoalUpdateRequest
+    },
+    goal_model::GoalType,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalGetCommand {
+    pub goal_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalGetCommand {
+    pub fn new(goal_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { goal_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalCreateCommand {
+    pub user_id: Uuid,
+    pub goal_name: String,
+    pub goal_type: GoalType,
+    pub goal_target_base_minor: i64,
+
+    pub goal_target_date: Option<NaiveDate>,
+    pub goal_priority: Option<i32>,
+    pub goal_linked_account_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalCreateCommand {
+    pub fn new(request: GoalCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            goal_name: request.goal_name,
+            goal_type: request.goal_type,
+            goal_target_base_minor: request.goal_target_base_minor,
+            goal_target_date: request.goal_target_date,
+            goal_priority: request.goal_priority,
+            goal_linked_account_id: request.goal_linked_account_id,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalUpdateCommand {
+    pub goal_id: Uuid,
+
+    pub goal_name: String,
+    pub goal_type: GoalType,
+    pub goal_target_base_minor: i64,
+    pub goal_target_date: Option<NaiveDate>,
+    pub goal_priority: i32,
+    pub goal_linked_account_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalUpdateCommand {
+    pub fn new(goal_id: Uuid, request: GoalUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            goal_id,
+            goal_name: request.goal_name,
+            goal_type: request.goal_type,
+            goal_target_base_minor: request.goal_target_base_minor,
+            goal_target_date: request.goal_target_date,
+            goal_priority: request.goal_priority,
+            goal_linked_account_id: request.goal_linked_account_id,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalDeleteCommand {
+    pub goal_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalDeleteCommand {
+    pub fn new(goal_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { goal_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalSimulateCommand {
+    pub goal_id: Uuid,
+
+    pub monthly_contribution_base_minor: Option<i64>,
+    pub lump_sum_base_minor: Option<i64>,
+    pub expected_annual_return: Option<Decimal>,
+    pub target_date: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalSimulateCommand {
+    pub fn new(goal_id: Uuid, request: GoalSimulationRequest, auth_user: AuthUser) -> Self {
+        Self {
+            goal_id,
+            monthly_contribution_base_minor: request.monthly_contribution_base_minor,
+            lump_sum_base_minor: request.lump_sum_base_minor,
+            expected_annual_return: request.expected_annual_return,
+            target_date: request.target_date,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalMonteCarloCommand {
+    pub goal_id: Uuid,
+
+    pub path_count: Option<u32>,
+    pub expected_annual_return: Option<Decimal>,
+    pub annual_volatility: Option<Decimal>,
+    pub annual_inflation: Option<Decimal>,
+    pub lump_sum_base_minor: Option<i64>,
+    pub contribution_schedule: Option<Vec<GoalMonteCarloContributionStep>>,
+    pub target_date: Option<NaiveDate>,
+    pub horizon_months: Option<i32>,
+    pub seed: Option<u64>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalMonteCarloCommand {
+    pub fn new(goal_id: Uuid, request: GoalMonteCarloRequest, auth_user: AuthUser) -> Self {
+        Self {
+            goal_id,
+            path_count: request.path_count,
+            expected_annual_return: request.expected_annual_return,
+            annual_volatility: request.annual_volatility,
+            annual_inflation: request.annual_inflation,
+            lump_sum_base_minor: request.lump_sum_base_minor,
+            contribution_schedule: request.contribution_schedule,
+            target_date: request.target_date,
+            horizon_months: request.horizon_months,
+            seed: request.seed,
+            auth_user,
+        }
+    }
+}
+
+
+// --- Contribution plans ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionPlanCreateCommand {
+    pub user_id: Uuid,
+    pub goal_id: Uuid,
+    pub plan_amount_base_minor: i64,
+    pub plan_start_month: NaiveDate,
+
+    pub plan_day_of_month: Option<u8>,
+    pub plan_from_account_id: Option<Uuid>,
+    pub plan_to_account_id: Option<Uuid>,
+    pub plan_end_month: Option<NaiveDate>,
+    pub plan_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionPlanCreateCommand {
+    pub fn new(goal_id: Uuid, request: GoalContributionPlanCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            goal_id,
+            plan_amount_base_minor: request.plan_amount_base_minor,
+            plan_start_month: request.plan_start_month,
+            plan_day_of_month: request.plan_day_of_month,
+            plan_from_account_id: request.plan_from_account_id,
+            plan_to_account_id: request.plan_to_account_id,
+            plan_end_month: request.plan_end_month,
+            plan_note: request.plan_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionPlanUpdateCommand {
+    pub plan_id: Uuid,
+
+    pub plan_amount_base_minor: i64,
+    pub plan_day_of_month: u8,
+    pub plan_from_account_id: Option<Uuid>,
+    pub plan_to_account_id: Option<Uuid>,
+    pub plan_end_month: Option<NaiveDate>,
+    pub plan_active: bool,
+    pub plan_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionPlanUpdateCommand {
+    pub fn new(plan_id: Uuid, request: GoalContributionPlanUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            plan_id,
+            plan_amount_base_minor: request.plan_amount_base_minor,
+            plan_day_of_month: request.plan_day_of_month,
+            plan_from_account_id: request.plan_from_account_id,
+            plan_to_account_id: request.plan_to_account_id,
+            plan_end_month: request.plan_end_month,
+            plan_active: request.plan_active,
+            plan_note: request.plan_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionPlanDeleteCommand {
+    pub plan_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionPlanDeleteCommand {
+    pub fn new(plan_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { plan_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionPlanListCommand {
+    pub goal_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionPlanListCommand {
+    pub fn new(goal_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { goal_id, auth_user }
+    }
+}
+
+
+// --- Contributions ---
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionCreateCommand {
+    pub user_id: Uuid,
+    pub goal_id: Uuid,
+    pub plan_id: Option<Uuid>,
+    pub contribution_due_date: NaiveDate,
+    pub contribution_planned_base_minor: i64,
+    pub contribution_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionCreateCommand {
+    pub fn new(goal_id: Uuid, request: GoalContributionCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            goal_id,
+            plan_id: None,
+            contribution_due_date: request.contribution_due_date,
+            contribution_planned_base_minor: request.contribution_planned_base_minor,
+            contribution_note: request.contribution_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionListCommand {
+    pub goal_id: Uuid,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionListCommand {
+    pub fn new(goal_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, auth_user: AuthUser) -> Self {
+        Self { goal_id, from, to, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionGenerateCommand {
+    pub user_id: Uuid,
+    pub month: NaiveDate,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionGenerateCommand {
+    pub fn new(user_id: Uuid, month: NaiveDate, auth_user: AuthUser) -> Self {
+        Self { user_id, month, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionDoneCommand {
+    pub contribution_id: Uuid,
+
+    pub contribution_transaction_id: Uuid,
+    pub contribution_executed_base_minor: Option<i64>,
+    pub contribution_executed_at: Option<DateTime<Utc>>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionDoneCommand {
+    pub fn new(contribution_id: Uuid, request: GoalContributionDoneRequest, auth_user: AuthUser) -> Self {
+        Self {
+            contribution_id,
+            contribution_transaction_id: request.contribution_transaction_id,
+            contribution_executed_base_minor: request.contribution_executed_base_minor,
+            contribution_executed_at: request.contribution_executed_at,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalContributionSkipCommand {
+    pub contribution_id: Uuid,
+
+    pub contribution_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl GoalContributionSkipCommand {
+    pub fn new(contribution_id: Uuid, request: GoalContributionSkipRequest, auth_user: AuthUser) -> Self {
+        Self {
+            contribution_id,
+            contribution_note: request.contribution_note,
+            auth_user,
+        }
+    }
+}
@@ -0,0 +1,612 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post, put}, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::goals::{
+    goal_command::*,
+    goal_dto::*,
+    goal_service::{GoalService, GoalServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_goals).post(post_goal))
+        .route("/contribution-plans/{plan_id}", put(put_contribution_plan).delete(delete_contribution_plan))
+        .route("/contributions/generate", post(post_generate_contributions))
+        .route("/contributions/{contribution_id}/done", put(put_contribution_done))
+        .route("/contributions/{contribution_id}/skip", put(put_contribution_skip))
+        .route("/{goal_id}", get(get_goal).put(put_goal).delete(delete_goal))
+        .route("/{goal_id}/progress", get(get_goal_progress))
+        .route("/{goal_id}/simulate", post(post_goal_simulation))
+        .route("/{goal_id}/monte-carlo", post(post_goal_monte_carlo))
+        .route("/{goal_id}/contribution-plans", get(get_contribution_plans).post(post_contribution_plan))
+        .route("/{goal_id}/contributions", get(get_contributions).post(post_contribution))
+        .route("/{goal_id}/contributions/summary", get(get_contribution_summary))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/goals",
+    responses(
+        (status = StatusCode::OK, description = "List of goals for current user, with progress", body = Vec<GoalResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn get_goals(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<GoalResponse>>, StatusCode> {
+    let command = GoalListByUserCommand::new(auth_user.user_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let goals = goal_service.get_by_user(command).await;
+    match goals {
+        Ok(goals) => Ok(Json(goals)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals",
+    responses(
+        (status = StatusCode::OK, description = "Goal successfully created", body = GoalResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid goal target"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn post_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(goal_create_request): Json<GoalCreateRequest>
+) -> Result<Json<GoalResponse>, StatusCode> {
+    let command = GoalCreateCommand::new(goal_create_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let goal = goal_service.create(command).await;
+    match goal {
+        Ok(goal) => Ok(Json(goal)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/goals/{goal_id}",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Goal found successfully", body = GoalResponse),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn get_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+) -> Result<Json<GoalResponse>, StatusCode> {
+    let command = GoalGetCommand::new(goal_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let goal = goal_service.get(command).await;
+    match goal {
+        Ok(goal) => {
+            match goal {
+                Some(goal) => Ok(Json(goal)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/goals/{goal_id}",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Goal updated successfully", body = GoalResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid goal target"),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn put_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+    Json(goal_update_request): Json<GoalUpdateRequest>
+) -> Result<Json<GoalResponse>, StatusCode> {
+    let command = GoalUpdateCommand::new(goal_id, goal_update_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let goal = goal_service.update(command).await;
+    match goal {
+        Ok(goal) => {
+            match goal {
+                Some(goal) => Ok(Json(goal)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/goals/{goal_id}",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Goal deleted"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn delete_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = GoalDeleteCommand::new(goal_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let response = goal_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/goals/{goal_id}/progress",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Current progress of the goal", body = GoalProgressResponse),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn get_goal_progress(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+) -> Result<Json<GoalProgressResponse>, StatusCode> {
+    let command = GoalGetCommand::new(goal_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let goal = goal_service.get(command).await;
+    match goal {
+        Ok(goal) => {
+            match goal.and_then(|goal| goal.goal_progress) {
+                Some(progress) => Ok(Json(progress)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/{goal_id}/simulate",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Projection of the goal, nothing is saved", body = GoalSimulationResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Missing contribution and target date, or invalid amounts"),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn post_goal_simulation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+    Json(goal_simulation_request): Json<GoalSimulationRequest>
+) -> Result<Json<GoalSimulationResponse>, StatusCode> {
+    let command = GoalSimulateCommand::new(goal_id, goal_simulation_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let simulation = goal_service.simulate(command).await;
+    match simulation {
+        Ok(simulation) => {
+            match simulation {
+                Some(simulation) => Ok(Json(simulation)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/{goal_id}/monte-carlo",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Percentile bands and probability of reaching the target, nothing is saved", body = GoalMonteCarloResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Debt goal, target date in the past, or invalid parameters"),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn post_goal_monte_carlo(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+    Json(goal_monte_carlo_request): Json<GoalMonteCarloRequest>
+) -> Result<Json<GoalMonteCarloResponse>, StatusCode> {
+    let command = GoalMonteCarloCommand::new(goal_id, goal_monte_carlo_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let monte_carlo = goal_service.monte_carlo(command).await;
+    match monte_carlo {
+        Ok(monte_carlo) => {
+            match monte_carlo {
+                Some(monte_carlo) => Ok(Json(monte_carlo)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/goals/{goal_id}/contribution-plans",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Contribution plans of the goal", body = Vec<GoalContributionPlanResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn get_contribution_plans(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+) -> Result<Json<Vec<GoalContributionPlanResponse>>, StatusCode> {
+    let command = GoalContributionPlanListCommand::new(goal_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let plans = goal_service.list_contribution_plans(command).await;
+    match plans {
+        Ok(plans) => Ok(Json(plans)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/{goal_id}/contribution-plans",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Contribution plan successfully created", body = GoalContributionPlanResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid amount, day or months"),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn post_contribution_plan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+    Json(plan_create_request): Json<GoalContributionPlanCreateRequest>
+) -> Result<Json<GoalContributionPlanResponse>, StatusCode> {
+    let command = GoalContributionPlanCreateCommand::new(goal_id, plan_create_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let plan = goal_service.create_contribution_plan(command).await;
+    match plan {
+        Ok(plan) => {
+            match plan {
+                Some(plan) => Ok(Json(plan)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/goals/contribution-plans/{plan_id}",
+    params(
+        ("plan_id", description = "contribution plan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Contribution plan successfully updated", body = GoalContributionPlanResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid amount, day or months"),
+        (status = StatusCode::NOT_FOUND, description = "Contribution plan not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn put_contribution_plan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(plan_id): Path<Uuid>,
+    Json(plan_update_request): Json<GoalContributionPlanUpdateRequest>
+) -> Result<Json<GoalContributionPlanResponse>, StatusCode> {
+    let command = GoalContributionPlanUpdateCommand::new(plan_id, plan_update_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let plan = goal_service.update_contribution_plan(command).await;
+    match plan {
+        Ok(plan) => {
+            match plan {
+                Some(plan) => Ok(Json(plan)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/goals/contribution-plans/{plan_id}",
+    params(
+        ("plan_id", description = "contribution plan identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Contribution plan deleted, its contributions are kept"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn delete_contribution_plan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(plan_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = GoalContributionPlanDeleteCommand::new(plan_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let response = goal_service.delete_contribution_plan(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/goals/{goal_id}/contributions",
+    params(
+        ("goal_id", description = "goal identifier in uuid"),
+        GoalContributionListQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Expected contributions of the goal, by due date", body = Vec<GoalContributionResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn get_contributions(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+    Query(query): Query<GoalContributionListQuery>,
+) -> Result<Json<Vec<GoalContributionResponse>>, StatusCode> {
+    let command = GoalContributionListCommand::new(goal_id, query.from, query.to, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let contributions = goal_service.list_contributions(command).await;
+    match contributions {
+        Ok(contributions) => Ok(Json(contributions)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/{goal_id}/contributions",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "One-off contribution successfully planned", body = GoalContributionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid amount"),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn post_contribution(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+    Json(contribution_create_request): Json<GoalContributionCreateRequest>
+) -> Result<Json<GoalContributionResponse>, StatusCode> {
+    let command = GoalContributionCreateCommand::new(goal_id, contribution_create_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let contribution = goal_service.create_contribution(command).await;
+    match contribution {
+        Ok(contribution) => {
+            match contribution {
+                Some(contribution) => Ok(Json(contribution)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/contributions/generate",
+    responses(
+        (status = StatusCode::OK, description = "Expected contributions of the month for every active plan", body = Vec<GoalContributionResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn post_generate_contributions(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(generate_request): Json<GoalContributionGenerateRequest>
+) -> Result<Json<Vec<GoalContributionResponse>>, StatusCode> {
+    let month = generate_request.month.unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let command = GoalContributionGenerateCommand::new(auth_user.user_id, month, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let contributions = goal_service.generate_contributions(command).await;
+    match contributions {
+        Ok(contributions) => Ok(Json(contributions)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/goals/contributions/{contribution_id}/done",
+    params(
+        ("contribution_id", description = "contribution identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Contribution linked to its transaction and marked done", body = GoalContributionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid executed amount"),
+        (status = StatusCode::NOT_FOUND, description = "Contribution not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn put_contribution_done(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(contribution_id): Path<Uuid>,
+    Json(done_request): Json<GoalContributionDoneRequest>
+) -> Result<Json<GoalContributionResponse>, StatusCode> {
+    let command = GoalContributionDoneCommand::new(contribution_id, done_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let contribution = goal_service.mark_contribution_done(command).await;
+    match contribution {
+        Ok(contribution) => {
+            match contribution {
+                Some(contribution) => Ok(Json(contribution)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/goals/contributions/{contribution_id}/skip",
+    params(
+        ("contribution_id", description = "contribution identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Contribution marked skipped", body = GoalContributionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Contribution already done"),
+        (status = StatusCode::NOT_FOUND, description = "Contribution not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn put_contribution_skip(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(contribution_id): Path<Uuid>,
+    Json(skip_request): Json<GoalContributionSkipRequest>
+) -> Result<Json<GoalContributionResponse>, StatusCode> {
+    let command = GoalContributionSkipCommand::new(contribution_id, skip_request, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let contribution = goal_service.skip_contribution(command).await;
+    match contribution {
+        Ok(contribution) => {
+            match contribution {
+                Some(contribution) => Ok(Json(contribution)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/goals/{goal_id}/contributions/summary",
+    params(
+        ("goal_id", description = "goal identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Planned vs executed contributions and streaks", body = GoalContributionSummaryResponse),
+        (status = StatusCode::NOT_FOUND, description = "Goal not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal"
+)]
+pub async fn get_contribution_summary(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(goal_id): Path<Uuid>,
+) -> Result<Json<GoalContributionSummaryResponse>, StatusCode> {
+    let command = GoalGetCommand::new(goal_id, auth_user);
+    let goal_service = GoalService::from(&state);
+
+    let summary = goal_service.contribution_summary(command).await;
+    match summary {
+        Ok(summary) => {
+            match summary {
+                Some(summary) => Ok(Json(summary)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
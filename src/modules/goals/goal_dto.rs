@@ -0,0 +1,391 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::goals::goal_model::{ContributionStatus, Goal, GoalContribution, GoalContributionPlan, GoalType};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalProgressSource {
+    LinkedAccount,
+    Transactions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalProgressStatus {
+    Achieved,
+    OnTrack,
+    Behind,
+    Overdue,
+    NoDeadline,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoalProgressResponse {
+    pub source: GoalProgressSource,
+
+    pub current_base_minor: i64,
+    pub percent: Decimal,
+    pub remaining_base_minor: i64,
+
+    /// Months left until the target date, current month included
+    pub months_remaining: Option<i32>,
+    pub required_monthly_base_minor: Option<i64>,
+    /// Where the goal should be today on a straight line from its creation to its target date
+    pub expected_base_minor: Option<i64>,
+
+    pub status: GoalProgressStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoalResponse {
+    pub goal_id: Uuid,
+    pub user_id: Uuid,
+    pub goal_name: String,
+    pub goal_type: GoalType,
+    pub goal_target_base_minor: i64,
+    pub goal_target_date: Option<NaiveDate>,
+    pub goal_priority: i32,
+    pub goal_linked_account_id: Option<Uuid>,
+
+    pub goal_progress: Option<GoalProgressResponse>,
+    /// Planned vs executed contributions, only filled on the single goal view
+    pub goal_contributions: Option<GoalContributionSummaryResponse>,
+
+    pub goal_created_at: Option<DateTime<Utc>>,
+    pub goal_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Goal> for GoalResponse {
+    fn from(goal: &Goal) -> Self {
+        Self {
+            goal_id: bu(goal.id.clone().unwrap().as_slice()),
+            user_id: bu(goal.user_id.as_slice()),
+            goal_name: goal.name.clone(),
+            goal_type: goal.goal_type,
+            goal_target_base_minor: goal.target_base_minor,
+            goal_target_date: goal.target_date,
+            goal_priority: goal.priority,
+            goal_linked_account_id: obu(goal.linked_account_id.as_deref()),
+            goal_progress: None,
+            goal_contributions: None,
+            goal_created_at: goal.created_at,
+            goal_updated_at: goal.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalCreateRequest {
+    pub goal_name: String,
+    pub goal_type: GoalType,
+    pub goal_target_base_minor: i64,
+
+    pub goal_target_date: Option<NaiveDate>,
+    pub goal_priority: Option<i32>,
+    /// Progress follows this account's balance instead of tagged transactions
+    pub goal_linked_account_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalUpdateRequest {
+    pub goal_name: String,
+    pub goal_type: GoalType,
+    pub goal_target_base_minor: i64,
+    pub goal_target_date: Option<NaiveDate>,
+    pub goal_priority: i32,
+    pub goal_linked_account_id: Option<Uuid>,
+}
+
+
+// --- Simulation ---
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalSimulationRequest {
+    /// Contribution to project; leave empty to solve for the contribution that reaches the target date
+    pub monthly_contribution_base_minor: Option<i64>,
+    /// One-off amount added right away
+    pub lump_sum_base_minor: Option<i64>,
+    /// Expected yearly return as a fraction (0.05 = 5%), at most 1
+    pub expected_annual_return: Option<Decimal>,
+    /// Overrides the goal target date when solving for the contribution
+    pub target_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalSimulationMode {
+    /// Given a monthly contribution, when is the target reached?
+    Projection,
+    /// Given a target date, which monthly contribution is needed?
+    RequiredContribution,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalSimulationPoint {
+    pub month: NaiveDate,
+    pub contribution_base_minor: i64,
+    pub growth_base_minor: i64,
+    pub balance_base_minor: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalSimulationResponse {
+    pub goal_id: Uuid,
+    pub mode: GoalSimulationMode,
+
+    pub start_base_minor: i64,
+    pub lump_sum_base_minor: i64,
+    pub target_base_minor: i64,
+    pub target_date: Option<NaiveDate>,
+    pub monthly_contribution_base_minor: i64,
+    pub expected_annual_return: Decimal,
+
+    /// None when the target is not reached within the simulation horizon
+    pub months_to_target: Option<i32>,
+    pub completion_date: Option<NaiveDate>,
+    pub reaches_target_on_time: Option<bool>,
+
+    pub total_contributed_base_minor: i64,
+    pub total_growth_base_minor: i64,
+
+    pub schedule: Vec<GoalSimulationPoint>,
+}
+
+
+// --- Monte Carlo ---
+
+/// Monthly contribution applied from `from_month` until the next step
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoalMonteCarloContributionStep {
+    pub from_month: NaiveDate,
+    pub monthly_contribution_base_minor: i64,
+}
+
+/// Amounts are in today's money: returns are deflated by the inflation and contributions stay constant in real terms
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalMonteCarloRequest {
+    /// 1000 by default, 10000 at most
+    pub path_count: Option<u32>,
+    /// Expected yearly return as a fraction (0.05 = 5%), 0 by default, at most 1
+    pub expected_annual_return: Option<Decimal>,
+    /// Yearly standard deviation of the return as a fraction (0.15 = 15%), 0 by default
+    pub annual_volatility: Option<Decimal>,
+    /// Yearly inflation as a fraction (0.02 = 2%), 0 by default
+    pub annual_inflation: Option<Decimal>,
+    /// One-off amount added right away
+    pub lump_sum_base_minor: Option<i64>,
+    /// Defaults to the active contribution plans of the goal
+    pub contribution_schedule: Option<Vec<GoalMonteCarloContributionStep>>,
+    /// Overrides the goal target date
+    pub target_date: Option<NaiveDate>,
+    /// Months simulated, up to the target date by default (30 years without one) and 600 at most
+    pub horizon_months: Option<i32>,
+    /// Same seed, same paths; random when empty
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalMonteCarloBand {
+    pub month: NaiveDate,
+    /// Contributed since the start, lump sum excluded
+    pub contributed_base_minor: i64,
+    pub p10_base_minor: i64,
+    pub p50_base_minor: i64,
+    pub p90_base_minor: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalMonteCarloResponse {
+    pub goal_id: Uuid,
+    pub path_count: u32,
+    pub seed: u64,
+
+    pub start_base_minor: i64,
+    pub lump_sum_base_minor: i64,
+    pub target_base_minor: i64,
+    pub target_date: Option<NaiveDate>,
+    pub expected_annual_return: Decimal,
+    pub annual_volatility: Decimal,
+    pub annual_inflation: Decimal,
+    pub horizon_months: i32,
+
+    /// Share of the paths reaching the target by the end of `probability_month`
+    pub target_probability_percent: Decimal,
+    /// Month of the target date, or the last simulated month without one
+    pub probability_month: NaiveDate,
+    pub total_contributed_base_minor: i64,
+
+    /// Balances at the end of each month
+    pub bands: Vec<GoalMonteCarloBand>,
+}
+
+
+// --- Contribution plans ---
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionPlanResponse {
+    pub plan_id: Uuid,
+    pub goal_id: Uuid,
+    pub plan_amount_base_minor: i64,
+    pub plan_day_of_month: u8,
+    pub plan_from_account_id: Option<Uuid>,
+    pub plan_to_account_id: Option<Uuid>,
+    pub plan_start_month: NaiveDate,
+    pub plan_end_month: Option<NaiveDate>,
+    pub plan_active: bool,
+    pub plan_note: Option<String>,
+
+    pub plan_created_at: Option<DateTime<Utc>>,
+    pub plan_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&GoalContributionPlan> for GoalContributionPlanResponse {
+    fn from(plan: &GoalContributionPlan) -> Self {
+        Self {
+            plan_id: bu(plan.id.clone().unwrap().as_slice()),
+            goal_id: bu(plan.goal_id.as_slice()),
+            plan_amount_base_minor: plan.amount_base_minor,
+            plan_day_of_month: plan.day_of_month,
+            plan_from_account_id: obu(plan.from_account_id.as_deref()),
+            plan_to_account_id: obu(plan.to_account_id.as_deref()),
+            plan_start_month: plan.start_month,
+            plan_end_month: plan.end_month,
+            plan_active: plan.active,
+            plan_note: plan.note.clone(),
+            plan_created_at: plan.created_at,
+            plan_updated_at: plan.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionPlanCreateRequest {
+    pub plan_amount_base_minor: i64,
+    /// First month of the plan (any day of the month)
+    pub plan_start_month: NaiveDate,
+
+    /// Day of the month the transfer is expected (1 by default, clamped to the end of short months)
+    pub plan_day_of_month: Option<u8>,
+    pub plan_from_account_id: Option<Uuid>,
+    pub plan_to_account_id: Option<Uuid>,
+    pub plan_end_month: Option<NaiveDate>,
+    pub plan_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionPlanUpdateRequest {
+    pub plan_amount_base_minor: i64,
+    pub plan_day_of_month: u8,
+    pub plan_from_account_id: Option<Uuid>,
+    pub plan_to_account_id: Option<Uuid>,
+    pub plan_end_month: Option<NaiveDate>,
+    pub plan_active: bool,
+    pub plan_note: Option<String>,
+}
+
+
+// --- Contributions ---
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionResponse {
+    pub contribution_id: Uuid,
+    pub goal_id: Uuid,
+    pub plan_id: Option<Uuid>,
+    pub contribution_due_date: NaiveDate,
+    pub contribution_planned_base_minor: i64,
+    pub contribution_status: ContributionStatus,
+
+    pub contribution_transaction_id: Option<Uuid>,
+    pub contribution_executed_base_minor: Option<i64>,
+    pub contribution_executed_at: Option<DateTime<Utc>>,
+    /// Still planned after its due date
+    pub contribution_missed: bool,
+
+    pub contribution_note: Option<String>,
+    pub contribution_created_at: Option<DateTime<Utc>>,
+    pub contribution_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&GoalContribution> for GoalContributionResponse {
+    fn from(contribution: &GoalContribution) -> Self {
+        Self {
+            contribution_id: bu(contribution.id.clone().unwrap().as_slice()),
+            goal_id: bu(contribution.goal_id.as_slice()),
+            plan_id: obu(contribution.plan_id.as_deref()),
+            contribution_due_date: contribution.due_date,
+            contribution_planned_base_minor: contribution.planned_base_minor,
+            contribution_status: contribution.status,
+            contribution_transaction_id: obu(contribution.transaction_id.as_deref()),
+            contribution_executed_base_minor: contribution.executed_base_minor,
+            contribution_executed_at: contribution.executed_at,
+            contribution_missed: false,
+            contribution_note: contribution.note.clone(),
+            contribution_created_at: contribution.created_at,
+            contribution_updated_at: contribution.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionCreateRequest {
+    pub contribution_due_date: NaiveDate,
+    pub contribution_planned_base_minor: i64,
+    pub contribution_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionGenerateRequest {
+    /// Month to generate (current month by default)
+    pub month: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionDoneRequest {
+    /// Real transaction (or transfer leg) that executed the contribution
+    pub contribution_transaction_id: Uuid,
+    /// Defaults to the planned amount
+    pub contribution_executed_base_minor: Option<i64>,
+    pub contribution_executed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionSkipRequest {
+    pub contribution_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GoalContributionListQuery {
+    /// First due date included
+    pub from: Option<NaiveDate>,
+    /// Last due date included
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoalContributionSummaryResponse {
+    pub goal_id: Uuid,
+
+    pub done_count: i32,
+    pub skipped_count: i32,
+    pub missed_count: i32,
+    pub upcoming_count: i32,
+
+    /// Planned amounts due up to today, skipped contributions excluded
+    pub planned_to_date_base_minor: i64,
+    pub executed_base_minor: i64,
+    /// Executed minus planned to date (negative when behind the plan)
+    pub variance_base_minor: i64,
+    pub execution_percent: Decimal,
+    pub upcoming_base_minor: i64,
+
+    /// Contributions done in a row, up to the last one due
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub next_due_date: Option<NaiveDate>,
+}
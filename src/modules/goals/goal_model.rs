@@ -0,0 +1,234 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::goals::goal_command::{GoalContributionCreateCommand, GoalContributionPlanCreateCommand, GoalCreateCommand};
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum GoalType {
+    Savings,
+    Debt,
+    Investment,
+    OneShot,
+}
+
+impl GoalType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalType::Savings => "savings",
+            GoalType::Debt => "debt",
+            GoalType::Investment => "investment",
+            GoalType::OneShot => "one_shot",
+        }
+    }
+}
+
+
+/// Goal row.
+/// The procedures also return the two progress sources: the balance of the linked account
+/// (NULL without linked account) and the sum of transactions tagged with the goal, both in base currency.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Goal {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub goal_type: GoalType,
+    pub target_base_minor: i64,
+    pub target_date: Option<NaiveDate>,
+    pub priority: i32,
+    pub linked_account_id: Option<Vec<u8>>,
+
+    pub linked_balance_base_minor: Option<i64>,
+    pub tagged_base_minor: i64,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Goal {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            goal_type: row.try_get(index_map["goal_type"])?,
+            target_base_minor: row.try_get(index_map["target_base_minor"])?,
+            target_date: row.try_get(index_map["target_date"])?,
+            priority: row.try_get(index_map["priority"])?,
+            linked_account_id: row.try_get(index_map["linked_account_id"])?,
+            linked_balance_base_minor: row.try_get(index_map["linked_balance_base_minor"])?,
+            tagged_base_minor: row.try_get(index_map["tagged_base_minor"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<GoalCreateCommand> for Goal {
+    fn from(command: GoalCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            name: command.goal_name,
+            goal_type: command.goal_type,
+            target_base_minor: command.goal_target_base_minor,
+            target_date: command.goal_target_date,
+            priority: command.goal_priority.unwrap_or(0),
+            linked_account_id: oub(command.goal_linked_account_id),
+            linked_balance_base_minor: None,
+            tagged_base_minor: 0,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum ContributionStatus {
+    Planned,
+    Done,
+    Skipped,
+}
+
+
+/// Recurring contribution plan of a goal: a "virtual" transfer of `amount_base_minor`
+/// from one account to another on `day_of_month`, between `start_month` and `end_month`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct GoalContributionPlan {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub goal_id: Vec<u8>,
+    pub amount_base_minor: i64,
+    pub day_of_month: u8,
+    pub from_account_id: Option<Vec<u8>>,
+    pub to_account_id: Option<Vec<u8>>,
+    pub start_month: NaiveDate,
+    pub end_month: Option<NaiveDate>,
+    pub active: bool,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl GoalContributionPlan {
+    /// Whether the plan expects a contribution in the month starting on `month`
+    pub fn covers(&self, month: NaiveDate) -> bool {
+        self.active && self.start_month <= month && self.end_month.is_none_or(|end_month| month <= end_month)
+    }
+}
+
+impl FromSqlRow for GoalContributionPlan {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            goal_id: row.try_get(index_map["goal_id"])?,
+            amount_base_minor: row.try_get(index_map["amount_base_minor"])?,
+            day_of_month: row.try_get(index_map["day_of_month"])?,
+            from_account_id: row.try_get(index_map["from_account_id"])?,
+            to_account_id: row.try_get(index_map["to_account_id"])?,
+            start_month: row.try_get(index_map["start_month"])?,
+            end_month: row.try_get(index_map["end_month"])?,
+            active: row.try_get(index_map["active"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<GoalContributionPlanCreateCommand> for GoalContributionPlan {
+    fn from(command: GoalContributionPlanCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            goal_id: ub(command.goal_id),
+            amount_base_minor: command.plan_amount_base_minor,
+            day_of_month: command.plan_day_of_month.unwrap_or(1),
+            from_account_id: oub(command.plan_from_account_id),
+            to_account_id: oub(command.plan_to_account_id),
+            start_month: command.plan_start_month,
+            end_month: command.plan_end_month,
+            active: true,
+            note: command.plan_note,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Expected contribution to a goal, generated from a plan (or added by hand),
+/// later marked done with the real transaction or skipped.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct GoalContribution {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub goal_id: Vec<u8>,
+    pub plan_id: Option<Vec<u8>>,
+    pub due_date: NaiveDate,
+    pub planned_base_minor: i64,
+    pub status: ContributionStatus,
+
+    pub transaction_id: Option<Vec<u8>>,
+    pub executed_base_minor: Option<i64>,
+    pub executed_at: Option<DateTime<Utc>>,
+
+    pub note: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for GoalContribution {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            goal_id: row.try_get(index_map["goal_id"])?,
+            plan_id: row.try_get(index_map["plan_id"])?,
+            due_date: row.try_get(index_map["due_date"])?,
+            planned_base_minor: row.try_get(index_map["planned_base_minor"])?,
+            status: row.try_get(index_map["status"])?,
+            transaction_id: row.try_get(index_map["transaction_id"])?,
+            executed_base_minor: row.try_get(index_map["executed_base_minor"])?,
+            executed_at: row.try_get(index_map["executed_at"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<GoalContributionCreateCommand> for GoalContribution {
+    fn from(command: GoalContributionCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            goal_id: ub(command.goal_id),
+            plan_id: oub(command.plan_id),
+            due_date: command.contribution_due_date,
+            planned_base_minor: command.contribution_planned_base_minor,
+            status: ContributionStatus::Planned,
+            transaction_id: None,
+            executed_base_minor: None,
+            executed_at: None,
+            note: command.contribution_note,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
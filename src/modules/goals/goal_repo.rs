@@ -0,0 +1,307 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::goals::goal_model::{Goal, GoalContribution, GoalContributionPlan};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait GoalRepositoryInterface {
+
+    async fn get(&self, goal_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Goal>, Error>;
+
+    async fn create(&self, goal: Goal, meta_user: Option<Uuid>) -> Result<Goal, Error>;
+
+    async fn update(&self, goal: Goal, meta_user: Option<Uuid>) -> Result<Option<Goal>, Error>;
+
+    async fn delete(&self, goal_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Goal>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct GoalRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for GoalRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Goal> for GoalRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl GoalRepositoryInterface for GoalRepository {
+    async fn get(&self, goal_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Goal>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(goal_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_get_by_id", params).await
+    }
+
+    async fn create(&self, goal: Goal, meta_user: Option<Uuid>) -> Result<Goal, Error> {
+        let params = vec![
+            MySqlParam::from(goal.user_id),
+            MySqlParam::from(goal.name),
+            MySqlParam::from(goal.goal_type.as_str()),
+            MySqlParam::from(goal.target_base_minor),
+            MySqlParam::from(goal.target_date),
+            MySqlParam::from(goal.priority),
+            MySqlParam::from(goal.linked_account_id),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_goal_create", params).await
+    }
+
+    async fn update(&self, goal: Goal, meta_user: Option<Uuid>) -> Result<Option<Goal>, Error> {
+        let params = vec![
+            MySqlParam::from(goal.id),
+            MySqlParam::from(goal.name),
+            MySqlParam::from(goal.goal_type.as_str()),
+            MySqlParam::from(goal.target_base_minor),
+            MySqlParam::from(goal.target_date),
+            MySqlParam::from(goal.priority),
+            MySqlParam::from(goal.linked_account_id),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_update", params).await
+    }
+
+    async fn delete(&self, goal_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(goal_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_goal_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Goal>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_goal_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait GoalContributionPlanRepositoryInterface {
+
+    async fn get(&self, plan_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<GoalContributionPlan>, Error>;
+
+    async fn create(&self, plan: GoalContributionPlan, meta_user: Option<Uuid>) -> Result<GoalContributionPlan, Error>;
+
+    async fn update(&self, plan: GoalContributionPlan, meta_user: Option<Uuid>) -> Result<Option<GoalContributionPlan>, Error>;
+
+    async fn delete(&self, plan_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_goal(&self, goal_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<GoalContributionPlan>, Error>;
+
+    async fn get_active_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<GoalContributionPlan>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct GoalContributionPlanRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for GoalContributionPlanRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<GoalContributionPlan> for GoalContributionPlanRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl GoalContributionPlanRepositoryInterface for GoalContributionPlanRepository {
+    async fn get(&self, plan_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<GoalContributionPlan>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(plan_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_contribution_plan_get_by_id", params).await
+    }
+
+    async fn create(&self, plan: GoalContributionPlan, meta_user: Option<Uuid>) -> Result<GoalContributionPlan, Error> {
+        let params = vec![
+            MySqlParam::from(plan.user_id),
+            MySqlParam::from(plan.goal_id),
+            MySqlParam::from(plan.amount_base_minor),
+            MySqlParam::from(plan.day_of_month),
+            MySqlParam::from(plan.from_account_id),
+            MySqlParam::from(plan.to_account_id),
+            MySqlParam::from(plan.start_month),
+            MySqlParam::from(plan.end_month),
+            MySqlParam::from(plan.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_goal_contribution_plan_create", params).await
+    }
+
+    async fn update(&self, plan: GoalContributionPlan, meta_user: Option<Uuid>) -> Result<Option<GoalContributionPlan>, Error> {
+        let params = vec![
+            MySqlParam::from(plan.id),
+            MySqlParam::from(plan.amount_base_minor),
+            MySqlParam::from(plan.day_of_month),
+            MySqlParam::from(plan.from_account_id),
+            MySqlParam::from(plan.to_account_id),
+            MySqlParam::from(plan.end_month),
+            MySqlParam::from(plan.active),
+            MySqlParam::from(plan.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_contribution_plan_update", params).await
+    }
+
+    async fn delete(&self, plan_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(plan_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_goal_contribution_plan_delete", params).await
+    }
+
+    async fn get_by_goal(&self, goal_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<GoalContributionPlan>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(goal_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_goal_contribution_plan_by_goal", params).await
+    }
+
+    async fn get_active_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<GoalContributionPlan>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_goal_contribution_plan_active_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait GoalContributionRepositoryInterface {
+
+    async fn get(&self, contribution_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<GoalContribution>, Error>;
+
+    /// Idempotent for plan occurrences: returns the existing row when the plan already has one on that due date
+    async fn create(&self, contribution: GoalContribution, meta_user: Option<Uuid>) -> Result<GoalContribution, Error>;
+
+    async fn get_by_goal(&self, goal_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<GoalContribution>, Error>;
+
+    async fn mark_done(&self, contribution_id: Uuid, transaction_id: Uuid, executed_base_minor: i64, executed_at: DateTime<Utc>, meta_user: Option<Uuid>) -> Result<Option<GoalContribution>, Error>;
+
+    async fn mark_skipped(&self, contribution_id: Uuid, note: Option<String>, meta_user: Option<Uuid>) -> Result<Option<GoalContribution>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct GoalContributionRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for GoalContributionRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<GoalContribution> for GoalContributionRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl GoalContributionRepositoryInterface for GoalContributionRepository {
+    async fn get(&self, contribution_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<GoalContribution>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(contribution_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_contribution_get_by_id", params).await
+    }
+
+    async fn create(&self, contribution: GoalContribution, meta_user: Option<Uuid>) -> Result<GoalContribution, Error> {
+        let params = vec![
+            MySqlParam::from(contribution.user_id),
+            MySqlParam::from(contribution.goal_id),
+            MySqlParam::from(contribution.plan_id),
+            MySqlParam::from(contribution.due_date),
+            MySqlParam::from(contribution.planned_base_minor),
+            MySqlParam::from(contribution.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_goal_contribution_create", params).await
+    }
+
+    async fn get_by_goal(&self, goal_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<GoalContribution>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(goal_id)),
+            MySqlParam::from(from),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_goal_contribution_by_goal", params).await
+    }
+
+    async fn mark_done(&self, contribution_id: Uuid, transaction_id: Uuid, executed_base_minor: i64, executed_at: DateTime<Utc>, meta_user: Option<Uuid>) -> Result<Option<GoalContribution>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(contribution_id)),
+            MySqlParam::from(ub(transaction_id)),
+            MySqlParam::from(executed_base_minor),
+            MySqlParam::from(executed_at),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_contribution_mark_done", params).await
+    }
+
+    async fn mark_skipped(&self, contribution_id: Uuid, note: Option<String>, meta_user: Option<Uuid>) -> Result<Option<GoalContribution>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(contribution_id)),
+            MySqlParam::from(note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_goal_contribution_mark_skipped", params).await
+    }
+}
@@ -0,0 +1,780 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use uuid::Uuid;
+
+use crate::modules::goals::{
+    goal_command::*,
+    goal_dto::*,
+    goal_model::{ContributionStatus, Goal, GoalContribution, GoalContributionPlan, GoalType},
+    montecarlo_model::MonteCarlo,
+    goal_repo::{
+        GoalRepository, GoalRepositoryInterface,
+        GoalContributionPlanRepository, GoalContributionPlanRepositoryInterface,
+        GoalContributionRepository, GoalContributionRepositoryInterface
+    }
+};
+use crate::shared::dates::{add_months, day_in_month, month_start, months_between};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait GoalServiceInterface {
+
+    async fn get(&self, command: GoalGetCommand) -> Result<Option<GoalResponse>, Error>;
+
+    async fn create(&self, command: GoalCreateCommand) -> Result<GoalResponse, Error>;
+
+    async fn update(&self, command: GoalUpdateCommand) -> Result<Option<GoalResponse>, Error>;
+
+    async fn delete(&self, command: GoalDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: GoalListByUserCommand) -> Result<Vec<GoalResponse>, Error>;
+
+    async fn simulate(&self, command: GoalSimulateCommand) -> Result<Option<GoalSimulationResponse>, Error>;
+
+    /// Percentile bands over random return paths and the probability of reaching the target
+    async fn monte_carlo(&self, command: GoalMonteCarloCommand) -> Result<Option<GoalMonteCarloResponse>, Error>;
+
+
+    // --- Contribution plans ---
+
+    async fn create_contribution_plan(&self, command: GoalContributionPlanCreateCommand) -> Result<Option<GoalContributionPlanResponse>, Error>;
+
+    async fn update_contribution_plan(&self, command: GoalContributionPlanUpdateCommand) -> Result<Option<GoalContributionPlanResponse>, Error>;
+
+    async fn delete_contribution_plan(&self, command: GoalContributionPlanDeleteCommand) -> Result<(), Error>;
+
+    async fn list_contribution_plans(&self, command: GoalContributionPlanListCommand) -> Result<Vec<GoalContributionPlanResponse>, Error>;
+
+
+    // --- Contributions ---
+
+    async fn create_contribution(&self, command: GoalContributionCreateCommand) -> Result<Option<GoalContributionResponse>, Error>;
+
+    async fn list_contributions(&self, command: GoalContributionListCommand) -> Result<Vec<GoalContributionResponse>, Error>;
+
+    async fn generate_contributions(&self, command: GoalContributionGenerateCommand) -> Result<Vec<GoalContributionResponse>, Error>;
+
+    async fn mark_contribution_done(&self, command: GoalContributionDoneCommand) -> Result<Option<GoalContributionResponse>, Error>;
+
+    async fn skip_contribution(&self, command: GoalContributionSkipCommand) -> Result<Option<GoalContributionResponse>, Error>;
+
+    async fn contribution_summary(&self, command: GoalGetCommand) -> Result<Option<GoalContributionSummaryResponse>, Error>;
+
+}
+
+/// Longest projection we run when the target is never reached (50 years)
+const MAX_SIMULATION_MONTHS: i32 = 600;
+
+/// Highest yearly return accepted by the simulations (100%)
+const MAX_EXPECTED_ANNUAL_RETURN: f64 = 1.0;
+
+/// Monte Carlo horizon when the goal has no target date (30 years)
+const DEFAULT_MONTE_CARLO_MONTHS: i32 = 360;
+const DEFAULT_MONTE_CARLO_PATHS: u32 = 1000;
+const MAX_MONTE_CARLO_PATHS: u32 = 10000;
+
+/// Goals are not cached in Redis: their progress is derived from balances and transactions
+/// that change outside of this service.
+#[derive(Clone)]
+pub struct GoalService {
+    goal_repo: GoalRepository,
+    plan_repo: GoalContributionPlanRepository,
+    contribution_repo: GoalContributionRepository,
+}
+
+impl From<&AppState> for GoalService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            goal_repo: GoalRepository::from(app_state),
+            plan_repo: GoalContributionPlanRepository::from(app_state),
+            contribution_repo: GoalContributionRepository::from(app_state),
+        }
+    }
+}
+
+impl GoalService {
+    fn goal_response(goal: &Goal, today: NaiveDate) -> GoalResponse {
+        let mut response = GoalResponse::from(goal);
+        response.goal_progress = Some(Self::progress(goal, today));
+        response
+    }
+
+    /// Amount reached so far.
+    /// A linked account wins over tagged transactions. For debt goals the linked account balance is
+    /// what is still owed (negative), and tagged transactions are repayments (outflows).
+    fn current_amount(goal: &Goal) -> (GoalProgressSource, i64) {
+        match goal.linked_balance_base_minor {
+            Some(balance) if goal.goal_type == GoalType::Debt => (GoalProgressSource::LinkedAccount, goal.target_base_minor + balance.min(0)),
+            Some(balance) => (GoalProgressSource::LinkedAccount, balance),
+            None if goal.goal_type == GoalType::Debt => (GoalProgressSource::Transactions, -goal.tagged_base_minor),
+            None => (GoalProgressSource::Transactions, goal.tagged_base_minor),
+        }
+    }
+
+    /// Progress of a goal as of `today`.
+    /// On-track means at or above a straight line from the goal creation to its target date.
+    pub fn progress(goal: &Goal, today: NaiveDate) -> GoalProgressResponse {
+        let (source, current) = Self::current_amount(goal);
+        let current = current.max(0);
+        let target = goal.target_base_minor.max(0);
+        let remaining = (target - current).max(0);
+
+        let percent = if target > 0 {
+            (Decimal::from(current) * Decimal::from(100) / Decimal::from(target)).round_dp(2)
+        } else {
+            Decimal::from(100)
+        };
+
+        let Some(target_date) = goal.target_date else {
+            return GoalProgressResponse {
+                source,
+                current_base_minor: current,
+                percent,
+                remaining_base_minor: remaining,
+                months_remaining: None,
+                required_monthly_base_minor: None,
+                expected_base_minor: None,
+                status: if remaining == 0 { GoalProgressStatus::Achieved } else { GoalProgressStatus::NoDeadline },
+            };
+        };
+
+        let months_remaining = (months_between(today, target_date) + 1).max(0);
+        let required_monthly = if months_remaining > 0 {
+            (remaining + months_remaining as i64 - 1) / months_remaining as i64
+        } else {
+            remaining
+        };
+
+        let start = goal.created_at.map(|created_at| created_at.date_naive()).unwrap_or(today);
+        let total_days = (target_date - start).num_days();
+        let elapsed_days = (today - start).num_days().clamp(0, total_days.max(0));
+        let expected = if total_days > 0 {
+            (target as i128 * elapsed_days as i128 / total_days as i128) as i64
+        } else {
+            target
+        };
+
+        let status = if remaining == 0 {
+            GoalProgressStatus::Achieved
+        } else if today > target_date {
+            GoalProgressStatus::Overdue
+        } else if current < expected {
+            GoalProgressStatus::Behind
+        } else {
+            GoalProgressStatus::OnTrack
+        };
+
+        GoalProgressResponse {
+            source,
+            current_base_minor: current,
+            percent,
+            remaining_base_minor: remaining,
+            months_remaining: Some(months_remaining),
+            required_monthly_base_minor: Some(required_monthly),
+            expected_base_minor: Some(expected),
+            status,
+        }
+    }
+
+    /// Monthly rate equivalent to a yearly return, compounded monthly
+    pub fn monthly_rate(expected_annual_return: Decimal) -> Result<f64, Error> {
+        let annual = expected_annual_return.to_f64().unwrap_or(0.0);
+        if annual <= -1.0 || annual > MAX_EXPECTED_ANNUAL_RETURN {
+            return Err(AppError::BadRequest("expected annual return must be above -100% and at most 100%".into()).into());
+        }
+        Ok((1.0 + annual).powf(1.0 / 12.0) - 1.0)
+    }
+
+    /// Smallest monthly contribution that brings `start` to `target` after `months` months
+    /// (growth applied on the balance, contribution added at the end of each month)
+    fn required_contribution(start: i64, target: i64, months: i32, rate: f64) -> i64 {
+        let gap = (target - start) as f64;
+        let contribution = if rate.abs() < f64::EPSILON {
+            gap / months as f64
+        } else {
+            let growth = (1.0 + rate).powi(months);
+            (target as f64 - start as f64 * growth) * rate / (growth - 1.0)
+        };
+        contribution.ceil().max(0.0) as i64
+    }
+
+    /// Month-by-month projection starting at `first_month`.
+    /// Stops once the target is reached, unless `horizon` asks for a fixed number of months.
+    pub fn project(
+        start: i64,
+        target: i64,
+        contribution: i64,
+        rate: f64,
+        first_month: NaiveDate,
+        horizon: Option<i32>,
+    ) -> (Vec<GoalSimulationPoint>, Option<i32>) {
+        let mut schedule = Vec::new();
+        let mut balance = start;
+        let mut months_to_target = if balance >= target { Some(0) } else { None };
+
+        let last_month = horizon.unwrap_or(MAX_SIMULATION_MONTHS);
+        for index in 0..last_month {
+            if horizon.is_none() && months_to_target.is_some() {
+                break;
+            }
+
+            let growth = (balance as f64 * rate).round() as i64;
+            balance = balance.saturating_add(growth).saturating_add(contribution);
+            schedule.push(GoalSimulationPoint {
+                month: add_months(first_month, index),
+                contribution_base_minor: contribution,
+                growth_base_minor: growth,
+                balance_base_minor: balance,
+            });
+
+            if months_to_target.is_none() && balance >= target {
+                months_to_target = Some(index + 1);
+            }
+        }
+
+        (schedule, months_to_target)
+    }
+
+    fn contribution_response(contribution: &GoalContribution, today: NaiveDate) -> GoalContributionResponse {
+        let mut response = GoalContributionResponse::from(contribution);
+        response.contribution_missed = contribution.status == ContributionStatus::Planned && contribution.due_date < today;
+        response
+    }
+
+    /// Planned vs executed contributions of a goal as of `today`.
+    /// A contribution still planned after its due date is missed and, like a skipped one, breaks the streak;
+    /// contributions due today or later are upcoming and do not count yet.
+    pub fn contribution_summary_of(goal_id: Uuid, contributions: &[GoalContribution], today: NaiveDate) -> GoalContributionSummaryResponse {
+        let mut sorted: Vec<&GoalContribution> = contributions.iter().collect();
+        sorted.sort_by_key(|contribution| contribution.due_date);
+
+        let (mut done_count, mut skipped_count, mut missed_count, mut upcoming_count) = (0, 0, 0, 0);
+        let (mut planned_to_date, mut executed, mut upcoming) = (0i64, 0i64, 0i64);
+        let (mut current_streak, mut longest_streak) = (0, 0);
+        let mut next_due_date = None;
+
+        for contribution in sorted {
+            match contribution.status {
+                ContributionStatus::Done => {
+                    done_count += 1;
+                    executed += contribution.executed_base_minor.unwrap_or(contribution.planned_base_minor);
+                    if contribution.due_date <= today {
+                        planned_to_date += contribution.planned_base_minor;
+                    }
+                    current_streak += 1;
+                    longest_streak = longest_streak.max(current_streak);
+                },
+                ContributionStatus::Skipped => {
+                    skipped_count += 1;
+                    if contribution.due_date <= today {
+                        current_streak = 0;
+                    }
+                },
+                ContributionStatus::Planned if contribution.due_date < today => {
+                    missed_count += 1;
+                    planned_to_date += contribution.planned_base_minor;
+                    current_streak = 0;
+                },
+                ContributionStatus::Planned => {
+                    upcoming_count += 1;
+                    upcoming += contribution.planned_base_minor;
+                    if contribution.due_date == today {
+                        planned_to_date += contribution.planned_base_minor;
+                    }
+                    next_due_date = next_due_date.or(Some(contribution.due_date));
+                },
+            }
+        }
+
+        let execution_percent = if planned_to_date > 0 {
+            (Decimal::from(executed) * Decimal::from(100) / Decimal::from(planned_to_date)).round_dp(2)
+        } else {
+            Decimal::from(100)
+        };
+
+        GoalContributionSummaryResponse {
+            goal_id,
+            done_count,
+            skipped_count,
+            missed_count,
+            upcoming_count,
+            planned_to_date_base_minor: planned_to_date,
+            executed_base_minor: executed,
+            variance_base_minor: executed - planned_to_date,
+            execution_percent,
+            upcoming_base_minor: upcoming,
+            current_streak,
+            longest_streak,
+            next_due_date,
+        }
+    }
+
+    fn validate_plan(amount_base_minor: i64, day_of_month: u8, start_month: Option<NaiveDate>, end_month: Option<NaiveDate>) -> Result<(), Error> {
+        if amount_base_minor <= 0 {
+            return Err(AppError::BadRequest("contribution amount must be positive".into()).into());
+        }
+        if !(1..=31).contains(&day_of_month) {
+            return Err(AppError::BadRequest("day of month must be between 1 and 31".into()).into());
+        }
+        if let (Some(start_month), Some(end_month)) = (start_month, end_month)
+            && month_start(end_month) < month_start(start_month) {
+            return Err(AppError::BadRequest("plan ends before it starts".into()).into());
+        }
+        Ok(())
+    }
+
+    fn validate_target(target_base_minor: i64) -> Result<(), Error> {
+        if target_base_minor <= 0 {
+            return Err(AppError::BadRequest("goal target must be positive".into()).into());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GoalServiceInterface for GoalService {
+    async fn get(&self, command: GoalGetCommand) -> Result<Option<GoalResponse>, Error> {
+        let today = Utc::now().date_naive();
+        let meta_user = Some(command.auth_user.user_id);
+
+        let goal = match self.goal_repo.get(command.goal_id, meta_user).await {
+            Ok(Some(goal)) => goal,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting goal")),
+        };
+
+        let contributions = self.contribution_repo.get_by_goal(command.goal_id, None, None, meta_user).await;
+        match contributions {
+            Ok(contributions) => {
+                let mut response = Self::goal_response(&goal, today);
+                response.goal_contributions = Some(Self::contribution_summary_of(command.goal_id, &contributions, today));
+                Ok(Some(response))
+            },
+            Err(_) => Err(Error::msg("Error getting goal contributions")),
+        }
+    }
+
+    async fn create(&self, command: GoalCreateCommand) -> Result<GoalResponse, Error> {
+        Self::validate_target(command.goal_target_base_minor)?;
+
+        let meta_user = command.auth_user.user_id;
+        let goal_create = Goal::from(command);
+
+        let goal = self.goal_repo.create(goal_create, Some(meta_user)).await;
+        match goal {
+            Ok(goal) => Ok(Self::goal_response(&goal, Utc::now().date_naive())),
+            Err(_) => Err(Error::msg("Error creating goal")),
+        }
+    }
+
+    async fn update(&self, command: GoalUpdateCommand) -> Result<Option<GoalResponse>, Error> {
+        Self::validate_target(command.goal_target_base_minor)?;
+
+        let meta_user = command.auth_user.user_id;
+        let goal_update = Goal {
+            id: Some(ub(command.goal_id)),
+            user_id: ub(meta_user),
+            name: command.goal_name,
+            goal_type: command.goal_type,
+            target_base_minor: command.goal_target_base_minor,
+            target_date: command.goal_target_date,
+            priority: command.goal_priority,
+            linked_account_id: oub(command.goal_linked_account_id),
+            linked_balance_base_minor: None,
+            tagged_base_minor: 0,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let goal = self.goal_repo.update(goal_update, Some(meta_user)).await;
+        match goal {
+            Ok(goal) => Ok(goal.map(|goal| Self::goal_response(&goal, Utc::now().date_naive()))),
+            Err(_) => Err(Error::msg("Error updating goal")),
+        }
+    }
+
+    async fn delete(&self, command: GoalDeleteCommand) -> Result<(), Error> {
+        let result = self.goal_repo.delete(command.goal_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting goal")),
+        }
+    }
+
+    async fn get_by_user(&self, command: GoalListByUserCommand) -> Result<Vec<GoalResponse>, Error> {
+        let today = Utc::now().date_naive();
+
+        let goals = self.goal_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await;
+        match goals {
+            Ok(goals) => Ok(goals.iter().map(|goal| Self::goal_response(goal, today)).collect()),
+            Err(_) => Err(Error::msg("Error getting goals")),
+        }
+    }
+
+    async fn simulate(&self, command: GoalSimulateCommand) -> Result<Option<GoalSimulationResponse>, Error> {
+        let lump_sum = command.lump_sum_base_minor.unwrap_or(0);
+        if lump_sum < 0 || command.monthly_contribution_base_minor.is_some_and(|contribution| contribution < 0) {
+            return Err(AppError::BadRequest("contributions cannot be negative".into()).into());
+        }
+        let expected_annual_return = command.expected_annual_return.unwrap_or(Decimal::ZERO);
+        let rate = Self::monthly_rate(expected_annual_return)?;
+
+        let goal = match self.goal_repo.get(command.goal_id, Some(command.auth_user.user_id)).await {
+            Ok(Some(goal)) => goal,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting goal")),
+        };
+
+        let today = Utc::now().date_naive();
+        let first_month = month_start(today);
+        let progress = Self::progress(&goal, today);
+        let start = progress.current_base_minor.saturating_add(lump_sum);
+        let target = goal.target_base_minor;
+        let target_date = command.target_date.or(goal.target_date);
+
+        let (mode, contribution, horizon) = match command.monthly_contribution_base_minor {
+            Some(contribution) => (GoalSimulationMode::Projection, contribution, None),
+            None => {
+                let Some(target_date) = target_date else {
+                    return Err(AppError::BadRequest("a monthly contribution or a target date is required".into()).into());
+                };
+                let months = months_between(first_month, target_date) + 1;
+                if months <= 0 {
+                    return Err(AppError::BadRequest("target date is in the past".into()).into());
+                }
+                (GoalSimulationMode::RequiredContribution, Self::required_contribution(start, target, months, rate), Some(months))
+            }
+        };
+
+        let (schedule, months_to_target) = Self::project(start, target, contribution, rate, first_month, horizon);
+        let completion_date = months_to_target.map(|months| add_months(first_month, (months - 1).max(0)));
+        let total_contributed = contribution * schedule.len() as i64;
+        let total_growth = schedule.iter().map(|point| point.growth_base_minor).sum();
+
+        Ok(Some(GoalSimulationResponse {
+            goal_id: command.goal_id,
+            mode,
+            start_base_minor: progress.current_base_minor,
+            lump_sum_base_minor: lump_sum,
+            target_base_minor: target,
+            target_date,
+            monthly_contribution_base_minor: contribution,
+            expected_annual_return,
+            months_to_target,
+            completion_date,
+            reaches_target_on_time: match (completion_date, target_date) {
+                (Some(completion_date), Some(target_date)) => Some(completion_date <= target_date),
+                (None, Some(_)) => Some(false),
+                _ => None,
+            },
+            total_contributed_base_minor: total_contributed,
+            total_growth_base_minor: total_growth,
+            schedule,
+        }))
+    }
+
+    async fn monte_carlo(&self, command: GoalMonteCarloCommand) -> Result<Option<GoalMonteCarloResponse>, Error> {
+        let path_count = command.path_count.unwrap_or(DEFAULT_MONTE_CARLO_PATHS);
+        if path_count == 0 || path_count > MAX_MONTE_CARLO_PATHS {
+            return Err(AppError::BadRequest(format!("path count must be between 1 and {}", MAX_MONTE_CARLO_PATHS)).into());
+        }
+        let lump_sum = command.lump_sum_base_minor.unwrap_or(0);
+        if lump_sum < 0 || command.contribution_schedule.iter().flatten().any(|step| step.monthly_contribution_base_minor < 0) {
+            return Err(AppError::BadRequest("contributions cannot be negative".into()).into());
+        }
+        let expected_annual_return = command.expected_annual_return.unwrap_or(Decimal::ZERO);
+        Self::monthly_rate(expected_annual_return)?;
+        let annual_volatility = command.annual_volatility.unwrap_or(Decimal::ZERO);
+        if annual_volatility < Decimal::ZERO {
+            return Err(AppError::BadRequest("annual volatility cannot be negative".into()).into());
+        }
+        let annual_inflation = command.annual_inflation.unwrap_or(Decimal::ZERO);
+        if annual_inflation <= Decimal::NEGATIVE_ONE {
+            return Err(AppError::BadRequest("annual inflation must be above -100%".into()).into());
+        }
+
+        let goal = match self.goal_repo.get(command.goal_id, Some(command.auth_user.user_id)).await {
+            Ok(Some(goal)) => goal,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting goal")),
+        };
+        if goal.goal_type == GoalType::Debt {
+            return Err(AppError::BadRequest("debt goals do not grow with market returns".into()).into());
+        }
+
+        let today = Utc::now().date_naive();
+        let first_month = month_start(today);
+        let progress = Self::progress(&goal, today);
+        let start = progress.current_base_minor.saturating_add(lump_sum);
+        let target = goal.target_base_minor;
+        let target_date = command.target_date.or(goal.target_date);
+
+        let target_months = match target_date {
+            Some(target_date) => {
+                let months = months_between(first_month, target_date) + 1;
+                if months <= 0 {
+                    return Err(AppError::BadRequest("target date is in the past".into()).into());
+                }
+                Some(months)
+            }
+            None => None,
+        };
+        let horizon = command.horizon_months.or(target_months).unwrap_or(DEFAULT_MONTE_CARLO_MONTHS);
+        if horizon <= 0 || horizon > MAX_SIMULATION_MONTHS {
+            return Err(AppError::BadRequest(format!("horizon must be between 1 and {} months", MAX_SIMULATION_MONTHS)).into());
+        }
+        let probability_months = target_months.map_or(horizon, |months| months.min(horizon));
+
+        let months: Vec<NaiveDate> = (0..horizon).map(|index| add_months(first_month, index)).collect();
+        let contributions: Vec<i64> = match command.contribution_schedule {
+            Some(schedule) => months.iter()
+                .map(|month| schedule.iter()
+                    .filter(|step| month_start(step.from_month) <= *month)
+                    .max_by_key(|step| step.from_month)
+                    .map_or(0, |step| step.monthly_contribution_base_minor))
+                .collect(),
+            None => {
+                let plans = match self.plan_repo.get_by_goal(command.goal_id, Some(command.auth_user.user_id)).await {
+                    Ok(plans) => plans,
+                    Err(_) => return Err(Error::msg("Error getting contribution plans")),
+                };
+                months.iter()
+                    .map(|month| plans.iter().filter(|plan| plan.covers(*month)).map(|plan| plan.amount_base_minor).sum())
+                    .collect()
+            }
+        };
+
+        let seed = command.seed.unwrap_or_else(rand::random);
+        let outcome = MonteCarlo::new(
+            expected_annual_return.to_f64().unwrap_or(0.0),
+            annual_volatility.to_f64().unwrap_or(0.0),
+            annual_inflation.to_f64().unwrap_or(0.0),
+        ).run(start, &contributions, path_count as usize, seed, target, probability_months as usize);
+
+        let mut contributed = 0;
+        let bands = months.iter().zip(&contributions).zip(&outcome.bands)
+            .map(|((month, contribution), band)| {
+                contributed += contribution;
+                GoalMonteCarloBand {
+                    month: *month,
+                    contributed_base_minor: contributed,
+                    p10_base_minor: band.p10,
+                    p50_base_minor: band.p50,
+                    p90_base_minor: band.p90,
+                }
+            })
+            .collect();
+
+        Ok(Some(GoalMonteCarloResponse {
+            goal_id: command.goal_id,
+            path_count,
+            seed,
+            start_base_minor: progress.current_base_minor,
+            lump_sum_base_minor: lump_sum,
+            target_base_minor: target,
+            target_date,
+            expected_annual_return,
+            annual_volatility,
+            annual_inflation,
+            horizon_months: horizon,
+            target_probability_percent: (Decimal::from(outcome.reached_count) * Decimal::ONE_HUNDRED / Decimal::from(path_count)).round_dp(2),
+            probability_month: add_months(first_month, probability_months - 1),
+            total_contributed_base_minor: contributions.iter().sum(),
+            bands,
+        }))
+    }
+
+    async fn create_contribution_plan(&self, command: GoalContributionPlanCreateCommand) -> Result<Option<GoalContributionPlanResponse>, Error> {
+        let day_of_month = command.plan_day_of_month.unwrap_or(1);
+        Self::validate_plan(command.plan_amount_base_minor, day_of_month, Some(command.plan_start_month), command.plan_end_month)?;
+
+        let meta_user = command.auth_user.user_id;
+        match self.goal_repo.get(command.goal_id, Some(meta_user)).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting goal")),
+        }
+
+        let mut plan_create = GoalContributionPlan::from(command);
+        plan_create.start_month = month_start(plan_create.start_month);
+        plan_create.end_month = plan_create.end_month.map(month_start);
+
+        let plan = self.plan_repo.create(plan_create, Some(meta_user)).await;
+        match plan {
+            Ok(plan) => Ok(Some(GoalContributionPlanResponse::from(&plan))),
+            Err(_) => Err(Error::msg("Error creating contribution plan")),
+        }
+    }
+
+    /// Only occurrences generated after the update follow the new amount and day;
+    /// contributions already generated keep what was planned at the time.
+    async fn update_contribution_plan(&self, command: GoalContributionPlanUpdateCommand) -> Result<Option<GoalContributionPlanResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+
+        let existing = match self.plan_repo.get(command.plan_id, meta_user).await {
+            Ok(Some(plan)) => plan,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting contribution plan")),
+        };
+        Self::validate_plan(command.plan_amount_base_minor, command.plan_day_of_month, Some(existing.start_month), command.plan_end_month)?;
+
+        let plan_update = GoalContributionPlan {
+            amount_base_minor: command.plan_amount_base_minor,
+            day_of_month: command.plan_day_of_month,
+            from_account_id: oub(command.plan_from_account_id),
+            to_account_id: oub(command.plan_to_account_id),
+            end_month: command.plan_end_month.map(month_start),
+            active: command.plan_active,
+            note: command.plan_note,
+            ..existing
+        };
+
+        let plan = self.plan_repo.update(plan_update, meta_user).await;
+        match plan {
+            Ok(plan) => Ok(plan.as_ref().map(GoalContributionPlanResponse::from)),
+            Err(_) => Err(Error::msg("Error updating contribution plan")),
+        }
+    }
+
+    async fn delete_contribution_plan(&self, command: GoalContributionPlanDeleteCommand) -> Result<(), Error> {
+        let result = self.plan_repo.delete(command.plan_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting contribution plan")),
+        }
+    }
+
+    async fn list_contribution_plans(&self, command: GoalContributionPlanListCommand) -> Result<Vec<GoalContributionPlanResponse>, Error> {
+        let plans = self.plan_repo.get_by_goal(command.goal_id, Some(command.auth_user.user_id)).await;
+        match plans {
+            Ok(plans) => Ok(plans.iter().map(GoalContributionPlanResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting contribution plans")),
+        }
+    }
+
+    async fn create_contribution(&self, command: GoalContributionCreateCommand) -> Result<Option<GoalContributionResponse>, Error> {
+        if command.contribution_planned_base_minor <= 0 {
+            return Err(AppError::BadRequest("contribution amount must be positive".into()).into());
+        }
+
+        let meta_user = command.auth_user.user_id;
+        match self.goal_repo.get(command.goal_id, Some(meta_user)).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting goal")),
+        }
+
+        let contribution = self.contribution_repo.create(GoalContribution::from(command), Some(meta_user)).await;
+        match contribution {
+            Ok(contribution) => Ok(Some(Self::contribution_response(&contribution, Utc::now().date_naive()))),
+            Err(_) => Err(Error::msg("Error creating contribution")),
+        }
+    }
+
+    async fn list_contributions(&self, command: GoalContributionListCommand) -> Result<Vec<GoalContributionResponse>, Error> {
+        let today = Utc::now().date_naive();
+
+        let contributions = self.contribution_repo.get_by_goal(command.goal_id, command.from, command.to, Some(command.auth_user.user_id)).await;
+        match contributions {
+            Ok(contributions) => Ok(contributions.iter().map(|contribution| Self::contribution_response(contribution, today)).collect()),
+            Err(_) => Err(Error::msg("Error getting contributions")),
+        }
+    }
+
+    /// Expected contributions of every active plan of the user for the month.
+    /// Safe to call repeatedly: the procedure returns the existing occurrence of a plan for a due date.
+    async fn generate_contributions(&self, command: GoalContributionGenerateCommand) -> Result<Vec<GoalContributionResponse>, Error> {
+        let today = Utc::now().date_naive();
+        let month = month_start(command.month);
+        let meta_user = command.auth_user.user_id;
+
+        let plans = match self.plan_repo.get_active_by_user(command.user_id, Some(meta_user)).await {
+            Ok(plans) => plans,
+            Err(_) => return Err(Error::msg("Error getting contribution plans")),
+        };
+
+        let mut contributions = Vec::new();
+        for plan in plans.iter().filter(|plan| plan.covers(month)) {
+            let contribution_create = GoalContribution {
+                id: None,
+                user_id: plan.user_id.clone(),
+                goal_id: plan.goal_id.clone(),
+                plan_id: plan.id.clone(),
+                due_date: day_in_month(month, plan.day_of_month as u32),
+                planned_base_minor: plan.amount_base_minor,
+                status: ContributionStatus::Planned,
+                transaction_id: None,
+                executed_base_minor: None,
+                executed_at: None,
+                note: None,
+                created_at: None,
+                updated_at: None,
+            };
+
+            match self.contribution_repo.create(contribution_create, Some(meta_user)).await {
+                Ok(contribution) => contributions.push(Self::contribution_response(&contribution, today)),
+                Err(_) => return Err(Error::msg("Error generating contributions")),
+            }
+        }
+
+        Ok(contributions)
+    }
+
+    async fn mark_contribution_done(&self, command: GoalContributionDoneCommand) -> Result<Option<GoalContributionResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+
+        let existing = match self.contribution_repo.get(command.contribution_id, meta_user).await {
+            Ok(Some(contribution)) => contribution,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting contribution")),
+        };
+
+        let executed = command.contribution_executed_base_minor.unwrap_or(existing.planned_base_minor);
+        if executed <= 0 {
+            return Err(AppError::BadRequest("executed amount must be positive".into()).into());
+        }
+        let executed_at = command.contribution_executed_at.unwrap_or_else(Utc::now);
+
+        let contribution = self.contribution_repo.mark_done(
+            command.contribution_id,
+            command.contribution_transaction_id,
+            executed,
+            executed_at,
+            meta_user
+        ).await;
+        match contribution {
+            Ok(contribution) => Ok(contribution.map(|contribution| Self::contribution_response(&contribution, Utc::now().date_naive()))),
+            Err(_) => Err(Error::msg("Error marking contribution as done")),
+        }
+    }
+
+    async fn skip_contribution(&self, command: GoalContributionSkipCommand) -> Result<Option<GoalContributionResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+
+        match self.contribution_repo.get(command.contribution_id, meta_user).await {
+            Ok(Some(contribution)) if contribution.status == ContributionStatus::Done => {
+                return Err(AppError::BadRequest("contribution is already done".into()).into());
+            },
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting contribution")),
+        }
+
+        let contribution = self.contribution_repo.mark_skipped(command.contribution_id, command.contribution_note, meta_user).await;
+        match contribution {
+            Ok(contribution) => Ok(contribution.map(|contribution| Self::contribution_response(&contribution, Utc::now().date_naive()))),
+            Err(_) => Err(Error::msg("Error skipping contribution")),
+        }
+    }
+
+    async fn contribution_summary(&self, command: GoalGetCommand) -> Result<Option<GoalContributionSummaryResponse>, Error> {
+        let goal = self.get(command).await?;
+        Ok(goal.and_then(|goal| goal.goal_contributions))
+    }
+}
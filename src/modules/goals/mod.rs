@@ -0,0 +1,11 @@
+pub mod goal_model;
+pub mod goal_repo;
+mod goal_command;
+mod goal_service;
+pub mod goal_dto;
+pub mod goal_controller;
+mod planner_command;
+mod planner_service;
+pub mod planner_dto;
+pub mod planner_controller;
+mod montecarlo_model;
\ No newline at end of file
@@ -0,0 +1,151 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+
+/// Percentiles of the simulated balances at the end of a month
+#[derive(Debug, Clone, Copy)]
+pub struct MonteCarloPercentiles {
+    pub p10: i64,
+    pub p50: i64,
+    pub p90: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonteCarloOutcome {
+    /// One entry per simulated month
+    pub bands: Vec<MonteCarloPercentiles>,
+    /// Paths at or above the target at least once within the first `target_months` months
+    pub reached_count: usize,
+}
+
+
+/// Log-normal monthly returns in today's money: the yearly return is the expected one,
+/// the volatility its yearly standard deviation, and every month is deflated by the inflation.
+/// Contributions are taken in real terms, already in today's money, so they are not deflated.
+#[derive(Debug, Clone, Copy)]
+pub struct MonteCarlo {
+    log_drift: f64,
+    log_volatility: f64,
+    deflator: f64,
+}
+
+impl MonteCarlo {
+    /// Yearly fractions (0.05 = 5%); the return and the inflation must be above -100%
+    pub fn new(annual_return: f64, annual_volatility: f64, annual_inflation: f64) -> Self {
+        let log_volatility = annual_volatility / 12f64.sqrt();
+        Self {
+            log_drift: (1.0 + annual_return).ln() / 12.0 - log_volatility * log_volatility / 2.0,
+            log_volatility,
+            deflator: (1.0 + annual_inflation).powf(1.0 / 12.0),
+        }
+    }
+
+    fn standard_normal(rng: &mut StdRng) -> f64 {
+        let u1: f64 = 1.0 - rng.r#gen::<f64>();
+        let u2: f64 = rng.r#gen();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+
+    fn percentile(sorted: &[f64], percent: f64) -> i64 {
+        let index = ((sorted.len() - 1) as f64 * percent / 100.0).round() as usize;
+        sorted[index].round() as i64
+    }
+
+    /// Runs `path_count` paths from `start`, month `m` growing the balance then adding `contributions[m]`
+    /// (in today's money).
+    /// The same seed gives the same outcome.
+    pub fn run(&self, start: i64, contributions: &[i64], path_count: usize, seed: u64, target: i64, target_months: usize) -> MonteCarloOutcome {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut balances = vec![start as f64; path_count];
+        let mut reached = vec![start >= target; path_count];
+        let mut bands = Vec::with_capacity(contributions.len());
+
+        for (month, contribution) in contributions.iter().enumerate() {
+            for (balance, reached) in balances.iter_mut().zip(reached.iter_mut()) {
+                let growth = (self.log_drift + self.log_volatility * Self::standard_normal(&mut rng)).exp() / self.deflator;
+                *balance = *balance * growth + *contribution as f64;
+                if month < target_months && *balance >= target as f64 {
+                    *reached = true;
+                }
+            }
+
+            let mut sorted = balances.clone();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            bands.push(MonteCarloPercentiles {
+                p10: Self::percentile(&sorted, 10.0),
+                p50: Self::percentile(&sorted, 50.0),
+                p90: Self::percentile(&sorted, 90.0),
+            });
+        }
+
+        MonteCarloOutcome {
+            bands,
+            reached_count: reached.iter().filter(|reached| **reached).count(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::modules::goals::goal_service::GoalService;
+
+    fn bands(outcome: &MonteCarloOutcome) -> Vec<(i64, i64, i64)> {
+        outcome.bands.iter().map(|band| (band.p10, band.p50, band.p90)).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_bands() {
+        let model = MonteCarlo::new(0.06, 0.15, 0.02);
+        let contributions = vec![50_000; 120];
+        let first = model.run(1_000_000, &contributions, 500, 42, 10_000_000, 120);
+        let again = model.run(1_000_000, &contributions, 500, 42, 10_000_000, 120);
+        let other = model.run(1_000_000, &contributions, 500, 43, 10_000_000, 120);
+
+        assert_eq!(bands(&first), bands(&again));
+        assert_eq!(first.reached_count, again.reached_count);
+        assert_ne!(bands(&first), bands(&other));
+    }
+
+    #[test]
+    fn percentiles_are_ordered_every_month() {
+        let outcome = MonteCarlo::new(0.07, 0.2, 0.0).run(1_000_000, &vec![10_000; 240], 1000, 7, i64::MAX, 240);
+
+        assert_eq!(outcome.bands.len(), 240);
+        assert!(outcome.bands.iter().all(|band| band.p10 <= band.p50 && band.p50 <= band.p90));
+        assert!(outcome.bands.last().is_some_and(|band| band.p10 < band.p90));
+    }
+
+    #[test]
+    fn without_volatility_nor_inflation_it_follows_the_projection() {
+        let annual_return = Decimal::new(5, 2);
+        let rate = GoalService::monthly_rate(annual_return).unwrap();
+        let first_month = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let (schedule, _) = GoalService::project(1_000_000, i64::MAX, 20_000, rate, first_month, Some(120));
+
+        let outcome = MonteCarlo::new(0.05, 0.0, 0.0).run(1_000_000, &[20_000; 120], 10, 1, i64::MAX, 120);
+
+        assert_eq!(outcome.bands.len(), schedule.len());
+        for (month, (band, point)) in outcome.bands.iter().zip(&schedule).enumerate() {
+            assert_eq!((band.p10, band.p50), (band.p90, band.p90));
+            // the projection rounds the growth of each month
+            assert!((band.p50 - point.balance_base_minor).abs() <= month as i64 + 1, "month {}", month);
+        }
+    }
+
+    #[test]
+    fn reached_count_only_looks_at_the_target_months() {
+        let model = MonteCarlo::new(0.0, 0.0, 0.0);
+        let contributions = [100; 24];
+
+        // 1000 is reached with the 10th contribution
+        assert_eq!(model.run(0, &contributions, 20, 3, 1000, 9).reached_count, 0);
+        assert_eq!(model.run(0, &contributions, 20, 3, 1000, 10).reached_count, 20);
+        assert_eq!(model.run(1000, &contributions, 20, 3, 1000, 0).reached_count, 20);
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::goals::planner_dto::FundingPlanRequest;
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundingPlanCommand {
+    pub user_id: Uuid,
+    pub month: Option<NaiveDate>,
+    pub free_cashflow_base_minor: Option<i64>,
+    pub income_base_minor: Option<i64>,
+    pub fixed_charges_base_minor: Option<i64>,
+    pub day_of_month: Option<u32>,
+
+    pub auth_user: AuthUser,
+}
+
+impl FundingPlanCommand {
+    pub fn new(request: FundingPlanRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            month: request.month,
+            free_cashflow_base_minor: request.free_cashflow_base_minor,
+            income_base_minor: request.income_base_minor,
+            fixed_charges_base_minor: request.fixed_charges_base_minor,
+            day_of_month: request.day_of_month,
+            auth_user,
+        }
+    }
+}
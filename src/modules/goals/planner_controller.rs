@@ -0,0 +1,71 @@
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+
+use crate::modules::goals::{
+    planner_command::*,
+    planner_dto::*,
+    planner_service::{FundingPlannerService, FundingPlannerServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/planner", post(post_funding_plan))
+        .route("/planner/accept", post(post_funding_plan_accept))
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/planner",
+    responses(
+        (status = StatusCode::OK, description = "Proposed allocation of the month's free cashflow across goals and projects", body = FundingPlanResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid cashflow"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal Planner"
+)]
+pub async fn post_funding_plan(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(funding_plan_request): Json<FundingPlanRequest>
+) -> Result<Json<FundingPlanResponse>, StatusCode> {
+    let command = FundingPlanCommand::new(funding_plan_request, auth_user);
+    let planner_service = FundingPlannerService::from(&state);
+
+    let plan = planner_service.propose(command).await;
+    match plan {
+        Ok(plan) => Ok(Json(plan)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/goals/planner/accept",
+    responses(
+        (status = StatusCode::OK, description = "Allocation saved as planned contributions", body = FundingPlanAcceptResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid cashflow"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Goal Planner"
+)]
+pub async fn post_funding_plan_accept(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(funding_plan_request): Json<FundingPlanRequest>
+) -> Result<Json<FundingPlanAcceptResponse>, StatusCode> {
+    let command = FundingPlanCommand::new(funding_plan_request, auth_user);
+    let planner_service = FundingPlannerService::from(&state);
+
+    let plan = planner_service.accept(command).await;
+    match plan {
+        Ok(plan) => Ok(Json(plan)),
+        Err(e) => Err(error_status(&e))
+    }
+}
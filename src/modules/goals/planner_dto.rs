@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::goals::goal_dto::GoalContributionResponse;
+
+
+/// Month to fund. The free cashflow is either given as is, or computed as
+/// income - budget envelopes of the month - fixed charges.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FundingPlanRequest {
+    /// Current month by default
+    pub month: Option<NaiveDate>,
+    /// Wins over the income breakdown when given
+    pub free_cashflow_base_minor: Option<i64>,
+    pub income_base_minor: Option<i64>,
+    /// Rent, subscriptions, loan payments... not covered by the envelopes
+    pub fixed_charges_base_minor: Option<i64>,
+    /// Day of the month the accepted contributions are due (1 by default, clamped to the end of short months)
+    pub day_of_month: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FundingTargetKind {
+    Goal,
+    Project,
+}
+
+/// One goal or project competing for the month's free cashflow.
+/// A project is funded through its linked goal, which then does not get a line of its own.
+/// Projects sharing a goal split its saved balance and planned contributions, in turn.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FundingPlanLineResponse {
+    pub kind: FundingTargetKind,
+    /// Goal receiving the planned contribution
+    pub goal_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub name: String,
+    pub priority: i32,
+    /// Goal target date or project due date
+    pub deadline: Option<NaiveDate>,
+
+    /// Goal remaining amount, or project funding needed less the goal saved balance
+    pub needed_base_minor: i64,
+    /// Contributions already planned on the goal from this month on, not executed yet
+    pub already_planned_base_minor: i64,
+    /// needed - already planned
+    pub remaining_base_minor: i64,
+    /// Monthly amount keeping the deadline, None without deadline
+    pub required_monthly_base_minor: Option<i64>,
+
+    pub allocated_base_minor: i64,
+    /// Part of the required monthly amount the cashflow could not cover
+    pub shortfall_base_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FundingPlanResponse {
+    pub month: NaiveDate,
+
+    pub income_base_minor: Option<i64>,
+    pub envelopes_base_minor: Option<i64>,
+    pub fixed_charges_base_minor: Option<i64>,
+    pub free_cashflow_base_minor: i64,
+
+    pub allocated_base_minor: i64,
+    pub unallocated_base_minor: i64,
+
+    /// Allocation order: priority (highest first), then deadline (soonest first), then remaining (smallest first)
+    pub lines: Vec<FundingPlanLineResponse>,
+    /// Open projects with funding needed but no linked goal to receive contributions
+    pub projects_without_goal: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FundingPlanAcceptResponse {
+    pub plan: FundingPlanResponse,
+    /// One planned contribution per line with an allocation
+    pub contributions: Vec<GoalContributionResponse>,
+}
@@ -0,0 +1,302 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::budgets::budget_repo::{
+    BudgetEnvelopeRepository, BudgetEnvelopeRepositoryInterface, BudgetRepository, BudgetRepositoryInterface,
+};
+use crate::modules::goals::{
+    goal_dto::GoalContributionResponse,
+    goal_model::{ContributionStatus, Goal, GoalContribution},
+    goal_repo::{GoalContributionRepository, GoalContributionRepositoryInterface, GoalRepository, GoalRepositoryInterface},
+    goal_service::GoalService,
+    planner_command::FundingPlanCommand,
+    planner_dto::*,
+};
+use crate::modules::projects::{
+    project_command::{ProjectFinancialsCommand, ProjectListByUserCommand},
+    project_model::ProjectStatus,
+    project_service::{ProjectService, ProjectServiceInterface},
+};
+use crate::shared::dates::{day_in_month, month_start, months_between};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, ub};
+
+
+#[async_trait]
+pub trait FundingPlannerServiceInterface {
+
+    /// Proposed allocation of the month's free cashflow, nothing is saved
+    async fn propose(&self, command: FundingPlanCommand) -> Result<FundingPlanResponse, Error>;
+
+    /// Same allocation, saved as planned contributions due in the month
+    async fn accept(&self, command: FundingPlanCommand) -> Result<FundingPlanAcceptResponse, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct FundingPlannerService {
+    goal_repo: GoalRepository,
+    contribution_repo: GoalContributionRepository,
+    budget_repo: BudgetRepository,
+    envelope_repo: BudgetEnvelopeRepository,
+    project_service: ProjectService,
+}
+
+impl From<&AppState> for FundingPlannerService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            goal_repo: GoalRepository::from(app_state),
+            contribution_repo: GoalContributionRepository::from(app_state),
+            budget_repo: BudgetRepository::from(app_state),
+            envelope_repo: BudgetEnvelopeRepository::from(app_state),
+            project_service: ProjectService::from(app_state),
+        }
+    }
+}
+
+impl FundingPlannerService {
+    fn validate(command: &FundingPlanCommand) -> Result<(), Error> {
+        if command.free_cashflow_base_minor.is_none() && command.income_base_minor.is_none() {
+            return Err(AppError::BadRequest("free cashflow or income is required".into()).into());
+        }
+        if command.fixed_charges_base_minor.is_some_and(|amount| amount < 0) {
+            return Err(AppError::BadRequest("fixed charges cannot be negative".into()).into());
+        }
+        if command.day_of_month.is_some_and(|day| !(1..=31).contains(&day)) {
+            return Err(AppError::BadRequest("day of month must be between 1 and 31".into()).into());
+        }
+        Ok(())
+    }
+
+    /// Monthly amount keeping the deadline: the remaining amount spread over the months left,
+    /// the funded month included. A deadline already passed needs everything now.
+    fn required_monthly(remaining: i64, deadline: Option<NaiveDate>, month: NaiveDate) -> Option<i64> {
+        let deadline = deadline?;
+        let months_left = (months_between(month, deadline) + 1).max(1) as i64;
+        Some((remaining + months_left - 1) / months_left)
+    }
+
+    /// Two passes over the lines in priority order: the required monthly amounts first,
+    /// then the leftover up to each remaining amount. Ties are broken on the goal then the project.
+    fn allocate(lines: &mut [FundingPlanLineResponse], free_cashflow: i64) -> i64 {
+        lines.sort_by_key(|line| (
+            Reverse(line.priority),
+            line.deadline.is_none(),
+            line.deadline,
+            line.remaining_base_minor,
+            line.goal_id,
+            line.project_id,
+        ));
+
+        let mut left = free_cashflow.max(0);
+        for line in lines.iter_mut() {
+            let required = line.required_monthly_base_minor.unwrap_or(0);
+            line.allocated_base_minor = required.min(left);
+            line.shortfall_base_minor = required - line.allocated_base_minor;
+            left -= line.allocated_base_minor;
+        }
+        for line in lines.iter_mut() {
+            let extra = (line.remaining_base_minor - line.allocated_base_minor).min(left);
+            line.allocated_base_minor += extra;
+            left -= extra;
+        }
+        left
+    }
+
+    async fn envelopes(&self, user_id: Uuid, month: NaiveDate, meta_user: Option<Uuid>) -> Result<Option<i64>, Error> {
+        let budgets = match self.budget_repo.get_by_user(user_id, meta_user).await {
+            Ok(budgets) => budgets,
+            Err(_) => return Err(Error::msg("Error getting budgets")),
+        };
+        let Some(budget) = budgets.iter().find(|budget| month_start(budget.month) == month) else {
+            return Ok(None);
+        };
+        match self.envelope_repo.get_by_budget(bu(budget.id.as_deref().unwrap()), meta_user).await {
+            Ok(envelopes) => Ok(Some(envelopes.iter().map(|envelope| envelope.planned_base_minor).sum())),
+            Err(_) => Err(Error::msg("Error getting budget envelopes")),
+        }
+    }
+
+    /// Contributions planned on the goal from `month` on and not executed yet;
+    /// planned ones left behind in earlier months are not counted on
+    async fn already_planned(&self, goal: &Goal, month: NaiveDate, meta_user: Option<Uuid>) -> Result<i64, Error> {
+        match self.contribution_repo.get_by_goal(bu(goal.id.as_deref().unwrap()), Some(month), None, meta_user).await {
+            Ok(contributions) => Ok(contributions.iter()
+                .filter(|contribution| contribution.status == ContributionStatus::Planned && contribution.due_date >= month)
+                .map(|contribution| contribution.planned_base_minor)
+                .sum()),
+            Err(_) => Err(Error::msg("Error getting goal contributions")),
+        }
+    }
+
+    async fn plan(&self, command: &FundingPlanCommand) -> Result<FundingPlanResponse, Error> {
+        Self::validate(command)?;
+        let meta_user = Some(command.auth_user.user_id);
+        let today = Utc::now().date_naive();
+        let month = month_start(command.month.unwrap_or(today));
+
+        let (envelopes, free_cashflow) = match command.free_cashflow_base_minor {
+            Some(free_cashflow) => (None, free_cashflow),
+            None => {
+                let envelopes = self.envelopes(command.user_id, month, meta_user).await?;
+                let income = command.income_base_minor.unwrap_or(0);
+                let fixed_charges = command.fixed_charges_base_minor.unwrap_or(0);
+                (envelopes, income - envelopes.unwrap_or(0) - fixed_charges)
+            },
+        };
+
+        let goals = match self.goal_repo.get_by_user(command.user_id, meta_user).await {
+            Ok(goals) => goals,
+            Err(_) => return Err(Error::msg("Error getting goals")),
+        };
+        let goals: Vec<(Uuid, Goal)> = goals.into_iter()
+            .map(|goal| (bu(goal.id.as_deref().unwrap()), goal))
+            .collect();
+
+        let projects = self.project_service.get_by_user(ProjectListByUserCommand {
+            user_id: command.user_id,
+            status: None,
+            priority: None,
+            person_id: None,
+            location_id: None,
+            auth_user: command.auth_user.clone(),
+        }).await?;
+
+        let mut lines = Vec::new();
+        let mut projects_without_goal = Vec::new();
+        let mut project_goals = Vec::new();
+        // Saved balance and planned contributions of a goal shared by several projects
+        // are set aside once, for the projects in turn
+        let mut goals_left: HashMap<Uuid, (i64, i64)> = HashMap::new();
+        for project in projects.iter().filter(|project| matches!(project.project_status, ProjectStatus::Planned | ProjectStatus::Active)) {
+            let financials = self.project_service.financials(ProjectFinancialsCommand {
+                project_id: project.project_id,
+                as_of: Some(today),
+                auth_user: command.auth_user.clone(),
+            }).await?;
+            let Some(financials) = financials else { continue };
+            if financials.funding_needed_base_minor <= 0 {
+                continue;
+            }
+            let Some((goal_id, goal)) = project.project_goal_id
+                .and_then(|goal_id| goals.iter().find(|(id, _)| *id == goal_id)) else {
+                projects_without_goal.push(project.project_id);
+                continue;
+            };
+            project_goals.push(*goal_id);
+
+            // What the goal already holds is set aside for the project
+            let (saved_left, planned_left) = match goals_left.get_mut(goal_id) {
+                Some(left) => left,
+                None => {
+                    let saved = GoalService::progress(goal, today).current_base_minor.max(0);
+                    let already_planned = self.already_planned(goal, month, meta_user).await?;
+                    goals_left.entry(*goal_id).or_insert((saved, already_planned))
+                },
+            };
+            let saved = financials.funding_needed_base_minor.min(*saved_left);
+            *saved_left -= saved;
+            let needed = financials.funding_needed_base_minor - saved;
+            let already_planned = needed.min(*planned_left);
+            *planned_left -= already_planned;
+            lines.push(FundingPlanLineResponse {
+                project_id: Some(project.project_id),
+                name: project.project_name.clone(),
+                priority: project.project_priority,
+                deadline: project.project_due_date,
+                ..Self::line(FundingTargetKind::Project, goal, needed, already_planned)
+            });
+        }
+
+        for (goal_id, goal) in &goals {
+            if project_goals.contains(goal_id) {
+                continue;
+            }
+            let needed = GoalService::progress(goal, today).remaining_base_minor;
+            let already_planned = self.already_planned(goal, month, meta_user).await?;
+            lines.push(Self::line(FundingTargetKind::Goal, goal, needed, already_planned));
+        }
+        lines.retain(|line| line.remaining_base_minor > 0);
+        for line in lines.iter_mut() {
+            line.required_monthly_base_minor = Self::required_monthly(line.remaining_base_minor, line.deadline, month);
+        }
+
+        let unallocated = Self::allocate(&mut lines, free_cashflow);
+        Ok(FundingPlanResponse {
+            month,
+            income_base_minor: command.free_cashflow_base_minor.is_none().then(|| command.income_base_minor.unwrap_or(0)),
+            envelopes_base_minor: envelopes,
+            fixed_charges_base_minor: command.free_cashflow_base_minor.is_none().then(|| command.fixed_charges_base_minor.unwrap_or(0)),
+            free_cashflow_base_minor: free_cashflow,
+            allocated_base_minor: lines.iter().map(|line| line.allocated_base_minor).sum(),
+            unallocated_base_minor: unallocated,
+            lines,
+            projects_without_goal,
+        })
+    }
+
+    /// Line funding the goal itself; a project line overrides the name, priority and deadline
+    fn line(kind: FundingTargetKind, goal: &Goal, needed: i64, already_planned: i64) -> FundingPlanLineResponse {
+        FundingPlanLineResponse {
+            kind,
+            goal_id: bu(goal.id.as_deref().unwrap()),
+            project_id: None,
+            name: goal.name.clone(),
+            priority: goal.priority,
+            deadline: goal.target_date,
+            needed_base_minor: needed,
+            already_planned_base_minor: already_planned,
+            remaining_base_minor: (needed - already_planned).max(0),
+            required_monthly_base_minor: None,
+            allocated_base_minor: 0,
+            shortfall_base_minor: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl FundingPlannerServiceInterface for FundingPlannerService {
+    async fn propose(&self, command: FundingPlanCommand) -> Result<FundingPlanResponse, Error> {
+        self.plan(&command).await
+    }
+
+    async fn accept(&self, command: FundingPlanCommand) -> Result<FundingPlanAcceptResponse, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let plan = self.plan(&command).await?;
+        let due_date = day_in_month(plan.month, command.day_of_month.unwrap_or(1));
+
+        let mut contributions = Vec::new();
+        for line in plan.lines.iter().filter(|line| line.allocated_base_minor > 0) {
+            let note = match line.kind {
+                FundingTargetKind::Goal => "Funding planner".to_string(),
+                FundingTargetKind::Project => format!("Funding planner: {}", line.name),
+            };
+            let contribution = GoalContribution {
+                id: None,
+                user_id: ub(command.user_id),
+                goal_id: ub(line.goal_id),
+                plan_id: None,
+                due_date,
+                planned_base_minor: line.allocated_base_minor,
+                status: ContributionStatus::Planned,
+                transaction_id: None,
+                executed_base_minor: None,
+                executed_at: None,
+                note: Some(note),
+                created_at: None,
+                updated_at: None,
+            };
+            match self.contribution_repo.create(contribution, meta_user).await {
+                Ok(contribution) => contributions.push(GoalContributionResponse::from(&contribution)),
+                Err(_) => return Err(Error::msg("Error creating goal contribution")),
+            }
+        }
+
+        Ok(FundingPlanAcceptResponse { plan, contributions })
+    }
+}
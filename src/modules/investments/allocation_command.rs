@@ -0,0 +1,67 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::{
+    allocation_dto::{AllocationTargetRequest, AllocationTargetSetRequest, RebalancingQuery},
+    allocation_model::AllocationKind,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AllocationTargetGetCommand {
+    pub portfolio_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AllocationTargetGetCommand {
+    pub fn new(portfolio_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { portfolio_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AllocationTargetSetCommand {
+    pub portfolio_id: Uuid,
+    pub allocation_kind: AllocationKind,
+    pub targets: Vec<AllocationTargetRequest>,
+
+    pub auth_user: AuthUser,
+}
+
+impl AllocationTargetSetCommand {
+    pub fn new(portfolio_id: Uuid, request: AllocationTargetSetRequest, auth_user: AuthUser) -> Self {
+        Self {
+            portfolio_id,
+            allocation_kind: request.allocation_kind,
+            targets: request.targets,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RebalancingCommand {
+    pub portfolio_id: Uuid,
+    pub as_of: Option<NaiveDate>,
+    pub contribution_minor: Option<i64>,
+    pub min_trade_minor: Option<i64>,
+    pub contributions_only: bool,
+
+    pub auth_user: AuthUser,
+}
+
+impl RebalancingCommand {
+    pub fn new(portfolio_id: Uuid, query: RebalancingQuery, auth_user: AuthUser) -> Self {
+        Self {
+            portfolio_id,
+            as_of: query.as_of,
+            contribution_minor: query.contribution_minor,
+            min_trade_minor: query.min_trade_minor,
+            contributions_only: query.contributions_only.unwrap_or(false),
+            auth_user,
+        }
+    }
+}
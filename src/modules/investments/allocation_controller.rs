@@ -0,0 +1,127 @@
+use axum::{extract::{Path, Query, State}, http::StatusCode, routing::get, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    allocation_command::*,
+    allocation_dto::*,
+    allocation_service::{AllocationService, AllocationServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/portfolios/{portfolio_id}/allocation-targets", get(get_allocation_targets).put(put_allocation_targets))
+        .route("/portfolios/{portfolio_id}/rebalancing", get(get_rebalancing))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}/allocation-targets",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Target allocation of the portfolio", body = AllocationTargetsResponse),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Allocation"
+)]
+pub async fn get_allocation_targets(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+) -> Result<Json<AllocationTargetsResponse>, StatusCode> {
+    let command = AllocationTargetGetCommand::new(portfolio_id, auth_user);
+    let allocation_service = AllocationService::from(&state);
+
+    let targets = allocation_service.get_targets(command).await;
+    match targets {
+        Ok(targets) => {
+            match targets {
+                Some(targets) => Ok(Json(targets)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/investments/portfolios/{portfolio_id}/allocation-targets",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Target allocation replaced successfully", body = AllocationTargetsResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid targets"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Allocation"
+)]
+pub async fn put_allocation_targets(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Json(allocation_target_set_request): Json<AllocationTargetSetRequest>
+) -> Result<Json<AllocationTargetsResponse>, StatusCode> {
+    let command = AllocationTargetSetCommand::new(portfolio_id, allocation_target_set_request, auth_user);
+    let allocation_service = AllocationService::from(&state);
+
+    let targets = allocation_service.set_targets(command).await;
+    match targets {
+        Ok(targets) => {
+            match targets {
+                Some(targets) => Ok(Json(targets)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}/rebalancing",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid"),
+        RebalancingQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Current vs target allocation, drift and rebalancing trades in base currency", body = RebalancingResponse),
+        (status = StatusCode::BAD_REQUEST, description = "No target, negative amounts or missing fx rate"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Allocation"
+)]
+pub async fn get_rebalancing(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Query(query): Query<RebalancingQuery>,
+) -> Result<Json<RebalancingResponse>, StatusCode> {
+    let command = RebalancingCommand::new(portfolio_id, query, auth_user);
+    let allocation_service = AllocationService::from(&state);
+
+    let rebalancing = allocation_service.rebalancing(command).await;
+    match rebalancing {
+        Ok(rebalancing) => {
+            match rebalancing {
+                Some(rebalancing) => Ok(Json(rebalancing)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    allocation_model::{AllocationKind, AllocationTarget},
+    instrument_model::InstrumentType,
+};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AllocationTargetResponse {
+    pub allocation_target_id: Uuid,
+    pub asset_class: Option<InstrumentType>,
+    pub instrument_id: Option<Uuid>,
+    pub tag: Option<String>,
+    pub weight_percent: Decimal,
+}
+
+impl From<&AllocationTarget> for AllocationTargetResponse {
+    fn from(target: &AllocationTarget) -> Self {
+        Self {
+            allocation_target_id: bu(target.id.clone().unwrap().as_slice()),
+            asset_class: target.asset_class,
+            instrument_id: obu(target.instrument_id.as_deref()),
+            tag: target.tag.clone(),
+            weight_percent: target.weight_percent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AllocationTargetsResponse {
+    pub portfolio_id: Uuid,
+    /// None while the portfolio has no target
+    pub allocation_kind: Option<AllocationKind>,
+    pub targets: Vec<AllocationTargetResponse>,
+}
+
+/// Only the field matching the allocation kind is read
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AllocationTargetRequest {
+    pub asset_class: Option<InstrumentType>,
+    pub instrument_id: Option<Uuid>,
+    pub tag: Option<String>,
+    pub weight_percent: Decimal,
+}
+
+/// Replaces all the targets of the portfolio; weights must sum to 100, an empty list clears them
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AllocationTargetSetRequest {
+    pub allocation_kind: AllocationKind,
+    pub targets: Vec<AllocationTargetRequest>,
+}
+
+// --- Rebalancing ---
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RebalancingQuery {
+    /// Today by default: trades up to that day, latest price on or before it
+    pub as_of: Option<NaiveDate>,
+    /// New money to invest, in base currency
+    pub contribution_minor: Option<i64>,
+    /// Trades below this amount in base currency are left out
+    pub min_trade_minor: Option<i64>,
+    /// Only invest the contribution, never sell
+    pub contributions_only: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RebalanceAction {
+    Buy,
+    Sell,
+    Hold,
+}
+
+/// Current and target weight of an asset class, instrument or tag, amounts in base currency.
+/// Held groups without target have a target of 0.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AllocationLineResponse {
+    pub asset_class: Option<InstrumentType>,
+    pub instrument_id: Option<Uuid>,
+    pub tag: Option<String>,
+    pub name: String,
+
+    pub current_value_base_minor: i64,
+    pub current_percent: Decimal,
+    pub target_percent: Decimal,
+    /// current - target, in percentage points
+    pub drift_percent: Decimal,
+
+    /// Value at the target weight once the contribution is invested
+    pub target_value_base_minor: i64,
+    /// Positive to buy, negative to sell
+    pub trade_base_minor: i64,
+    pub action: RebalanceAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RebalancingResponse {
+    pub portfolio_id: Uuid,
+    pub as_of: NaiveDate,
+    pub base_currency_code: String,
+    pub allocation_kind: AllocationKind,
+
+    /// Market value of the open positions, cost basis for positions without price
+    pub current_value_base_minor: i64,
+    pub contribution_base_minor: i64,
+    pub min_trade_base_minor: i64,
+    pub contributions_only: bool,
+
+    pub lines: Vec<AllocationLineResponse>,
+    /// Largest absolute drift of the lines
+    pub max_drift_percent: Decimal,
+    /// Contribution left uninvested by the proposal (trades below the minimum size)
+    pub uninvested_base_minor: i64,
+    /// Open positions without a price on or before `as_of`, valued at cost: the proposal rests on stale values
+    pub missing_price_instrument_ids: Vec<Uuid>,
+}
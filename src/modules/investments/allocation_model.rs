@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    allocation_dto::AllocationTargetRequest,
+    instrument_model::{Instrument, InstrumentType},
+};
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+/// What the target weights of a portfolio apply to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AllocationKind {
+    /// Instrument type (stock, etf, bond…)
+    AssetClass,
+    Instrument,
+    /// Instrument tag
+    Tag,
+}
+
+impl AllocationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllocationKind::AssetClass => "asset_class",
+            AllocationKind::Instrument => "instrument",
+            AllocationKind::Tag => "tag",
+        }
+    }
+}
+
+
+/// Group of instruments an allocation line covers; a missing tag is its own group
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AllocationKey {
+    AssetClass(InstrumentType),
+    Instrument(Vec<u8>),
+    Tag(Option<String>),
+}
+
+impl AllocationKey {
+    /// Group of `instrument` for allocations of `kind`
+    pub fn of(kind: AllocationKind, instrument: &Instrument) -> Self {
+        match kind {
+            AllocationKind::AssetClass => AllocationKey::AssetClass(instrument.instrument_type),
+            AllocationKind::Instrument => AllocationKey::Instrument(instrument.id.clone().unwrap_or_default()),
+            AllocationKind::Tag => AllocationKey::Tag(instrument.tag.clone()),
+        }
+    }
+}
+
+
+/// Target weight of an asset class, an instrument or a tag in a portfolio.
+/// Only the column matching `target_kind` is set.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AllocationTarget {
+    pub id: Option<Vec<u8>>,
+
+    pub portfolio_id: Vec<u8>,
+    pub target_kind: AllocationKind,
+    pub asset_class: Option<InstrumentType>,
+    pub instrument_id: Option<Vec<u8>>,
+    pub tag: Option<String>,
+    /// Percent of the portfolio value, the weights of a portfolio sum to 100
+    pub weight_percent: Decimal,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl AllocationTarget {
+    pub fn key(&self) -> AllocationKey {
+        // the columns match the kind, checked by chk_allocation_targets_key
+        match self.target_kind {
+            AllocationKind::AssetClass => AllocationKey::AssetClass(self.asset_class.unwrap_or(InstrumentType::Other)),
+            AllocationKind::Instrument => AllocationKey::Instrument(self.instrument_id.clone().unwrap_or_default()),
+            AllocationKind::Tag => AllocationKey::Tag(self.tag.clone()),
+        }
+    }
+}
+
+impl FromSqlRow for AllocationTarget {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            portfolio_id: row.try_get(index_map["portfolio_id"])?,
+            target_kind: row.try_get(index_map["target_kind"])?,
+            asset_class: row.try_get(index_map["asset_class"])?,
+            instrument_id: row.try_get(index_map["instrument_id"])?,
+            tag: row.try_get(index_map["tag"])?,
+            weight_percent: row.try_get(index_map["weight_percent"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl AllocationTarget {
+    /// Target of the request for the portfolio `portfolio_id`, keeping only the field of `kind`
+    pub fn of_portfolio(kind: AllocationKind, request: &AllocationTargetRequest, portfolio_id: Uuid) -> Self {
+        Self {
+            id: None,
+            portfolio_id: ub(portfolio_id),
+            target_kind: kind,
+            asset_class: request.asset_class.filter(|_| kind == AllocationKind::AssetClass),
+            instrument_id: oub(request.instrument_id.filter(|_| kind == AllocationKind::Instrument)),
+            tag: request.tag.as_ref()
+                .filter(|_| kind == AllocationKind::Tag)
+                .map(|tag| tag.trim().to_string()),
+            weight_percent: request.weight_percent,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
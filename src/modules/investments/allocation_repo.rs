@@ -0,0 +1,77 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::investments::allocation_model::AllocationTarget;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait AllocationTargetRepositoryInterface {
+
+    async fn create(&self, target: AllocationTarget, meta_user: Option<Uuid>) -> Result<AllocationTarget, Error>;
+
+    /// Removes every target of the portfolio before a new set is created
+    async fn delete_by_portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by weight, largest first
+    async fn get_by_portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AllocationTarget>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AllocationTargetRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AllocationTargetRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AllocationTarget> for AllocationTargetRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AllocationTargetRepositoryInterface for AllocationTargetRepository {
+    async fn create(&self, target: AllocationTarget, meta_user: Option<Uuid>) -> Result<AllocationTarget, Error> {
+        let params = vec![
+            MySqlParam::from(target.portfolio_id),
+            MySqlParam::from(target.target_kind.as_str()),
+            MySqlParam::from(target.asset_class.map(|asset_class| asset_class.as_str())),
+            MySqlParam::from(target.instrument_id),
+            MySqlParam::from(target.tag),
+            MySqlParam::from(target.weight_percent),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_allocation_target_create", params).await
+    }
+
+    async fn delete_by_portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(portfolio_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_allocation_target_delete_by_portfolio", params).await
+    }
+
+    async fn get_by_portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AllocationTarget>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(portfolio_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_allocation_target_by_portfolio", params).await
+    }
+}
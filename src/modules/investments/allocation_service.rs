@@ -0,0 +1,346 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::modules::currencies::{
+    currency_model::FxTable,
+    currency_repo::{CurrencyRepository, CurrencyRepositoryInterface, FxRateRepository, FxRateRepositoryInterface},
+};
+use crate::modules::investments::{
+    allocation_command::*,
+    allocation_dto::*,
+    allocation_model::{AllocationKey, AllocationKind, AllocationTarget},
+    allocation_repo::{AllocationTargetRepository, AllocationTargetRepositoryInterface},
+    instrument_model::Instrument,
+    instrument_repo::{InstrumentPriceRepository, InstrumentPriceRepositoryInterface, InstrumentRepository, InstrumentRepositoryInterface},
+    portfolio_model::Portfolio,
+    portfolio_repo::{PortfolioRepository, PortfolioRepositoryInterface},
+    portfolio_service::PortfolioService,
+    trade_model::Holdings,
+    trade_repo::{TradeRepository, TradeRepositoryInterface},
+};
+use crate::modules::users::user::user_repo::{UserRepository, UserRepositoryInterface};
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu};
+
+
+#[async_trait]
+pub trait AllocationServiceInterface {
+
+    /// None when the portfolio does not exist
+    async fn get_targets(&self, command: AllocationTargetGetCommand) -> Result<Option<AllocationTargetsResponse>, Error>;
+
+    /// Replaces the targets of the portfolio. None when the portfolio does not exist.
+    async fn set_targets(&self, command: AllocationTargetSetCommand) -> Result<Option<AllocationTargetsResponse>, Error>;
+
+    /// Current vs target allocation with the trades bringing the portfolio back to its targets.
+    /// None when the portfolio does not exist.
+    async fn rebalancing(&self, command: RebalancingCommand) -> Result<Option<RebalancingResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct AllocationService {
+    user_repo: UserRepository,
+    currency_repo: CurrencyRepository,
+    fx_rate_repo: FxRateRepository,
+    portfolio_repo: PortfolioRepository,
+    instrument_repo: InstrumentRepository,
+    price_repo: InstrumentPriceRepository,
+    trade_repo: TradeRepository,
+    target_repo: AllocationTargetRepository,
+}
+
+impl From<&AppState> for AllocationService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            user_repo: UserRepository::from(app_state),
+            currency_repo: CurrencyRepository::from(app_state),
+            fx_rate_repo: FxRateRepository::from(app_state),
+            portfolio_repo: PortfolioRepository::from(app_state),
+            instrument_repo: InstrumentRepository::from(app_state),
+            price_repo: InstrumentPriceRepository::from(app_state),
+            trade_repo: TradeRepository::from(app_state),
+            target_repo: AllocationTargetRepository::from(app_state),
+        }
+    }
+}
+
+impl AllocationService {
+    fn validate(command: &AllocationTargetSetCommand, instruments: &[Instrument]) -> Result<(), Error> {
+        let mut keys = HashSet::new();
+        for target in &command.targets {
+            if target.weight_percent <= Decimal::ZERO || target.weight_percent > Decimal::from(100) {
+                return Err(AppError::BadRequest("target weight must be between 0 and 100".into()).into());
+            }
+            let key = match command.allocation_kind {
+                AllocationKind::AssetClass => match target.asset_class {
+                    Some(asset_class) => asset_class.as_str().to_string(),
+                    None => return Err(AppError::BadRequest("asset class is required".into()).into()),
+                },
+                AllocationKind::Instrument => match target.instrument_id {
+                    Some(instrument_id) if instruments.iter().any(|instrument| instrument.id.as_deref().map(bu) == Some(instrument_id)) => instrument_id.to_string(),
+                    Some(_) => return Err(AppError::BadRequest("target instrument not found".into()).into()),
+                    None => return Err(AppError::BadRequest("instrument is required".into()).into()),
+                },
+                AllocationKind::Tag => match target.tag.as_deref().map(str::trim) {
+                    Some(tag) if !tag.is_empty() && tag.chars().count() <= 50 => tag.to_string(),
+                    _ => return Err(AppError::BadRequest("tag is required, 50 characters at most".into()).into()),
+                },
+            };
+            if !keys.insert(key) {
+                return Err(AppError::BadRequest("targets must not repeat an asset class, instrument or tag".into()).into());
+            }
+        }
+        let total: Decimal = command.targets.iter().map(|target| target.weight_percent).sum();
+        if !command.targets.is_empty() && total != Decimal::from(100) {
+            return Err(AppError::BadRequest("target weights must sum to 100".into()).into());
+        }
+        Ok(())
+    }
+
+    fn percent(part: Decimal, total: Decimal) -> Decimal {
+        if total.is_zero() {
+            return Decimal::ZERO;
+        }
+        (part * Decimal::from(100) / total).round_dp(2)
+    }
+
+    /// Trade of each group, positive to buy, before the minimum size is applied.
+    /// Contributions only: the contribution goes to the underweight groups in proportion of their gap.
+    fn trades(gaps: &[Decimal], contribution: Decimal, contributions_only: bool) -> Vec<Decimal> {
+        if !contributions_only {
+            return gaps.to_vec();
+        }
+        let underweight: Decimal = gaps.iter().filter(|gap| **gap > Decimal::ZERO).sum();
+        if underweight.is_zero() {
+            return vec![Decimal::ZERO; gaps.len()];
+        }
+        let share = (contribution / underweight).min(Decimal::ONE);
+        gaps.iter()
+            .map(|gap| if *gap > Decimal::ZERO { *gap * share } else { Decimal::ZERO })
+            .collect()
+    }
+
+    fn name(key: &AllocationKey, instruments: &[Instrument]) -> String {
+        match key {
+            AllocationKey::AssetClass(asset_class) => asset_class.as_str().to_string(),
+            AllocationKey::Instrument(instrument_id) => instruments.iter()
+                .find(|instrument| instrument.id.as_ref() == Some(instrument_id))
+                .map(|instrument| instrument.name.clone())
+                .unwrap_or_default(),
+            AllocationKey::Tag(tag) => tag.clone().unwrap_or_else(|| "Untagged".to_string()),
+        }
+    }
+
+    fn targets_response(portfolio_id: Uuid, targets: &[AllocationTarget]) -> AllocationTargetsResponse {
+        AllocationTargetsResponse {
+            portfolio_id,
+            allocation_kind: targets.first().map(|target| target.target_kind),
+            targets: targets.iter().map(AllocationTargetResponse::from).collect(),
+        }
+    }
+
+    async fn portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Portfolio>, Error> {
+        match self.portfolio_repo.get(portfolio_id, meta_user).await {
+            Ok(portfolio) => Ok(portfolio),
+            Err(_) => Err(Error::msg("Error getting portfolio")),
+        }
+    }
+
+    async fn targets(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<AllocationTarget>, Error> {
+        match self.target_repo.get_by_portfolio(portfolio_id, meta_user).await {
+            Ok(targets) => Ok(targets),
+            Err(_) => Err(Error::msg("Error getting allocation targets")),
+        }
+    }
+
+    async fn instruments(&self, portfolio: &Portfolio, meta_user: Option<Uuid>) -> Result<Vec<Instrument>, Error> {
+        match self.instrument_repo.get_by_user(bu(&portfolio.user_id), meta_user).await {
+            Ok(instruments) => Ok(instruments),
+            Err(_) => Err(Error::msg("Error getting instruments")),
+        }
+    }
+}
+
+#[async_trait]
+impl AllocationServiceInterface for AllocationService {
+    async fn get_targets(&self, command: AllocationTargetGetCommand) -> Result<Option<AllocationTargetsResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        if self.portfolio(command.portfolio_id, meta_user).await?.is_none() {
+            return Ok(None);
+        }
+
+        let targets = self.targets(command.portfolio_id, meta_user).await?;
+        Ok(Some(Self::targets_response(command.portfolio_id, &targets)))
+    }
+
+    async fn set_targets(&self, command: AllocationTargetSetCommand) -> Result<Option<AllocationTargetsResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio = match self.portfolio(command.portfolio_id, meta_user).await? {
+            Some(portfolio) => portfolio,
+            None => return Ok(None),
+        };
+        let instruments = self.instruments(&portfolio, meta_user).await?;
+        Self::validate(&command, &instruments)?;
+
+        if self.target_repo.delete_by_portfolio(command.portfolio_id, meta_user).await.is_err() {
+            return Err(Error::msg("Error deleting allocation targets"));
+        }
+        for request in &command.targets {
+            let target = AllocationTarget::of_portfolio(command.allocation_kind, request, command.portfolio_id);
+            if self.target_repo.create(target, meta_user).await.is_err() {
+                return Err(Error::msg("Error creating allocation target"));
+            }
+        }
+
+        let targets = self.targets(command.portfolio_id, meta_user).await?;
+        Ok(Some(Self::targets_response(command.portfolio_id, &targets)))
+    }
+
+    async fn rebalancing(&self, command: RebalancingCommand) -> Result<Option<RebalancingResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio = match self.portfolio(command.portfolio_id, meta_user).await? {
+            Some(portfolio) => portfolio,
+            None => return Ok(None),
+        };
+        let contribution_minor = command.contribution_minor.unwrap_or(0);
+        let min_trade_minor = command.min_trade_minor.unwrap_or(0);
+        if contribution_minor < 0 || min_trade_minor < 0 {
+            return Err(AppError::BadRequest("contribution and minimum trade size cannot be negative".into()).into());
+        }
+        let targets = self.targets(command.portfolio_id, meta_user).await?;
+        let Some(kind) = targets.first().map(|target| target.target_kind) else {
+            return Err(AppError::BadRequest("portfolio has no allocation target".into()).into());
+        };
+        let as_of = command.as_of.unwrap_or(Utc::now().date_naive());
+
+        let base_code = match self.user_repo.get(command.auth_user.user_id, meta_user).await {
+            Ok(Some(user)) => user.base_currency_code,
+            Ok(None) => return Err(Error::msg("User not found")),
+            Err(_) => return Err(Error::msg("Error getting user")),
+        };
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let fx_rates = match self.fx_rate_repo.get_by_base_code(base_code.clone(), meta_user).await {
+            Ok(fx_rates) => fx_rates,
+            Err(_) => return Err(Error::msg("Error getting fx rates")),
+        };
+        let fx = FxTable::new(&base_code, &fx_rates);
+        let base_scale = scale_factor(minor_units.get(&base_code).copied().unwrap_or(2));
+
+        let trades = match self.trade_repo.get_by_portfolio(command.portfolio_id, meta_user).await {
+            Ok(trades) => trades,
+            Err(_) => return Err(Error::msg("Error getting portfolio trades")),
+        };
+        let trades: Vec<_> = trades.into_iter().filter(|trade| trade.trade_date <= as_of).collect();
+        let instruments = self.instruments(&portfolio, meta_user).await?;
+        let holdings = match Holdings::replay(&trades, &PortfolioService::scales_of(&instruments)) {
+            Ok(holdings) => holdings,
+            Err(_) => return Err(Error::msg("Error replaying portfolio trades")),
+        };
+        let prices = match self.price_repo.get_latest_by_portfolio(command.portfolio_id, as_of, meta_user).await {
+            Ok(prices) => prices,
+            Err(_) => return Err(Error::msg("Error getting instrument prices")),
+        };
+
+        // current value of each group, in base minor units
+        let mut current: BTreeMap<AllocationKey, Decimal> = BTreeMap::new();
+        let mut missing_price_instrument_ids = Vec::new();
+        for target in &targets {
+            current.entry(target.key()).or_default();
+        }
+        for instrument in &instruments {
+            let instrument_id = instrument.id.as_ref().unwrap();
+            let Some(holding) = holdings.by_instrument.get(instrument_id).filter(|holding| holding.quantity > Decimal::ZERO) else {
+                continue;
+            };
+            let scale = scale_factor(instrument.minor_unit);
+            let value_minor = match prices.iter().find(|price| &price.instrument_id == instrument_id) {
+                Some(price) => holding.quantity * price.price * scale,
+                None => {
+                    missing_price_instrument_ids.push(bu(instrument_id));
+                    holding.cost_minor
+                },
+            };
+            let value_base = match fx.to_base(value_minor / scale, &instrument.currency_code, as_of) {
+                Some(value_base) => value_base,
+                None => return Err(AppError::BadRequest(format!("missing fx rate {}/{}", base_code, instrument.currency_code)).into()),
+            };
+            *current.entry(AllocationKey::of(kind, instrument)).or_default() += value_base * base_scale;
+        }
+
+        let weights: HashMap<AllocationKey, Decimal> = targets.iter()
+            .map(|target| (target.key(), target.weight_percent))
+            .collect();
+        let current_total: Decimal = current.values().sum();
+        let contribution = Decimal::from(contribution_minor);
+        let total = current_total + contribution;
+        let gaps: Vec<Decimal> = current.iter()
+            .map(|(key, value)| total * weights.get(key).copied().unwrap_or_default() / Decimal::from(100) - value)
+            .collect();
+        let trades = Self::trades(&gaps, contribution, command.contributions_only);
+
+        let mut lines = Vec::new();
+        let mut traded = 0;
+        for ((key, value), trade) in current.iter().zip(trades) {
+            let weight = weights.get(key).copied().unwrap_or_default();
+            let mut trade_minor = trade.round().to_i64().unwrap_or(0);
+            if trade_minor.abs() < min_trade_minor.max(1) {
+                trade_minor = 0;
+            }
+            traded += trade_minor;
+            let current_percent = Self::percent(*value, current_total);
+            lines.push(AllocationLineResponse {
+                asset_class: match key {
+                    AllocationKey::AssetClass(asset_class) => Some(*asset_class),
+                    _ => None,
+                },
+                instrument_id: match key {
+                    AllocationKey::Instrument(instrument_id) => obu(Some(instrument_id)),
+                    _ => None,
+                },
+                tag: match key {
+                    AllocationKey::Tag(tag) => tag.clone(),
+                    _ => None,
+                },
+                name: Self::name(key, &instruments),
+                current_value_base_minor: value.round().to_i64().unwrap_or(0),
+                current_percent,
+                target_percent: weight,
+                drift_percent: current_percent - weight,
+                target_value_base_minor: (total * weight / Decimal::from(100)).round().to_i64().unwrap_or(0),
+                trade_base_minor: trade_minor,
+                action: match trade_minor {
+                    0 => RebalanceAction::Hold,
+                    trade_minor if trade_minor > 0 => RebalanceAction::Buy,
+                    _ => RebalanceAction::Sell,
+                },
+            });
+        }
+        lines.sort_by(|a, b| b.target_percent.cmp(&a.target_percent).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(Some(RebalancingResponse {
+            portfolio_id: command.portfolio_id,
+            as_of,
+            base_currency_code: base_code,
+            allocation_kind: kind,
+            current_value_base_minor: current_total.round().to_i64().unwrap_or(0),
+            contribution_base_minor: contribution_minor,
+            min_trade_base_minor: min_trade_minor,
+            contributions_only: command.contributions_only,
+            max_drift_percent: lines.iter().map(|line| line.drift_percent.abs()).max().unwrap_or_default(),
+            lines,
+            uninvested_base_minor: contribution_minor - traded,
+            missing_price_instrument_ids,
+        }))
+    }
+}
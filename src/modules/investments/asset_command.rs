@@ -0,0 +1,186 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::{
+    asset_dto::{AssetCreateRequest, AssetSnapshotQuery, AssetUpdateRequest, AssetValuationListQuery, AssetValuationSetRequest},
+    asset_model::AssetKind,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetGetCommand {
+    pub asset_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetGetCommand {
+    pub fn new(asset_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { asset_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetCreateCommand {
+    pub user_id: Uuid,
+    pub asset_name: String,
+    pub asset_currency_code: String,
+    pub asset_kind: Option<AssetKind>,
+    pub asset_account_id: Option<Uuid>,
+    pub asset_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetCreateCommand {
+    pub fn new(request: AssetCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            asset_name: request.asset_name,
+            asset_currency_code: request.asset_currency_code,
+            asset_kind: request.asset_kind,
+            asset_account_id: request.asset_account_id,
+            asset_note: request.asset_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetUpdateCommand {
+    pub asset_id: Uuid,
+    pub asset_name: String,
+    pub asset_currency_code: String,
+    pub asset_kind: AssetKind,
+    pub asset_account_id: Option<Uuid>,
+    pub asset_note: Option<String>,
+    pub asset_archived: bool,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetUpdateCommand {
+    pub fn new(asset_id: Uuid, request: AssetUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            asset_id,
+            asset_name: request.asset_name,
+            asset_currency_code: request.asset_currency_code,
+            asset_kind: request.asset_kind,
+            asset_account_id: request.asset_account_id,
+            asset_note: request.asset_note,
+            asset_archived: request.asset_archived,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetDeleteCommand {
+    pub asset_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetDeleteCommand {
+    pub fn new(asset_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { asset_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetValuationSetCommand {
+    pub asset_id: Uuid,
+    pub valuation_month: NaiveDate,
+    pub value_minor: i64,
+    pub valuation_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetValuationSetCommand {
+    pub fn new(asset_id: Uuid, request: AssetValuationSetRequest, auth_user: AuthUser) -> Self {
+        Self {
+            asset_id,
+            valuation_month: request.valuation_month,
+            value_minor: request.value_minor,
+            valuation_note: request.valuation_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetValuationDeleteCommand {
+    pub asset_id: Uuid,
+    pub valuation_month: NaiveDate,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetValuationDeleteCommand {
+    pub fn new(asset_id: Uuid, valuation_month: NaiveDate, auth_user: AuthUser) -> Self {
+        Self { asset_id, valuation_month, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetValuationListCommand {
+    pub asset_id: Uuid,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetValuationListCommand {
+    pub fn new(asset_id: Uuid, query: AssetValuationListQuery, auth_user: AuthUser) -> Self {
+        Self { asset_id, from: query.from, to: query.to, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetValuationImportCommand {
+    pub asset_id: Uuid,
+    pub content: String,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetValuationImportCommand {
+    pub fn new(asset_id: Uuid, content: String, auth_user: AuthUser) -> Self {
+        Self { asset_id, content, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetSnapshotCommand {
+    pub user_id: Uuid,
+    pub month: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl AssetSnapshotCommand {
+    pub fn new(query: AssetSnapshotQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            month: query.month,
+            auth_user,
+        }
+    }
+}
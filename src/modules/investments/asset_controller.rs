@@ -0,0 +1,341 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{delete, get, post}, Json, Router};
+use axum::extract::Query;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    asset_command::*,
+    asset_dto::*,
+    asset_service::{AssetService, AssetServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/assets", get(get_assets).post(post_asset))
+        .route("/assets/snapshot", get(get_asset_snapshot))
+        .route("/assets/{asset_id}", get(get_asset).put(put_asset).delete(delete_asset))
+        .route("/assets/{asset_id}/valuations", get(get_asset_valuations).put(put_asset_valuation))
+        .route("/assets/{asset_id}/valuations/import", post(post_asset_valuations_import))
+        .route("/assets/{asset_id}/valuations/{valuation_month}", delete(delete_asset_valuation))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/assets",
+    responses(
+        (status = StatusCode::OK, description = "Manual assets of current user", body = Vec<AssetResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn get_assets(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<AssetResponse>>, StatusCode> {
+    let command = AssetListByUserCommand::new(auth_user.user_id, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let assets = asset_service.get_by_user(command).await;
+    match assets {
+        Ok(assets) => Ok(Json(assets)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/assets",
+    responses(
+        (status = StatusCode::OK, description = "Asset created successfully", body = AssetResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid asset"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn post_asset(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(asset_create_request): Json<AssetCreateRequest>
+) -> Result<Json<AssetResponse>, StatusCode> {
+    let command = AssetCreateCommand::new(asset_create_request, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let asset = asset_service.create(command).await;
+    match asset {
+        Ok(asset) => Ok(Json(asset)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/assets/snapshot",
+    params(
+        AssetSnapshotQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Value of the active assets for the month, in the base currency", body = AssetSnapshotResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Missing fx rate"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn get_asset_snapshot(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<AssetSnapshotQuery>,
+) -> Result<Json<AssetSnapshotResponse>, StatusCode> {
+    let command = AssetSnapshotCommand::new(query, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let snapshot = asset_service.snapshot(command).await;
+    match snapshot {
+        Ok(snapshot) => Ok(Json(snapshot)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/assets/{asset_id}",
+    params(
+        ("asset_id", description = "asset identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Asset", body = AssetResponse),
+        (status = StatusCode::NOT_FOUND, description = "Asset not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn get_asset(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(asset_id): Path<Uuid>,
+) -> Result<Json<AssetResponse>, StatusCode> {
+    let command = AssetGetCommand::new(asset_id, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let asset = asset_service.get_by_id(command).await;
+    match asset {
+        Ok(asset) => {
+            match asset {
+                Some(asset) => Ok(Json(asset)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/investments/assets/{asset_id}",
+    params(
+        ("asset_id", description = "asset identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Asset updated successfully", body = AssetResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid asset"),
+        (status = StatusCode::NOT_FOUND, description = "Asset not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn put_asset(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(asset_id): Path<Uuid>,
+    Json(asset_update_request): Json<AssetUpdateRequest>
+) -> Result<Json<AssetResponse>, StatusCode> {
+    let command = AssetUpdateCommand::new(asset_id, asset_update_request, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let asset = asset_service.update(command).await;
+    match asset {
+        Ok(asset) => {
+            match asset {
+                Some(asset) => Ok(Json(asset)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/investments/assets/{asset_id}",
+    params(
+        ("asset_id", description = "asset identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Asset deleted successfully, with its valuations"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn delete_asset(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(asset_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = AssetDeleteCommand::new(asset_id, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let response = asset_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/assets/{asset_id}/valuations",
+    params(
+        ("asset_id", description = "asset identifier in uuid"),
+        AssetValuationListQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Monthly valuations of the asset", body = Vec<AssetValuationResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Asset not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn get_asset_valuations(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(asset_id): Path<Uuid>,
+    Query(query): Query<AssetValuationListQuery>,
+) -> Result<Json<Vec<AssetValuationResponse>>, StatusCode> {
+    let command = AssetValuationListCommand::new(asset_id, query, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let valuations = asset_service.get_valuations(command).await;
+    match valuations {
+        Ok(valuations) => {
+            match valuations {
+                Some(valuations) => Ok(Json(valuations)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/investments/assets/{asset_id}/valuations",
+    params(
+        ("asset_id", description = "asset identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Valuation of the month recorded successfully", body = AssetValuationResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid valuation"),
+        (status = StatusCode::NOT_FOUND, description = "Asset not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn put_asset_valuation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(asset_id): Path<Uuid>,
+    Json(asset_valuation_set_request): Json<AssetValuationSetRequest>
+) -> Result<Json<AssetValuationResponse>, StatusCode> {
+    let command = AssetValuationSetCommand::new(asset_id, asset_valuation_set_request, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let valuation = asset_service.set_valuation(command).await;
+    match valuation {
+        Ok(valuation) => {
+            match valuation {
+                Some(valuation) => Ok(Json(valuation)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/assets/{asset_id}/valuations/import",
+    params(
+        ("asset_id", description = "asset identifier in uuid")
+    ),
+    request_body(content = String, description = "CSV with month (or date) and value columns, values in major units", content_type = "text/csv"),
+    responses(
+        (status = StatusCode::OK, description = "Valuations imported, with the lines left out", body = ValuationImportResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Empty CSV or missing columns"),
+        (status = StatusCode::NOT_FOUND, description = "Asset not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn post_asset_valuations_import(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(asset_id): Path<Uuid>,
+    content: String,
+) -> Result<Json<ValuationImportResponse>, StatusCode> {
+    let command = AssetValuationImportCommand::new(asset_id, content, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let import = asset_service.import_valuations(command).await;
+    match import {
+        Ok(import) => {
+            match import {
+                Some(import) => Ok(Json(import)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/investments/assets/{asset_id}/valuations/{valuation_month}",
+    params(
+        ("asset_id", description = "asset identifier in uuid"),
+        ("valuation_month", description = "any day of the month, YYYY-MM-DD")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Valuation of the month deleted successfully"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Asset"
+)]
+pub async fn delete_asset_valuation(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((asset_id, valuation_month)): Path<(Uuid, NaiveDate)>,
+) -> Result<StatusCode, StatusCode> {
+    let command = AssetValuationDeleteCommand::new(asset_id, valuation_month, auth_user);
+    let asset_service = AssetService::from(&state);
+
+    let response = asset_service.delete_valuation(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    asset_model::{Asset, AssetKind, AssetValuation},
+    instrument_dto::ImportRejectResponse,
+};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssetResponse {
+    pub asset_id: Uuid,
+    pub user_id: Uuid,
+    pub asset_account_id: Option<Uuid>,
+    pub asset_name: String,
+    pub asset_kind: AssetKind,
+    pub asset_currency_code: String,
+    pub asset_note: Option<String>,
+    pub asset_archived: bool,
+
+    pub asset_created_at: Option<DateTime<Utc>>,
+    pub asset_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Asset> for AssetResponse {
+    fn from(asset: &Asset) -> Self {
+        Self {
+            asset_id: bu(asset.id.clone().unwrap().as_slice()),
+            user_id: bu(asset.user_id.as_slice()),
+            asset_account_id: obu(asset.account_id.as_deref()),
+            asset_name: asset.name.clone(),
+            asset_kind: asset.asset_kind,
+            asset_currency_code: asset.currency_code.clone(),
+            asset_note: asset.note.clone(),
+            asset_archived: asset.archived,
+            asset_created_at: asset.created_at,
+            asset_updated_at: asset.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AssetCreateRequest {
+    pub asset_name: String,
+    /// Currency of the valuations
+    pub asset_currency_code: String,
+    /// Other by default
+    pub asset_kind: Option<AssetKind>,
+    /// Related account, e.g. the mortgage of a property
+    pub asset_account_id: Option<Uuid>,
+    pub asset_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AssetUpdateRequest {
+    pub asset_name: String,
+    pub asset_currency_code: String,
+    pub asset_kind: AssetKind,
+    pub asset_account_id: Option<Uuid>,
+    pub asset_note: Option<String>,
+    pub asset_archived: bool,
+}
+
+// --- Valuations ---
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssetValuationResponse {
+    pub asset_valuation_id: Uuid,
+    pub asset_id: Uuid,
+    /// First day of the month
+    pub valuation_month: NaiveDate,
+    /// In the asset currency
+    pub value_minor: i64,
+    pub valuation_source: String,
+    pub valuation_note: Option<String>,
+
+    pub valuation_created_at: Option<DateTime<Utc>>,
+    pub valuation_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&AssetValuation> for AssetValuationResponse {
+    fn from(valuation: &AssetValuation) -> Self {
+        Self {
+            asset_valuation_id: bu(valuation.id.clone().unwrap().as_slice()),
+            asset_id: bu(valuation.asset_id.as_slice()),
+            valuation_month: valuation.valuation_month,
+            value_minor: valuation.value_minor,
+            valuation_source: valuation.source.clone(),
+            valuation_note: valuation.note.clone(),
+            valuation_created_at: valuation.created_at,
+            valuation_updated_at: valuation.updated_at,
+        }
+    }
+}
+
+/// Replaces the valuation already recorded for that month, if any
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AssetValuationSetRequest {
+    /// Any day of the month
+    pub valuation_month: NaiveDate,
+    pub value_minor: i64,
+    pub valuation_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AssetValuationListQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ValuationImportResponse {
+    /// Valuations recorded, replacing those already recorded for the same month
+    pub imported: u32,
+    pub rejected: Vec<ImportRejectResponse>,
+}
+
+// --- Monthly snapshot ---
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AssetSnapshotQuery {
+    /// Any day of the month, current month by default
+    pub month: Option<NaiveDate>,
+}
+
+/// Value of an asset for the month: its valuation of the month, else the latest one before
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssetSnapshotLineResponse {
+    pub asset_id: Uuid,
+    pub asset_name: String,
+    pub asset_kind: AssetKind,
+    pub currency_code: String,
+
+    /// None without any valuation up to the month
+    pub valuation_month: Option<NaiveDate>,
+    pub value_minor: Option<i64>,
+    /// At the fx rate of the end of the month
+    pub value_base_minor: Option<i64>,
+    /// Valued in an earlier month
+    pub carried_forward: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssetSnapshotResponse {
+    pub month: NaiveDate,
+    pub base_currency_code: String,
+    /// Active assets, archived ones are left out
+    pub assets: Vec<AssetSnapshotLineResponse>,
+    pub total_base_minor: i64,
+    /// Assets without valuation this month, carried forward or missing
+    pub stale_count: u32,
+}
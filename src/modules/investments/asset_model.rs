@@ -0,0 +1,145 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::investments::asset_command::{AssetCreateCommand, AssetValuationSetCommand};
+use crate::shared::dates::month_start;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AssetKind {
+    RealEstate,
+    Pension,
+    Vehicle,
+    Collectible,
+    Other,
+}
+
+impl AssetKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssetKind::RealEstate => "real_estate",
+            AssetKind::Pension => "pension",
+            AssetKind::Vehicle => "vehicle",
+            AssetKind::Collectible => "collectible",
+            AssetKind::Other => "other",
+        }
+    }
+}
+
+
+/// Asset without ticker (real estate, pension fund…) valued by monthly snapshots, in `currency_code`
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Asset {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    /// Related account, e.g. the mortgage of a property
+    pub account_id: Option<Vec<u8>>,
+    pub name: String,
+    pub asset_kind: AssetKind,
+    pub currency_code: String,
+    pub note: Option<String>,
+    pub archived: bool,
+
+    /// Minor unit of the asset currency, read only
+    pub minor_unit: u8,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Asset {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            account_id: row.try_get(index_map["account_id"])?,
+            name: row.try_get(index_map["name"])?,
+            asset_kind: row.try_get(index_map["asset_kind"])?,
+            currency_code: row.try_get(index_map["currency_code"])?,
+            note: row.try_get(index_map["note"])?,
+            archived: row.try_get(index_map["archived"])?,
+            minor_unit: row.try_get(index_map["minor_unit"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<AssetCreateCommand> for Asset {
+    fn from(command: AssetCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            account_id: oub(command.asset_account_id),
+            name: command.asset_name,
+            asset_kind: command.asset_kind.unwrap_or(AssetKind::Other),
+            currency_code: command.asset_currency_code,
+            note: command.asset_note,
+            archived: false,
+            minor_unit: 0,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Value of an asset for a month (first day of the month), in minor units of the asset currency
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AssetValuation {
+    pub id: Option<Vec<u8>>,
+
+    pub asset_id: Vec<u8>,
+    pub valuation_month: NaiveDate,
+    pub value_minor: i64,
+    pub source: String,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for AssetValuation {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            asset_id: row.try_get(index_map["asset_id"])?,
+            valuation_month: row.try_get(index_map["valuation_month"])?,
+            value_minor: row.try_get(index_map["value_minor"])?,
+            source: row.try_get(index_map["source"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<AssetValuationSetCommand> for AssetValuation {
+    fn from(command: AssetValuationSetCommand) -> Self {
+        Self::of_source(ub(command.asset_id), command.valuation_month, command.value_minor, "manual", command.valuation_note)
+    }
+}
+
+impl AssetValuation {
+    /// Valuation of the month of `date` coming from `source`
+    pub fn of_source(asset_id: Vec<u8>, date: NaiveDate, value_minor: i64, source: &str, note: Option<String>) -> Self {
+        Self {
+            id: None,
+            asset_id,
+            valuation_month: month_start(date),
+            value_minor,
+            source: source.to_string(),
+            note,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
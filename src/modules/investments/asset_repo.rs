@@ -0,0 +1,187 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::investments::asset_model::{Asset, AssetValuation};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait AssetRepositoryInterface {
+
+    async fn get(&self, asset_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Asset>, Error>;
+
+    async fn create(&self, asset: Asset, meta_user: Option<Uuid>) -> Result<Asset, Error>;
+
+    async fn update(&self, asset: Asset, meta_user: Option<Uuid>) -> Result<Option<Asset>, Error>;
+
+    /// Deletes the valuations with the asset
+    async fn delete(&self, asset_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Asset>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AssetRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AssetRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Asset> for AssetRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AssetRepositoryInterface for AssetRepository {
+    async fn get(&self, asset_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Asset>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(asset_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_manual_asset_get_by_id", params).await
+    }
+
+    async fn create(&self, asset: Asset, meta_user: Option<Uuid>) -> Result<Asset, Error> {
+        let params = vec![
+            MySqlParam::from(asset.user_id),
+            MySqlParam::from(asset.account_id),
+            MySqlParam::from(asset.name),
+            MySqlParam::from(asset.asset_kind.as_str()),
+            MySqlParam::from(asset.currency_code),
+            MySqlParam::from(asset.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_manual_asset_create", params).await
+    }
+
+    async fn update(&self, asset: Asset, meta_user: Option<Uuid>) -> Result<Option<Asset>, Error> {
+        let params = vec![
+            MySqlParam::from(asset.id),
+            MySqlParam::from(asset.account_id),
+            MySqlParam::from(asset.name),
+            MySqlParam::from(asset.asset_kind.as_str()),
+            MySqlParam::from(asset.currency_code),
+            MySqlParam::from(asset.note),
+            MySqlParam::from(asset.archived),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_manual_asset_update", params).await
+    }
+
+    async fn delete(&self, asset_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(asset_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_manual_asset_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Asset>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_manual_asset_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait AssetValuationRepositoryInterface {
+
+    /// Inserts or replaces the valuation of the asset for that month
+    async fn upsert(&self, valuation: AssetValuation, meta_user: Option<Uuid>) -> Result<AssetValuation, Error>;
+
+    async fn delete(&self, asset_id: Uuid, valuation_month: NaiveDate, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by month
+    async fn get_by_asset(&self, asset_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<AssetValuation>, Error>;
+
+    /// Every valuation of the user's assets up to `to`, ordered by month
+    async fn get_by_user(&self, user_id: Uuid, to: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<AssetValuation>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct AssetValuationRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for AssetValuationRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<AssetValuation> for AssetValuationRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AssetValuationRepositoryInterface for AssetValuationRepository {
+    async fn upsert(&self, valuation: AssetValuation, meta_user: Option<Uuid>) -> Result<AssetValuation, Error> {
+        let params = vec![
+            MySqlParam::from(valuation.asset_id),
+            MySqlParam::from(valuation.valuation_month),
+            MySqlParam::from(valuation.value_minor),
+            MySqlParam::from(valuation.source),
+            MySqlParam::from(valuation.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_asset_valuation_upsert", params).await
+    }
+
+    async fn delete(&self, asset_id: Uuid, valuation_month: NaiveDate, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(asset_id)),
+            MySqlParam::from(valuation_month),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_asset_valuation_delete", params).await
+    }
+
+    async fn get_by_asset(&self, asset_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<AssetValuation>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(asset_id)),
+            MySqlParam::from(from),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_asset_valuation_by_asset", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, to: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<AssetValuation>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_asset_valuation_by_user", params).await
+    }
+}
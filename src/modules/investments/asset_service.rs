@@ -0,0 +1,316 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::currencies::{
+    currency_model::FxTable,
+    currency_repo::{CurrencyRepository, CurrencyRepositoryInterface, FxRateRepository, FxRateRepositoryInterface},
+};
+use crate::modules::investments::{
+    asset_command::*,
+    asset_dto::*,
+    asset_model::{Asset, AssetValuation},
+    asset_repo::{AssetRepository, AssetRepositoryInterface, AssetValuationRepository, AssetValuationRepositoryInterface},
+    instrument_dto::ImportRejectResponse,
+};
+use crate::modules::users::user::user_repo::{UserRepository, UserRepositoryInterface};
+use crate::shared::csv;
+use crate::shared::dates::{add_months, month_start};
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, oub};
+
+
+#[async_trait]
+pub trait AssetServiceInterface {
+
+    async fn get_by_id(&self, command: AssetGetCommand) -> Result<Option<AssetResponse>, Error>;
+
+    async fn create(&self, command: AssetCreateCommand) -> Result<AssetResponse, Error>;
+
+    async fn update(&self, command: AssetUpdateCommand) -> Result<Option<AssetResponse>, Error>;
+
+    async fn delete(&self, command: AssetDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: AssetListByUserCommand) -> Result<Vec<AssetResponse>, Error>;
+
+    /// Records the valuation of the month, replacing the previous one. None when the asset does not exist.
+    async fn set_valuation(&self, command: AssetValuationSetCommand) -> Result<Option<AssetValuationResponse>, Error>;
+
+    async fn delete_valuation(&self, command: AssetValuationDeleteCommand) -> Result<(), Error>;
+
+    /// None when the asset does not exist
+    async fn get_valuations(&self, command: AssetValuationListCommand) -> Result<Option<Vec<AssetValuationResponse>>, Error>;
+
+    /// Records the valid lines of a CSV with month (or date) and value columns, values in major units.
+    /// None when the asset does not exist.
+    async fn import_valuations(&self, command: AssetValuationImportCommand) -> Result<Option<ValuationImportResponse>, Error>;
+
+    /// Value of the user's active assets for a month, in the base currency
+    async fn snapshot(&self, command: AssetSnapshotCommand) -> Result<AssetSnapshotResponse, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct AssetService {
+    user_repo: UserRepository,
+    currency_repo: CurrencyRepository,
+    fx_rate_repo: FxRateRepository,
+    asset_repo: AssetRepository,
+    valuation_repo: AssetValuationRepository,
+}
+
+impl From<&AppState> for AssetService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            user_repo: UserRepository::from(app_state),
+            currency_repo: CurrencyRepository::from(app_state),
+            fx_rate_repo: FxRateRepository::from(app_state),
+            asset_repo: AssetRepository::from(app_state),
+            valuation_repo: AssetValuationRepository::from(app_state),
+        }
+    }
+}
+
+impl AssetService {
+    fn validate(name: &str, currency_code: &str) -> Result<(), Error> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest("asset name is required".into()).into());
+        }
+        if currency_code.len() != 3 {
+            return Err(AppError::BadRequest("asset currency must be a 3 letter code".into()).into());
+        }
+        Ok(())
+    }
+
+    /// Snapshot of `assets` on a day from their valuations up to the month of that day, in any order.
+    /// Values are converted at the rate of the day; `base_scale` is 10^minor unit of the base currency.
+    /// Err with the currency code missing a rate.
+    pub fn snapshot_of(on: NaiveDate, assets: &[Asset], valuations: &[AssetValuation], fx: &FxTable, base_scale: Decimal) -> Result<AssetSnapshotResponse, String> {
+        let month = month_start(on);
+        let mut latest: HashMap<&[u8], &AssetValuation> = HashMap::new();
+        for valuation in valuations.iter().filter(|valuation| valuation.valuation_month <= month) {
+            let entry = latest.entry(valuation.asset_id.as_slice()).or_insert(valuation);
+            if valuation.valuation_month > entry.valuation_month {
+                *entry = valuation;
+            }
+        }
+
+        let mut lines = Vec::new();
+        let mut total = Decimal::ZERO;
+        for asset in assets.iter().filter(|asset| !asset.archived) {
+            let valuation = latest.get(asset.id.as_deref().unwrap()).copied();
+            let value_base = match valuation {
+                Some(valuation) => {
+                    let value = Decimal::from(valuation.value_minor) / scale_factor(asset.minor_unit);
+                    match fx.to_base(value, &asset.currency_code, on) {
+                        Some(value_base) => Some(value_base * base_scale),
+                        None => return Err(asset.currency_code.clone()),
+                    }
+                },
+                None => None,
+            };
+            total += value_base.unwrap_or_default();
+            lines.push(AssetSnapshotLineResponse {
+                asset_id: bu(asset.id.as_deref().unwrap()),
+                asset_name: asset.name.clone(),
+                asset_kind: asset.asset_kind,
+                currency_code: asset.currency_code.clone(),
+                valuation_month: valuation.map(|valuation| valuation.valuation_month),
+                value_minor: valuation.map(|valuation| valuation.value_minor),
+                value_base_minor: value_base.map(|value_base| value_base.round().to_i64().unwrap_or(0)),
+                carried_forward: valuation.is_some_and(|valuation| valuation.valuation_month < month),
+            });
+        }
+
+        Ok(AssetSnapshotResponse {
+            month,
+            base_currency_code: fx.base_code.clone(),
+            stale_count: lines.iter().filter(|line| line.valuation_month != Some(month)).count() as u32,
+            total_base_minor: total.round().to_i64().unwrap_or(0),
+            assets: lines,
+        })
+    }
+
+    async fn asset(&self, asset_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Asset>, Error> {
+        match self.asset_repo.get(asset_id, meta_user).await {
+            Ok(asset) => Ok(asset),
+            Err(_) => Err(Error::msg("Error getting asset")),
+        }
+    }
+}
+
+#[async_trait]
+impl AssetServiceInterface for AssetService {
+    async fn get_by_id(&self, command: AssetGetCommand) -> Result<Option<AssetResponse>, Error> {
+        let asset = self.asset(command.asset_id, Some(command.auth_user.user_id)).await?;
+        Ok(asset.as_ref().map(AssetResponse::from))
+    }
+
+    async fn create(&self, command: AssetCreateCommand) -> Result<AssetResponse, Error> {
+        Self::validate(&command.asset_name, &command.asset_currency_code)?;
+        let meta_user = Some(command.auth_user.user_id);
+        match self.asset_repo.create(Asset::from(command), meta_user).await {
+            Ok(asset) => Ok(AssetResponse::from(&asset)),
+            Err(_) => Err(Error::msg("Error creating asset")),
+        }
+    }
+
+    async fn update(&self, command: AssetUpdateCommand) -> Result<Option<AssetResponse>, Error> {
+        Self::validate(&command.asset_name, &command.asset_currency_code)?;
+        let meta_user = Some(command.auth_user.user_id);
+        let mut asset = match self.asset(command.asset_id, meta_user).await? {
+            Some(asset) => asset,
+            None => return Ok(None),
+        };
+        asset.account_id = oub(command.asset_account_id);
+        asset.name = command.asset_name;
+        asset.asset_kind = command.asset_kind;
+        asset.currency_code = command.asset_currency_code;
+        asset.note = command.asset_note;
+        asset.archived = command.asset_archived;
+
+        match self.asset_repo.update(asset, meta_user).await {
+            Ok(asset) => Ok(asset.as_ref().map(AssetResponse::from)),
+            Err(_) => Err(Error::msg("Error updating asset")),
+        }
+    }
+
+    async fn delete(&self, command: AssetDeleteCommand) -> Result<(), Error> {
+        match self.asset_repo.delete(command.asset_id, Some(command.auth_user.user_id)).await {
+            Ok(()) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting asset")),
+        }
+    }
+
+    async fn get_by_user(&self, command: AssetListByUserCommand) -> Result<Vec<AssetResponse>, Error> {
+        match self.asset_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await {
+            Ok(assets) => Ok(assets.iter().map(AssetResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting assets")),
+        }
+    }
+
+    async fn set_valuation(&self, command: AssetValuationSetCommand) -> Result<Option<AssetValuationResponse>, Error> {
+        if command.value_minor < 0 {
+            return Err(AppError::BadRequest("asset value cannot be negative".into()).into());
+        }
+        let meta_user = Some(command.auth_user.user_id);
+        if self.asset(command.asset_id, meta_user).await?.is_none() {
+            return Ok(None);
+        }
+
+        match self.valuation_repo.upsert(AssetValuation::from(command), meta_user).await {
+            Ok(valuation) => Ok(Some(AssetValuationResponse::from(&valuation))),
+            Err(_) => Err(Error::msg("Error recording asset valuation")),
+        }
+    }
+
+    async fn delete_valuation(&self, command: AssetValuationDeleteCommand) -> Result<(), Error> {
+        let month = month_start(command.valuation_month);
+        match self.valuation_repo.delete(command.asset_id, month, Some(command.auth_user.user_id)).await {
+            Ok(()) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting asset valuation")),
+        }
+    }
+
+    async fn get_valuations(&self, command: AssetValuationListCommand) -> Result<Option<Vec<AssetValuationResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        if self.asset(command.asset_id, meta_user).await?.is_none() {
+            return Ok(None);
+        }
+
+        let from = command.from.map(month_start);
+        match self.valuation_repo.get_by_asset(command.asset_id, from, command.to, meta_user).await {
+            Ok(valuations) => Ok(Some(valuations.iter().map(AssetValuationResponse::from).collect())),
+            Err(_) => Err(Error::msg("Error getting asset valuations")),
+        }
+    }
+
+    async fn import_valuations(&self, command: AssetValuationImportCommand) -> Result<Option<ValuationImportResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let records = csv::parse(&command.content);
+        let Some((_, header)) = records.first() else {
+            return Err(AppError::BadRequest("csv is empty".into()).into());
+        };
+        let (Some(month_column), Some(value_column)) = (csv::column(header, &["month", "date"]), csv::column(header, &["value", "valuation"])) else {
+            return Err(AppError::BadRequest("csv needs month and value columns".into()).into());
+        };
+        let note_column = csv::column(header, &["note"]);
+        let asset = match self.asset(command.asset_id, meta_user).await? {
+            Some(asset) => asset,
+            None => return Ok(None),
+        };
+        let scale = scale_factor(asset.minor_unit);
+
+        let mut imported = 0;
+        let mut rejected = Vec::new();
+        for (line, record) in records.iter().skip(1) {
+            let reject = |reason: &str| ImportRejectResponse { line: *line, reason: reason.to_string() };
+            let Some(month) = record.get(month_column).and_then(|month| csv::date(month)) else {
+                rejected.push(reject("invalid month"));
+                continue;
+            };
+            let value_minor = match record.get(value_column).and_then(|value| csv::decimal(value)) {
+                Some(value) if value >= Decimal::ZERO => (value * scale).round().to_i64(),
+                _ => None,
+            };
+            let Some(value_minor) = value_minor else {
+                rejected.push(reject("invalid value"));
+                continue;
+            };
+            let note = note_column
+                .and_then(|column| record.get(column))
+                .map(|note| note.trim().to_string())
+                .filter(|note| !note.is_empty());
+
+            let valuation = AssetValuation::of_source(asset.id.clone().unwrap(), month, value_minor, "csv", note);
+            if self.valuation_repo.upsert(valuation, meta_user).await.is_err() {
+                return Err(Error::msg("Error recording asset valuation"));
+            }
+            imported += 1;
+        }
+
+        Ok(Some(ValuationImportResponse { imported, rejected }))
+    }
+
+    async fn snapshot(&self, command: AssetSnapshotCommand) -> Result<AssetSnapshotResponse, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let month = month_start(command.month.unwrap_or(Utc::now().date_naive()));
+
+        let base_code = match self.user_repo.get(command.user_id, meta_user).await {
+            Ok(Some(user)) => user.base_currency_code,
+            Ok(None) => return Err(Error::msg("User not found")),
+            Err(_) => return Err(Error::msg("Error getting user")),
+        };
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let fx_rates = match self.fx_rate_repo.get_by_base_code(base_code.clone(), meta_user).await {
+            Ok(fx_rates) => fx_rates,
+            Err(_) => return Err(Error::msg("Error getting fx rates")),
+        };
+        let fx = FxTable::new(&base_code, &fx_rates);
+        let base_scale = scale_factor(minor_units.get(&base_code).copied().unwrap_or(2));
+
+        let assets = match self.asset_repo.get_by_user(command.user_id, meta_user).await {
+            Ok(assets) => assets,
+            Err(_) => return Err(Error::msg("Error getting assets")),
+        };
+        let valuations = match self.valuation_repo.get_by_user(command.user_id, month, meta_user).await {
+            Ok(valuations) => valuations,
+            Err(_) => return Err(Error::msg("Error getting asset valuations")),
+        };
+
+        let month_end = add_months(month, 1) - Days::new(1);
+        match Self::snapshot_of(month_end, &assets, &valuations, &fx, base_scale) {
+            Ok(snapshot) => Ok(snapshot),
+            Err(code) => Err(AppError::BadRequest(format!("missing fx rate {}/{}", base_code, code)).into()),
+        }
+    }
+}
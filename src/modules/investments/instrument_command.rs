@@ -0,0 +1,186 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::{
+    instrument_dto::{InstrumentCreateRequest, InstrumentPriceCreateRequest, InstrumentPriceListQuery, InstrumentUpdateRequest, PriceSyncQuery},
+    instrument_model::InstrumentType,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentGetCommand {
+    pub instrument_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentGetCommand {
+    pub fn new(instrument_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { instrument_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentCreateCommand {
+    pub user_id: Uuid,
+    pub instrument_name: String,
+    pub instrument_currency_code: String,
+
+    pub instrument_symbol: Option<String>,
+    pub instrument_isin: Option<String>,
+    pub instrument_tag: Option<String>,
+    pub instrument_type: Option<InstrumentType>,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentCreateCommand {
+    pub fn new(request: InstrumentCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            instrument_name: request.instrument_name,
+            instrument_currency_code: request.instrument_currency_code,
+            instrument_symbol: request.instrument_symbol,
+            instrument_isin: request.instrument_isin,
+            instrument_tag: request.instrument_tag,
+            instrument_type: request.instrument_type,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentUpdateCommand {
+    pub instrument_id: Uuid,
+
+    pub instrument_name: String,
+    pub instrument_currency_code: String,
+    pub instrument_symbol: Option<String>,
+    pub instrument_isin: Option<String>,
+    pub instrument_tag: Option<String>,
+    pub instrument_type: InstrumentType,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentUpdateCommand {
+    pub fn new(instrument_id: Uuid, request: InstrumentUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            instrument_id,
+            instrument_name: request.instrument_name,
+            instrument_currency_code: request.instrument_currency_code,
+            instrument_symbol: request.instrument_symbol,
+            instrument_isin: request.instrument_isin,
+            instrument_tag: request.instrument_tag,
+            instrument_type: request.instrument_type,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentDeleteCommand {
+    pub instrument_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentDeleteCommand {
+    pub fn new(instrument_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { instrument_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentPriceCreateCommand {
+    pub instrument_id: Uuid,
+    pub price_date: NaiveDate,
+    pub price: Decimal,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentPriceCreateCommand {
+    pub fn new(instrument_id: Uuid, request: InstrumentPriceCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            instrument_id,
+            price_date: request.price_date,
+            price: request.price,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentPriceListCommand {
+    pub instrument_id: Uuid,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentPriceListCommand {
+    pub fn new(instrument_id: Uuid, query: InstrumentPriceListQuery, auth_user: AuthUser) -> Self {
+        Self { instrument_id, from: query.from, to: query.to, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentPriceImportCommand {
+    pub user_id: Uuid,
+    /// Instruments are found by the symbol or ISIN column when None
+    pub instrument_id: Option<Uuid>,
+    pub content: String,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentPriceImportCommand {
+    pub fn new(instrument_id: Option<Uuid>, content: String, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            instrument_id,
+            content,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstrumentPriceSyncCommand {
+    pub user_id: Uuid,
+    pub instrument_id: Option<Uuid>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl InstrumentPriceSyncCommand {
+    pub fn new(query: PriceSyncQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            instrument_id: query.instrument_id,
+            from: query.from,
+            to: query.to,
+            auth_user,
+        }
+    }
+}
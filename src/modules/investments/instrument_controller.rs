@@ -0,0 +1,349 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    instrument_command::*,
+    instrument_dto::*,
+    instrument_service::{InstrumentService, InstrumentServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/instruments", get(get_instruments).post(post_instrument))
+        .route("/instruments/{instrument_id}", get(get_instrument).put(put_instrument).delete(delete_instrument))
+        .route("/instruments/{instrument_id}/prices", get(get_instrument_prices).post(post_instrument_price))
+        .route("/instruments/{instrument_id}/prices/import", post(post_instrument_prices_import))
+        .route("/instruments/prices/import", post(post_prices_import))
+        .route("/instruments/prices/sync", post(post_prices_sync))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/instruments",
+    responses(
+        (status = StatusCode::OK, description = "Instruments of current user", body = Vec<InstrumentResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn get_instruments(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<InstrumentResponse>>, StatusCode> {
+    let command = InstrumentListByUserCommand::new(auth_user.user_id, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let instruments = instrument_service.get_by_user(command).await;
+    match instruments {
+        Ok(instruments) => Ok(Json(instruments)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/instruments",
+    responses(
+        (status = StatusCode::OK, description = "Instrument created successfully", body = InstrumentResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid instrument"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn post_instrument(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(instrument_create_request): Json<InstrumentCreateRequest>
+) -> Result<Json<InstrumentResponse>, StatusCode> {
+    let command = InstrumentCreateCommand::new(instrument_create_request, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let instrument = instrument_service.create(command).await;
+    match instrument {
+        Ok(instrument) => Ok(Json(instrument)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/instruments/{instrument_id}",
+    params(
+        ("instrument_id", description = "instrument identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Instrument", body = InstrumentResponse),
+        (status = StatusCode::NOT_FOUND, description = "Instrument not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn get_instrument(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(instrument_id): Path<Uuid>,
+) -> Result<Json<InstrumentResponse>, StatusCode> {
+    let command = InstrumentGetCommand::new(instrument_id, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let instrument = instrument_service.get(command).await;
+    match instrument {
+        Ok(instrument) => {
+            match instrument {
+                Some(instrument) => Ok(Json(instrument)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/investments/instruments/{instrument_id}",
+    params(
+        ("instrument_id", description = "instrument identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Instrument updated successfully", body = InstrumentResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid instrument"),
+        (status = StatusCode::NOT_FOUND, description = "Instrument not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn put_instrument(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(instrument_id): Path<Uuid>,
+    Json(instrument_update_request): Json<InstrumentUpdateRequest>
+) -> Result<Json<InstrumentResponse>, StatusCode> {
+    let command = InstrumentUpdateCommand::new(instrument_id, instrument_update_request, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let instrument = instrument_service.update(command).await;
+    match instrument {
+        Ok(instrument) => {
+            match instrument {
+                Some(instrument) => Ok(Json(instrument)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/investments/instruments/{instrument_id}",
+    params(
+        ("instrument_id", description = "instrument identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Instrument deleted successfully, with its prices"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn delete_instrument(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(instrument_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = InstrumentDeleteCommand::new(instrument_id, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let response = instrument_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/instruments/{instrument_id}/prices",
+    params(
+        ("instrument_id", description = "instrument identifier in uuid"),
+        InstrumentPriceListQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recorded prices of the instrument by date", body = Vec<InstrumentPriceResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Instrument not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn get_instrument_prices(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(instrument_id): Path<Uuid>,
+    Query(query): Query<InstrumentPriceListQuery>,
+) -> Result<Json<Vec<InstrumentPriceResponse>>, StatusCode> {
+    let command = InstrumentPriceListCommand::new(instrument_id, query, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let prices = instrument_service.get_prices(command).await;
+    match prices {
+        Ok(prices) => {
+            match prices {
+                Some(prices) => Ok(Json(prices)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/instruments/{instrument_id}/prices",
+    params(
+        ("instrument_id", description = "instrument identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Price recorded successfully", body = InstrumentPriceResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid price"),
+        (status = StatusCode::NOT_FOUND, description = "Instrument not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn post_instrument_price(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(instrument_id): Path<Uuid>,
+    Json(instrument_price_create_request): Json<InstrumentPriceCreateRequest>
+) -> Result<Json<InstrumentPriceResponse>, StatusCode> {
+    let command = InstrumentPriceCreateCommand::new(instrument_id, instrument_price_create_request, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let price = instrument_service.add_price(command).await;
+    match price {
+        Ok(price) => {
+            match price {
+                Some(price) => Ok(Json(price)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/instruments/{instrument_id}/prices/import",
+    params(
+        ("instrument_id", description = "instrument identifier in uuid")
+    ),
+    request_body(content = String, description = "CSV with date and price columns", content_type = "text/csv"),
+    responses(
+        (status = StatusCode::OK, description = "Prices imported, with the lines left out", body = PriceImportResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Empty CSV or missing columns"),
+        (status = StatusCode::NOT_FOUND, description = "Instrument not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn post_instrument_prices_import(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(instrument_id): Path<Uuid>,
+    content: String,
+) -> Result<Json<PriceImportResponse>, StatusCode> {
+    let command = InstrumentPriceImportCommand::new(Some(instrument_id), content, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let import = instrument_service.import_prices(command).await;
+    match import {
+        Ok(import) => {
+            match import {
+                Some(import) => Ok(Json(import)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/instruments/prices/import",
+    request_body(content = String, description = "CSV with date, price and symbol or isin columns", content_type = "text/csv"),
+    responses(
+        (status = StatusCode::OK, description = "Prices imported, with the lines left out", body = PriceImportResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Empty CSV or missing columns"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn post_prices_import(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    content: String,
+) -> Result<Json<PriceImportResponse>, StatusCode> {
+    let command = InstrumentPriceImportCommand::new(None, content, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let import = instrument_service.import_prices(command).await;
+    match import {
+        Ok(import) => {
+            match import {
+                Some(import) => Ok(Json(import)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/instruments/prices/sync",
+    params(
+        PriceSyncQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Prices recorded from the configured price provider", body = PriceSyncResponse),
+        (status = StatusCode::BAD_REQUEST, description = "No price provider configured or invalid period"),
+        (status = StatusCode::NOT_FOUND, description = "Instrument not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Instrument"
+)]
+pub async fn post_prices_sync(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<PriceSyncQuery>,
+) -> Result<Json<PriceSyncResponse>, StatusCode> {
+    let command = InstrumentPriceSyncCommand::new(query, auth_user);
+    let instrument_service = InstrumentService::from(&state);
+
+    let sync = instrument_service.sync_prices(command).await;
+    match sync {
+        Ok(sync) => {
+            match sync {
+                Some(sync) => Ok(Json(sync)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
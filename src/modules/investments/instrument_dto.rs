@@ -0,0 +1,148 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::investments::instrument_model::{Instrument, InstrumentPrice, InstrumentType};
+use crate::shared::utils::bu;
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InstrumentResponse {
+    pub instrument_id: Uuid,
+    pub user_id: Uuid,
+    pub instrument_name: String,
+    pub instrument_symbol: Option<String>,
+    pub instrument_isin: Option<String>,
+    pub instrument_type: InstrumentType,
+    pub instrument_currency_code: String,
+    pub instrument_tag: Option<String>,
+
+    pub instrument_created_at: Option<DateTime<Utc>>,
+    pub instrument_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Instrument> for InstrumentResponse {
+    fn from(instrument: &Instrument) -> Self {
+        Self {
+            instrument_id: bu(instrument.id.clone().unwrap().as_slice()),
+            user_id: bu(instrument.user_id.as_slice()),
+            instrument_name: instrument.name.clone(),
+            instrument_symbol: instrument.symbol.clone(),
+            instrument_isin: instrument.isin.clone(),
+            instrument_type: instrument.instrument_type,
+            instrument_currency_code: instrument.currency_code.clone(),
+            instrument_tag: instrument.tag.clone(),
+            instrument_created_at: instrument.created_at,
+            instrument_updated_at: instrument.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InstrumentCreateRequest {
+    pub instrument_name: String,
+    /// Currency the instrument is quoted and traded in
+    pub instrument_currency_code: String,
+    pub instrument_symbol: Option<String>,
+    /// 12 characters
+    pub instrument_isin: Option<String>,
+    /// Stock by default
+    pub instrument_type: Option<InstrumentType>,
+    /// Label grouping instruments for the target allocation
+    pub instrument_tag: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InstrumentUpdateRequest {
+    pub instrument_name: String,
+    pub instrument_currency_code: String,
+    pub instrument_symbol: Option<String>,
+    pub instrument_isin: Option<String>,
+    pub instrument_type: InstrumentType,
+    pub instrument_tag: Option<String>,
+}
+
+// --- Prices ---
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InstrumentPriceResponse {
+    pub instrument_price_id: Uuid,
+    pub instrument_id: Uuid,
+    pub price_date: NaiveDate,
+    /// In the instrument currency
+    pub price: Decimal,
+    pub price_source: String,
+
+    pub price_created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&InstrumentPrice> for InstrumentPriceResponse {
+    fn from(price: &InstrumentPrice) -> Self {
+        Self {
+            instrument_price_id: bu(price.id.clone().unwrap().as_slice()),
+            instrument_id: bu(price.instrument_id.as_slice()),
+            price_date: price.price_date,
+            price: price.price,
+            price_source: price.source.clone(),
+            price_created_at: price.created_at,
+        }
+    }
+}
+
+/// Replaces the price already recorded on that day, if any
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InstrumentPriceCreateRequest {
+    pub price_date: NaiveDate,
+    pub price: Decimal,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct InstrumentPriceListQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+// --- Price import ---
+
+/// Line of an imported CSV left out, with the reason
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ImportRejectResponse {
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PriceImportResponse {
+    /// Prices recorded, replacing those already recorded on the same day
+    pub imported: u32,
+    pub rejected: Vec<ImportRejectResponse>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PriceSyncQuery {
+    /// All instruments with a symbol or an ISIN by default
+    pub instrument_id: Option<Uuid>,
+    /// One year before `to` by default
+    pub from: Option<NaiveDate>,
+    /// Today by default
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PriceSyncLineResponse {
+    pub instrument_id: Uuid,
+    pub instrument_name: String,
+    /// False when the provider has no series for the instrument
+    pub found: bool,
+    pub imported: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PriceSyncResponse {
+    pub source: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub instruments: Vec<PriceSyncLineResponse>,
+}
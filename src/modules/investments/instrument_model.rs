@@ -0,0 +1,148 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::investments::instrument_command::{InstrumentCreateCommand, InstrumentPriceCreateCommand};
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::ub;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum InstrumentType {
+    Stock,
+    Etf,
+    Fund,
+    Bond,
+    Crypto,
+    Other,
+}
+
+impl InstrumentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstrumentType::Stock => "stock",
+            InstrumentType::Etf => "etf",
+            InstrumentType::Fund => "fund",
+            InstrumentType::Bond => "bond",
+            InstrumentType::Crypto => "crypto",
+            InstrumentType::Other => "other",
+        }
+    }
+}
+
+
+/// Security of the user, quoted in `currency_code`
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Instrument {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub symbol: Option<String>,
+    pub isin: Option<String>,
+    pub instrument_type: InstrumentType,
+    pub currency_code: String,
+    /// Free label grouping instruments for the target allocation (region, sector…)
+    pub tag: Option<String>,
+
+    /// Minor unit of the instrument currency, read only
+    pub minor_unit: u8,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Instrument {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            symbol: row.try_get(index_map["symbol"])?,
+            isin: row.try_get(index_map["isin"])?,
+            instrument_type: row.try_get(index_map["instrument_type"])?,
+            currency_code: row.try_get(index_map["currency_code"])?,
+            tag: row.try_get(index_map["tag"])?,
+            minor_unit: row.try_get(index_map["minor_unit"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<InstrumentCreateCommand> for Instrument {
+    fn from(command: InstrumentCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            name: command.instrument_name,
+            symbol: command.instrument_symbol,
+            isin: command.instrument_isin,
+            instrument_type: command.instrument_type.unwrap_or(InstrumentType::Stock),
+            currency_code: command.instrument_currency_code,
+            tag: command.instrument_tag,
+            minor_unit: 0,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Closing price of an instrument on a day, in the instrument currency
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct InstrumentPrice {
+    pub id: Option<Vec<u8>>,
+
+    pub instrument_id: Vec<u8>,
+    pub price_date: NaiveDate,
+    pub price: Decimal,
+    pub source: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for InstrumentPrice {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            instrument_id: row.try_get(index_map["instrument_id"])?,
+            price_date: row.try_get(index_map["price_date"])?,
+            price: row.try_get(index_map["price"])?,
+            source: row.try_get(index_map["source"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+        })
+    }
+}
+
+impl From<InstrumentPriceCreateCommand> for InstrumentPrice {
+    fn from(command: InstrumentPriceCreateCommand) -> Self {
+        Self {
+            id: None,
+            instrument_id: ub(command.instrument_id),
+            price_date: command.price_date,
+            price: command.price,
+            source: "manual".to_string(),
+            created_at: None,
+        }
+    }
+}
+
+impl InstrumentPrice {
+    /// Price of the instrument on `price_date` coming from `source` (csv import, price provider)
+    pub fn of_source(instrument_id: Vec<u8>, price_date: NaiveDate, price: Decimal, source: &str) -> Self {
+        Self {
+            id: None,
+            instrument_id,
+            price_date,
+            price,
+            source: source.to_string(),
+            created_at: None,
+        }
+    }
+}
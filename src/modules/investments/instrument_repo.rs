@@ -0,0 +1,175 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::investments::instrument_model::{Instrument, InstrumentPrice};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait InstrumentRepositoryInterface {
+
+    async fn get(&self, instrument_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Instrument>, Error>;
+
+    async fn create(&self, instrument: Instrument, meta_user: Option<Uuid>) -> Result<Instrument, Error>;
+
+    async fn update(&self, instrument: Instrument, meta_user: Option<Uuid>) -> Result<Option<Instrument>, Error>;
+
+    /// Fails while trades still use the instrument
+    async fn delete(&self, instrument_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Instrument>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct InstrumentRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for InstrumentRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Instrument> for InstrumentRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl InstrumentRepositoryInterface for InstrumentRepository {
+    async fn get(&self, instrument_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Instrument>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(instrument_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_instrument_get_by_id", params).await
+    }
+
+    async fn create(&self, instrument: Instrument, meta_user: Option<Uuid>) -> Result<Instrument, Error> {
+        let params = vec![
+            MySqlParam::from(instrument.user_id),
+            MySqlParam::from(instrument.name),
+            MySqlParam::from(instrument.symbol),
+            MySqlParam::from(instrument.isin),
+            MySqlParam::from(instrument.instrument_type.as_str()),
+            MySqlParam::from(instrument.currency_code),
+            MySqlParam::from(instrument.tag),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_instrument_create", params).await
+    }
+
+    async fn update(&self, instrument: Instrument, meta_user: Option<Uuid>) -> Result<Option<Instrument>, Error> {
+        let params = vec![
+            MySqlParam::from(instrument.id),
+            MySqlParam::from(instrument.name),
+            MySqlParam::from(instrument.symbol),
+            MySqlParam::from(instrument.isin),
+            MySqlParam::from(instrument.instrument_type.as_str()),
+            MySqlParam::from(instrument.currency_code),
+            MySqlParam::from(instrument.tag),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_instrument_update", params).await
+    }
+
+    async fn delete(&self, instrument_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(instrument_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_instrument_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Instrument>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_instrument_by_user", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait InstrumentPriceRepositoryInterface {
+
+    /// Inserts or replaces the price of the instrument on that day
+    async fn upsert(&self, price: InstrumentPrice, meta_user: Option<Uuid>) -> Result<InstrumentPrice, Error>;
+
+    /// Ordered by date
+    async fn get_by_instrument(&self, instrument_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<InstrumentPrice>, Error>;
+
+    /// Latest price on or before `as_of` of every instrument traded in the portfolio
+    async fn get_latest_by_portfolio(&self, portfolio_id: Uuid, as_of: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<InstrumentPrice>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct InstrumentPriceRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for InstrumentPriceRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<InstrumentPrice> for InstrumentPriceRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl InstrumentPriceRepositoryInterface for InstrumentPriceRepository {
+    async fn upsert(&self, price: InstrumentPrice, meta_user: Option<Uuid>) -> Result<InstrumentPrice, Error> {
+        let params = vec![
+            MySqlParam::from(price.instrument_id),
+            MySqlParam::from(price.price_date),
+            MySqlParam::from(price.price),
+            MySqlParam::from(price.source),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_instrument_price_upsert", params).await
+    }
+
+    async fn get_by_instrument(&self, instrument_id: Uuid, from: Option<NaiveDate>, to: Option<NaiveDate>, meta_user: Option<Uuid>) -> Result<Vec<InstrumentPrice>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(instrument_id)),
+            MySqlParam::from(from),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_instrument_price_by_instrument", params).await
+    }
+
+    async fn get_latest_by_portfolio(&self, portfolio_id: Uuid, as_of: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<InstrumentPrice>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(portfolio_id)),
+            MySqlParam::from(as_of),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_instrument_price_latest_by_portfolio", params).await
+    }
+}
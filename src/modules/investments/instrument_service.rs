@@ -0,0 +1,308 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{Months, Utc};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    instrument_command::*,
+    instrument_dto::*,
+    instrument_model::{Instrument, InstrumentPrice},
+    instrument_repo::{InstrumentPriceRepository, InstrumentPriceRepositoryInterface, InstrumentRepository, InstrumentRepositoryInterface},
+    price_provider::{self, PriceProvider},
+};
+use crate::shared::csv;
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::bu;
+
+
+#[async_trait]
+pub trait InstrumentServiceInterface {
+
+    async fn get(&self, command: InstrumentGetCommand) -> Result<Option<InstrumentResponse>, Error>;
+
+    async fn create(&self, command: InstrumentCreateCommand) -> Result<InstrumentResponse, Error>;
+
+    async fn update(&self, command: InstrumentUpdateCommand) -> Result<Option<InstrumentResponse>, Error>;
+
+    async fn delete(&self, command: InstrumentDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: InstrumentListByUserCommand) -> Result<Vec<InstrumentResponse>, Error>;
+
+    /// None when the instrument does not exist
+    async fn add_price(&self, command: InstrumentPriceCreateCommand) -> Result<Option<InstrumentPriceResponse>, Error>;
+
+    /// None when the instrument does not exist
+    async fn get_prices(&self, command: InstrumentPriceListCommand) -> Result<Option<Vec<InstrumentPriceResponse>>, Error>;
+
+    /// Records the valid lines of a CSV with date and price columns, plus symbol or ISIN without instrument.
+    /// None when the instrument does not exist.
+    async fn import_prices(&self, command: InstrumentPriceImportCommand) -> Result<Option<PriceImportResponse>, Error>;
+
+    /// Records the prices of the configured provider. None when the instrument does not exist.
+    async fn sync_prices(&self, command: InstrumentPriceSyncCommand) -> Result<Option<PriceSyncResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct InstrumentService {
+    instrument_repo: InstrumentRepository,
+    price_repo: InstrumentPriceRepository,
+    price_provider: Option<Arc<dyn PriceProvider>>,
+}
+
+impl From<&AppState> for InstrumentService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            instrument_repo: InstrumentRepository::from(app_state),
+            price_repo: InstrumentPriceRepository::from(app_state),
+            price_provider: price_provider::configured(&app_state.config),
+        }
+    }
+}
+
+impl InstrumentService {
+    fn validate(name: &str, currency_code: &str, symbol: Option<&str>, isin: Option<&str>) -> Result<(), Error> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest("instrument name is required".into()).into());
+        }
+        if currency_code.len() != 3 {
+            return Err(AppError::BadRequest("instrument currency must be a 3 letter code".into()).into());
+        }
+        if symbol.is_some_and(|symbol| symbol.trim().is_empty()) {
+            return Err(AppError::BadRequest("instrument symbol cannot be empty".into()).into());
+        }
+        if isin.is_some_and(|isin| isin.len() != 12 || !isin.chars().all(|c| c.is_ascii_alphanumeric())) {
+            return Err(AppError::BadRequest("instrument ISIN must be 12 alphanumeric characters".into()).into());
+        }
+        Ok(())
+    }
+
+    /// Instrument with the symbol or ISIN of a CSV line
+    fn line_instrument<'a>(instruments: &'a [Instrument], record: &[String], code_columns: (Option<usize>, Option<usize>)) -> Option<&'a Instrument> {
+        let code = |column: Option<usize>| column
+            .and_then(|column| record.get(column))
+            .map(|code| code.trim())
+            .filter(|code| !code.is_empty());
+        let (symbol, isin) = (code(code_columns.0), code(code_columns.1));
+        instruments.iter().find(|instrument| {
+            symbol.is_some_and(|symbol| instrument.symbol.as_deref().is_some_and(|code| code.eq_ignore_ascii_case(symbol)))
+                || isin.is_some_and(|isin| instrument.isin.as_deref().is_some_and(|code| code.eq_ignore_ascii_case(isin)))
+        })
+    }
+
+    async fn instrument(&self, instrument_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Instrument>, Error> {
+        match self.instrument_repo.get(instrument_id, meta_user).await {
+            Ok(instrument) => Ok(instrument),
+            Err(_) => Err(Error::msg("Error getting instrument")),
+        }
+    }
+}
+
+#[async_trait]
+impl InstrumentServiceInterface for InstrumentService {
+    async fn get(&self, command: InstrumentGetCommand) -> Result<Option<InstrumentResponse>, Error> {
+        let instrument = self.instrument(command.instrument_id, Some(command.auth_user.user_id)).await?;
+        Ok(instrument.as_ref().map(InstrumentResponse::from))
+    }
+
+    async fn create(&self, command: InstrumentCreateCommand) -> Result<InstrumentResponse, Error> {
+        Self::validate(&command.instrument_name, &command.instrument_currency_code,
+            command.instrument_symbol.as_deref(), command.instrument_isin.as_deref())?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        let instrument = self.instrument_repo.create(Instrument::from(command), meta_user).await;
+        match instrument {
+            Ok(instrument) => Ok(InstrumentResponse::from(&instrument)),
+            Err(_) => Err(Error::msg("Error creating instrument")),
+        }
+    }
+
+    async fn update(&self, command: InstrumentUpdateCommand) -> Result<Option<InstrumentResponse>, Error> {
+        Self::validate(&command.instrument_name, &command.instrument_currency_code,
+            command.instrument_symbol.as_deref(), command.instrument_isin.as_deref())?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        let instrument = match self.instrument(command.instrument_id, meta_user).await? {
+            Some(instrument) => instrument,
+            None => return Ok(None),
+        };
+
+        let instrument_update = Instrument {
+            name: command.instrument_name,
+            symbol: command.instrument_symbol,
+            isin: command.instrument_isin,
+            instrument_type: command.instrument_type,
+            currency_code: command.instrument_currency_code,
+            tag: command.instrument_tag,
+            ..instrument
+        };
+
+        let instrument = self.instrument_repo.update(instrument_update, meta_user).await;
+        match instrument {
+            Ok(instrument) => Ok(instrument.as_ref().map(InstrumentResponse::from)),
+            Err(_) => Err(Error::msg("Error updating instrument")),
+        }
+    }
+
+    async fn delete(&self, command: InstrumentDeleteCommand) -> Result<(), Error> {
+        let result = self.instrument_repo.delete(command.instrument_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting instrument")),
+        }
+    }
+
+    async fn get_by_user(&self, command: InstrumentListByUserCommand) -> Result<Vec<InstrumentResponse>, Error> {
+        let instruments = self.instrument_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await;
+        match instruments {
+            Ok(instruments) => Ok(instruments.iter().map(InstrumentResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting instruments")),
+        }
+    }
+
+    async fn add_price(&self, command: InstrumentPriceCreateCommand) -> Result<Option<InstrumentPriceResponse>, Error> {
+        if command.price < Decimal::ZERO {
+            return Err(AppError::BadRequest("price cannot be negative".into()).into());
+        }
+
+        let meta_user = Some(command.auth_user.user_id);
+        if self.instrument(command.instrument_id, meta_user).await?.is_none() {
+            return Ok(None);
+        }
+
+        let price = self.price_repo.upsert(InstrumentPrice::from(command), meta_user).await;
+        match price {
+            Ok(price) => Ok(Some(InstrumentPriceResponse::from(&price))),
+            Err(_) => Err(Error::msg("Error recording instrument price")),
+        }
+    }
+
+    async fn get_prices(&self, command: InstrumentPriceListCommand) -> Result<Option<Vec<InstrumentPriceResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        if self.instrument(command.instrument_id, meta_user).await?.is_none() {
+            return Ok(None);
+        }
+
+        let prices = self.price_repo.get_by_instrument(command.instrument_id, command.from, command.to, meta_user).await;
+        match prices {
+            Ok(prices) => Ok(Some(prices.iter().map(InstrumentPriceResponse::from).collect())),
+            Err(_) => Err(Error::msg("Error getting instrument prices")),
+        }
+    }
+
+    async fn import_prices(&self, command: InstrumentPriceImportCommand) -> Result<Option<PriceImportResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let records = csv::parse(&command.content);
+        let Some((_, header)) = records.first() else {
+            return Err(AppError::BadRequest("csv is empty".into()).into());
+        };
+        let (Some(date_column), Some(price_column)) = (csv::column(header, &["date"]), csv::column(header, &["price", "close"])) else {
+            return Err(AppError::BadRequest("csv needs date and price columns".into()).into());
+        };
+        let code_columns = (csv::column(header, &["symbol", "ticker"]), csv::column(header, &["isin"]));
+
+        let instruments = match command.instrument_id {
+            Some(instrument_id) => match self.instrument(instrument_id, meta_user).await? {
+                Some(instrument) => vec![instrument],
+                None => return Ok(None),
+            },
+            None if code_columns == (None, None) => {
+                return Err(AppError::BadRequest("csv needs a symbol or isin column".into()).into());
+            },
+            None => match self.instrument_repo.get_by_user(command.user_id, meta_user).await {
+                Ok(instruments) => instruments,
+                Err(_) => return Err(Error::msg("Error getting instruments")),
+            },
+        };
+
+        let mut imported = 0;
+        let mut rejected = Vec::new();
+        for (line, record) in records.iter().skip(1) {
+            let reject = |reason: &str| ImportRejectResponse { line: *line, reason: reason.to_string() };
+            let instrument = match command.instrument_id {
+                Some(_) => instruments.first(),
+                None => Self::line_instrument(&instruments, record, code_columns),
+            };
+            let Some(instrument) = instrument else {
+                rejected.push(reject("unknown instrument"));
+                continue;
+            };
+            let Some(price_date) = record.get(date_column).and_then(|date| csv::date(date)) else {
+                rejected.push(reject("invalid date"));
+                continue;
+            };
+            let price = match record.get(price_column).and_then(|price| csv::decimal(price)) {
+                Some(price) if price >= Decimal::ZERO => price,
+                _ => {
+                    rejected.push(reject("invalid price"));
+                    continue;
+                },
+            };
+
+            let price = InstrumentPrice::of_source(instrument.id.clone().unwrap(), price_date, price, "csv");
+            if self.price_repo.upsert(price, meta_user).await.is_err() {
+                return Err(Error::msg("Error recording instrument price"));
+            }
+            imported += 1;
+        }
+
+        Ok(Some(PriceImportResponse { imported, rejected }))
+    }
+
+    async fn sync_prices(&self, command: InstrumentPriceSyncCommand) -> Result<Option<PriceSyncResponse>, Error> {
+        let Some(provider) = self.price_provider.as_ref() else {
+            return Err(AppError::BadRequest("no price provider configured".into()).into());
+        };
+        let to = command.to.unwrap_or(Utc::now().date_naive());
+        let from = command.from.unwrap_or(to - Months::new(12));
+        if from > to {
+            return Err(AppError::BadRequest("period start must be before its end".into()).into());
+        }
+
+        let meta_user = Some(command.auth_user.user_id);
+        let instruments = match command.instrument_id {
+            Some(instrument_id) => match self.instrument(instrument_id, meta_user).await? {
+                Some(instrument) => vec![instrument],
+                None => return Ok(None),
+            },
+            None => match self.instrument_repo.get_by_user(command.user_id, meta_user).await {
+                Ok(instruments) => instruments.into_iter()
+                    .filter(|instrument| instrument.symbol.is_some() || instrument.isin.is_some())
+                    .collect(),
+                Err(_) => return Err(Error::msg("Error getting instruments")),
+            },
+        };
+
+        let mut lines = Vec::new();
+        for instrument in &instruments {
+            let prices = match provider.daily_prices(instrument, from, to).await {
+                Ok(prices) => prices,
+                Err(_) => return Err(Error::msg("Error reading provider prices")),
+            };
+            let mut imported = 0;
+            for (price_date, price) in prices.iter().flatten() {
+                let price = InstrumentPrice::of_source(instrument.id.clone().unwrap(), *price_date, *price, provider.source());
+                if self.price_repo.upsert(price, meta_user).await.is_err() {
+                    return Err(Error::msg("Error recording instrument price"));
+                }
+                imported += 1;
+            }
+            lines.push(PriceSyncLineResponse {
+                instrument_id: bu(instrument.id.as_deref().unwrap()),
+                instrument_name: instrument.name.clone(),
+                found: prices.is_some(),
+                imported,
+            });
+        }
+
+        Ok(Some(PriceSyncResponse {
+            source: provider.source().to_string(),
+            from,
+            to,
+            instruments: lines,
+        }))
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::lot_dto::{LotListQuery, TaxReportQuery};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LotListCommand {
+    pub portfolio_id: Uuid,
+    pub as_of: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl LotListCommand {
+    pub fn new(portfolio_id: Uuid, query: LotListQuery, auth_user: AuthUser) -> Self {
+        Self {
+            portfolio_id,
+            as_of: query.as_of,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxReportCommand {
+    pub user_id: Uuid,
+    pub year: i32,
+    /// All portfolios of the user when None
+    pub portfolio_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaxReportCommand {
+    pub fn new(query: TaxReportQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            year: query.year,
+            portfolio_id: query.portfolio_id,
+            auth_user,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use axum::{extract::{Path, Query, State}, http::{header, StatusCode}, routing::get, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    lot_command::*,
+    lot_dto::*,
+    lot_service::{LotService, LotServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/portfolios/{portfolio_id}/lots", get(get_portfolio_lots))
+        .route("/tax-report", get(get_tax_report))
+        .route("/tax-report/csv", get(get_tax_report_csv))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}/lots",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid"),
+        LotListQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Open tax lots of the portfolio with its cost method", body = PortfolioLotsResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Missing fx rate or sell exceeding the quantity held"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Tax"
+)]
+pub async fn get_portfolio_lots(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Query(query): Query<LotListQuery>,
+) -> Result<Json<PortfolioLotsResponse>, StatusCode> {
+    let command = LotListCommand::new(portfolio_id, query, auth_user);
+    let lot_service = LotService::from(&state);
+
+    let lots = lot_service.get_by_portfolio(command).await;
+    match lots {
+        Ok(lots) => {
+            match lots {
+                Some(lots) => Ok(Json(lots)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/tax-report",
+    params(
+        TaxReportQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Realized gains and losses and dividend income of the year, in base currency at transaction date fx rates", body = TaxReportResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid year, missing fx rate or sell exceeding the quantity held"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Tax"
+)]
+pub async fn get_tax_report(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<TaxReportQuery>,
+) -> Result<Json<TaxReportResponse>, StatusCode> {
+    let command = TaxReportCommand::new(query, auth_user);
+    let lot_service = LotService::from(&state);
+
+    let report = lot_service.tax_report(command).await;
+    match report {
+        Ok(report) => {
+            match report {
+                Some(report) => Ok(Json(report)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/tax-report/csv",
+    params(
+        TaxReportQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Tax report as CSV, one line per sell then per dividend", body = String, content_type = "text/csv"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid year, missing fx rate or sell exceeding the quantity held"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Tax"
+)]
+pub async fn get_tax_report_csv(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<TaxReportQuery>,
+) -> Result<([(header::HeaderName, String); 2], String), StatusCode> {
+    let filename = format!("attachment; filename=\"tax-report-{}.csv\"", query.year);
+    let command = TaxReportCommand::new(query, auth_user);
+    let lot_service = LotService::from(&state);
+
+    let report = lot_service.tax_report_csv(command).await;
+    match report {
+        Ok(report) => {
+            match report {
+                Some(report) => Ok(([
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                    (header::CONTENT_DISPOSITION, filename),
+                ], report)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
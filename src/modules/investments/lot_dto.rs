@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::investments::portfolio_model::CostMethod;
+
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct LotListQuery {
+    /// Today by default: trades up to that day
+    pub as_of: Option<NaiveDate>,
+}
+
+/// Units of one buy still held, or the pooled units under the average cost method
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LotResponse {
+    pub instrument_id: Uuid,
+    pub instrument_name: String,
+    /// None for a pool merging several buys
+    pub buy_trade_id: Option<Uuid>,
+    pub acquired_on: NaiveDate,
+    pub holding_days: i64,
+
+    pub quantity: Decimal,
+    pub currency_code: String,
+    /// Cost of the units left, buy fee included, in the instrument currency
+    pub cost_minor: i64,
+    pub unit_cost: Decimal,
+    /// Cost at the buy date fx rate
+    pub cost_base_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PortfolioLotsResponse {
+    pub portfolio_id: Uuid,
+    pub cost_method: CostMethod,
+    pub as_of: NaiveDate,
+    pub base_currency_code: String,
+    pub lots: Vec<LotResponse>,
+}
+
+// --- Tax report ---
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TaxReportQuery {
+    /// Calendar year of the sells and dividends
+    pub year: i32,
+    /// All portfolios of the user by default
+    pub portfolio_id: Option<Uuid>,
+}
+
+/// Part of a buy lot matched by a sell
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LotMatchResponse {
+    /// None for a pool merging several buys
+    pub buy_trade_id: Option<Uuid>,
+    pub acquired_on: NaiveDate,
+    pub holding_days: i64,
+    pub quantity: Decimal,
+    pub cost_base_minor: i64,
+}
+
+/// Gain or loss of a sell with the portfolio cost method.
+/// Base amounts use the fx rate of the sell date for proceeds and of each buy date for costs.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RealizedGainResponse {
+    pub portfolio_id: Uuid,
+    pub portfolio_name: String,
+    pub instrument_id: Uuid,
+    pub instrument_name: String,
+    pub sell_trade_id: Uuid,
+    pub sold_on: NaiveDate,
+    pub quantity: Decimal,
+
+    pub currency_code: String,
+    /// Net of the sell fee
+    pub proceeds_minor: i64,
+    pub cost_minor: i64,
+    pub gain_minor: i64,
+
+    pub proceeds_base_minor: i64,
+    pub cost_base_minor: i64,
+    pub gain_base_minor: i64,
+
+    pub lots: Vec<LotMatchResponse>,
+}
+
+/// Dividend converted at the fx rate of its payment date
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DividendIncomeResponse {
+    pub portfolio_id: Uuid,
+    pub portfolio_name: String,
+    pub instrument_id: Uuid,
+    pub instrument_name: String,
+    pub dividend_trade_id: Uuid,
+    pub paid_on: NaiveDate,
+
+    pub currency_code: String,
+    pub amount_minor: i64,
+    pub amount_base_minor: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct TaxReportTotalsResponse {
+    pub proceeds_base_minor: i64,
+    pub cost_base_minor: i64,
+    /// Sum of the sells with a gain
+    pub gains_base_minor: i64,
+    /// Sum of the sells with a loss, negative
+    pub losses_base_minor: i64,
+    pub net_gain_base_minor: i64,
+    pub dividends_base_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaxReportResponse {
+    pub year: i32,
+    pub portfolio_id: Option<Uuid>,
+    pub base_currency_code: String,
+    pub sales: Vec<RealizedGainResponse>,
+    pub dividends: Vec<DividendIncomeResponse>,
+    pub totals: TaxReportTotalsResponse,
+}
@@ -0,0 +1,337 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+use crate::modules::investments::{
+    portfolio_model::CostMethod,
+    trade_model::{Trade, TradeType},
+};
+
+
+/// Units of one buy still held. Costs include the buy fee; `cost_minor` is in minor units of the
+/// instrument currency, `cost_base` in major units of the base currency at the buy date rate.
+/// Under the average method a single pooled lot carries the first buy date and no trade once several buys are merged.
+#[derive(Debug, Clone)]
+pub struct Lot {
+    pub instrument_id: Vec<u8>,
+    pub trade_id: Option<Vec<u8>>,
+    pub acquired_on: NaiveDate,
+    pub quantity: Decimal,
+    pub cost_minor: Decimal,
+    pub cost_base: Decimal,
+}
+
+impl Lot {
+    /// Removes `quantity` units with their share of the costs
+    fn take(&mut self, quantity: Decimal) -> LotMatch {
+        let (cost_minor, cost_base) = if quantity >= self.quantity {
+            (self.cost_minor, self.cost_base)
+        } else {
+            (self.cost_minor * quantity / self.quantity, self.cost_base * quantity / self.quantity)
+        };
+        let taken = quantity.min(self.quantity);
+        self.quantity -= taken;
+        self.cost_minor -= cost_minor;
+        self.cost_base -= cost_base;
+        LotMatch {
+            trade_id: self.trade_id.clone(),
+            acquired_on: self.acquired_on,
+            quantity: taken,
+            cost_minor,
+            cost_base,
+        }
+    }
+}
+
+/// Part of a lot consumed by a sell
+#[derive(Debug, Clone)]
+pub struct LotMatch {
+    pub trade_id: Option<Vec<u8>>,
+    pub acquired_on: NaiveDate,
+    pub quantity: Decimal,
+    pub cost_minor: Decimal,
+    pub cost_base: Decimal,
+}
+
+/// Realized gain of a sell; proceeds are net of the sell fee and converted at the sell date rate
+#[derive(Debug, Clone)]
+pub struct Sale {
+    pub trade: Trade,
+    pub proceeds_minor: Decimal,
+    pub proceeds_base: Decimal,
+    pub lots: Vec<LotMatch>,
+}
+
+impl Sale {
+    pub fn cost_minor(&self) -> Decimal {
+        self.lots.iter().map(|lot| lot.cost_minor).sum()
+    }
+
+    pub fn cost_base(&self) -> Decimal {
+        self.lots.iter().map(|lot| lot.cost_base).sum()
+    }
+}
+
+
+/// Why a replay of the trades could not build the lots
+#[derive(Debug, Clone)]
+pub enum LotBookError {
+    /// Error of `base_rate`, the currency code missing a rate
+    MissingRate(String),
+    /// Date of the first sell exceeding the quantity held at that point
+    Oversold(NaiveDate),
+}
+
+
+/// Open lots and realized sales of a portfolio, built by replaying its trades with the portfolio cost method
+#[derive(Debug, Clone)]
+pub struct LotBook {
+    pub method: CostMethod,
+    /// By instrument id, in buy order
+    pub lots: HashMap<Vec<u8>, Vec<Lot>>,
+    pub sales: Vec<Sale>,
+}
+
+impl LotBook {
+    pub fn new(method: CostMethod) -> Self {
+        Self { method, lots: HashMap::new(), sales: Vec::new() }
+    }
+
+    /// Replays the trades by date, then creation, like `Holdings::replay`.
+    /// `scales` gives 10^minor unit per instrument id, 1 when missing;
+    /// `base_rate` gives the base currency value of one major unit of the instrument currency on a date.
+    /// Err with the error of `base_rate`, or with the first sell exceeding the open lots.
+    pub fn replay<F>(method: CostMethod, trades: &[&Trade], scales: &HashMap<Vec<u8>, Decimal>, base_rate: F) -> Result<Self, LotBookError>
+    where
+        F: Fn(&[u8], NaiveDate) -> Result<Decimal, String>,
+    {
+        let mut ordered: Vec<&Trade> = trades.to_vec();
+        ordered.sort_by_key(|trade| (trade.trade_date, trade.created_at.unwrap_or(DateTime::<Utc>::MAX_UTC)));
+
+        let mut book = Self::new(method);
+        for trade in ordered {
+            let Some(instrument_id) = trade.instrument_id.as_ref() else {
+                continue;
+            };
+            if !matches!(trade.trade_type, TradeType::Buy | TradeType::Sell | TradeType::Split) {
+                continue;
+            }
+            let scale = scales.get(instrument_id).copied().unwrap_or(Decimal::ONE);
+            let rate = match trade.trade_type {
+                TradeType::Split => Decimal::ONE,
+                _ => base_rate(instrument_id, trade.trade_date).map_err(LotBookError::MissingRate)?,
+            };
+            if !book.apply(trade, scale, rate) {
+                return Err(LotBookError::Oversold(trade.trade_date));
+            }
+        }
+        Ok(book)
+    }
+
+    /// Applies a buy, sell or split of an instrument; `rate` converts the instrument currency to the base currency.
+    /// False, and nothing applied, when a sell exceeds the open lots.
+    fn apply(&mut self, trade: &Trade, scale: Decimal, rate: Decimal) -> bool {
+        let instrument_id = trade.instrument_id.clone().unwrap_or_default();
+        let quantity = trade.quantity.unwrap_or_default();
+        let gross = quantity * trade.price.unwrap_or_default() * scale;
+        let lots = self.lots.entry(instrument_id.clone()).or_default();
+        match trade.trade_type {
+            TradeType::Buy => {
+                let cost_minor = gross + Decimal::from(trade.fee_minor);
+                let lot = Lot {
+                    instrument_id,
+                    trade_id: trade.id.clone(),
+                    acquired_on: trade.trade_date,
+                    quantity,
+                    cost_minor,
+                    cost_base: cost_minor / scale * rate,
+                };
+                match (self.method, lots.first_mut()) {
+                    (CostMethod::Average, Some(pool)) => {
+                        pool.quantity += lot.quantity;
+                        pool.cost_minor += lot.cost_minor;
+                        pool.cost_base += lot.cost_base;
+                        if pool.quantity == lot.quantity {
+                            pool.trade_id = lot.trade_id;
+                            pool.acquired_on = lot.acquired_on;
+                        } else {
+                            pool.trade_id = None;
+                        }
+                    },
+                    _ => lots.push(lot),
+                }
+            },
+            TradeType::Sell => {
+                if quantity > lots.iter().map(|lot| lot.quantity).sum::<Decimal>() {
+                    return false;
+                }
+                let proceeds_minor = gross - Decimal::from(trade.fee_minor);
+                let mut remaining = quantity;
+                let mut matched = Vec::new();
+                while remaining > Decimal::ZERO {
+                    let lot = match self.method {
+                        CostMethod::Lifo => lots.iter_mut().rev().find(|lot| lot.quantity > Decimal::ZERO),
+                        CostMethod::Fifo | CostMethod::Average => lots.iter_mut().find(|lot| lot.quantity > Decimal::ZERO),
+                    };
+                    let Some(lot) = lot else {
+                        break;
+                    };
+                    let lot_match = lot.take(remaining);
+                    remaining -= lot_match.quantity;
+                    matched.push(lot_match);
+                }
+                match self.method {
+                    // the pool is kept empty so the next buy starts it again with its own date
+                    CostMethod::Average => {},
+                    CostMethod::Fifo | CostMethod::Lifo => lots.retain(|lot| lot.quantity > Decimal::ZERO),
+                }
+                self.sales.push(Sale {
+                    trade: trade.clone(),
+                    proceeds_minor,
+                    proceeds_base: proceeds_minor / scale * rate,
+                    lots: matched,
+                });
+            },
+            TradeType::Split => {
+                let ratio = trade.split_ratio.unwrap_or(Decimal::ONE);
+                for lot in lots.iter_mut() {
+                    lot.quantity *= ratio;
+                }
+            },
+            TradeType::Dividend | TradeType::Fee => {},
+        }
+        true
+    }
+
+    /// Lots still holding units, by instrument then acquisition date
+    pub fn open_lots(&self) -> Vec<&Lot> {
+        let mut open: Vec<&Lot> = self.lots.values()
+            .flatten()
+            .filter(|lot| lot.quantity > Decimal::ZERO)
+            .collect();
+        open.sort_by(|a, b| (&a.instrument_id, a.acquired_on).cmp(&(&b.instrument_id, b.acquired_on)));
+        open
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    fn trade(id: u8, trade_type: TradeType, day: u32, quantity: i64, price: i64) -> Trade {
+        Trade {
+            id: Some(vec![id]),
+            portfolio_id: vec![9],
+            instrument_id: Some(vec![1]),
+            trade_type,
+            trade_date: d(day),
+            quantity: Some(Decimal::from(quantity)),
+            price: Some(Decimal::from(price)),
+            amount_minor: None,
+            fee_minor: 0,
+            split_ratio: None,
+            note: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// 1 base per unit of the instrument currency until the 2nd, 2 from then on
+    fn replay(method: CostMethod, trades: &[Trade]) -> Result<LotBook, LotBookError> {
+        let trades: Vec<&Trade> = trades.iter().collect();
+        LotBook::replay(method, &trades, &HashMap::new(), |_, date| Ok(if date < d(2) { Decimal::ONE } else { Decimal::TWO }))
+    }
+
+    fn buy_buy_sell() -> Vec<Trade> {
+        vec![
+            trade(1, TradeType::Buy, 1, 10, 100),
+            trade(2, TradeType::Buy, 2, 10, 200),
+            trade(3, TradeType::Sell, 3, 10, 300),
+        ]
+    }
+
+    #[test]
+    fn each_method_matches_different_lots() {
+        let costs = |method| {
+            let book = replay(method, &buy_buy_sell()).unwrap();
+            assert_eq!(book.sales.len(), 1);
+            assert_eq!(book.sales[0].proceeds_minor, Decimal::from(3000));
+            (book.sales[0].cost_minor(), book.sales[0].cost_base())
+        };
+
+        assert_eq!(costs(CostMethod::Fifo), (Decimal::from(1000), Decimal::from(1000)));
+        assert_eq!(costs(CostMethod::Lifo), (Decimal::from(2000), Decimal::from(4000)));
+        assert_eq!(costs(CostMethod::Average), (Decimal::from(1500), Decimal::from(2500)));
+    }
+
+    #[test]
+    fn fifo_and_lifo_keep_the_other_lot_open() {
+        let fifo = replay(CostMethod::Fifo, &buy_buy_sell()).unwrap();
+        let open = fifo.open_lots();
+        assert_eq!(open.len(), 1);
+        assert_eq!((open[0].trade_id.clone(), open[0].acquired_on), (Some(vec![2]), d(2)));
+
+        let lifo = replay(CostMethod::Lifo, &buy_buy_sell()).unwrap();
+        let open = lifo.open_lots();
+        assert_eq!(open.len(), 1);
+        assert_eq!((open[0].trade_id.clone(), open[0].acquired_on), (Some(vec![1]), d(1)));
+    }
+
+    #[test]
+    fn average_pool_merges_buys_and_restarts_once_empty() {
+        let mut trades = buy_buy_sell();
+        let book = replay(CostMethod::Average, &trades).unwrap();
+        let pool = &book.lots[&vec![1]];
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool[0].trade_id, None);
+        assert_eq!(pool[0].acquired_on, d(1));
+        assert_eq!(pool[0].quantity, Decimal::from(10));
+        assert_eq!(pool[0].cost_minor, Decimal::from(1500));
+
+        trades.push(trade(4, TradeType::Sell, 4, 10, 300));
+        trades.push(trade(5, TradeType::Buy, 5, 4, 250));
+        let book = replay(CostMethod::Average, &trades).unwrap();
+        let pool = &book.lots[&vec![1]];
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool[0].trade_id, Some(vec![5]));
+        assert_eq!(pool[0].acquired_on, d(5));
+        assert_eq!(pool[0].quantity, Decimal::from(4));
+        assert_eq!(pool[0].cost_minor, Decimal::from(1000));
+        assert_eq!(pool[0].cost_base, Decimal::from(2000));
+    }
+
+    #[test]
+    fn split_scales_quantities_and_keeps_costs() {
+        let mut split = trade(2, TradeType::Split, 2, 0, 0);
+        split.split_ratio = Some(Decimal::TWO);
+        let trades = vec![trade(1, TradeType::Buy, 1, 10, 100), split, trade(3, TradeType::Sell, 3, 5, 60)];
+        let book = replay(CostMethod::Fifo, &trades).unwrap();
+
+        let open = book.open_lots();
+        assert_eq!(open[0].quantity, Decimal::from(15));
+        assert_eq!(open[0].cost_minor, Decimal::from(750));
+        assert_eq!(book.sales[0].cost_minor(), Decimal::from(250));
+    }
+
+    #[test]
+    fn oversell_is_rejected_and_leaves_the_book_unchanged() {
+        let mut trades = buy_buy_sell();
+        trades.push(trade(4, TradeType::Sell, 4, 11, 300));
+        assert!(matches!(replay(CostMethod::Fifo, &trades), Err(LotBookError::Oversold(date)) if date == d(4)));
+
+        for method in [CostMethod::Fifo, CostMethod::Lifo, CostMethod::Average] {
+            let mut book = replay(method, &buy_buy_sell()).unwrap();
+            let lots = |book: &LotBook| book.open_lots().iter().map(|lot| (lot.quantity, lot.cost_minor, lot.cost_base)).collect::<Vec<_>>();
+            let before = lots(&book);
+
+            assert!(!book.apply(&trade(4, TradeType::Sell, 4, 11, 300), Decimal::ONE, Decimal::TWO));
+            assert_eq!(lots(&book), before);
+            assert_eq!(book.sales.len(), 1);
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::currencies::{
+    currency_model::FxTable,
+    currency_repo::{CurrencyRepository, CurrencyRepositoryInterface, FxRateRepository, FxRateRepositoryInterface},
+};
+use crate::modules::investments::{
+    instrument_model::Instrument,
+    instrument_repo::{InstrumentRepository, InstrumentRepositoryInterface},
+    lot_command::*,
+    lot_dto::*,
+    lot_model::{LotBook, LotBookError, LotMatch, Sale},
+    portfolio_model::Portfolio,
+    portfolio_repo::{PortfolioRepository, PortfolioRepositoryInterface},
+    portfolio_service::PortfolioService,
+    trade_model::{Trade, TradeType},
+    trade_repo::{TradeRepository, TradeRepositoryInterface},
+};
+use crate::modules::users::user::user_repo::{UserRepository, UserRepositoryInterface};
+use crate::shared::csv;
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu};
+
+
+#[async_trait]
+pub trait LotServiceInterface {
+
+    /// Open tax lots of the portfolio with its cost method. None when the portfolio does not exist.
+    async fn get_by_portfolio(&self, command: LotListCommand) -> Result<Option<PortfolioLotsResponse>, Error>;
+
+    /// Realized gains and dividends of the year, in base currency at transaction date rates.
+    /// None when the portfolio does not exist.
+    async fn tax_report(&self, command: TaxReportCommand) -> Result<Option<TaxReportResponse>, Error>;
+
+    /// Same report as CSV, one line per sell then per dividend
+    async fn tax_report_csv(&self, command: TaxReportCommand) -> Result<Option<String>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct LotService {
+    user_repo: UserRepository,
+    currency_repo: CurrencyRepository,
+    fx_rate_repo: FxRateRepository,
+    portfolio_repo: PortfolioRepository,
+    instrument_repo: InstrumentRepository,
+    trade_repo: TradeRepository,
+}
+
+impl From<&AppState> for LotService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            user_repo: UserRepository::from(app_state),
+            currency_repo: CurrencyRepository::from(app_state),
+            fx_rate_repo: FxRateRepository::from(app_state),
+            portfolio_repo: PortfolioRepository::from(app_state),
+            instrument_repo: InstrumentRepository::from(app_state),
+            trade_repo: TradeRepository::from(app_state),
+        }
+    }
+}
+
+/// Base currency, fx rates and instruments of the user, shared by the lot computations
+struct LotContext {
+    base_scale: Decimal,
+    fx: FxTable,
+    instruments: HashMap<Vec<u8>, Instrument>,
+    scales: HashMap<Vec<u8>, Decimal>,
+}
+
+impl LotContext {
+    fn minor(&self, amount_base: Decimal) -> i64 {
+        (amount_base * self.base_scale).round().to_i64().unwrap_or(0)
+    }
+
+    fn instrument_name(&self, instrument_id: &[u8]) -> String {
+        self.instruments.get(instrument_id).map(|instrument| instrument.name.clone()).unwrap_or_default()
+    }
+
+    fn currency_code(&self, instrument_id: &[u8]) -> String {
+        self.instruments.get(instrument_id).map(|instrument| instrument.currency_code.clone()).unwrap_or_default()
+    }
+
+    /// Base value of one major unit of the instrument currency on `date`
+    fn base_rate(&self, instrument_id: &[u8], date: NaiveDate) -> Result<Decimal, String> {
+        let currency_code = self.currency_code(instrument_id);
+        self.fx.to_base(Decimal::ONE, &currency_code, date).ok_or(currency_code)
+    }
+
+    fn missing_fx(&self, currency_code: String) -> Error {
+        AppError::BadRequest(format!("missing fx rate {}/{}", self.fx.base_code, currency_code)).into()
+    }
+
+    fn book(&self, portfolio: &Portfolio, trades: &[Trade]) -> Result<LotBook, Error> {
+        let trades: Vec<&Trade> = trades.iter().collect();
+        LotBook::replay(portfolio.cost_method, &trades, &self.scales, |instrument_id, date| self.base_rate(instrument_id, date))
+            .map_err(|error| match error {
+                LotBookError::MissingRate(currency_code) => self.missing_fx(currency_code),
+                LotBookError::Oversold(date) => AppError::BadRequest(format!("sell on {} exceeds the quantity held", date)).into(),
+            })
+    }
+
+    fn lot_match(&self, lot: &LotMatch, sold_on: NaiveDate) -> LotMatchResponse {
+        LotMatchResponse {
+            buy_trade_id: obu(lot.trade_id.as_deref()),
+            acquired_on: lot.acquired_on,
+            holding_days: (sold_on - lot.acquired_on).num_days(),
+            quantity: lot.quantity,
+            cost_base_minor: self.minor(lot.cost_base),
+        }
+    }
+
+    fn realized(&self, portfolio: &Portfolio, sale: &Sale) -> RealizedGainResponse {
+        let instrument_id = sale.trade.instrument_id.clone().unwrap_or_default();
+        let proceeds_minor = sale.proceeds_minor.round().to_i64().unwrap_or(0);
+        let cost_minor = sale.cost_minor().round().to_i64().unwrap_or(0);
+        let proceeds_base_minor = self.minor(sale.proceeds_base);
+        let cost_base_minor = self.minor(sale.cost_base());
+        RealizedGainResponse {
+            portfolio_id: bu(portfolio.id.as_deref().unwrap()),
+            portfolio_name: portfolio.name.clone(),
+            instrument_id: bu(&instrument_id),
+            instrument_name: self.instrument_name(&instrument_id),
+            sell_trade_id: bu(sale.trade.id.as_deref().unwrap()),
+            sold_on: sale.trade.trade_date,
+            quantity: sale.trade.quantity.unwrap_or_default(),
+            currency_code: self.currency_code(&instrument_id),
+            proceeds_minor,
+            cost_minor,
+            gain_minor: proceeds_minor - cost_minor,
+            proceeds_base_minor,
+            cost_base_minor,
+            gain_base_minor: proceeds_base_minor - cost_base_minor,
+            lots: sale.lots.iter().map(|lot| self.lot_match(lot, sale.trade.trade_date)).collect(),
+        }
+    }
+
+    fn dividend(&self, portfolio: &Portfolio, trade: &Trade) -> Result<DividendIncomeResponse, Error> {
+        let instrument_id = trade.instrument_id.clone().unwrap_or_default();
+        let amount_minor = trade.amount_minor.unwrap_or(0);
+        let scale = self.scales.get(&instrument_id).copied().unwrap_or(Decimal::ONE);
+        let rate = self.base_rate(&instrument_id, trade.trade_date).map_err(|currency_code| self.missing_fx(currency_code))?;
+        Ok(DividendIncomeResponse {
+            portfolio_id: bu(portfolio.id.as_deref().unwrap()),
+            portfolio_name: portfolio.name.clone(),
+            instrument_id: bu(&instrument_id),
+            instrument_name: self.instrument_name(&instrument_id),
+            dividend_trade_id: bu(trade.id.as_deref().unwrap()),
+            paid_on: trade.trade_date,
+            currency_code: self.currency_code(&instrument_id),
+            amount_minor,
+            amount_base_minor: self.minor(Decimal::from(amount_minor) / scale * rate),
+        })
+    }
+}
+
+impl LotService {
+    async fn context(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<LotContext, Error> {
+        let base_code = match self.user_repo.get(user_id, meta_user).await {
+            Ok(Some(user)) => user.base_currency_code,
+            Ok(None) => return Err(Error::msg("User not found")),
+            Err(_) => return Err(Error::msg("Error getting user")),
+        };
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let fx_rates = match self.fx_rate_repo.get_by_base_code(base_code.clone(), meta_user).await {
+            Ok(fx_rates) => fx_rates,
+            Err(_) => return Err(Error::msg("Error getting fx rates")),
+        };
+        let instruments = match self.instrument_repo.get_by_user(user_id, meta_user).await {
+            Ok(instruments) => instruments,
+            Err(_) => return Err(Error::msg("Error getting instruments")),
+        };
+
+        Ok(LotContext {
+            base_scale: scale_factor(minor_units.get(&base_code).copied().unwrap_or(2)),
+            fx: FxTable::new(&base_code, &fx_rates),
+            scales: PortfolioService::scales_of(&instruments),
+            instruments: instruments.into_iter()
+                .map(|instrument| (instrument.id.clone().unwrap(), instrument))
+                .collect(),
+        })
+    }
+
+    async fn trades(&self, portfolio: &Portfolio, until: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<Trade>, Error> {
+        match self.trade_repo.get_by_portfolio(bu(portfolio.id.as_deref().unwrap()), meta_user).await {
+            Ok(mut trades) => {
+                trades.retain(|trade| trade.trade_date <= until);
+                Ok(trades)
+            },
+            Err(_) => Err(Error::msg("Error getting portfolio trades")),
+        }
+    }
+
+    async fn portfolios(&self, command: &TaxReportCommand, meta_user: Option<Uuid>) -> Result<Option<Vec<Portfolio>>, Error> {
+        match command.portfolio_id {
+            Some(portfolio_id) => match self.portfolio_repo.get(portfolio_id, meta_user).await {
+                Ok(portfolio) => Ok(portfolio.map(|portfolio| vec![portfolio])),
+                Err(_) => Err(Error::msg("Error getting portfolio")),
+            },
+            None => match self.portfolio_repo.get_by_user(command.user_id, meta_user).await {
+                Ok(portfolios) => Ok(Some(portfolios)),
+                Err(_) => Err(Error::msg("Error getting portfolios")),
+            },
+        }
+    }
+
+    /// Amount in minor units written in major units, e.g. 12345 with 2 decimals as 123.45
+    fn major(amount_minor: i64, minor_unit: u32) -> String {
+        Decimal::new(amount_minor, minor_unit).to_string()
+    }
+
+    fn minor_unit(minor_units: &HashMap<String, u8>, currency_code: &str) -> u32 {
+        minor_units.get(currency_code).copied().unwrap_or(2) as u32
+    }
+}
+
+#[async_trait]
+impl LotServiceInterface for LotService {
+    async fn get_by_portfolio(&self, command: LotListCommand) -> Result<Option<PortfolioLotsResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio = match self.portfolio_repo.get(command.portfolio_id, meta_user).await {
+            Ok(Some(portfolio)) => portfolio,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting portfolio")),
+        };
+
+        let as_of = command.as_of.unwrap_or(Utc::now().date_naive());
+        let context = self.context(command.auth_user.user_id, meta_user).await?;
+        let trades = self.trades(&portfolio, as_of, meta_user).await?;
+        let book = context.book(&portfolio, &trades)?;
+
+        let lots = book.open_lots().into_iter()
+            .map(|lot| {
+                let scale = context.scales.get(&lot.instrument_id).copied().unwrap_or(Decimal::ONE);
+                LotResponse {
+                    instrument_id: bu(&lot.instrument_id),
+                    instrument_name: context.instrument_name(&lot.instrument_id),
+                    buy_trade_id: obu(lot.trade_id.as_deref()),
+                    acquired_on: lot.acquired_on,
+                    holding_days: (as_of - lot.acquired_on).num_days(),
+                    quantity: lot.quantity,
+                    currency_code: context.currency_code(&lot.instrument_id),
+                    cost_minor: lot.cost_minor.round().to_i64().unwrap_or(0),
+                    unit_cost: (lot.cost_minor / lot.quantity / scale).round_dp(8),
+                    cost_base_minor: context.minor(lot.cost_base),
+                }
+            })
+            .collect();
+
+        Ok(Some(PortfolioLotsResponse {
+            portfolio_id: command.portfolio_id,
+            cost_method: portfolio.cost_method,
+            as_of,
+            base_currency_code: context.fx.base_code.clone(),
+            lots,
+        }))
+    }
+
+    async fn tax_report(&self, command: TaxReportCommand) -> Result<Option<TaxReportResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let (Some(from), Some(to)) = (NaiveDate::from_ymd_opt(command.year, 1, 1), NaiveDate::from_ymd_opt(command.year, 12, 31)) else {
+            return Err(AppError::BadRequest("invalid year".into()).into());
+        };
+        let portfolios = match self.portfolios(&command, meta_user).await? {
+            Some(portfolios) => portfolios,
+            None => return Ok(None),
+        };
+        let context = self.context(command.user_id, meta_user).await?;
+
+        let mut sales = Vec::new();
+        let mut dividends = Vec::new();
+        for portfolio in &portfolios {
+            let trades = self.trades(portfolio, to, meta_user).await?;
+            let book = context.book(portfolio, &trades)?;
+            sales.extend(book.sales.iter()
+                .filter(|sale| sale.trade.trade_date >= from)
+                .map(|sale| context.realized(portfolio, sale)));
+            for trade in trades.iter().filter(|trade| trade.trade_type == TradeType::Dividend && trade.trade_date >= from) {
+                dividends.push(context.dividend(portfolio, trade)?);
+            }
+        }
+        sales.sort_by_key(|sale| sale.sold_on);
+        dividends.sort_by_key(|dividend| dividend.paid_on);
+
+        let mut totals = TaxReportTotalsResponse::default();
+        for sale in &sales {
+            totals.proceeds_base_minor += sale.proceeds_base_minor;
+            totals.cost_base_minor += sale.cost_base_minor;
+            if sale.gain_base_minor >= 0 {
+                totals.gains_base_minor += sale.gain_base_minor;
+            } else {
+                totals.losses_base_minor += sale.gain_base_minor;
+            }
+        }
+        totals.net_gain_base_minor = totals.gains_base_minor + totals.losses_base_minor;
+        totals.dividends_base_minor = dividends.iter().map(|dividend| dividend.amount_base_minor).sum();
+
+        Ok(Some(TaxReportResponse {
+            year: command.year,
+            portfolio_id: command.portfolio_id,
+            base_currency_code: context.fx.base_code.clone(),
+            sales,
+            dividends,
+            totals,
+        }))
+    }
+
+    async fn tax_report_csv(&self, command: TaxReportCommand) -> Result<Option<String>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let report = match self.tax_report(command).await? {
+            Some(report) => report,
+            None => return Ok(None),
+        };
+        let base_code = report.base_currency_code.clone();
+        let base_unit = Self::minor_unit(&minor_units, &base_code);
+
+        let mut content = csv::row(&[
+            "type", "date", "portfolio", "instrument", "quantity", "acquired_on", "currency",
+            "proceeds", "cost", "gain", "base_currency", "proceeds_base", "cost_base", "gain_base",
+        ]);
+        for sale in &report.sales {
+            let unit = Self::minor_unit(&minor_units, &sale.currency_code);
+            let acquired_on = sale.lots.iter().map(|lot| lot.acquired_on).min();
+            content.push_str(&csv::row(&[
+                "sale".to_string(),
+                sale.sold_on.to_string(),
+                sale.portfolio_name.clone(),
+                sale.instrument_name.clone(),
+                sale.quantity.normalize().to_string(),
+                acquired_on.map(|date| date.to_string()).unwrap_or_default(),
+                sale.currency_code.clone(),
+                Self::major(sale.proceeds_minor, unit),
+                Self::major(sale.cost_minor, unit),
+                Self::major(sale.gain_minor, unit),
+                base_code.clone(),
+                Self::major(sale.proceeds_base_minor, base_unit),
+                Self::major(sale.cost_base_minor, base_unit),
+                Self::major(sale.gain_base_minor, base_unit),
+            ]));
+        }
+        for dividend in &report.dividends {
+            let unit = Self::minor_unit(&minor_units, &dividend.currency_code);
+            content.push_str(&csv::row(&[
+                "dividend".to_string(),
+                dividend.paid_on.to_string(),
+                dividend.portfolio_name.clone(),
+                dividend.instrument_name.clone(),
+                String::new(),
+                String::new(),
+                dividend.currency_code.clone(),
+                Self::major(dividend.amount_minor, unit),
+                String::new(),
+                Self::major(dividend.amount_minor, unit),
+                base_code.clone(),
+                Self::major(dividend.amount_base_minor, base_unit),
+                String::new(),
+                Self::major(dividend.amount_base_minor, base_unit),
+            ]));
+        }
+        Ok(Some(content))
+    }
+}
@@ -0,0 +1,41 @@
+pub mod portfolio_model;
+pub mod portfolio_repo;
+mod portfolio_command;
+mod portfolio_service;
+pub mod portfolio_dto;
+pub mod portfolio_controller;
+pub mod instrument_model;
+pub mod instrument_repo;
+mod instrument_command;
+mod instrument_service;
+pub mod instrument_dto;
+pub mod instrument_controller;
+pub mod trade_model;
+pub mod trade_repo;
+mod trade_command;
+mod trade_service;
+pub mod trade_dto;
+pub mod trade_controller;
+pub mod performance_model;
+mod performance_command;
+mod performance_service;
+pub mod performance_dto;
+pub mod performance_controller;
+mod lot_model;
+mod lot_command;
+mod lot_service;
+pub mod lot_dto;
+pub mod lot_controller;
+mod allocation_model;
+mod allocation_repo;
+mod allocation_command;
+mod allocation_service;
+pub mod allocation_dto;
+pub mod allocation_controller;
+mod price_provider;
+pub mod asset_model;
+pub mod asset_repo;
+mod asset_command;
+pub mod asset_service;
+pub mod asset_dto;
+pub mod asset_controller;
\ No newline at end of file
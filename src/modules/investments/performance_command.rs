@@ -0,0 +1,32 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::performance_dto::PerformanceQuery;
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PerformanceCommand {
+    pub user_id: Uuid,
+    /// All portfolios of the user when None
+    pub portfolio_id: Option<Uuid>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub benchmark_instrument_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl PerformanceCommand {
+    pub fn new(portfolio_id: Option<Uuid>, query: PerformanceQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            portfolio_id,
+            from: query.from,
+            to: query.to,
+            benchmark_instrument_id: query.benchmark_instrument_id,
+            auth_user,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use axum::{extract::{Path, Query, State}, http::StatusCode, routing::get, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    performance_command::*,
+    performance_dto::*,
+    performance_service::{PerformanceService, PerformanceServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/performance", get(get_performance))
+        .route("/portfolios/{portfolio_id}/performance", get(get_portfolio_performance))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/performance",
+    params(
+        PerformanceQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Time-weighted and money-weighted returns of all portfolios, per portfolio and per position, in base currency", body = PerformanceResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid period, unknown benchmark or missing fx rate"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Performance"
+)]
+pub async fn get_performance(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<PerformanceQuery>,
+) -> Result<Json<PerformanceResponse>, StatusCode> {
+    let command = PerformanceCommand::new(None, query, auth_user);
+    let performance_service = PerformanceService::from(&state);
+
+    let performance = performance_service.performance(command).await;
+    match performance {
+        Ok(performance) => {
+            match performance {
+                Some(performance) => Ok(Json(performance)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}/performance",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid"),
+        PerformanceQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Time-weighted and money-weighted returns of the portfolio and its positions, in base currency", body = PerformanceResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid period, unknown benchmark or missing fx rate"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Performance"
+)]
+pub async fn get_portfolio_performance(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Query(query): Query<PerformanceQuery>,
+) -> Result<Json<PerformanceResponse>, StatusCode> {
+    let command = PerformanceCommand::new(Some(portfolio_id), query, auth_user);
+    let performance_service = PerformanceService::from(&state);
+
+    let performance = performance_service.performance(command).await;
+    match performance {
+        Ok(performance) => {
+            match performance {
+                Some(performance) => Ok(Json(performance)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PerformanceQuery {
+    /// Start of the period: the value at the end of that day is the starting investment.
+    /// Day before the first trade by default.
+    pub from: Option<NaiveDate>,
+    /// Today by default
+    pub to: Option<NaiveDate>,
+    /// Instrument whose recorded prices are the benchmark series
+    pub benchmark_instrument_id: Option<Uuid>,
+}
+
+/// Performance of a position, a portfolio or all portfolios, in the user's base currency.
+/// Buys and fees are contributions, sells and dividends withdrawals.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PerformanceLineResponse {
+    /// None for the total of all portfolios
+    pub portfolio_id: Option<Uuid>,
+    /// None for a portfolio or total line
+    pub instrument_id: Option<Uuid>,
+    pub name: String,
+
+    pub start_value_base_minor: i64,
+    pub end_value_base_minor: i64,
+    pub contributions_base_minor: i64,
+    pub withdrawals_base_minor: i64,
+    /// end - start - contributions + withdrawals
+    pub gain_base_minor: i64,
+
+    /// Time-weighted return over the period, independent of the timing of contributions
+    pub twr_percent: Option<Decimal>,
+    /// Only for periods of a year or more
+    pub annualized_twr_percent: Option<Decimal>,
+    /// Money-weighted annual return (XIRR) of the flows and values
+    pub xirr_percent: Option<Decimal>,
+    /// TWR minus the benchmark return over the same period
+    pub excess_twr_percent: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BenchmarkResponse {
+    pub instrument_id: Uuid,
+    pub name: String,
+    /// Latest price on or before each end of the period, in the instrument currency
+    pub start_price: Option<Decimal>,
+    pub end_price: Option<Decimal>,
+    pub return_percent: Option<Decimal>,
+    pub annualized_return_percent: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PerformanceResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub base_currency_code: String,
+
+    pub total: PerformanceLineResponse,
+    /// One line per portfolio, only for the performance of all portfolios
+    pub portfolios: Vec<PerformanceLineResponse>,
+    /// One line per instrument and portfolio with a value or flows over the period
+    pub positions: Vec<PerformanceLineResponse>,
+    pub benchmark: Option<BenchmarkResponse>,
+}
@@ -0,0 +1,309 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::modules::currencies::currency_model::FxTable;
+use crate::modules::investments::{
+    instrument_model::{Instrument, InstrumentPrice},
+    trade_model::{Trade, TradeType},
+};
+use crate::shared::money::scale_factor;
+
+
+/// Prices, splits and currencies needed to value trades in the base currency on any day.
+/// Prices are the recorded ones, completed by buy/sell prices on days without a recorded price.
+/// `minor_units` gives the minor unit of every currency, to read `_minor` amounts.
+pub struct MarketData {
+    prices: HashMap<Vec<u8>, BTreeMap<NaiveDate, Decimal>>,
+    splits: HashMap<Vec<u8>, Vec<(NaiveDate, Decimal)>>,
+    instruments: HashMap<Vec<u8>, Instrument>,
+    portfolio_currencies: HashMap<Vec<u8>, String>,
+    minor_units: HashMap<String, u8>,
+    fx: FxTable,
+}
+
+impl MarketData {
+    pub fn new(trades: &[Trade], instruments: Vec<Instrument>, prices: &[InstrumentPrice], portfolio_currencies: HashMap<Vec<u8>, String>, minor_units: HashMap<String, u8>, fx: FxTable) -> Self {
+        let mut price_series: HashMap<Vec<u8>, BTreeMap<NaiveDate, Decimal>> = HashMap::new();
+        let mut splits: HashMap<Vec<u8>, Vec<(NaiveDate, Decimal)>> = HashMap::new();
+        for trade in trades {
+            let Some(instrument_id) = trade.instrument_id.clone() else { continue };
+            match (trade.trade_type, trade.price, trade.split_ratio) {
+                (TradeType::Buy | TradeType::Sell, Some(price), _) => {
+                    price_series.entry(instrument_id).or_default().insert(trade.trade_date, price);
+                },
+                (TradeType::Split, _, Some(ratio)) => splits.entry(instrument_id).or_default().push((trade.trade_date, ratio)),
+                _ => {},
+            }
+        }
+        for price in prices {
+            price_series.entry(price.instrument_id.clone()).or_default().insert(price.price_date, price.price);
+        }
+
+        Self {
+            prices: price_series,
+            splits,
+            instruments: instruments.into_iter().map(|instrument| (instrument.id.clone().unwrap(), instrument)).collect(),
+            portfolio_currencies,
+            minor_units,
+            fx,
+        }
+    }
+
+    /// Latest known price on or before `date`, adjusted for the splits since that price
+    pub fn price_on(&self, instrument_id: &[u8], date: NaiveDate) -> Option<Decimal> {
+        let (price_date, price) = self.prices.get(instrument_id)?.range(..=date).next_back()?;
+        let ratio: Decimal = self.splits.get(instrument_id)
+            .map(|splits| splits.iter()
+                .filter(|(split_date, _)| split_date > price_date && *split_date <= date)
+                .map(|(_, ratio)| *ratio)
+                .product())
+            .unwrap_or(Decimal::ONE);
+        Some(*price / ratio)
+    }
+
+    fn to_base(&self, amount: Decimal, currency_code: &str, date: NaiveDate) -> Result<Decimal, String> {
+        self.fx.to_base(amount, currency_code, date).ok_or_else(|| currency_code.to_string())
+    }
+
+    /// Market value in base currency of the units held at the end of `date`.
+    /// Err with the currency code missing an fx rate.
+    pub fn value_on(&self, trades: &[&Trade], date: NaiveDate) -> Result<Decimal, String> {
+        let mut quantities: HashMap<&[u8], Decimal> = HashMap::new();
+        for trade in trades.iter().filter(|trade| trade.trade_date <= date) {
+            let Some(instrument_id) = trade.instrument_id.as_deref() else { continue };
+            let quantity = quantities.entry(instrument_id).or_default();
+            match trade.trade_type {
+                TradeType::Buy => *quantity += trade.quantity.unwrap_or_default(),
+                TradeType::Sell => *quantity -= trade.quantity.unwrap_or_default(),
+                TradeType::Split => *quantity *= trade.split_ratio.unwrap_or(Decimal::ONE),
+                TradeType::Dividend | TradeType::Fee => {},
+            }
+        }
+
+        let mut value = Decimal::ZERO;
+        for (instrument_id, quantity) in quantities {
+            if quantity.is_zero() {
+                continue;
+            }
+            let (Some(instrument), Some(price)) = (self.instruments.get(instrument_id), self.price_on(instrument_id, date)) else {
+                continue;
+            };
+            value += self.to_base(quantity * price, &instrument.currency_code, date)?;
+        }
+        Ok(value)
+    }
+
+    /// Money put into the holdings by the trade, in base currency: buys and fees are positive,
+    /// sells and dividends (money coming out) negative, splits zero
+    pub fn flow_of(&self, trade: &Trade) -> Result<Decimal, String> {
+        let currency_code = match trade.instrument_id.as_ref().and_then(|instrument_id| self.instruments.get(instrument_id)) {
+            Some(instrument) => instrument.currency_code.as_str(),
+            None => self.portfolio_currencies.get(&trade.portfolio_id).map_or(self.fx.base_code.as_str(), String::as_str),
+        };
+        let scale = scale_factor(self.minor_units.get(currency_code).copied().unwrap_or(2));
+        let gross = trade.quantity.unwrap_or_default() * trade.price.unwrap_or_default();
+        let fee = Decimal::from(trade.fee_minor) / scale;
+        let amount = Decimal::from(trade.amount_minor.unwrap_or(0)) / scale;
+
+        let flow = match trade.trade_type {
+            TradeType::Buy => gross + fee,
+            TradeType::Sell => -(gross - fee),
+            TradeType::Dividend => -amount,
+            TradeType::Fee => amount,
+            TradeType::Split => Decimal::ZERO,
+        };
+        self.to_base(flow, currency_code, trade.trade_date)
+    }
+}
+
+
+/// Performance of a set of trades over (from, to], in base currency major units.
+/// The value at the end of `from` is the starting investment.
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceFigures {
+    pub start_value: Decimal,
+    pub end_value: Decimal,
+    /// Buys and fees
+    pub contributions: Decimal,
+    /// Sells and dividends
+    pub withdrawals: Decimal,
+    /// Time-weighted return over the period, None when nothing was held
+    pub twr: Option<Decimal>,
+    /// Money-weighted annual return, None when it does not converge
+    pub xirr: Option<Decimal>,
+}
+
+impl PerformanceFigures {
+    /// Err with the currency code missing an fx rate
+    pub fn of(market: &MarketData, trades: &[&Trade], from: NaiveDate, to: NaiveDate) -> Result<Self, String> {
+        let mut flows: BTreeMap<NaiveDate, Decimal> = BTreeMap::new();
+        let mut figures = Self::default();
+        for trade in trades.iter().filter(|trade| trade.trade_date > from && trade.trade_date <= to) {
+            let flow = market.flow_of(trade)?;
+            *flows.entry(trade.trade_date).or_default() += flow;
+            if flow > Decimal::ZERO {
+                figures.contributions += flow;
+            } else {
+                figures.withdrawals -= flow;
+            }
+        }
+        figures.start_value = market.value_on(trades, from)?;
+        figures.end_value = market.value_on(trades, to)?;
+
+        // Sub-periods end on each flow day, valued before that day's flows
+        let mut growth = Decimal::ONE;
+        let mut invested = false;
+        let mut previous_value = figures.start_value;
+        for (&date, &flow) in &flows {
+            let value = market.value_on(trades, date)?;
+            if previous_value > Decimal::ZERO {
+                growth *= (value - flow) / previous_value;
+                invested = true;
+            }
+            previous_value = value;
+        }
+        if flows.keys().next_back() != Some(&to) && previous_value > Decimal::ZERO {
+            growth *= figures.end_value / previous_value;
+            invested = true;
+        }
+        figures.twr = invested.then(|| growth - Decimal::ONE);
+
+        let mut cash_flows: Vec<(NaiveDate, Decimal)> = Vec::new();
+        if figures.start_value > Decimal::ZERO {
+            cash_flows.push((from, -figures.start_value));
+        }
+        cash_flows.extend(flows.iter().map(|(&date, &flow)| (date, -flow)));
+        cash_flows.push((to, figures.end_value));
+        figures.xirr = xirr(&cash_flows);
+
+        Ok(figures)
+    }
+}
+
+/// Annual rate r such that the flows discounted at r sum to zero, by bisection.
+/// Flows are from the investor side: money put in is negative, money received positive.
+pub fn xirr(cash_flows: &[(NaiveDate, Decimal)]) -> Option<Decimal> {
+    let first_date = cash_flows.iter().map(|(date, _)| *date).min()?;
+    let flows: Vec<(f64, f64)> = cash_flows.iter()
+        .filter_map(|(date, amount)| Some(((*date - first_date).num_days() as f64 / 365.0, amount.to_f64()?)))
+        .collect();
+    if !flows.iter().any(|(_, amount)| *amount < 0.0) || !flows.iter().any(|(_, amount)| *amount > 0.0) {
+        return None;
+    }
+
+    let npv = |rate: f64| flows.iter().map(|(years, amount)| amount / (1.0 + rate).powf(*years)).sum::<f64>();
+    let (mut low, mut high) = (-0.9999, 100.0);
+    let (mut npv_low, npv_high) = (npv(low), npv(high));
+    if !npv_low.is_finite() || !npv_high.is_finite() || npv_low.signum() == npv_high.signum() {
+        return None;
+    }
+    for _ in 0..200 {
+        let middle = (low + high) / 2.0;
+        let npv_middle = npv(middle);
+        if npv_middle.signum() == npv_low.signum() {
+            low = middle;
+            npv_low = npv_middle;
+        } else {
+            high = middle;
+        }
+    }
+    Decimal::from_f64((low + high) / 2.0)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::investments::instrument_model::InstrumentType;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn buy(date: NaiveDate, quantity: i64, price: i64) -> Trade {
+        Trade {
+            id: None,
+            portfolio_id: vec![9],
+            instrument_id: Some(vec![1]),
+            trade_type: TradeType::Buy,
+            trade_date: date,
+            quantity: Some(Decimal::from(quantity)),
+            price: Some(Decimal::from(price)),
+            amount_minor: None,
+            fee_minor: 0,
+            split_ratio: None,
+            note: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn market(trades: &[Trade], prices: &[(NaiveDate, i64)]) -> MarketData {
+        let instrument = Instrument {
+            id: Some(vec![1]),
+            user_id: vec![],
+            name: "World ETF".to_string(),
+            symbol: None,
+            isin: None,
+            instrument_type: InstrumentType::Etf,
+            currency_code: "EUR".to_string(),
+            tag: None,
+            minor_unit: 2,
+            created_at: None,
+            updated_at: None,
+        };
+        let prices: Vec<InstrumentPrice> = prices.iter()
+            .map(|(date, price)| InstrumentPrice {
+                id: None,
+                instrument_id: vec![1],
+                price_date: *date,
+                price: Decimal::from(*price),
+                source: "manual".to_string(),
+                created_at: None,
+            })
+            .collect();
+        MarketData::new(
+            trades,
+            vec![instrument],
+            &prices,
+            HashMap::from([(vec![9], "EUR".to_string())]),
+            HashMap::from([("EUR".to_string(), 2)]),
+            FxTable::new("EUR", &[]),
+        )
+    }
+
+    #[test]
+    fn xirr_of_ten_percent_over_one_year() {
+        let cash_flows = [
+            (d(2023, 1, 1), Decimal::from(-1000)),
+            (d(2024, 1, 1), Decimal::from(1100)),
+        ];
+        let rate = xirr(&cash_flows).unwrap().to_f64().unwrap();
+        assert!((rate - 0.10).abs() < 1e-6, "xirr {}", rate);
+    }
+
+    #[test]
+    fn xirr_needs_money_in_and_out() {
+        assert_eq!(xirr(&[(d(2023, 1, 1), Decimal::from(-1000))]), None);
+        assert_eq!(xirr(&[]), None);
+    }
+
+    #[test]
+    fn twr_ignores_a_mid_period_deposit() {
+        let trades = vec![buy(d(2023, 12, 15), 10, 100), buy(d(2024, 6, 30), 10, 120)];
+        let market = market(&trades, &[(d(2024, 1, 1), 100), (d(2024, 12, 31), 132)]);
+        let trades: Vec<&Trade> = trades.iter().collect();
+
+        let figures = PerformanceFigures::of(&market, &trades, d(2024, 1, 1), d(2024, 12, 31)).unwrap();
+
+        assert_eq!(figures.start_value, Decimal::from(1000));
+        assert_eq!(figures.end_value, Decimal::from(2640));
+        assert_eq!(figures.contributions, Decimal::from(1200));
+        assert_eq!(figures.withdrawals, Decimal::ZERO);
+        // +20% before the deposit, then +10% on the doubled position
+        assert_eq!(figures.twr, Some(Decimal::new(32, 2)));
+        assert!(figures.xirr.unwrap() > Decimal::ZERO);
+    }
+}
@@ -0,0 +1,266 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+use crate::modules::currencies::{
+    currency_model::FxTable,
+    currency_repo::{CurrencyRepository, CurrencyRepositoryInterface, FxRateRepository, FxRateRepositoryInterface},
+};
+use crate::modules::investments::{
+    instrument_repo::{InstrumentPriceRepository, InstrumentPriceRepositoryInterface, InstrumentRepository, InstrumentRepositoryInterface},
+    performance_command::PerformanceCommand,
+    performance_dto::*,
+    performance_model::{MarketData, PerformanceFigures},
+    portfolio_model::Portfolio,
+    portfolio_repo::{PortfolioRepository, PortfolioRepositoryInterface},
+    trade_model::Trade,
+    trade_repo::{TradeRepository, TradeRepositoryInterface},
+};
+use crate::modules::users::user::user_repo::{UserRepository, UserRepositoryInterface};
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu};
+
+
+#[async_trait]
+pub trait PerformanceServiceInterface {
+
+    /// TWR and XIRR of one portfolio (`portfolio_id`) or of all portfolios, with their positions.
+    /// None when the portfolio does not exist.
+    async fn performance(&self, command: PerformanceCommand) -> Result<Option<PerformanceResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct PerformanceService {
+    user_repo: UserRepository,
+    currency_repo: CurrencyRepository,
+    fx_rate_repo: FxRateRepository,
+    portfolio_repo: PortfolioRepository,
+    instrument_repo: InstrumentRepository,
+    price_repo: InstrumentPriceRepository,
+    trade_repo: TradeRepository,
+}
+
+impl From<&AppState> for PerformanceService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            user_repo: UserRepository::from(app_state),
+            currency_repo: CurrencyRepository::from(app_state),
+            fx_rate_repo: FxRateRepository::from(app_state),
+            portfolio_repo: PortfolioRepository::from(app_state),
+            instrument_repo: InstrumentRepository::from(app_state),
+            price_repo: InstrumentPriceRepository::from(app_state),
+            trade_repo: TradeRepository::from(app_state),
+        }
+    }
+}
+
+impl PerformanceService {
+    fn percent(ratio: Decimal) -> Decimal {
+        (ratio * Decimal::from(100)).round_dp(2)
+    }
+
+    /// Compound annual rate of a period return, only for periods of a year or more
+    fn annualized(ratio: Decimal, from: NaiveDate, to: NaiveDate) -> Option<Decimal> {
+        let days = (to - from).num_days();
+        if days < 365 {
+            return None;
+        }
+        let annual = (1.0 + ratio.to_f64()?).powf(365.0 / days as f64) - 1.0;
+        Decimal::from_f64(annual)
+    }
+
+    fn line(figures: &PerformanceFigures, base_scale: Decimal, from: NaiveDate, to: NaiveDate, benchmark: Option<Decimal>) -> PerformanceLineResponse {
+        let minor = |amount: Decimal| (amount * base_scale).round().to_i64().unwrap_or(0);
+        let start = minor(figures.start_value);
+        let end = minor(figures.end_value);
+        let contributions = minor(figures.contributions);
+        let withdrawals = minor(figures.withdrawals);
+        PerformanceLineResponse {
+            portfolio_id: None,
+            instrument_id: None,
+            name: String::new(),
+            start_value_base_minor: start,
+            end_value_base_minor: end,
+            contributions_base_minor: contributions,
+            withdrawals_base_minor: withdrawals,
+            gain_base_minor: end - start - contributions + withdrawals,
+            twr_percent: figures.twr.map(Self::percent),
+            annualized_twr_percent: figures.twr.and_then(|twr| Self::annualized(twr, from, to)).map(Self::percent),
+            xirr_percent: figures.xirr.map(Self::percent),
+            excess_twr_percent: figures.twr.zip(benchmark).map(|(twr, benchmark)| Self::percent(twr - benchmark)),
+        }
+    }
+
+    fn missing_fx(base_code: &str) -> impl Fn(String) -> Error + '_ {
+        move |currency_code| AppError::BadRequest(format!("missing fx rate {}/{}", base_code, currency_code)).into()
+    }
+
+    async fn portfolios(&self, command: &PerformanceCommand, meta_user: Option<Uuid>) -> Result<Option<Vec<Portfolio>>, Error> {
+        match command.portfolio_id {
+            Some(portfolio_id) => match self.portfolio_repo.get(portfolio_id, meta_user).await {
+                Ok(portfolio) => Ok(portfolio.map(|portfolio| vec![portfolio])),
+                Err(_) => Err(Error::msg("Error getting portfolio")),
+            },
+            None => match self.portfolio_repo.get_by_user(command.user_id, meta_user).await {
+                Ok(portfolios) => Ok(Some(portfolios)),
+                Err(_) => Err(Error::msg("Error getting portfolios")),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl PerformanceServiceInterface for PerformanceService {
+    async fn performance(&self, command: PerformanceCommand) -> Result<Option<PerformanceResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolios = match self.portfolios(&command, meta_user).await? {
+            Some(portfolios) => portfolios,
+            None => return Ok(None),
+        };
+        let base_code = match self.user_repo.get(command.user_id, meta_user).await {
+            Ok(Some(user)) => user.base_currency_code,
+            Ok(None) => return Err(Error::msg("User not found")),
+            Err(_) => return Err(Error::msg("Error getting user")),
+        };
+
+        let mut trades: Vec<Trade> = Vec::new();
+        for portfolio in &portfolios {
+            match self.trade_repo.get_by_portfolio(bu(portfolio.id.as_deref().unwrap()), meta_user).await {
+                Ok(portfolio_trades) => trades.extend(portfolio_trades),
+                Err(_) => return Err(Error::msg("Error getting portfolio trades")),
+            }
+        }
+
+        let to = command.to.unwrap_or(Utc::now().date_naive());
+        let from = match command.from {
+            Some(from) if from >= to => return Err(AppError::BadRequest("period start must be before its end".into()).into()),
+            Some(from) => from,
+            None => trades.iter()
+                .map(|trade| trade.trade_date)
+                .min()
+                .and_then(|first| first.pred_opt())
+                .map_or(to, |from| from.min(to)),
+        };
+        trades.retain(|trade| trade.trade_date <= to);
+
+        let instruments = match self.instrument_repo.get_by_user(command.user_id, meta_user).await {
+            Ok(instruments) => instruments,
+            Err(_) => return Err(Error::msg("Error getting instruments")),
+        };
+        let benchmark_instrument = match command.benchmark_instrument_id {
+            Some(benchmark_id) => match instruments.iter().find(|instrument| instrument.id.as_deref().map(bu) == Some(benchmark_id)) {
+                Some(instrument) => Some(instrument.clone()),
+                None => return Err(AppError::BadRequest("benchmark instrument not found".into()).into()),
+            },
+            None => None,
+        };
+
+        let priced: BTreeSet<Vec<u8>> = trades.iter()
+            .filter_map(|trade| trade.instrument_id.clone())
+            .chain(benchmark_instrument.as_ref().and_then(|instrument| instrument.id.clone()))
+            .collect();
+        let mut prices = Vec::new();
+        for instrument_id in &priced {
+            match self.price_repo.get_by_instrument(bu(instrument_id), None, Some(to), meta_user).await {
+                Ok(instrument_prices) => prices.extend(instrument_prices),
+                Err(_) => return Err(Error::msg("Error getting instrument prices")),
+            }
+        }
+
+        let minor_units: HashMap<String, u8> = match self.currency_repo.get_all(meta_user).await {
+            Ok(currencies) => currencies.into_iter().map(|currency| (currency.code, currency.minor_unit)).collect(),
+            Err(_) => return Err(Error::msg("Error getting currencies")),
+        };
+        let fx_rates = match self.fx_rate_repo.get_by_base_code(base_code.clone(), meta_user).await {
+            Ok(fx_rates) => fx_rates,
+            Err(_) => return Err(Error::msg("Error getting fx rates")),
+        };
+        let base_scale = scale_factor(minor_units.get(&base_code).copied().unwrap_or(2));
+        let portfolio_currencies = portfolios.iter()
+            .map(|portfolio| (portfolio.id.clone().unwrap(), portfolio.currency_code.clone()))
+            .collect();
+        let market = MarketData::new(&trades, instruments.clone(), &prices, portfolio_currencies, minor_units, FxTable::new(&base_code, &fx_rates));
+
+        let benchmark = benchmark_instrument.map(|instrument| {
+            let instrument_id = instrument.id.clone().unwrap();
+            let start_price = market.price_on(&instrument_id, from);
+            let end_price = market.price_on(&instrument_id, to);
+            let ratio = start_price.zip(end_price)
+                .filter(|(start_price, _)| *start_price > Decimal::ZERO)
+                .map(|(start_price, end_price)| end_price / start_price - Decimal::ONE);
+            (ratio, BenchmarkResponse {
+                instrument_id: bu(&instrument_id),
+                name: instrument.name,
+                start_price,
+                end_price,
+                return_percent: ratio.map(Self::percent),
+                annualized_return_percent: ratio.and_then(|ratio| Self::annualized(ratio, from, to)).map(Self::percent),
+            })
+        });
+        let benchmark_ratio = benchmark.as_ref().and_then(|(ratio, _)| *ratio);
+        let missing_fx = Self::missing_fx(&base_code);
+
+        let all: Vec<&Trade> = trades.iter().collect();
+        let figures = PerformanceFigures::of(&market, &all, from, to).map_err(&missing_fx)?;
+        let mut total = Self::line(&figures, base_scale, from, to, benchmark_ratio);
+        total.portfolio_id = command.portfolio_id;
+        total.name = match command.portfolio_id {
+            Some(_) => portfolios[0].name.clone(),
+            None => "All portfolios".to_string(),
+        };
+
+        let mut portfolio_lines = Vec::new();
+        let mut positions = Vec::new();
+        for portfolio in &portfolios {
+            let portfolio_trades: Vec<&Trade> = trades.iter()
+                .filter(|trade| Some(&trade.portfolio_id) == portfolio.id.as_ref())
+                .collect();
+            if command.portfolio_id.is_none() {
+                let figures = PerformanceFigures::of(&market, &portfolio_trades, from, to).map_err(&missing_fx)?;
+                portfolio_lines.push(PerformanceLineResponse {
+                    portfolio_id: obu(portfolio.id.as_deref()),
+                    name: portfolio.name.clone(),
+                    ..Self::line(&figures, base_scale, from, to, benchmark_ratio)
+                });
+            }
+
+            for instrument in &instruments {
+                let position_trades: Vec<&Trade> = portfolio_trades.iter()
+                    .filter(|trade| trade.instrument_id == instrument.id)
+                    .copied()
+                    .collect();
+                if position_trades.is_empty() {
+                    continue;
+                }
+                let figures = PerformanceFigures::of(&market, &position_trades, from, to).map_err(&missing_fx)?;
+                if figures.start_value.is_zero() && figures.end_value.is_zero()
+                    && figures.contributions.is_zero() && figures.withdrawals.is_zero() {
+                    continue;
+                }
+                positions.push(PerformanceLineResponse {
+                    portfolio_id: obu(portfolio.id.as_deref()),
+                    instrument_id: obu(instrument.id.as_deref()),
+                    name: instrument.name.clone(),
+                    ..Self::line(&figures, base_scale, from, to, benchmark_ratio)
+                });
+            }
+        }
+
+        Ok(Some(PerformanceResponse {
+            from,
+            to,
+            base_currency_code: base_code.clone(),
+            total,
+            portfolios: portfolio_lines,
+            positions,
+            benchmark: benchmark.map(|(_, benchmark)| benchmark),
+        }))
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::{
+    portfolio_dto::{PortfolioCreateRequest, PortfolioPositionsQuery, PortfolioUpdateRequest},
+    portfolio_model::CostMethod,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioGetCommand {
+    pub portfolio_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PortfolioGetCommand {
+    pub fn new(portfolio_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { portfolio_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioCreateCommand {
+    pub user_id: Uuid,
+    pub portfolio_account_id: Uuid,
+    pub portfolio_name: String,
+    pub portfolio_cost_method: Option<CostMethod>,
+    pub portfolio_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl PortfolioCreateCommand {
+    pub fn new(request: PortfolioCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            portfolio_account_id: request.portfolio_account_id,
+            portfolio_name: request.portfolio_name,
+            portfolio_cost_method: request.portfolio_cost_method,
+            portfolio_note: request.portfolio_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioUpdateCommand {
+    pub portfolio_id: Uuid,
+
+    pub portfolio_account_id: Uuid,
+    pub portfolio_name: String,
+    pub portfolio_cost_method: CostMethod,
+    pub portfolio_note: Option<String>,
+    pub portfolio_archived: bool,
+
+    pub auth_user: AuthUser,
+}
+
+impl PortfolioUpdateCommand {
+    pub fn new(portfolio_id: Uuid, request: PortfolioUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            portfolio_id,
+            portfolio_account_id: request.portfolio_account_id,
+            portfolio_name: request.portfolio_name,
+            portfolio_cost_method: request.portfolio_cost_method,
+            portfolio_note: request.portfolio_note,
+            portfolio_archived: request.portfolio_archived,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioDeleteCommand {
+    pub portfolio_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PortfolioDeleteCommand {
+    pub fn new(portfolio_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { portfolio_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PortfolioListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortfolioPositionsCommand {
+    pub portfolio_id: Uuid,
+    pub as_of: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl PortfolioPositionsCommand {
+    pub fn new(portfolio_id: Uuid, query: PortfolioPositionsQuery, auth_user: AuthUser) -> Self {
+        Self { portfolio_id, as_of: query.as_of, auth_user }
+    }
+}
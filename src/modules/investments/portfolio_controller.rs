@@ -0,0 +1,206 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::get, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    portfolio_command::*,
+    portfolio_dto::*,
+    portfolio_service::{PortfolioService, PortfolioServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/portfolios", get(get_portfolios).post(post_portfolio))
+        .route("/portfolios/{portfolio_id}", get(get_portfolio).put(put_portfolio).delete(delete_portfolio))
+        .route("/portfolios/{portfolio_id}/positions", get(get_portfolio_positions))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios",
+    responses(
+        (status = StatusCode::OK, description = "Portfolios of current user", body = Vec<PortfolioResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Portfolio"
+)]
+pub async fn get_portfolios(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<PortfolioResponse>>, StatusCode> {
+    let command = PortfolioListByUserCommand::new(auth_user.user_id, auth_user);
+    let portfolio_service = PortfolioService::from(&state);
+
+    let portfolios = portfolio_service.get_by_user(command).await;
+    match portfolios {
+        Ok(portfolios) => Ok(Json(portfolios)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/portfolios",
+    responses(
+        (status = StatusCode::OK, description = "Portfolio created successfully", body = PortfolioResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid portfolio"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Portfolio"
+)]
+pub async fn post_portfolio(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(portfolio_create_request): Json<PortfolioCreateRequest>
+) -> Result<Json<PortfolioResponse>, StatusCode> {
+    let command = PortfolioCreateCommand::new(portfolio_create_request, auth_user);
+    let portfolio_service = PortfolioService::from(&state);
+
+    let portfolio = portfolio_service.create(command).await;
+    match portfolio {
+        Ok(portfolio) => Ok(Json(portfolio)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Portfolio", body = PortfolioResponse),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Portfolio"
+)]
+pub async fn get_portfolio(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+) -> Result<Json<PortfolioResponse>, StatusCode> {
+    let command = PortfolioGetCommand::new(portfolio_id, auth_user);
+    let portfolio_service = PortfolioService::from(&state);
+
+    let portfolio = portfolio_service.get(command).await;
+    match portfolio {
+        Ok(portfolio) => {
+            match portfolio {
+                Some(portfolio) => Ok(Json(portfolio)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/investments/portfolios/{portfolio_id}",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Portfolio updated successfully", body = PortfolioResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid portfolio"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Portfolio"
+)]
+pub async fn put_portfolio(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Json(portfolio_update_request): Json<PortfolioUpdateRequest>
+) -> Result<Json<PortfolioResponse>, StatusCode> {
+    let command = PortfolioUpdateCommand::new(portfolio_id, portfolio_update_request, auth_user);
+    let portfolio_service = PortfolioService::from(&state);
+
+    let portfolio = portfolio_service.update(command).await;
+    match portfolio {
+        Ok(portfolio) => {
+            match portfolio {
+                Some(portfolio) => Ok(Json(portfolio)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/investments/portfolios/{portfolio_id}",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Portfolio deleted successfully, with its trades"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Portfolio"
+)]
+pub async fn delete_portfolio(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = PortfolioDeleteCommand::new(portfolio_id, auth_user);
+    let portfolio_service = PortfolioService::from(&state);
+
+    let response = portfolio_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}/positions",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid"),
+        PortfolioPositionsQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Positions with quantity, average cost and market value at the latest recorded price", body = PortfolioPositionsResponse),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Portfolio"
+)]
+pub async fn get_portfolio_positions(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Query(query): Query<PortfolioPositionsQuery>,
+) -> Result<Json<PortfolioPositionsResponse>, StatusCode> {
+    let command = PortfolioPositionsCommand::new(portfolio_id, query, auth_user);
+    let portfolio_service = PortfolioService::from(&state);
+
+    let positions = portfolio_service.positions(command).await;
+    match positions {
+        Ok(positions) => {
+            match positions {
+                Some(positions) => Ok(Json(positions)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
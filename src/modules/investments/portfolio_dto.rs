@@ -0,0 +1,109 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::investments::portfolio_model::{CostMethod, Portfolio};
+use crate::shared::utils::bu;
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PortfolioResponse {
+    pub portfolio_id: Uuid,
+    pub user_id: Uuid,
+    pub portfolio_account_id: Uuid,
+    pub portfolio_name: String,
+    pub portfolio_cost_method: CostMethod,
+    pub portfolio_note: Option<String>,
+    pub portfolio_archived: bool,
+    /// Currency of the broker account
+    pub portfolio_currency_code: String,
+
+    pub portfolio_created_at: Option<DateTime<Utc>>,
+    pub portfolio_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Portfolio> for PortfolioResponse {
+    fn from(portfolio: &Portfolio) -> Self {
+        Self {
+            portfolio_id: bu(portfolio.id.clone().unwrap().as_slice()),
+            user_id: bu(portfolio.user_id.as_slice()),
+            portfolio_account_id: bu(portfolio.account_id.as_slice()),
+            portfolio_name: portfolio.name.clone(),
+            portfolio_cost_method: portfolio.cost_method,
+            portfolio_note: portfolio.note.clone(),
+            portfolio_archived: portfolio.archived,
+            portfolio_currency_code: portfolio.currency_code.clone(),
+            portfolio_created_at: portfolio.created_at,
+            portfolio_updated_at: portfolio.updated_at,
+        }
+    }
+}
+
+/// The account must be a broker account
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PortfolioCreateRequest {
+    pub portfolio_account_id: Uuid,
+    pub portfolio_name: String,
+    /// How sells are matched with buy lots for realized gains, FIFO by default
+    pub portfolio_cost_method: Option<CostMethod>,
+    pub portfolio_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PortfolioUpdateRequest {
+    pub portfolio_account_id: Uuid,
+    pub portfolio_name: String,
+    pub portfolio_cost_method: CostMethod,
+    pub portfolio_note: Option<String>,
+    pub portfolio_archived: bool,
+}
+
+// --- Positions ---
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PortfolioPositionsQuery {
+    /// Today by default: trades up to that day, latest price on or before it
+    pub as_of: Option<NaiveDate>,
+}
+
+/// Holding of one instrument at average cost, in the instrument currency
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PositionResponse {
+    pub instrument_id: Uuid,
+    pub instrument_name: String,
+    pub instrument_symbol: Option<String>,
+    pub currency_code: String,
+
+    pub quantity: Decimal,
+    /// Cost per unit held, buy fees included; None once the position is closed
+    pub average_cost: Option<Decimal>,
+    pub cost_basis_minor: i64,
+
+    /// Latest recorded price on or before the as-of date
+    pub last_price: Option<Decimal>,
+    pub last_price_date: Option<NaiveDate>,
+    /// quantity × last price, None without recorded price
+    pub market_value_minor: Option<i64>,
+    /// market value - cost basis
+    pub unrealized_minor: Option<i64>,
+    pub unrealized_percent: Option<Decimal>,
+
+    /// Gains and losses of the units sold, net of sell fees
+    pub realized_minor: i64,
+    pub dividends_minor: i64,
+    pub fees_minor: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PortfolioPositionsResponse {
+    pub portfolio_id: Uuid,
+    pub as_of: NaiveDate,
+    pub currency_code: String,
+
+    /// Open positions first, then closed ones (quantity 0) keeping their realized gains
+    pub positions: Vec<PositionResponse>,
+    /// Fees not tied to an instrument, in the portfolio currency
+    pub portfolio_fees_minor: i64,
+}
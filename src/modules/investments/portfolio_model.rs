@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::investments::portfolio_command::PortfolioCreateCommand;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::ub;
+
+
+/// How sells are matched with the buy lots to compute realized gains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum CostMethod {
+    /// First bought, first sold
+    Fifo,
+    /// Last bought, first sold
+    Lifo,
+    /// Weighted average cost of all units held
+    Average,
+}
+
+impl CostMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CostMethod::Fifo => "fifo",
+            CostMethod::Lifo => "lifo",
+            CostMethod::Average => "average",
+        }
+    }
+}
+
+
+/// Portfolio held on a broker account; its currency is the account currency
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Portfolio {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub account_id: Vec<u8>,
+    pub name: String,
+    pub cost_method: CostMethod,
+    pub note: Option<String>,
+    pub archived: bool,
+
+    /// Currency of the broker account, read only
+    pub currency_code: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Portfolio {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            account_id: row.try_get(index_map["account_id"])?,
+            name: row.try_get(index_map["name"])?,
+            cost_method: row.try_get(index_map["cost_method"])?,
+            note: row.try_get(index_map["note"])?,
+            archived: row.try_get(index_map["archived"])?,
+            currency_code: row.try_get(index_map["currency_code"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<PortfolioCreateCommand> for Portfolio {
+    fn from(command: PortfolioCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            account_id: ub(command.portfolio_account_id),
+            name: command.portfolio_name,
+            cost_method: command.portfolio_cost_method.unwrap_or(CostMethod::Fifo),
+            note: command.portfolio_note,
+            archived: false,
+            currency_code: String::new(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::investments::portfolio_model::Portfolio;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait PortfolioRepositoryInterface {
+
+    async fn get(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Portfolio>, Error>;
+
+    /// Fails when the account is not a broker account of the user
+    async fn create(&self, portfolio: Portfolio, meta_user: Option<Uuid>) -> Result<Portfolio, Error>;
+
+    /// Fails when the account is not a broker account of the user
+    async fn update(&self, portfolio: Portfolio, meta_user: Option<Uuid>) -> Result<Option<Portfolio>, Error>;
+
+    async fn delete(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Portfolio>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct PortfolioRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for PortfolioRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Portfolio> for PortfolioRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl PortfolioRepositoryInterface for PortfolioRepository {
+    async fn get(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Portfolio>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(portfolio_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_portfolio_get_by_id", params).await
+    }
+
+    async fn create(&self, portfolio: Portfolio, meta_user: Option<Uuid>) -> Result<Portfolio, Error> {
+        let params = vec![
+            MySqlParam::from(portfolio.user_id),
+            MySqlParam::from(portfolio.account_id),
+            MySqlParam::from(portfolio.name),
+            MySqlParam::from(portfolio.cost_method.as_str()),
+            MySqlParam::from(portfolio.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_portfolio_create", params).await
+    }
+
+    async fn update(&self, portfolio: Portfolio, meta_user: Option<Uuid>) -> Result<Option<Portfolio>, Error> {
+        let params = vec![
+            MySqlParam::from(portfolio.id),
+            MySqlParam::from(portfolio.account_id),
+            MySqlParam::from(portfolio.name),
+            MySqlParam::from(portfolio.cost_method.as_str()),
+            MySqlParam::from(portfolio.note),
+            MySqlParam::from(portfolio.archived),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_portfolio_update", params).await
+    }
+
+    async fn delete(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(portfolio_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_portfolio_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Portfolio>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_portfolio_by_user", params).await
+    }
+}
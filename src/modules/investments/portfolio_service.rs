@@ -0,0 +1,218 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    instrument_model::{Instrument, InstrumentPrice},
+    instrument_repo::{InstrumentPriceRepository, InstrumentPriceRepositoryInterface, InstrumentRepository, InstrumentRepositoryInterface},
+    portfolio_command::*,
+    portfolio_dto::*,
+    portfolio_model::Portfolio,
+    portfolio_repo::{PortfolioRepository, PortfolioRepositoryInterface},
+    trade_model::Holdings,
+    trade_repo::{TradeRepository, TradeRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::money::scale_factor;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, ub};
+
+
+#[async_trait]
+pub trait PortfolioServiceInterface {
+
+    async fn get(&self, command: PortfolioGetCommand) -> Result<Option<PortfolioResponse>, Error>;
+
+    async fn create(&self, command: PortfolioCreateCommand) -> Result<PortfolioResponse, Error>;
+
+    async fn update(&self, command: PortfolioUpdateCommand) -> Result<Option<PortfolioResponse>, Error>;
+
+    async fn delete(&self, command: PortfolioDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: PortfolioListByUserCommand) -> Result<Vec<PortfolioResponse>, Error>;
+
+    /// Positions derived from the trades up to the as-of date, valued at the latest recorded price.
+    /// None when the portfolio does not exist.
+    async fn positions(&self, command: PortfolioPositionsCommand) -> Result<Option<PortfolioPositionsResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct PortfolioService {
+    portfolio_repo: PortfolioRepository,
+    instrument_repo: InstrumentRepository,
+    price_repo: InstrumentPriceRepository,
+    trade_repo: TradeRepository,
+}
+
+impl From<&AppState> for PortfolioService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            portfolio_repo: PortfolioRepository::from(app_state),
+            instrument_repo: InstrumentRepository::from(app_state),
+            price_repo: InstrumentPriceRepository::from(app_state),
+            trade_repo: TradeRepository::from(app_state),
+        }
+    }
+}
+
+impl PortfolioService {
+    fn validate(name: &str) -> Result<(), Error> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest("portfolio name is required".into()).into());
+        }
+        Ok(())
+    }
+
+    /// 10^minor unit of each instrument currency, by instrument id
+    pub fn scales_of(instruments: &[Instrument]) -> HashMap<Vec<u8>, Decimal> {
+        instruments.iter()
+            .map(|instrument| (instrument.id.clone().unwrap(), scale_factor(instrument.minor_unit)))
+            .collect()
+    }
+
+    /// Positions of the holdings, valued with `prices` (at most one per instrument).
+    /// Open positions come first, then by instrument name.
+    pub fn positions_of(holdings: &Holdings, instruments: &[Instrument], prices: &[InstrumentPrice]) -> Vec<PositionResponse> {
+        let mut positions: Vec<PositionResponse> = instruments.iter()
+            .filter_map(|instrument| {
+                let instrument_id = instrument.id.as_ref().unwrap();
+                let holding = holdings.by_instrument.get(instrument_id)?;
+                let scale = scale_factor(instrument.minor_unit);
+                let price = prices.iter().find(|price| &price.instrument_id == instrument_id);
+
+                let cost_basis = holding.cost_minor.round().to_i64().unwrap_or(0);
+                let market_value = price.map(|price| (holding.quantity * price.price * scale).round().to_i64().unwrap_or(0));
+                let unrealized = market_value.map(|market_value| market_value - cost_basis);
+
+                Some(PositionResponse {
+                    instrument_id: bu(instrument_id),
+                    instrument_name: instrument.name.clone(),
+                    instrument_symbol: instrument.symbol.clone(),
+                    currency_code: instrument.currency_code.clone(),
+                    quantity: holding.quantity.normalize(),
+                    average_cost: holding.average_cost(scale).map(|cost| cost.round_dp(8).normalize()),
+                    cost_basis_minor: cost_basis,
+                    last_price: price.map(|price| price.price),
+                    last_price_date: price.map(|price| price.price_date),
+                    market_value_minor: market_value,
+                    unrealized_minor: unrealized,
+                    unrealized_percent: unrealized
+                        .filter(|_| cost_basis > 0)
+                        .map(|unrealized| (Decimal::from(unrealized) * Decimal::from(100) / Decimal::from(cost_basis)).round_dp(2)),
+                    realized_minor: holding.realized_minor.round().to_i64().unwrap_or(0),
+                    dividends_minor: holding.dividends_minor,
+                    fees_minor: holding.fees_minor,
+                })
+            })
+            .collect();
+        positions.sort_by(|a, b| (a.quantity.is_zero(), &a.instrument_name).cmp(&(b.quantity.is_zero(), &b.instrument_name)));
+        positions
+    }
+
+    async fn portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Portfolio>, Error> {
+        match self.portfolio_repo.get(portfolio_id, meta_user).await {
+            Ok(portfolio) => Ok(portfolio),
+            Err(_) => Err(Error::msg("Error getting portfolio")),
+        }
+    }
+}
+
+#[async_trait]
+impl PortfolioServiceInterface for PortfolioService {
+    async fn get(&self, command: PortfolioGetCommand) -> Result<Option<PortfolioResponse>, Error> {
+        let portfolio = self.portfolio(command.portfolio_id, Some(command.auth_user.user_id)).await?;
+        Ok(portfolio.as_ref().map(PortfolioResponse::from))
+    }
+
+    async fn create(&self, command: PortfolioCreateCommand) -> Result<PortfolioResponse, Error> {
+        Self::validate(&command.portfolio_name)?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio = self.portfolio_repo.create(Portfolio::from(command), meta_user).await;
+        match portfolio {
+            Ok(portfolio) => Ok(PortfolioResponse::from(&portfolio)),
+            Err(_) => Err(Error::msg("Error creating portfolio")),
+        }
+    }
+
+    async fn update(&self, command: PortfolioUpdateCommand) -> Result<Option<PortfolioResponse>, Error> {
+        Self::validate(&command.portfolio_name)?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio = match self.portfolio(command.portfolio_id, meta_user).await? {
+            Some(portfolio) => portfolio,
+            None => return Ok(None),
+        };
+
+        let portfolio_update = Portfolio {
+            account_id: ub(command.portfolio_account_id),
+            name: command.portfolio_name,
+            cost_method: command.portfolio_cost_method,
+            note: command.portfolio_note,
+            archived: command.portfolio_archived,
+            ..portfolio
+        };
+
+        let portfolio = self.portfolio_repo.update(portfolio_update, meta_user).await;
+        match portfolio {
+            Ok(portfolio) => Ok(portfolio.as_ref().map(PortfolioResponse::from)),
+            Err(_) => Err(Error::msg("Error updating portfolio")),
+        }
+    }
+
+    async fn delete(&self, command: PortfolioDeleteCommand) -> Result<(), Error> {
+        let result = self.portfolio_repo.delete(command.portfolio_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting portfolio")),
+        }
+    }
+
+    async fn get_by_user(&self, command: PortfolioListByUserCommand) -> Result<Vec<PortfolioResponse>, Error> {
+        let portfolios = self.portfolio_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await;
+        match portfolios {
+            Ok(portfolios) => Ok(portfolios.iter().map(PortfolioResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting portfolios")),
+        }
+    }
+
+    async fn positions(&self, command: PortfolioPositionsCommand) -> Result<Option<PortfolioPositionsResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio = match self.portfolio(command.portfolio_id, meta_user).await? {
+            Some(portfolio) => portfolio,
+            None => return Ok(None),
+        };
+        let as_of = command.as_of.unwrap_or(Utc::now().date_naive());
+
+        let trades = match self.trade_repo.get_by_portfolio(command.portfolio_id, meta_user).await {
+            Ok(trades) => trades,
+            Err(_) => return Err(Error::msg("Error getting portfolio trades")),
+        };
+        let trades: Vec<_> = trades.into_iter().filter(|trade| trade.trade_date <= as_of).collect();
+        let instruments = match self.instrument_repo.get_by_user(bu(&portfolio.user_id), meta_user).await {
+            Ok(instruments) => instruments,
+            Err(_) => return Err(Error::msg("Error getting instruments")),
+        };
+        let prices = match self.price_repo.get_latest_by_portfolio(command.portfolio_id, as_of, meta_user).await {
+            Ok(prices) => prices,
+            Err(_) => return Err(Error::msg("Error getting instrument prices")),
+        };
+
+        // Saved trades were checked on write; a failure here means inconsistent data
+        let holdings = Holdings::replay(&trades, &Self::scales_of(&instruments))
+            .map_err(|_| Error::msg("Error replaying portfolio trades"))?;
+
+        Ok(Some(PortfolioPositionsResponse {
+            portfolio_id: command.portfolio_id,
+            as_of,
+            currency_code: portfolio.currency_code,
+            positions: Self::positions_of(&holdings, &instruments, &prices),
+            portfolio_fees_minor: holdings.portfolio_fees_minor,
+        }))
+    }
+}
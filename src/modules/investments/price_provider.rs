@@ -0,0 +1,87 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::modules::investments::instrument_model::Instrument;
+use crate::shared::config::AppConfig;
+use crate::shared::csv;
+
+
+/// Source of daily closing prices, in the instrument currency
+#[async_trait]
+pub trait PriceProvider: Send + Sync {
+
+    /// Recorded as the source of the prices it provides
+    fn source(&self) -> &'static str;
+
+    /// Prices from `from` to `to` included, ordered by date.
+    /// None when the provider has no series for the instrument.
+    async fn daily_prices(&self, instrument: &Instrument, from: NaiveDate, to: NaiveDate) -> Result<Option<Vec<(NaiveDate, Decimal)>>, Error>;
+
+}
+
+/// Provider set up in the configuration, None when prices can only be entered or imported
+pub fn configured(config: &AppConfig) -> Option<Arc<dyn PriceProvider>> {
+    config.prices.files_dir.as_ref()
+        .map(|files_dir| Arc::new(LocalFileProvider::new(files_dir)) as Arc<dyn PriceProvider>)
+}
+
+
+/// Offline provider reading `<SYMBOL>.csv`, or `<ISIN>.csv`, from a directory.
+/// The files have a header with `date` and `price` (or `close`) columns.
+pub struct LocalFileProvider {
+    files_dir: PathBuf,
+}
+
+impl LocalFileProvider {
+    pub fn new(files_dir: &str) -> Self {
+        Self { files_dir: PathBuf::from(files_dir) }
+    }
+
+    /// File of the first code naming an existing file; codes with path characters are ignored
+    fn file_of(&self, instrument: &Instrument) -> Option<PathBuf> {
+        [instrument.symbol.as_deref(), instrument.isin.as_deref()].into_iter()
+            .flatten()
+            .filter(|code| !code.is_empty() && code.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '^')))
+            .filter(|code| !code.starts_with('.'))
+            .map(|code| self.files_dir.join(format!("{}.csv", code)))
+            .find(|path| path.is_file())
+    }
+}
+
+#[async_trait]
+impl PriceProvider for LocalFileProvider {
+    fn source(&self) -> &'static str {
+        "file"
+    }
+
+    async fn daily_prices(&self, instrument: &Instrument, from: NaiveDate, to: NaiveDate) -> Result<Option<Vec<(NaiveDate, Decimal)>>, Error> {
+        let Some(path) = self.file_of(instrument) else {
+            return Ok(None);
+        };
+        let content = tokio::task::spawn_blocking(move || std::fs::read_to_string(path)).await??;
+
+        let records = csv::parse(&content);
+        let Some((_, header)) = records.first() else {
+            return Ok(Some(Vec::new()));
+        };
+        let (Some(date_column), Some(price_column)) = (csv::column(header, &["date"]), csv::column(header, &["price", "close"])) else {
+            return Err(Error::msg("price file needs date and price columns"));
+        };
+
+        let mut prices: Vec<(NaiveDate, Decimal)> = records.iter()
+            .skip(1)
+            .filter_map(|(_, record)| {
+                let date = csv::date(record.get(date_column)?)?;
+                let price = csv::decimal(record.get(price_column)?)?;
+                (date >= from && date <= to && price >= Decimal::ZERO).then_some((date, price))
+            })
+            .collect();
+        prices.sort_by_key(|(date, _)| *date);
+        prices.dedup_by_key(|(date, _)| *date);
+        Ok(Some(prices))
+    }
+}
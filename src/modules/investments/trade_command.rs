@@ -0,0 +1,120 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::investments::{
+    trade_dto::{TradeCreateRequest, TradeUpdateRequest},
+    trade_model::TradeType,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TradeGetCommand {
+    pub trade_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TradeGetCommand {
+    pub fn new(trade_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { trade_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TradeCreateCommand {
+    pub portfolio_id: Uuid,
+    pub instrument_id: Option<Uuid>,
+    pub trade_type: TradeType,
+    pub trade_date: NaiveDate,
+
+    pub trade_quantity: Option<Decimal>,
+    pub trade_price: Option<Decimal>,
+    pub trade_amount_minor: Option<i64>,
+    pub trade_fee_minor: Option<i64>,
+    pub trade_split_ratio: Option<Decimal>,
+    pub trade_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TradeCreateCommand {
+    pub fn new(portfolio_id: Uuid, request: TradeCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            portfolio_id,
+            instrument_id: request.instrument_id,
+            trade_type: request.trade_type,
+            trade_date: request.trade_date,
+            trade_quantity: request.trade_quantity,
+            trade_price: request.trade_price,
+            trade_amount_minor: request.trade_amount_minor,
+            trade_fee_minor: request.trade_fee_minor,
+            trade_split_ratio: request.trade_split_ratio,
+            trade_note: request.trade_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TradeUpdateCommand {
+    pub trade_id: Uuid,
+
+    pub instrument_id: Option<Uuid>,
+    pub trade_type: TradeType,
+    pub trade_date: NaiveDate,
+    pub trade_quantity: Option<Decimal>,
+    pub trade_price: Option<Decimal>,
+    pub trade_amount_minor: Option<i64>,
+    pub trade_fee_minor: i64,
+    pub trade_split_ratio: Option<Decimal>,
+    pub trade_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TradeUpdateCommand {
+    pub fn new(trade_id: Uuid, request: TradeUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            trade_id,
+            instrument_id: request.instrument_id,
+            trade_type: request.trade_type,
+            trade_date: request.trade_date,
+            trade_quantity: request.trade_quantity,
+            trade_price: request.trade_price,
+            trade_amount_minor: request.trade_amount_minor,
+            trade_fee_minor: request.trade_fee_minor,
+            trade_split_ratio: request.trade_split_ratio,
+            trade_note: request.trade_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TradeDeleteCommand {
+    pub trade_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TradeDeleteCommand {
+    pub fn new(trade_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { trade_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TradeListByPortfolioCommand {
+    pub portfolio_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TradeListByPortfolioCommand {
+    pub fn new(portfolio_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { portfolio_id, auth_user }
+    }
+}
@@ -0,0 +1,189 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::get, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    trade_command::*,
+    trade_dto::*,
+    trade_service::{TradeService, TradeServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/portfolios/{portfolio_id}/trades", get(get_portfolio_trades).post(post_portfolio_trade))
+        .route("/trades/{trade_id}", get(get_trade).put(put_trade).delete(delete_trade))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/portfolios/{portfolio_id}/trades",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Trades of the portfolio by date", body = Vec<TradeResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Trade"
+)]
+pub async fn get_portfolio_trades(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+) -> Result<Json<Vec<TradeResponse>>, StatusCode> {
+    let command = TradeListByPortfolioCommand::new(portfolio_id, auth_user);
+    let trade_service = TradeService::from(&state);
+
+    let trades = trade_service.get_by_portfolio(command).await;
+    match trades {
+        Ok(trades) => {
+            match trades {
+                Some(trades) => Ok(Json(trades)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/investments/portfolios/{portfolio_id}/trades",
+    params(
+        ("portfolio_id", description = "portfolio identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Trade recorded successfully", body = TradeResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid trade, or sell exceeding the quantity held"),
+        (status = StatusCode::NOT_FOUND, description = "Portfolio not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Trade"
+)]
+pub async fn post_portfolio_trade(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(portfolio_id): Path<Uuid>,
+    Json(trade_create_request): Json<TradeCreateRequest>
+) -> Result<Json<TradeResponse>, StatusCode> {
+    let command = TradeCreateCommand::new(portfolio_id, trade_create_request, auth_user);
+    let trade_service = TradeService::from(&state);
+
+    let trade = trade_service.create(command).await;
+    match trade {
+        Ok(trade) => {
+            match trade {
+                Some(trade) => Ok(Json(trade)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/investments/trades/{trade_id}",
+    params(
+        ("trade_id", description = "trade identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Trade", body = TradeResponse),
+        (status = StatusCode::NOT_FOUND, description = "Trade not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Trade"
+)]
+pub async fn get_trade(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(trade_id): Path<Uuid>,
+) -> Result<Json<TradeResponse>, StatusCode> {
+    let command = TradeGetCommand::new(trade_id, auth_user);
+    let trade_service = TradeService::from(&state);
+
+    let trade = trade_service.get(command).await;
+    match trade {
+        Ok(trade) => {
+            match trade {
+                Some(trade) => Ok(Json(trade)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/investments/trades/{trade_id}",
+    params(
+        ("trade_id", description = "trade identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Trade updated successfully", body = TradeResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid trade, or sell exceeding the quantity held"),
+        (status = StatusCode::NOT_FOUND, description = "Trade not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Trade"
+)]
+pub async fn put_trade(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(trade_id): Path<Uuid>,
+    Json(trade_update_request): Json<TradeUpdateRequest>
+) -> Result<Json<TradeResponse>, StatusCode> {
+    let command = TradeUpdateCommand::new(trade_id, trade_update_request, auth_user);
+    let trade_service = TradeService::from(&state);
+
+    let trade = trade_service.update(command).await;
+    match trade {
+        Ok(trade) => {
+            match trade {
+                Some(trade) => Ok(Json(trade)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/investments/trades/{trade_id}",
+    params(
+        ("trade_id", description = "trade identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Trade deleted successfully"),
+        (status = StatusCode::BAD_REQUEST, description = "A later sell would exceed the quantity held"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Investment Trade"
+)]
+pub async fn delete_trade(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(trade_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = TradeDeleteCommand::new(trade_id, auth_user);
+    let trade_service = TradeService::from(&state);
+
+    let response = trade_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(e) => Err(error_status(&e))
+    }
+}
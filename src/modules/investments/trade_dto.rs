@@ -0,0 +1,79 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::investments::trade_model::{Trade, TradeType};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TradeResponse {
+    pub trade_id: Uuid,
+    pub portfolio_id: Uuid,
+    pub instrument_id: Option<Uuid>,
+    pub trade_type: TradeType,
+    pub trade_date: NaiveDate,
+
+    pub trade_quantity: Option<Decimal>,
+    pub trade_price: Option<Decimal>,
+    pub trade_amount_minor: Option<i64>,
+    pub trade_fee_minor: i64,
+    pub trade_split_ratio: Option<Decimal>,
+    pub trade_note: Option<String>,
+
+    pub trade_created_at: Option<DateTime<Utc>>,
+    pub trade_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Trade> for TradeResponse {
+    fn from(trade: &Trade) -> Self {
+        Self {
+            trade_id: bu(trade.id.clone().unwrap().as_slice()),
+            portfolio_id: bu(trade.portfolio_id.as_slice()),
+            instrument_id: obu(trade.instrument_id.as_deref()),
+            trade_type: trade.trade_type,
+            trade_date: trade.trade_date,
+            trade_quantity: trade.quantity,
+            trade_price: trade.price,
+            trade_amount_minor: trade.amount_minor,
+            trade_fee_minor: trade.fee_minor,
+            trade_split_ratio: trade.split_ratio,
+            trade_note: trade.note.clone(),
+            trade_created_at: trade.created_at,
+            trade_updated_at: trade.updated_at,
+        }
+    }
+}
+
+/// Buy/sell: instrument, quantity, unit price and optional commission.
+/// Dividend: instrument and amount. Fee: amount, instrument optional.
+/// Split: instrument and ratio (2 for a 2-for-1 split).
+/// Amounts are in minor units of the instrument currency, or the portfolio currency for a fee without instrument.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TradeCreateRequest {
+    pub instrument_id: Option<Uuid>,
+    pub trade_type: TradeType,
+    pub trade_date: NaiveDate,
+    pub trade_quantity: Option<Decimal>,
+    pub trade_price: Option<Decimal>,
+    pub trade_amount_minor: Option<i64>,
+    /// 0 by default
+    pub trade_fee_minor: Option<i64>,
+    pub trade_split_ratio: Option<Decimal>,
+    pub trade_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TradeUpdateRequest {
+    pub instrument_id: Option<Uuid>,
+    pub trade_type: TradeType,
+    pub trade_date: NaiveDate,
+    pub trade_quantity: Option<Decimal>,
+    pub trade_price: Option<Decimal>,
+    pub trade_amount_minor: Option<i64>,
+    pub trade_fee_minor: i64,
+    pub trade_split_ratio: Option<Decimal>,
+    pub trade_note: Option<String>,
+}
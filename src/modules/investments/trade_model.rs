@@ -0,0 +1,184 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::investments::trade_command::TradeCreateCommand;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum TradeType {
+    Buy,
+    Sell,
+    Fee,
+    Dividend,
+    Split,
+}
+
+impl TradeType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeType::Buy => "buy",
+            TradeType::Sell => "sell",
+            TradeType::Fee => "fee",
+            TradeType::Dividend => "dividend",
+            TradeType::Split => "split",
+        }
+    }
+}
+
+
+/// Operation of a portfolio.
+/// Buy/sell use `quantity`, `price` and `fee_minor`; dividend and fee use `amount_minor`;
+/// split uses `split_ratio` (new units per old unit). Amounts are in the instrument currency,
+/// or the portfolio currency for a fee without instrument.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Trade {
+    pub id: Option<Vec<u8>>,
+
+    pub portfolio_id: Vec<u8>,
+    pub instrument_id: Option<Vec<u8>>,
+    pub trade_type: TradeType,
+    pub trade_date: NaiveDate,
+
+    pub quantity: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub amount_minor: Option<i64>,
+    pub fee_minor: i64,
+    pub split_ratio: Option<Decimal>,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Trade {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            portfolio_id: row.try_get(index_map["portfolio_id"])?,
+            instrument_id: row.try_get(index_map["instrument_id"])?,
+            trade_type: row.try_get(index_map["trade_type"])?,
+            trade_date: row.try_get(index_map["trade_date"])?,
+            quantity: row.try_get(index_map["quantity"])?,
+            price: row.try_get(index_map["price"])?,
+            amount_minor: row.try_get(index_map["amount_minor"])?,
+            fee_minor: row.try_get(index_map["fee_minor"])?,
+            split_ratio: row.try_get(index_map["split_ratio"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<TradeCreateCommand> for Trade {
+    fn from(command: TradeCreateCommand) -> Self {
+        Self {
+            id: None,
+            portfolio_id: ub(command.portfolio_id),
+            instrument_id: oub(command.instrument_id),
+            trade_type: command.trade_type,
+            trade_date: command.trade_date,
+            quantity: command.trade_quantity,
+            price: command.trade_price,
+            amount_minor: command.trade_amount_minor,
+            fee_minor: command.trade_fee_minor.unwrap_or(0),
+            split_ratio: command.trade_split_ratio,
+            note: command.trade_note,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Position of one instrument at average cost, built by applying its trades in date order.
+/// Amounts are in minor units of the instrument currency, kept unrounded until the response.
+#[derive(Debug, Clone, Default)]
+pub struct Holding {
+    pub quantity: Decimal,
+    /// Cost of the units held, buy fees included
+    pub cost_minor: Decimal,
+    /// Sell proceeds net of fees minus the average cost of the units sold
+    pub realized_minor: Decimal,
+    pub dividends_minor: i64,
+    /// Buy/sell commissions and fee operations
+    pub fees_minor: i64,
+}
+
+impl Holding {
+    /// Applies the trade; `scale` is 10^minor unit of the instrument currency.
+    /// False when a sell exceeds the quantity held, the holding is then left unchanged.
+    pub fn apply(&mut self, trade: &Trade, scale: Decimal) -> bool {
+        let quantity = trade.quantity.unwrap_or_default();
+        let gross = quantity * trade.price.unwrap_or_default() * scale;
+        match trade.trade_type {
+            TradeType::Buy => {
+                self.quantity += quantity;
+                self.cost_minor += gross + Decimal::from(trade.fee_minor);
+                self.fees_minor += trade.fee_minor;
+            },
+            TradeType::Sell => {
+                if quantity > self.quantity {
+                    return false;
+                }
+                let released = if quantity == self.quantity {
+                    self.cost_minor
+                } else {
+                    self.cost_minor * quantity / self.quantity
+                };
+                self.realized_minor += gross - Decimal::from(trade.fee_minor) - released;
+                self.cost_minor -= released;
+                self.quantity -= quantity;
+                self.fees_minor += trade.fee_minor;
+            },
+            TradeType::Dividend => self.dividends_minor += trade.amount_minor.unwrap_or(0),
+            TradeType::Fee => self.fees_minor += trade.amount_minor.unwrap_or(0),
+            TradeType::Split => self.quantity *= trade.split_ratio.unwrap_or(Decimal::ONE),
+        }
+        true
+    }
+
+    /// Cost per unit in the instrument currency, None once the position is closed
+    pub fn average_cost(&self, scale: Decimal) -> Option<Decimal> {
+        (self.quantity > Decimal::ZERO).then(|| self.cost_minor / self.quantity / scale)
+    }
+}
+
+
+/// Holdings of a portfolio by instrument id, with the fees not tied to an instrument
+#[derive(Debug, Clone, Default)]
+pub struct Holdings {
+    pub by_instrument: HashMap<Vec<u8>, Holding>,
+    pub portfolio_fees_minor: i64,
+}
+
+impl Holdings {
+    /// Replays the trades by date, then creation (unsaved trades last on their day).
+    /// `scales` gives 10^minor unit per instrument id, 1 when missing.
+    /// Err with the first sell exceeding the quantity held at that point.
+    pub fn replay<'a>(trades: &'a [Trade], scales: &HashMap<Vec<u8>, Decimal>) -> Result<Self, &'a Trade> {
+        let mut ordered: Vec<&Trade> = trades.iter().collect();
+        ordered.sort_by_key(|trade| (trade.trade_date, trade.created_at.unwrap_or(DateTime::<Utc>::MAX_UTC)));
+
+        let mut holdings = Self::default();
+        for trade in ordered {
+            let Some(instrument_id) = trade.instrument_id.as_ref() else {
+                holdings.portfolio_fees_minor += trade.amount_minor.unwrap_or(0);
+                continue;
+            };
+            let scale = scales.get(instrument_id).copied().unwrap_or(Decimal::ONE);
+            if !holdings.by_instrument.entry(instrument_id.clone()).or_default().apply(trade, scale) {
+                return Err(trade);
+            }
+        }
+        Ok(holdings)
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::investments::trade_model::Trade;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait TradeRepositoryInterface {
+
+    async fn get(&self, trade_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Trade>, Error>;
+
+    async fn create(&self, trade: Trade, meta_user: Option<Uuid>) -> Result<Trade, Error>;
+
+    async fn update(&self, trade: Trade, meta_user: Option<Uuid>) -> Result<Option<Trade>, Error>;
+
+    async fn delete(&self, trade_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by trade date then creation, the order positions are replayed in
+    async fn get_by_portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Trade>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct TradeRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for TradeRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Trade> for TradeRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl TradeRepositoryInterface for TradeRepository {
+    async fn get(&self, trade_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Trade>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(trade_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_investment_trade_get_by_id", params).await
+    }
+
+    async fn create(&self, trade: Trade, meta_user: Option<Uuid>) -> Result<Trade, Error> {
+        let params = vec![
+            MySqlParam::from(trade.portfolio_id),
+            MySqlParam::from(trade.instrument_id),
+            MySqlParam::from(trade.trade_type.as_str()),
+            MySqlParam::from(trade.trade_date),
+            MySqlParam::from(trade.quantity),
+            MySqlParam::from(trade.price),
+            MySqlParam::from(trade.amount_minor),
+            MySqlParam::from(trade.fee_minor),
+            MySqlParam::from(trade.split_ratio),
+            MySqlParam::from(trade.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_investment_trade_create", params).await
+    }
+
+    async fn update(&self, trade: Trade, meta_user: Option<Uuid>) -> Result<Option<Trade>, Error> {
+        let params = vec![
+            MySqlParam::from(trade.id),
+            MySqlParam::from(trade.instrument_id),
+            MySqlParam::from(trade.trade_type.as_str()),
+            MySqlParam::from(trade.trade_date),
+            MySqlParam::from(trade.quantity),
+            MySqlParam::from(trade.price),
+            MySqlParam::from(trade.amount_minor),
+            MySqlParam::from(trade.fee_minor),
+            MySqlParam::from(trade.split_ratio),
+            MySqlParam::from(trade.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_investment_trade_update", params).await
+    }
+
+    async fn delete(&self, trade_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(trade_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_investment_trade_delete", params).await
+    }
+
+    async fn get_by_portfolio(&self, portfolio_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Trade>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(portfolio_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_investment_trade_by_portfolio", params).await
+    }
+}
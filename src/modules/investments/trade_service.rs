@@ -0,0 +1,218 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::investments::{
+    instrument_model::Instrument,
+    instrument_repo::{InstrumentRepository, InstrumentRepositoryInterface},
+    portfolio_repo::{PortfolioRepository, PortfolioRepositoryInterface},
+    trade_command::*,
+    trade_dto::*,
+    trade_model::{Holdings, Trade, TradeType},
+    trade_repo::{TradeRepository, TradeRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, oub};
+
+
+#[async_trait]
+pub trait TradeServiceInterface {
+
+    async fn get(&self, command: TradeGetCommand) -> Result<Option<TradeResponse>, Error>;
+
+    /// None when the portfolio does not exist
+    async fn create(&self, command: TradeCreateCommand) -> Result<Option<TradeResponse>, Error>;
+
+    async fn update(&self, command: TradeUpdateCommand) -> Result<Option<TradeResponse>, Error>;
+
+    /// Refused when a later sell would then exceed the quantity held
+    async fn delete(&self, command: TradeDeleteCommand) -> Result<(), Error>;
+
+    /// None when the portfolio does not exist
+    async fn get_by_portfolio(&self, command: TradeListByPortfolioCommand) -> Result<Option<Vec<TradeResponse>>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct TradeService {
+    portfolio_repo: PortfolioRepository,
+    instrument_repo: InstrumentRepository,
+    trade_repo: TradeRepository,
+}
+
+impl From<&AppState> for TradeService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            portfolio_repo: PortfolioRepository::from(app_state),
+            instrument_repo: InstrumentRepository::from(app_state),
+            trade_repo: TradeRepository::from(app_state),
+        }
+    }
+}
+
+impl TradeService {
+    fn validate(trade: &Trade) -> Result<(), Error> {
+        if trade.trade_type != TradeType::Fee && trade.instrument_id.is_none() {
+            return Err(AppError::BadRequest(format!("an instrument is required for a {} trade", trade.trade_type.as_str())).into());
+        }
+        if trade.fee_minor < 0 {
+            return Err(AppError::BadRequest("trade fee cannot be negative".into()).into());
+        }
+        match trade.trade_type {
+            TradeType::Buy | TradeType::Sell => {
+                if trade.quantity.is_none_or(|quantity| quantity <= Decimal::ZERO) {
+                    return Err(AppError::BadRequest("trade quantity must be positive".into()).into());
+                }
+                if trade.price.is_none_or(|price| price < Decimal::ZERO) {
+                    return Err(AppError::BadRequest("trade price is required and cannot be negative".into()).into());
+                }
+            },
+            TradeType::Dividend | TradeType::Fee => {
+                if trade.amount_minor.is_none_or(|amount| amount <= 0) {
+                    return Err(AppError::BadRequest("trade amount must be positive".into()).into());
+                }
+            },
+            TradeType::Split => {
+                if trade.split_ratio.is_none_or(|ratio| ratio <= Decimal::ZERO) {
+                    return Err(AppError::BadRequest("split ratio must be positive".into()).into());
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// The instrument of the trade must be one of the user's
+    async fn check_instrument(&self, trade: &Trade, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let Some(instrument_id) = trade.instrument_id.as_deref() else {
+            return Ok(());
+        };
+        let instrument: Option<Instrument> = match self.instrument_repo.get(bu(instrument_id), meta_user).await {
+            Ok(instrument) => instrument,
+            Err(_) => return Err(Error::msg("Error getting instrument")),
+        };
+        if instrument.is_none() {
+            return Err(AppError::BadRequest("instrument not found".into()).into());
+        }
+        Ok(())
+    }
+
+    /// Replays the portfolio trades with `change` applied (new or updated trade, or removal of `removed`)
+    /// and refuses the change when a sell would exceed the quantity held
+    async fn check_sequence(&self, portfolio_id: Uuid, change: Option<&Trade>, removed: Option<&[u8]>, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let mut trades = match self.trade_repo.get_by_portfolio(portfolio_id, meta_user).await {
+            Ok(trades) => trades,
+            Err(_) => return Err(Error::msg("Error getting portfolio trades")),
+        };
+        trades.retain(|trade| trade.id.as_deref() != removed);
+        if let Some(change) = change {
+            trades.push(change.clone());
+        }
+
+        // Quantities only: no currency scale needed
+        match Holdings::replay(&trades, &HashMap::new()) {
+            Ok(_) => Ok(()),
+            Err(trade) => Err(AppError::BadRequest(format!("sell on {} exceeds the quantity held", trade.trade_date)).into()),
+        }
+    }
+}
+
+#[async_trait]
+impl TradeServiceInterface for TradeService {
+    async fn get(&self, command: TradeGetCommand) -> Result<Option<TradeResponse>, Error> {
+        let trade = self.trade_repo.get(command.trade_id, Some(command.auth_user.user_id)).await;
+        match trade {
+            Ok(trade) => Ok(trade.as_ref().map(TradeResponse::from)),
+            Err(_) => Err(Error::msg("Error getting trade")),
+        }
+    }
+
+    async fn create(&self, command: TradeCreateCommand) -> Result<Option<TradeResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let portfolio_id = command.portfolio_id;
+        let trade = Trade::from(command);
+        Self::validate(&trade)?;
+
+        match self.portfolio_repo.get(portfolio_id, meta_user).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting portfolio")),
+        }
+        self.check_instrument(&trade, meta_user).await?;
+        if trade.trade_type == TradeType::Sell {
+            self.check_sequence(portfolio_id, Some(&trade), None, meta_user).await?;
+        }
+
+        let trade = self.trade_repo.create(trade, meta_user).await;
+        match trade {
+            Ok(trade) => Ok(Some(TradeResponse::from(&trade))),
+            Err(_) => Err(Error::msg("Error creating trade")),
+        }
+    }
+
+    async fn update(&self, command: TradeUpdateCommand) -> Result<Option<TradeResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let trade = match self.trade_repo.get(command.trade_id, meta_user).await {
+            Ok(Some(trade)) => trade,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting trade")),
+        };
+
+        let trade_update = Trade {
+            instrument_id: oub(command.instrument_id),
+            trade_type: command.trade_type,
+            trade_date: command.trade_date,
+            quantity: command.trade_quantity,
+            price: command.trade_price,
+            amount_minor: command.trade_amount_minor,
+            fee_minor: command.trade_fee_minor,
+            split_ratio: command.trade_split_ratio,
+            note: command.trade_note,
+            ..trade
+        };
+        Self::validate(&trade_update)?;
+        self.check_instrument(&trade_update, meta_user).await?;
+        self.check_sequence(bu(&trade_update.portfolio_id), Some(&trade_update), trade_update.id.as_deref(), meta_user).await?;
+
+        let trade = self.trade_repo.update(trade_update, meta_user).await;
+        match trade {
+            Ok(trade) => Ok(trade.as_ref().map(TradeResponse::from)),
+            Err(_) => Err(Error::msg("Error updating trade")),
+        }
+    }
+
+    async fn delete(&self, command: TradeDeleteCommand) -> Result<(), Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let trade = match self.trade_repo.get(command.trade_id, meta_user).await {
+            Ok(Some(trade)) => trade,
+            Ok(None) => return Ok(()),
+            Err(_) => return Err(Error::msg("Error getting trade")),
+        };
+        if matches!(trade.trade_type, TradeType::Buy | TradeType::Split) {
+            self.check_sequence(bu(&trade.portfolio_id), None, trade.id.as_deref(), meta_user).await?;
+        }
+
+        let result = self.trade_repo.delete(command.trade_id, meta_user).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting trade")),
+        }
+    }
+
+    async fn get_by_portfolio(&self, command: TradeListByPortfolioCommand) -> Result<Option<Vec<TradeResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        match self.portfolio_repo.get(command.portfolio_id, meta_user).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting portfolio")),
+        }
+
+        let trades = self.trade_repo.get_by_portfolio(command.portfolio_id, meta_user).await;
+        match trades {
+            Ok(trades) => Ok(Some(trades.iter().map(TradeResponse::from).collect())),
+            Err(_) => Err(Error::msg("Error getting portfolio trades")),
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod categories;
 pub mod transactions;
 pub mod budgets;
 pub mod goals;
+pub mod automations;
 pub mod projects;
 pub mod investments;
 pub mod reviews;
@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::{
+    milestone_dto::{MilestoneCreateRequest, MilestoneUpdateRequest},
+    milestone_model::MilestoneStatus,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneGetCommand {
+    pub milestone_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl MilestoneGetCommand {
+    pub fn new(milestone_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { milestone_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneCreateCommand {
+    pub project_id: Uuid,
+    pub milestone_title: String,
+
+    pub milestone_due_date: Option<NaiveDate>,
+    pub milestone_budget_base_minor: Option<i64>,
+    pub milestone_person_id: Option<Uuid>,
+    pub milestone_location_id: Option<Uuid>,
+    pub milestone_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl MilestoneCreateCommand {
+    pub fn new(project_id: Uuid, request: MilestoneCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            milestone_title: request.milestone_title,
+            milestone_due_date: request.milestone_due_date,
+            milestone_budget_base_minor: request.milestone_budget_base_minor,
+            milestone_person_id: request.milestone_person_id,
+            milestone_location_id: request.milestone_location_id,
+            milestone_note: request.milestone_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneUpdateCommand {
+    pub milestone_id: Uuid,
+
+    pub milestone_title: String,
+    pub milestone_due_date: Option<NaiveDate>,
+    pub milestone_status: MilestoneStatus,
+    pub milestone_budget_base_minor: Option<i64>,
+    pub milestone_person_id: Option<Uuid>,
+    pub milestone_location_id: Option<Uuid>,
+    pub milestone_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl MilestoneUpdateCommand {
+    pub fn new(milestone_id: Uuid, request: MilestoneUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            milestone_id,
+            milestone_title: request.milestone_title,
+            milestone_due_date: request.milestone_due_date,
+            milestone_status: request.milestone_status,
+            milestone_budget_base_minor: request.milestone_budget_base_minor,
+            milestone_person_id: request.milestone_person_id,
+            milestone_location_id: request.milestone_location_id,
+            milestone_note: request.milestone_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneDeleteCommand {
+    pub milestone_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl MilestoneDeleteCommand {
+    pub fn new(milestone_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { milestone_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneListByProjectCommand {
+    pub project_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl MilestoneListByProjectCommand {
+    pub fn new(project_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { project_id, auth_user }
+    }
+}
+
+/// Attaches (or detaches) a task or a transaction of the same project
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneAttachCommand {
+    pub milestone_id: Uuid,
+    pub entity_id: Uuid,
+    pub attached: bool,
+
+    pub auth_user: AuthUser,
+}
+
+impl MilestoneAttachCommand {
+    pub fn new(milestone_id: Uuid, entity_id: Uuid, attached: bool, auth_user: AuthUser) -> Self {
+        Self { milestone_id, entity_id, attached, auth_user }
+    }
+}
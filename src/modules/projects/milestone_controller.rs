@@ -0,0 +1,364 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, put}, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    milestone_command::*,
+    milestone_dto::*,
+    milestone_service::{MilestoneService, MilestoneServiceInterface},
+    task_dto::TaskResponse
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{project_id}/milestones", get(get_project_milestones).post(post_project_milestone))
+        .route("/milestones/{milestone_id}", get(get_project_milestone).put(put_project_milestone).delete(delete_project_milestone))
+        .route("/milestones/{milestone_id}/tasks", get(get_project_milestone_tasks))
+        .route("/milestones/{milestone_id}/tasks/{task_id}", put(put_project_milestone_task).delete(delete_project_milestone_task))
+        .route("/milestones/{milestone_id}/transactions/{transaction_id}", put(put_project_milestone_transaction).delete(delete_project_milestone_transaction))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/milestones",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Milestones of the project with remaining work, spending and risk", body = Vec<MilestoneResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn get_project_milestones(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<MilestoneResponse>>, StatusCode> {
+    let command = MilestoneListByProjectCommand::new(project_id, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestones = milestone_service.get_by_project(command).await;
+    match milestones {
+        Ok(milestones) => Ok(Json(milestones)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/{project_id}/milestones",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Milestone successfully created", body = MilestoneResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid title or budget"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn post_project_milestone(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(milestone_create_request): Json<MilestoneCreateRequest>
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneCreateCommand::new(project_id, milestone_create_request, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.create(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/milestones/{milestone_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Milestone found successfully", body = MilestoneResponse),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn get_project_milestone(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(milestone_id): Path<Uuid>,
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneGetCommand::new(milestone_id, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.get(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/milestones/{milestone_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Milestone successfully updated", body = MilestoneResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid title or budget"),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn put_project_milestone(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(milestone_id): Path<Uuid>,
+    Json(milestone_update_request): Json<MilestoneUpdateRequest>
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneUpdateCommand::new(milestone_id, milestone_update_request, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.update(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/milestones/{milestone_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Milestone successfully deleted, its tasks and transactions are detached"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn delete_project_milestone(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(milestone_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = MilestoneDeleteCommand::new(milestone_id, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let response = milestone_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/milestones/{milestone_id}/tasks",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Tasks attached to the milestone", body = Vec<TaskResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn get_project_milestone_tasks(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(milestone_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskResponse>>, StatusCode> {
+    let command = MilestoneGetCommand::new(milestone_id, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let tasks = milestone_service.tasks(command).await;
+    match tasks {
+        Ok(tasks) => {
+            match tasks {
+                Some(tasks) => Ok(Json(tasks)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/milestones/{milestone_id}/tasks/{task_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid"),
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task attached to the milestone", body = MilestoneResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Task is not part of the milestone project"),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn put_project_milestone_task(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((milestone_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneAttachCommand::new(milestone_id, task_id, true, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.attach_task(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/milestones/{milestone_id}/tasks/{task_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid"),
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task detached from the milestone", body = MilestoneResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Task is not part of the milestone project"),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn delete_project_milestone_task(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((milestone_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneAttachCommand::new(milestone_id, task_id, false, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.attach_task(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/milestones/{milestone_id}/transactions/{transaction_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid"),
+        ("transaction_id", description = "transaction identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Transaction of the project attached to the milestone", body = MilestoneResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Transaction is not part of the milestone project"),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn put_project_milestone_transaction(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((milestone_id, transaction_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneAttachCommand::new(milestone_id, transaction_id, true, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.attach_transaction(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/milestones/{milestone_id}/transactions/{transaction_id}",
+    params(
+        ("milestone_id", description = "milestone identifier in uuid"),
+        ("transaction_id", description = "transaction identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Transaction of the project detached from the milestone", body = MilestoneResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Transaction is not part of the milestone project"),
+        (status = StatusCode::NOT_FOUND, description = "Milestone not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Milestone"
+)]
+pub async fn delete_project_milestone_transaction(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((milestone_id, transaction_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<MilestoneResponse>, StatusCode> {
+    let command = MilestoneAttachCommand::new(milestone_id, transaction_id, false, auth_user);
+    let milestone_service = MilestoneService::from(&state);
+
+    let milestone = milestone_service.attach_transaction(command).await;
+    match milestone {
+        Ok(milestone) => {
+            match milestone {
+                Some(milestone) => Ok(Json(milestone)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::projects::milestone_model::{Milestone, MilestoneStatus};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MilestoneResponse {
+    pub milestone_id: Uuid,
+    pub project_id: Uuid,
+    pub milestone_title: String,
+    pub milestone_due_date: Option<NaiveDate>,
+    pub milestone_status: MilestoneStatus,
+    pub milestone_budget_base_minor: Option<i64>,
+    pub milestone_person_id: Option<Uuid>,
+    pub milestone_location_id: Option<Uuid>,
+    pub milestone_note: Option<String>,
+
+    pub milestone_progress: MilestoneProgressResponse,
+
+    pub milestone_created_at: Option<DateTime<Utc>>,
+    pub milestone_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Milestone> for MilestoneResponse {
+    fn from(milestone: &Milestone) -> Self {
+        Self {
+            milestone_id: bu(milestone.id.clone().unwrap().as_slice()),
+            project_id: bu(milestone.project_id.as_slice()),
+            milestone_title: milestone.title.clone(),
+            milestone_due_date: milestone.due_date,
+            milestone_status: milestone.status,
+            milestone_budget_base_minor: milestone.budget_base_minor,
+            milestone_person_id: obu(milestone.person_id.as_deref()),
+            milestone_location_id: obu(milestone.location_id.as_deref()),
+            milestone_note: milestone.note.clone(),
+            milestone_progress: MilestoneProgressResponse::default(),
+            milestone_created_at: milestone.created_at,
+            milestone_updated_at: milestone.updated_at,
+        }
+    }
+}
+
+/// Remaining work and spending of the tasks and transactions attached to the milestone
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct MilestoneProgressResponse {
+    pub task_count: i64,
+    /// Tasks not done yet
+    pub remaining_task_count: i64,
+    pub estimate_minutes: i64,
+    pub actual_minutes: i64,
+    /// Sum of max(estimate - actual, 0) over the remaining tasks
+    pub remaining_estimate_minutes: i64,
+
+    pub spent_base_minor: i64,
+    /// None without budget, negative once over budget
+    pub remaining_budget_base_minor: Option<i64>,
+    pub budget_used_percent: Option<Decimal>,
+
+    /// Negative once the due date is passed
+    pub days_left: Option<i64>,
+    pub milestone_overdue: bool,
+    /// Planned milestone due within the risk window (or overdue) with open work left
+    pub milestone_at_risk: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MilestoneCreateRequest {
+    pub milestone_title: String,
+    pub milestone_due_date: Option<NaiveDate>,
+    pub milestone_budget_base_minor: Option<i64>,
+    pub milestone_person_id: Option<Uuid>,
+    pub milestone_location_id: Option<Uuid>,
+    pub milestone_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MilestoneUpdateRequest {
+    pub milestone_title: String,
+    pub milestone_due_date: Option<NaiveDate>,
+    pub milestone_status: MilestoneStatus,
+    pub milestone_budget_base_minor: Option<i64>,
+    pub milestone_person_id: Option<Uuid>,
+    pub milestone_location_id: Option<Uuid>,
+    pub milestone_note: Option<String>,
+}
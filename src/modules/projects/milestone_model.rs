@@ -0,0 +1,118 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::projects::milestone_command::MilestoneCreateCommand;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum MilestoneStatus {
+    Planned,
+    Done,
+    Cancelled,
+}
+
+impl MilestoneStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MilestoneStatus::Planned => "planned",
+            MilestoneStatus::Done => "done",
+            MilestoneStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Milestone {
+    pub id: Option<Vec<u8>>,
+
+    pub project_id: Vec<u8>,
+    pub title: String,
+    pub due_date: Option<NaiveDate>,
+    pub status: MilestoneStatus,
+    pub budget_base_minor: Option<i64>,
+
+    pub person_id: Option<Vec<u8>>,
+    pub location_id: Option<Vec<u8>>,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Milestone {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+            title: row.try_get(index_map["title"])?,
+            due_date: row.try_get(index_map["due_date"])?,
+            status: row.try_get(index_map["status"])?,
+            budget_base_minor: row.try_get(index_map["budget_base_minor"])?,
+            person_id: row.try_get(index_map["person_id"])?,
+            location_id: row.try_get(index_map["location_id"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<MilestoneCreateCommand> for Milestone {
+    fn from(command: MilestoneCreateCommand) -> Self {
+        Self {
+            id: None,
+            project_id: ub(command.project_id),
+            title: command.milestone_title,
+            due_date: command.milestone_due_date,
+            status: MilestoneStatus::Planned,
+            budget_base_minor: command.milestone_budget_base_minor,
+            person_id: oub(command.milestone_person_id),
+            location_id: oub(command.milestone_location_id),
+            note: command.milestone_note,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// Spending of a milestone: project expenses attached to it, as a positive amount (refunds deduct)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MilestoneSpent {
+    pub milestone_id: Vec<u8>,
+    pub spent_base_minor: i64,
+}
+
+impl FromSqlRow for MilestoneSpent {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            milestone_id: row.try_get(index_map["milestone_id"])?,
+            spent_base_minor: row.try_get(index_map["spent_base_minor"])?,
+        })
+    }
+}
+
+
+/// Project link of a transaction of the current user, checked before attaching it to a milestone
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MilestoneTransaction {
+    pub transaction_id: Vec<u8>,
+    pub project_id: Option<Vec<u8>>,
+}
+
+impl FromSqlRow for MilestoneTransaction {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            transaction_id: row.try_get(index_map["transaction_id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+        })
+    }
+}
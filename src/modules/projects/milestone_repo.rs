@@ -0,0 +1,216 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::milestone_model::{Milestone, MilestoneSpent, MilestoneTransaction};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait MilestoneRepositoryInterface {
+
+    async fn get(&self, milestone_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Milestone>, Error>;
+
+    async fn create(&self, milestone: Milestone, meta_user: Option<Uuid>) -> Result<Milestone, Error>;
+
+    async fn update(&self, milestone: Milestone, meta_user: Option<Uuid>) -> Result<Option<Milestone>, Error>;
+
+    /// Attached tasks and transactions are kept, detached
+    async fn delete(&self, milestone_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by due date, milestones without due date last
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Milestone>, Error>;
+
+    /// Sets (or clears with `attached` false) project_tasks.milestone_id.
+    /// The task must belong to the milestone project.
+    async fn attach_task(&self, milestone_id: Uuid, task_id: Uuid, attached: bool, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Sets (or clears with `attached` false) transactions.milestone_id.
+    /// The transaction must belong to the milestone project.
+    async fn attach_transaction(&self, milestone_id: Uuid, transaction_id: Uuid, attached: bool, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct MilestoneRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for MilestoneRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Milestone> for MilestoneRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl MilestoneRepositoryInterface for MilestoneRepository {
+    async fn get(&self, milestone_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Milestone>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(milestone_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_milestone_get_by_id", params).await
+    }
+
+    async fn create(&self, milestone: Milestone, meta_user: Option<Uuid>) -> Result<Milestone, Error> {
+        let params = vec![
+            MySqlParam::from(milestone.project_id),
+            MySqlParam::from(milestone.title),
+            MySqlParam::from(milestone.due_date),
+            MySqlParam::from(milestone.status.as_str()),
+            MySqlParam::from(milestone.budget_base_minor),
+            MySqlParam::from(milestone.person_id),
+            MySqlParam::from(milestone.location_id),
+            MySqlParam::from(milestone.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_milestone_create", params).await
+    }
+
+    async fn update(&self, milestone: Milestone, meta_user: Option<Uuid>) -> Result<Option<Milestone>, Error> {
+        let params = vec![
+            MySqlParam::from(milestone.id),
+            MySqlParam::from(milestone.title),
+            MySqlParam::from(milestone.due_date),
+            MySqlParam::from(milestone.status.as_str()),
+            MySqlParam::from(milestone.budget_base_minor),
+            MySqlParam::from(milestone.person_id),
+            MySqlParam::from(milestone.location_id),
+            MySqlParam::from(milestone.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_milestone_update", params).await
+    }
+
+    async fn delete(&self, milestone_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(milestone_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_milestone_delete", params).await
+    }
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Milestone>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_milestone_by_project", params).await
+    }
+
+    async fn attach_task(&self, milestone_id: Uuid, task_id: Uuid, attached: bool, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(milestone_id)),
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(attached),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_milestone_attach_task", params).await
+    }
+
+    async fn attach_transaction(&self, milestone_id: Uuid, transaction_id: Uuid, attached: bool, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(milestone_id)),
+            MySqlParam::from(ub(transaction_id)),
+            MySqlParam::from(attached),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_milestone_attach_transaction", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait MilestoneSpentRepositoryInterface {
+
+    /// One row per milestone of the project having attached transactions
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<MilestoneSpent>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct MilestoneSpentRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for MilestoneSpentRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<MilestoneSpent> for MilestoneSpentRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl MilestoneSpentRepositoryInterface for MilestoneSpentRepository {
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<MilestoneSpent>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_milestone_spent_by_project", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait MilestoneTransactionRepositoryInterface {
+
+    /// None when the transaction does not exist or belongs to another user
+    async fn get(&self, transaction_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<MilestoneTransaction>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct MilestoneTransactionRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for MilestoneTransactionRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<MilestoneTransaction> for MilestoneTransactionRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl MilestoneTransactionRepositoryInterface for MilestoneTransactionRepository {
+    async fn get(&self, transaction_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<MilestoneTransaction>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(transaction_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_milestone_transaction_get_by_id", params).await
+    }
+}
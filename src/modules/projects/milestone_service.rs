@@ -0,0 +1,298 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    milestone_command::*,
+    milestone_dto::*,
+    milestone_model::{Milestone, MilestoneSpent, MilestoneStatus},
+    milestone_repo::{
+        MilestoneRepository, MilestoneRepositoryInterface,
+        MilestoneSpentRepository, MilestoneSpentRepositoryInterface,
+        MilestoneTransactionRepository, MilestoneTransactionRepositoryInterface
+    },
+    project_repo::{ProjectRepository, ProjectRepositoryInterface},
+    task_dto::TaskResponse,
+    task_model::{Task, TaskStatus},
+    task_repo::{TaskRepository, TaskRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, oub};
+
+
+/// A planned milestone with open work is at risk from this many days before its due date
+const AT_RISK_DAYS: i64 = 7;
+
+
+#[async_trait]
+pub trait MilestoneServiceInterface {
+
+    async fn get(&self, command: MilestoneGetCommand) -> Result<Option<MilestoneResponse>, Error>;
+
+    /// None when the project does not exist
+    async fn create(&self, command: MilestoneCreateCommand) -> Result<Option<MilestoneResponse>, Error>;
+
+    async fn update(&self, command: MilestoneUpdateCommand) -> Result<Option<MilestoneResponse>, Error>;
+
+    async fn delete(&self, command: MilestoneDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_project(&self, command: MilestoneListByProjectCommand) -> Result<Vec<MilestoneResponse>, Error>;
+
+
+    // --- Attached work ---
+
+    async fn tasks(&self, command: MilestoneGetCommand) -> Result<Option<Vec<TaskResponse>>, Error>;
+
+    async fn attach_task(&self, command: MilestoneAttachCommand) -> Result<Option<MilestoneResponse>, Error>;
+
+    async fn attach_transaction(&self, command: MilestoneAttachCommand) -> Result<Option<MilestoneResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct MilestoneService {
+    project_repo: ProjectRepository,
+    milestone_repo: MilestoneRepository,
+    spent_repo: MilestoneSpentRepository,
+    transaction_repo: MilestoneTransactionRepository,
+    task_repo: TaskRepository,
+}
+
+impl From<&AppState> for MilestoneService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            project_repo: ProjectRepository::from(app_state),
+            milestone_repo: MilestoneRepository::from(app_state),
+            spent_repo: MilestoneSpentRepository::from(app_state),
+            transaction_repo: MilestoneTransactionRepository::from(app_state),
+            task_repo: TaskRepository::from(app_state),
+        }
+    }
+}
+
+impl MilestoneService {
+    fn validate(title: &str, budget_base_minor: Option<i64>) -> Result<(), Error> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest("milestone title is required".into()).into());
+        }
+        if budget_base_minor.is_some_and(|budget| budget < 0) {
+            return Err(AppError::BadRequest("milestone budget cannot be negative".into()).into());
+        }
+        Ok(())
+    }
+
+    pub fn progress_of(milestone: &Milestone, tasks: &[Task], spent: &[MilestoneSpent], today: NaiveDate) -> MilestoneProgressResponse {
+        let milestone_id = milestone.id.as_deref();
+        let tasks: Vec<&Task> = tasks.iter()
+            .filter(|task| task.milestone_id.as_deref() == milestone_id)
+            .collect();
+        let remaining: Vec<&&Task> = tasks.iter()
+            .filter(|task| task.status != TaskStatus::Done)
+            .collect();
+        let remaining_estimate_minutes = remaining.iter()
+            .map(|task| (task.estimate_minutes.unwrap_or(0) as i64 - task.actual_minutes.unwrap_or(0) as i64).max(0))
+            .sum();
+
+        let spent_base_minor = spent.iter()
+            .filter(|spent| Some(spent.milestone_id.as_slice()) == milestone_id)
+            .map(|spent| spent.spent_base_minor)
+            .sum();
+        let budget_used_percent = milestone.budget_base_minor
+            .filter(|&budget| budget > 0)
+            .map(|budget| (Decimal::from(spent_base_minor) * Decimal::from(100) / Decimal::from(budget)).round_dp(2));
+
+        let days_left = milestone.due_date.map(|due_date| (due_date - today).num_days());
+        let planned = milestone.status == MilestoneStatus::Planned;
+        let open_work = !remaining.is_empty();
+
+        MilestoneProgressResponse {
+            task_count: tasks.len() as i64,
+            remaining_task_count: remaining.len() as i64,
+            estimate_minutes: tasks.iter().map(|task| task.estimate_minutes.unwrap_or(0) as i64).sum(),
+            actual_minutes: tasks.iter().map(|task| task.actual_minutes.unwrap_or(0) as i64).sum(),
+            remaining_estimate_minutes,
+            spent_base_minor,
+            remaining_budget_base_minor: milestone.budget_base_minor.map(|budget| budget - spent_base_minor),
+            budget_used_percent,
+            days_left,
+            milestone_overdue: planned && days_left.is_some_and(|days| days < 0),
+            milestone_at_risk: planned && open_work && days_left.is_some_and(|days| days <= AT_RISK_DAYS),
+        }
+    }
+
+    fn milestone_response(milestone: &Milestone, tasks: &[Task], spent: &[MilestoneSpent], today: NaiveDate) -> MilestoneResponse {
+        let mut response = MilestoneResponse::from(milestone);
+        response.milestone_progress = Self::progress_of(milestone, tasks, spent, today);
+        response
+    }
+
+    async fn project_work(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<(Vec<Task>, Vec<MilestoneSpent>), Error> {
+        let tasks = match self.task_repo.get_by_project(project_id, meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        let spent = match self.spent_repo.get_by_project(project_id, meta_user).await {
+            Ok(spent) => spent,
+            Err(_) => return Err(Error::msg("Error getting milestone spending")),
+        };
+        Ok((tasks, spent))
+    }
+
+    async fn with_progress(&self, milestone: Option<Milestone>, meta_user: Option<Uuid>) -> Result<Option<MilestoneResponse>, Error> {
+        let Some(milestone) = milestone else {
+            return Ok(None);
+        };
+        let (tasks, spent) = self.project_work(bu(milestone.project_id.as_slice()), meta_user).await?;
+        Ok(Some(Self::milestone_response(&milestone, &tasks, &spent, Utc::now().date_naive())))
+    }
+}
+
+#[async_trait]
+impl MilestoneServiceInterface for MilestoneService {
+    async fn get(&self, command: MilestoneGetCommand) -> Result<Option<MilestoneResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let milestone = match self.milestone_repo.get(command.milestone_id, meta_user).await {
+            Ok(milestone) => milestone,
+            Err(_) => return Err(Error::msg("Error getting milestone")),
+        };
+        self.with_progress(milestone, meta_user).await
+    }
+
+    async fn create(&self, command: MilestoneCreateCommand) -> Result<Option<MilestoneResponse>, Error> {
+        Self::validate(&command.milestone_title, command.milestone_budget_base_minor)?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        match self.project_repo.get(command.project_id, meta_user).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        }
+
+        let milestone_create = Milestone::from(command);
+        let milestone = match self.milestone_repo.create(milestone_create, meta_user).await {
+            Ok(milestone) => milestone,
+            Err(_) => return Err(Error::msg("Error creating milestone")),
+        };
+        self.with_progress(Some(milestone), meta_user).await
+    }
+
+    async fn update(&self, command: MilestoneUpdateCommand) -> Result<Option<MilestoneResponse>, Error> {
+        Self::validate(&command.milestone_title, command.milestone_budget_base_minor)?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        let milestone = match self.milestone_repo.get(command.milestone_id, meta_user).await {
+            Ok(Some(milestone)) => milestone,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting milestone")),
+        };
+
+        let milestone_update = Milestone {
+            title: command.milestone_title,
+            due_date: command.milestone_due_date,
+            status: command.milestone_status,
+            budget_base_minor: command.milestone_budget_base_minor,
+            person_id: oub(command.milestone_person_id),
+            location_id: oub(command.milestone_location_id),
+            note: command.milestone_note,
+            ..milestone
+        };
+
+        let milestone = match self.milestone_repo.update(milestone_update, meta_user).await {
+            Ok(milestone) => milestone,
+            Err(_) => return Err(Error::msg("Error updating milestone")),
+        };
+        self.with_progress(milestone, meta_user).await
+    }
+
+    async fn delete(&self, command: MilestoneDeleteCommand) -> Result<(), Error> {
+        let result = self.milestone_repo.delete(command.milestone_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting milestone")),
+        }
+    }
+
+    async fn get_by_project(&self, command: MilestoneListByProjectCommand) -> Result<Vec<MilestoneResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let milestones = match self.milestone_repo.get_by_project(command.project_id, meta_user).await {
+            Ok(milestones) => milestones,
+            Err(_) => return Err(Error::msg("Error getting milestones")),
+        };
+        if milestones.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (tasks, spent) = self.project_work(command.project_id, meta_user).await?;
+        let today = Utc::now().date_naive();
+        Ok(milestones.iter()
+            .map(|milestone| Self::milestone_response(milestone, &tasks, &spent, today))
+            .collect())
+    }
+
+    async fn tasks(&self, command: MilestoneGetCommand) -> Result<Option<Vec<TaskResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let milestone = match self.milestone_repo.get(command.milestone_id, meta_user).await {
+            Ok(Some(milestone)) => milestone,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting milestone")),
+        };
+
+        let tasks = match self.task_repo.get_by_project(bu(milestone.project_id.as_slice()), meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        Ok(Some(tasks.iter()
+            .filter(|task| task.milestone_id == milestone.id)
+            .map(TaskResponse::from)
+            .collect()))
+    }
+
+    async fn attach_task(&self, command: MilestoneAttachCommand) -> Result<Option<MilestoneResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let milestone = match self.milestone_repo.get(command.milestone_id, meta_user).await {
+            Ok(Some(milestone)) => milestone,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting milestone")),
+        };
+
+        let task = match self.task_repo.get(command.entity_id, meta_user).await {
+            Ok(task) => task,
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+        match task {
+            Some(task) if task.project_id == milestone.project_id => {},
+            _ => return Err(AppError::BadRequest("task is not part of the milestone project".into()).into()),
+        }
+
+        if self.milestone_repo.attach_task(command.milestone_id, command.entity_id, command.attached, meta_user).await.is_err() {
+            return Err(Error::msg("Error attaching task to milestone"));
+        }
+        self.with_progress(Some(milestone), meta_user).await
+    }
+
+    async fn attach_transaction(&self, command: MilestoneAttachCommand) -> Result<Option<MilestoneResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let milestone = match self.milestone_repo.get(command.milestone_id, meta_user).await {
+            Ok(Some(milestone)) => milestone,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting milestone")),
+        };
+
+        let transaction = match self.transaction_repo.get(command.entity_id, meta_user).await {
+            Ok(transaction) => transaction,
+            Err(_) => return Err(Error::msg("Error getting transaction")),
+        };
+        match transaction {
+            Some(transaction) if transaction.project_id.as_ref() == Some(&milestone.project_id) => {},
+            _ => return Err(AppError::BadRequest("transaction is not part of the milestone project".into()).into()),
+        }
+
+        if self.milestone_repo.attach_transaction(command.milestone_id, command.entity_id, command.attached, meta_user).await.is_err() {
+            return Err(Error::msg("Error attaching transaction to milestone"));
+        }
+        self.with_progress(Some(milestone), meta_user).await
+    }
+}
@@ -0,0 +1,42 @@
+pub mod project_model;
+mod project_repo;
+pub mod project_command;
+pub mod project_service;
+pub mod project_dto;
+pub mod project_controller;
+mod task_model;
+mod task_repo;
+mod task_command;
+mod task_service;
+pub mod task_dto;
+pub mod task_controller;
+mod milestone_model;
+mod milestone_repo;
+mod milestone_command;
+mod milestone_service;
+pub mod milestone_dto;
+pub mod milestone_controller;
+mod template_model;
+mod template_repo;
+mod template_command;
+mod template_service;
+pub mod template_dto;
+pub mod template_controller;
+mod time_entry_model;
+mod time_entry_repo;
+mod time_entry_command;
+mod time_entry_service;
+pub mod time_entry_dto;
+pub mod time_entry_controller;
+mod recurrence_model;
+mod recurrence_repo;
+mod recurrence_command;
+mod recurrence_service;
+pub mod recurrence_dto;
+pub mod recurrence_controller;
+pub mod purchase_model;
+pub mod purchase_repo;
+mod purchase_command;
+mod purchase_service;
+pub mod purchase_dto;
+pub mod purchase_controller;
\ No newline at end of file
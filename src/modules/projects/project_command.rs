@@ -0,0 +1,168 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::{
+    project_dto::{ProjectCreateRequest, ProjectFinancialsQuery, ProjectListQuery, ProjectStatusUpdateRequest, ProjectUpdateRequest},
+    project_model::ProjectStatus,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectGetCommand {
+    pub project_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectGetCommand {
+    pub fn new(project_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { project_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectCreateCommand {
+    pub user_id: Uuid,
+    pub project_name: String,
+
+    pub project_status: Option<ProjectStatus>,
+    pub project_priority: Option<i32>,
+    pub project_start_date: Option<NaiveDate>,
+    pub project_due_date: Option<NaiveDate>,
+    pub project_budget_base_minor: Option<i64>,
+    pub project_goal_id: Option<Uuid>,
+    pub project_person_id: Option<Uuid>,
+    pub project_location_id: Option<Uuid>,
+    pub project_description: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectCreateCommand {
+    pub fn new(request: ProjectCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            project_name: request.project_name,
+            project_status: request.project_status,
+            project_priority: request.project_priority,
+            project_start_date: request.project_start_date,
+            project_due_date: request.project_due_date,
+            project_budget_base_minor: request.project_budget_base_minor,
+            project_goal_id: request.project_goal_id,
+            project_person_id: request.project_person_id,
+            project_location_id: request.project_location_id,
+            project_description: request.project_description,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectUpdateCommand {
+    pub project_id: Uuid,
+
+    pub project_name: String,
+    pub project_priority: i32,
+    pub project_start_date: Option<NaiveDate>,
+    pub project_due_date: Option<NaiveDate>,
+    pub project_budget_base_minor: i64,
+    pub project_goal_id: Option<Uuid>,
+    pub project_person_id: Option<Uuid>,
+    pub project_location_id: Option<Uuid>,
+    pub project_description: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectUpdateCommand {
+    pub fn new(project_id: Uuid, request: ProjectUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            project_name: request.project_name,
+            project_priority: request.project_priority,
+            project_start_date: request.project_start_date,
+            project_due_date: request.project_due_date,
+            project_budget_base_minor: request.project_budget_base_minor,
+            project_goal_id: request.project_goal_id,
+            project_person_id: request.project_person_id,
+            project_location_id: request.project_location_id,
+            project_description: request.project_description,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectDeleteCommand {
+    pub project_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectDeleteCommand {
+    pub fn new(project_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { project_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectListByUserCommand {
+    pub user_id: Uuid,
+
+    pub status: Option<ProjectStatus>,
+    pub priority: Option<i32>,
+    pub person_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectListByUserCommand {
+    pub fn new(query: ProjectListQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            status: query.status,
+            priority: query.priority,
+            person_id: query.person_id,
+            location_id: query.location_id,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectStatusUpdateCommand {
+    pub project_id: Uuid,
+
+    pub project_status: ProjectStatus,
+    pub note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectStatusUpdateCommand {
+    pub fn new(project_id: Uuid, request: ProjectStatusUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            project_status: request.project_status,
+            note: request.note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectFinancialsCommand {
+    pub project_id: Uuid,
+    pub as_of: Option<NaiveDate>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectFinancialsCommand {
+    pub fn new(project_id: Uuid, query: ProjectFinancialsQuery, auth_user: AuthUser) -> Self {
+        Self { project_id, as_of: query.as_of, auth_user }
+    }
+}
@@ -0,0 +1,276 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, put}, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    project_command::*,
+    project_dto::*,
+    project_service::{ProjectService, ProjectServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_projects).post(post_project))
+        .route("/{project_id}", get(get_project).put(put_project).delete(delete_project))
+        .route("/{project_id}/status", put(put_project_status))
+        .route("/{project_id}/status-history", get(get_project_status_history))
+        .route("/{project_id}/financials", get(get_project_financials))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects",
+    params(
+        ProjectListQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Projects of current user, filtered by status, priority, person and location", body = Vec<ProjectResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn get_projects(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<ProjectListQuery>,
+) -> Result<Json<Vec<ProjectResponse>>, StatusCode> {
+    let command = ProjectListByUserCommand::new(query, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let projects = project_service.get_by_user(command).await;
+    match projects {
+        Ok(projects) => Ok(Json(projects)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects",
+    responses(
+        (status = StatusCode::OK, description = "Project successfully created", body = ProjectResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid name, budget, dates or initial status"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn post_project(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(project_create_request): Json<ProjectCreateRequest>
+) -> Result<Json<ProjectResponse>, StatusCode> {
+    let command = ProjectCreateCommand::new(project_create_request, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let project = project_service.create(command).await;
+    match project {
+        Ok(project) => Ok(Json(project)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project found successfully", body = ProjectResponse),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn get_project(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ProjectResponse>, StatusCode> {
+    let command = ProjectGetCommand::new(project_id, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let project = project_service.get(command).await;
+    match project {
+        Ok(project) => {
+            match project {
+                Some(project) => Ok(Json(project)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/{project_id}",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project successfully updated", body = ProjectResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid name, budget or dates"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn put_project(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(project_update_request): Json<ProjectUpdateRequest>
+) -> Result<Json<ProjectResponse>, StatusCode> {
+    let command = ProjectUpdateCommand::new(project_id, project_update_request, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let project = project_service.update(command).await;
+    match project {
+        Ok(project) => {
+            match project {
+                Some(project) => Ok(Json(project)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/{project_id}",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project deleted"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn delete_project(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = ProjectDeleteCommand::new(project_id, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let response = project_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/{project_id}/status",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project status changed and recorded in the history", body = ProjectResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Transition not allowed from the current status"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn put_project_status(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(status_update_request): Json<ProjectStatusUpdateRequest>
+) -> Result<Json<ProjectResponse>, StatusCode> {
+    let command = ProjectStatusUpdateCommand::new(project_id, status_update_request, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let project = project_service.update_status(command).await;
+    match project {
+        Ok(project) => {
+            match project {
+                Some(project) => Ok(Json(project)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/status-history",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Status changes of the project, oldest first", body = Vec<ProjectStatusChangeResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn get_project_status_history(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<ProjectStatusChangeResponse>>, StatusCode> {
+    let command = ProjectGetCommand::new(project_id, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let history = project_service.status_history(command).await;
+    match history {
+        Ok(history) => Ok(Json(history)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/financials",
+    params(
+        ("project_id", description = "project identifier in uuid"),
+        ProjectFinancialsQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Actual vs planned cost, monthly burn rate, projection, funding needed and breakdown by category and person", body = ProjectFinancialsResponse),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project"
+)]
+pub async fn get_project_financials(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<ProjectFinancialsQuery>,
+) -> Result<Json<ProjectFinancialsResponse>, StatusCode> {
+    let command = ProjectFinancialsCommand::new(project_id, query, auth_user);
+    let project_service = ProjectService::from(&state);
+
+    let financials = project_service.financials(command).await;
+    match financials {
+        Ok(financials) => {
+            match financials {
+                Some(financials) => Ok(Json(financials)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
@@ -0,0 +1,187 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::projects::project_model::{Project, ProjectStatus, ProjectStatusChange};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectResponse {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub project_name: String,
+    pub project_status: ProjectStatus,
+    /// Statuses the project can move to from its current status
+    pub project_allowed_transitions: Vec<ProjectStatus>,
+    pub project_priority: i32,
+    pub project_start_date: Option<NaiveDate>,
+    pub project_due_date: Option<NaiveDate>,
+    pub project_budget_base_minor: i64,
+    pub project_goal_id: Option<Uuid>,
+    pub project_person_id: Option<Uuid>,
+    pub project_location_id: Option<Uuid>,
+    pub project_description: Option<String>,
+
+    pub project_created_at: Option<DateTime<Utc>>,
+    pub project_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Project> for ProjectResponse {
+    fn from(project: &Project) -> Self {
+        Self {
+            project_id: bu(project.id.clone().unwrap().as_slice()),
+            user_id: bu(project.user_id.as_slice()),
+            project_name: project.name.clone(),
+            project_status: project.status,
+            project_allowed_transitions: project.status.allowed_transitions(),
+            project_priority: project.priority,
+            project_start_date: project.start_date,
+            project_due_date: project.due_date,
+            project_budget_base_minor: project.budget_base_minor,
+            project_goal_id: obu(project.goal_id.as_deref()),
+            project_person_id: obu(project.person_id.as_deref()),
+            project_location_id: obu(project.location_id.as_deref()),
+            project_description: project.description.clone(),
+            project_created_at: project.created_at,
+            project_updated_at: project.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectCreateRequest {
+    pub project_name: String,
+
+    /// Planned by default; a project can also start active
+    pub project_status: Option<ProjectStatus>,
+    pub project_priority: Option<i32>,
+    pub project_start_date: Option<NaiveDate>,
+    pub project_due_date: Option<NaiveDate>,
+    pub project_budget_base_minor: Option<i64>,
+    pub project_goal_id: Option<Uuid>,
+    pub project_person_id: Option<Uuid>,
+    pub project_location_id: Option<Uuid>,
+    pub project_description: Option<String>,
+}
+
+/// Status is not part of the update: it only changes through the status endpoint
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectUpdateRequest {
+    pub project_name: String,
+    pub project_priority: i32,
+    pub project_start_date: Option<NaiveDate>,
+    pub project_due_date: Option<NaiveDate>,
+    pub project_budget_base_minor: i64,
+    pub project_goal_id: Option<Uuid>,
+    pub project_person_id: Option<Uuid>,
+    pub project_location_id: Option<Uuid>,
+    pub project_description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ProjectListQuery {
+    pub status: Option<ProjectStatus>,
+    pub priority: Option<i32>,
+    pub person_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+}
+
+
+// --- Status ---
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectStatusUpdateRequest {
+    pub project_status: ProjectStatus,
+    /// Why the status changed, kept in the history
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectStatusChangeResponse {
+    pub status_change_id: Uuid,
+    pub project_id: Uuid,
+    /// None for the initial status at creation
+    pub from_status: Option<ProjectStatus>,
+    pub to_status: ProjectStatus,
+    pub note: Option<String>,
+    pub changed_by: Uuid,
+    pub changed_at: Option<DateTime<Utc>>,
+}
+
+impl From<&ProjectStatusChange> for ProjectStatusChangeResponse {
+    fn from(change: &ProjectStatusChange) -> Self {
+        Self {
+            status_change_id: bu(change.id.clone().unwrap().as_slice()),
+            project_id: bu(change.project_id.as_slice()),
+            from_status: change.from_status,
+            to_status: change.to_status,
+            note: change.note.clone(),
+            changed_by: bu(change.user_id.as_slice()),
+            changed_at: change.changed_at,
+        }
+    }
+}
+
+
+// --- Financials ---
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ProjectFinancialsQuery {
+    /// Today by default
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectSpendingMonthResponse {
+    pub month: NaiveDate,
+    pub spent_base_minor: i64,
+    pub cumulative_base_minor: i64,
+}
+
+/// Spending of one category or one person; `breakdown_id` is None for untagged transactions
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectSpendingBreakdownResponse {
+    pub breakdown_id: Option<Uuid>,
+    pub breakdown_name: Option<String>,
+    pub spent_base_minor: i64,
+    /// Share of the actual cost
+    pub percent: Decimal,
+    pub transaction_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectFinancialsResponse {
+    pub project_id: Uuid,
+    pub as_of: NaiveDate,
+
+    pub planned_base_minor: i64,
+    pub actual_base_minor: i64,
+    /// actual - planned, positive when over budget
+    pub variance_base_minor: i64,
+    /// None without budget
+    pub budget_used_percent: Option<Decimal>,
+    /// Negative once over budget
+    pub remaining_budget_base_minor: i64,
+
+    /// Months from the project start (or its first expense) to `as_of`, at least 1
+    pub burn_months: i32,
+    /// Average spending per month over `burn_months`
+    pub monthly_burn_base_minor: i64,
+    /// Actual cost plus the monthly burn until the due date.
+    /// None for an open project without due date.
+    pub projected_total_base_minor: Option<i64>,
+    /// projected - planned, positive when the project is heading over budget
+    pub projected_variance_base_minor: Option<i64>,
+    /// Estimated price of the approved purchases not bought yet
+    pub approved_purchases_base_minor: i64,
+    /// What still has to be funded: the larger of the budget and the projection, minus the actual cost,
+    /// and never less than the approved purchases still to buy
+    pub funding_needed_base_minor: i64,
+
+    pub months: Vec<ProjectSpendingMonthResponse>,
+    pub by_category: Vec<ProjectSpendingBreakdownResponse>,
+    pub by_person: Vec<ProjectSpendingBreakdownResponse>,
+}
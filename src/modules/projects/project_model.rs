@@ -0,0 +1,174 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::projects::project_command::ProjectCreateCommand;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum ProjectStatus {
+    Planned,
+    Active,
+    Paused,
+    Done,
+    Cancelled,
+}
+
+impl ProjectStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectStatus::Planned => "planned",
+            ProjectStatus::Active => "active",
+            ProjectStatus::Paused => "paused",
+            ProjectStatus::Done => "done",
+            ProjectStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Allowed lifecycle moves.
+    /// Done and cancelled projects are closed: they can only be reopened
+    /// (done back to active, cancelled back to planned).
+    pub fn can_transition_to(&self, next: ProjectStatus) -> bool {
+        use ProjectStatus::*;
+        matches!(
+            (self, next),
+            (Planned, Active) | (Planned, Cancelled)
+                | (Active, Paused) | (Active, Done) | (Active, Cancelled)
+                | (Paused, Active) | (Paused, Cancelled)
+                | (Done, Active)
+                | (Cancelled, Planned)
+        )
+    }
+
+    pub fn allowed_transitions(&self) -> Vec<ProjectStatus> {
+        [ProjectStatus::Planned, ProjectStatus::Active, ProjectStatus::Paused, ProjectStatus::Done, ProjectStatus::Cancelled]
+            .into_iter()
+            .filter(|next| self.can_transition_to(*next))
+            .collect()
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Project {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub status: ProjectStatus,
+    pub priority: i32,
+    pub start_date: Option<NaiveDate>,
+    pub due_date: Option<NaiveDate>,
+    pub budget_base_minor: i64,
+    pub goal_id: Option<Vec<u8>>,
+    pub person_id: Option<Vec<u8>>,
+    pub location_id: Option<Vec<u8>>,
+    pub description: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Project {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            status: row.try_get(index_map["status"])?,
+            priority: row.try_get(index_map["priority"])?,
+            start_date: row.try_get(index_map["start_date"])?,
+            due_date: row.try_get(index_map["due_date"])?,
+            budget_base_minor: row.try_get(index_map["budget_base_minor"])?,
+            goal_id: row.try_get(index_map["goal_id"])?,
+            person_id: row.try_get(index_map["person_id"])?,
+            location_id: row.try_get(index_map["location_id"])?,
+            description: row.try_get(index_map["description"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<ProjectCreateCommand> for Project {
+    fn from(command: ProjectCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            name: command.project_name,
+            status: command.project_status.unwrap_or(ProjectStatus::Planned),
+            priority: command.project_priority.unwrap_or(0),
+            start_date: command.project_start_date,
+            due_date: command.project_due_date,
+            budget_base_minor: command.project_budget_base_minor.unwrap_or(0),
+            goal_id: oub(command.project_goal_id),
+            person_id: oub(command.project_person_id),
+            location_id: oub(command.project_location_id),
+            description: command.project_description,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProjectStatusChange {
+    pub id: Option<Vec<u8>>,
+
+    pub project_id: Vec<u8>,
+    pub user_id: Vec<u8>,
+    pub from_status: Option<ProjectStatus>,
+    pub to_status: ProjectStatus,
+    pub note: Option<String>,
+
+    pub changed_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for ProjectStatusChange {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            from_status: row.try_get(index_map["from_status"])?,
+            to_status: row.try_get(index_map["to_status"])?,
+            note: row.try_get(index_map["note"])?,
+            changed_at: row.try_get(index_map["changed_at"])?,
+        })
+    }
+}
+
+
+/// Spending of a project for one month, category and person.
+/// `spent_base_minor` is positive for expenses; refunds and income linked to the project deduct.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProjectSpending {
+    pub month: NaiveDate,
+    pub category_id: Option<Vec<u8>>,
+    pub category_name: Option<String>,
+    pub person_id: Option<Vec<u8>>,
+    pub person_name: Option<String>,
+    pub spent_base_minor: i64,
+    pub transaction_count: i64,
+}
+
+impl FromSqlRow for ProjectSpending {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            month: row.try_get(index_map["month"])?,
+            category_id: row.try_get(index_map["category_id"])?,
+            category_name: row.try_get(index_map["category_name"])?,
+            person_id: row.try_get(index_map["person_id"])?,
+            person_name: row.try_get(index_map["person_name"])?,
+            spent_base_minor: row.try_get(index_map["spent_base_minor"])?,
+            transaction_count: row.try_get(index_map["transaction_count"])?,
+        })
+    }
+}
@@ -0,0 +1,227 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::project_model::{Project, ProjectSpending, ProjectStatus, ProjectStatusChange};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait ProjectRepositoryInterface {
+
+    async fn get(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Project>, Error>;
+
+    /// Also records the initial status in the history
+    async fn create(&self, project: Project, meta_user: Option<Uuid>) -> Result<Project, Error>;
+
+    async fn update(&self, project: Project, meta_user: Option<Uuid>) -> Result<Option<Project>, Error>;
+
+    async fn delete(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user_filtered(
+        &self,
+        user_id: Uuid,
+        status: Option<ProjectStatus>,
+        priority: Option<i32>,
+        person_id: Option<Uuid>,
+        location_id: Option<Uuid>,
+        meta_user: Option<Uuid>
+    ) -> Result<Vec<Project>, Error>;
+
+    /// Moves the project from `from_status` to `to_status` and appends the history row.
+    /// Returns None when the project is missing or its status is no longer `from_status`.
+    async fn update_status(&self, project_id: Uuid, from_status: ProjectStatus, to_status: ProjectStatus, note: Option<String>, meta_user: Option<Uuid>) -> Result<Option<Project>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct ProjectRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for ProjectRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Project> for ProjectRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ProjectRepositoryInterface for ProjectRepository {
+    async fn get(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Project>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_get_by_id", params).await
+    }
+
+    async fn create(&self, project: Project, meta_user: Option<Uuid>) -> Result<Project, Error> {
+        let params = vec![
+            MySqlParam::from(project.user_id),
+            MySqlParam::from(project.name),
+            MySqlParam::from(project.status.as_str()),
+            MySqlParam::from(project.priority),
+            MySqlParam::from(project.start_date),
+            MySqlParam::from(project.due_date),
+            MySqlParam::from(project.budget_base_minor),
+            MySqlParam::from(project.goal_id),
+            MySqlParam::from(project.person_id),
+            MySqlParam::from(project.location_id),
+            MySqlParam::from(project.description),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_create", params).await
+    }
+
+    async fn update(&self, project: Project, meta_user: Option<Uuid>) -> Result<Option<Project>, Error> {
+        let params = vec![
+            MySqlParam::from(project.id),
+            MySqlParam::from(project.name),
+            MySqlParam::from(project.priority),
+            MySqlParam::from(project.start_date),
+            MySqlParam::from(project.due_date),
+            MySqlParam::from(project.budget_base_minor),
+            MySqlParam::from(project.goal_id),
+            MySqlParam::from(project.person_id),
+            MySqlParam::from(project.location_id),
+            MySqlParam::from(project.description),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_update", params).await
+    }
+
+    async fn delete(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_delete", params).await
+    }
+
+    async fn get_by_user_filtered(
+        &self,
+        user_id: Uuid,
+        status: Option<ProjectStatus>,
+        priority: Option<i32>,
+        person_id: Option<Uuid>,
+        location_id: Option<Uuid>,
+        meta_user: Option<Uuid>
+    ) -> Result<Vec<Project>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(status.map(|status| status.as_str())),
+            MySqlParam::from(priority),
+            MySqlParam::from(oub(person_id)),
+            MySqlParam::from(oub(location_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_by_user_filtered", params).await
+    }
+
+    async fn update_status(&self, project_id: Uuid, from_status: ProjectStatus, to_status: ProjectStatus, note: Option<String>, meta_user: Option<Uuid>) -> Result<Option<Project>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(from_status.as_str()),
+            MySqlParam::from(to_status.as_str()),
+            MySqlParam::from(note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_update_status", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait ProjectStatusChangeRepositoryInterface {
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ProjectStatusChange>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct ProjectStatusChangeRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for ProjectStatusChangeRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<ProjectStatusChange> for ProjectStatusChangeRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ProjectStatusChangeRepositoryInterface for ProjectStatusChangeRepository {
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ProjectStatusChange>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_status_history_by_project", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait ProjectSpendingRepositoryInterface {
+
+    /// Transactions of the project up to `as_of` (included), grouped by month, category and person
+    async fn get_by_project(&self, project_id: Uuid, as_of: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<ProjectSpending>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct ProjectSpendingRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for ProjectSpendingRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<ProjectSpending> for ProjectSpendingRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ProjectSpendingRepositoryInterface for ProjectSpendingRepository {
+    async fn get_by_project(&self, project_id: Uuid, as_of: NaiveDate, meta_user: Option<Uuid>) -> Result<Vec<ProjectSpending>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(as_of),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_spending_by_month_category_person", params).await
+    }
+}
@@ -0,0 +1,328 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::modules::projects::{
+    project_command::*,
+    project_dto::*,
+    project_model::{Project, ProjectSpending, ProjectStatus},
+    project_repo::{
+        ProjectRepository, ProjectRepositoryInterface,
+        ProjectSpendingRepository, ProjectSpendingRepositoryInterface,
+        ProjectStatusChangeRepository, ProjectStatusChangeRepositoryInterface
+    },
+    purchase_model::{Purchase, PurchaseStatus},
+    purchase_repo::{PurchaseRepository, PurchaseRepositoryInterface},
+};
+use crate::shared::dates::{month_start, months_between};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu, oub, ub};
+
+
+#[async_trait]
+pub trait ProjectServiceInterface {
+
+    async fn get(&self, command: ProjectGetCommand) -> Result<Option<ProjectResponse>, Error>;
+
+    async fn create(&self, command: ProjectCreateCommand) -> Result<ProjectResponse, Error>;
+
+    async fn update(&self, command: ProjectUpdateCommand) -> Result<Option<ProjectResponse>, Error>;
+
+    async fn delete(&self, command: ProjectDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: ProjectListByUserCommand) -> Result<Vec<ProjectResponse>, Error>;
+
+
+    // --- Status ---
+
+    async fn update_status(&self, command: ProjectStatusUpdateCommand) -> Result<Option<ProjectResponse>, Error>;
+
+    async fn status_history(&self, command: ProjectGetCommand) -> Result<Vec<ProjectStatusChangeResponse>, Error>;
+
+
+    // --- Financials ---
+
+    async fn financials(&self, command: ProjectFinancialsCommand) -> Result<Option<ProjectFinancialsResponse>, Error>;
+
+}
+
+/// Projects are not cached in Redis: status transitions must always be checked
+/// against the stored status, and the list is filtered by the procedures.
+#[derive(Clone)]
+pub struct ProjectService {
+    project_repo: ProjectRepository,
+    status_change_repo: ProjectStatusChangeRepository,
+    spending_repo: ProjectSpendingRepository,
+    purchase_repo: PurchaseRepository,
+}
+
+impl From<&AppState> for ProjectService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            project_repo: ProjectRepository::from(app_state),
+            status_change_repo: ProjectStatusChangeRepository::from(app_state),
+            spending_repo: ProjectSpendingRepository::from(app_state),
+            purchase_repo: PurchaseRepository::from(app_state),
+        }
+    }
+}
+
+impl ProjectService {
+    fn validate(name: &str, budget_base_minor: i64, start_date: Option<NaiveDate>, due_date: Option<NaiveDate>) -> Result<(), Error> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest("project name is required".into()).into());
+        }
+        if budget_base_minor < 0 {
+            return Err(AppError::BadRequest("project budget cannot be negative".into()).into());
+        }
+        if let (Some(start_date), Some(due_date)) = (start_date, due_date)
+            && due_date < start_date {
+            return Err(AppError::BadRequest("project is due before it starts".into()).into());
+        }
+        Ok(())
+    }
+
+    fn percent_of(part: i64, total: i64) -> Decimal {
+        if total > 0 {
+            (Decimal::from(part) * Decimal::from(100) / Decimal::from(total)).round_dp(2)
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    fn breakdown<'a>(
+        spending: &'a [ProjectSpending],
+        key: impl Fn(&'a ProjectSpending) -> (Option<&'a [u8]>, Option<&'a String>),
+        actual: i64
+    ) -> Vec<ProjectSpendingBreakdownResponse> {
+        let mut groups: HashMap<Option<&[u8]>, ProjectSpendingBreakdownResponse> = HashMap::new();
+        for row in spending {
+            let (id, name) = key(row);
+            let group = groups.entry(id).or_insert_with(|| ProjectSpendingBreakdownResponse {
+                breakdown_id: obu(id),
+                breakdown_name: name.cloned(),
+                spent_base_minor: 0,
+                percent: Decimal::ZERO,
+                transaction_count: 0,
+            });
+            group.spent_base_minor += row.spent_base_minor;
+            group.transaction_count += row.transaction_count;
+        }
+
+        let mut breakdown: Vec<ProjectSpendingBreakdownResponse> = groups.into_values()
+            .map(|group| ProjectSpendingBreakdownResponse { percent: Self::percent_of(group.spent_base_minor, actual), ..group })
+            .collect();
+        breakdown.sort_by(|a, b| b.spent_base_minor.cmp(&a.spent_base_minor).then_with(|| a.breakdown_name.cmp(&b.breakdown_name)));
+        breakdown
+    }
+
+    /// Burn rate is the average monthly spending since the project start (or its first expense).
+    /// An open project is projected at that pace until its due date; a closed one stays at its actual cost.
+    /// Approved purchases not bought yet are still to be funded, whatever the projection says.
+    pub fn financials_of(project: &Project, spending: &[ProjectSpending], purchases: &[Purchase], as_of: NaiveDate) -> ProjectFinancialsResponse {
+        let planned = project.budget_base_minor;
+        let actual: i64 = spending.iter().map(|row| row.spent_base_minor).sum();
+
+        let mut monthly: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+        for row in spending {
+            *monthly.entry(month_start(row.month)).or_default() += row.spent_base_minor;
+        }
+        let mut cumulative = 0;
+        let months: Vec<ProjectSpendingMonthResponse> = monthly.iter()
+            .map(|(&month, &spent)| {
+                cumulative += spent;
+                ProjectSpendingMonthResponse { month, spent_base_minor: spent, cumulative_base_minor: cumulative }
+            })
+            .collect();
+
+        let closed = matches!(project.status, ProjectStatus::Done | ProjectStatus::Cancelled);
+        let first_month = project.start_date.map(month_start)
+            .into_iter()
+            .chain(monthly.keys().next().copied())
+            .min();
+        let last_month = if closed {
+            monthly.keys().next_back().copied().unwrap_or(month_start(as_of))
+        } else {
+            month_start(as_of)
+        };
+        let burn_months = first_month.map_or(1, |first_month| (months_between(first_month, last_month) + 1).max(1));
+        let monthly_burn = actual / burn_months as i64;
+
+        let projected_total = if closed {
+            Some(actual)
+        } else {
+            project.due_date.map(|due_date| actual + monthly_burn * months_between(as_of, due_date).max(0) as i64)
+        };
+        let funding_target = projected_total.map_or(planned, |projected| projected.max(planned));
+        let approved_purchases: i64 = purchases.iter()
+            .filter(|purchase| purchase.status == PurchaseStatus::Approved)
+            .map(|purchase| purchase.estimated_price_base_minor)
+            .sum();
+
+        ProjectFinancialsResponse {
+            project_id: bu(project.id.as_deref().unwrap()),
+            as_of,
+            planned_base_minor: planned,
+            actual_base_minor: actual,
+            variance_base_minor: actual - planned,
+            budget_used_percent: (planned > 0).then(|| Self::percent_of(actual, planned)),
+            remaining_budget_base_minor: planned - actual,
+            burn_months,
+            monthly_burn_base_minor: monthly_burn,
+            projected_total_base_minor: projected_total,
+            projected_variance_base_minor: projected_total.map(|projected| projected - planned),
+            approved_purchases_base_minor: approved_purchases,
+            funding_needed_base_minor: (funding_target - actual).max(approved_purchases).max(0),
+            months,
+            by_category: Self::breakdown(spending, |row| (row.category_id.as_deref(), row.category_name.as_ref()), actual),
+            by_person: Self::breakdown(spending, |row| (row.person_id.as_deref(), row.person_name.as_ref()), actual),
+        }
+    }
+}
+
+#[async_trait]
+impl ProjectServiceInterface for ProjectService {
+    async fn get(&self, command: ProjectGetCommand) -> Result<Option<ProjectResponse>, Error> {
+        let project = self.project_repo.get(command.project_id, Some(command.auth_user.user_id)).await;
+        match project {
+            Ok(project) => Ok(project.as_ref().map(ProjectResponse::from)),
+            Err(_) => Err(Error::msg("Error getting project")),
+        }
+    }
+
+    async fn create(&self, command: ProjectCreateCommand) -> Result<ProjectResponse, Error> {
+        Self::validate(
+            &command.project_name,
+            command.project_budget_base_minor.unwrap_or(0),
+            command.project_start_date,
+            command.project_due_date
+        )?;
+        if !matches!(command.project_status, None | Some(ProjectStatus::Planned) | Some(ProjectStatus::Active)) {
+            return Err(AppError::BadRequest("a new project is either planned or active".into()).into());
+        }
+
+        let meta_user = command.auth_user.user_id;
+        let project_create = Project::from(command);
+
+        let project = self.project_repo.create(project_create, Some(meta_user)).await;
+        match project {
+            Ok(project) => Ok(ProjectResponse::from(&project)),
+            Err(_) => Err(Error::msg("Error creating project")),
+        }
+    }
+
+    async fn update(&self, command: ProjectUpdateCommand) -> Result<Option<ProjectResponse>, Error> {
+        Self::validate(
+            &command.project_name,
+            command.project_budget_base_minor,
+            command.project_start_date,
+            command.project_due_date
+        )?;
+
+        let meta_user = command.auth_user.user_id;
+        let project_update = Project {
+            id: Some(ub(command.project_id)),
+            user_id: ub(meta_user),
+            name: command.project_name,
+            status: ProjectStatus::Planned,
+            priority: command.project_priority,
+            start_date: command.project_start_date,
+            due_date: command.project_due_date,
+            budget_base_minor: command.project_budget_base_minor,
+            goal_id: oub(command.project_goal_id),
+            person_id: oub(command.project_person_id),
+            location_id: oub(command.project_location_id),
+            description: command.project_description,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let project = self.project_repo.update(project_update, Some(meta_user)).await;
+        match project {
+            Ok(project) => Ok(project.as_ref().map(ProjectResponse::from)),
+            Err(_) => Err(Error::msg("Error updating project")),
+        }
+    }
+
+    async fn delete(&self, command: ProjectDeleteCommand) -> Result<(), Error> {
+        let result = self.project_repo.delete(command.project_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting project")),
+        }
+    }
+
+    async fn get_by_user(&self, command: ProjectListByUserCommand) -> Result<Vec<ProjectResponse>, Error> {
+        let projects = self.project_repo.get_by_user_filtered(
+            command.user_id,
+            command.status,
+            command.priority,
+            command.person_id,
+            command.location_id,
+            Some(command.auth_user.user_id)
+        ).await;
+        match projects {
+            Ok(projects) => Ok(projects.iter().map(ProjectResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting projects")),
+        }
+    }
+
+    async fn update_status(&self, command: ProjectStatusUpdateCommand) -> Result<Option<ProjectResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+
+        let project = match self.project_repo.get(command.project_id, meta_user).await {
+            Ok(Some(project)) => project,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        };
+
+        let from_status = project.status;
+        let to_status = command.project_status;
+        if from_status == to_status {
+            return Ok(Some(ProjectResponse::from(&project)));
+        }
+        if !from_status.can_transition_to(to_status) {
+            return Err(AppError::BadRequest(format!(
+                "project cannot go from {} to {}", from_status.as_str(), to_status.as_str()
+            )).into());
+        }
+
+        let project = self.project_repo.update_status(command.project_id, from_status, to_status, command.note, meta_user).await;
+        match project {
+            Ok(Some(project)) => Ok(Some(ProjectResponse::from(&project))),
+            Ok(None) => Err(AppError::BadRequest("project status changed meanwhile, reload and retry".into()).into()),
+            Err(_) => Err(Error::msg("Error updating project status")),
+        }
+    }
+
+    async fn status_history(&self, command: ProjectGetCommand) -> Result<Vec<ProjectStatusChangeResponse>, Error> {
+        let history = self.status_change_repo.get_by_project(command.project_id, Some(command.auth_user.user_id)).await;
+        match history {
+            Ok(history) => Ok(history.iter().map(ProjectStatusChangeResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting project status history")),
+        }
+    }
+
+    async fn financials(&self, command: ProjectFinancialsCommand) -> Result<Option<ProjectFinancialsResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let project = match self.project_repo.get(command.project_id, meta_user).await {
+            Ok(Some(project)) => project,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        };
+
+        let as_of = command.as_of.unwrap_or_else(|| Utc::now().date_naive());
+        let spending = match self.spending_repo.get_by_project(command.project_id, as_of, meta_user).await {
+            Ok(spending) => spending,
+            Err(_) => return Err(Error::msg("Error getting project spending")),
+        };
+        let purchases = match self.purchase_repo.get_by_project(command.project_id, meta_user).await {
+            Ok(purchases) => purchases,
+            Err(_) => return Err(Error::msg("Error getting project purchases")),
+        };
+        Ok(Some(Self::financials_of(&project, &spending, &purchases, as_of)))
+    }
+}
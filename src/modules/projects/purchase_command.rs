@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::{
+    purchase_dto::{PurchaseBuyRequest, PurchaseCreateRequest, PurchaseUpdateRequest},
+    purchase_model::PurchaseStatus,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseGetCommand {
+    pub purchase_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PurchaseGetCommand {
+    pub fn new(purchase_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { purchase_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseCreateCommand {
+    pub project_id: Uuid,
+    pub purchase_title: String,
+    pub purchase_estimated_price_base_minor: i64,
+
+    pub purchase_priority: Option<i32>,
+    pub purchase_planned_date: Option<NaiveDate>,
+    pub purchase_url: Option<String>,
+    pub purchase_note: Option<String>,
+    pub purchase_status: Option<PurchaseStatus>,
+
+    pub auth_user: AuthUser,
+}
+
+impl PurchaseCreateCommand {
+    pub fn new(project_id: Uuid, request: PurchaseCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            purchase_title: request.purchase_title,
+            purchase_estimated_price_base_minor: request.purchase_estimated_price_base_minor,
+            purchase_priority: request.purchase_priority,
+            purchase_planned_date: request.purchase_planned_date,
+            purchase_url: request.purchase_url,
+            purchase_note: request.purchase_note,
+            purchase_status: request.purchase_status,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseUpdateCommand {
+    pub purchase_id: Uuid,
+
+    pub purchase_title: String,
+    pub purchase_estimated_price_base_minor: i64,
+    pub purchase_priority: i32,
+    pub purchase_planned_date: Option<NaiveDate>,
+    pub purchase_url: Option<String>,
+    pub purchase_note: Option<String>,
+    pub purchase_status: PurchaseStatus,
+
+    pub auth_user: AuthUser,
+}
+
+impl PurchaseUpdateCommand {
+    pub fn new(purchase_id: Uuid, request: PurchaseUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            purchase_id,
+            purchase_title: request.purchase_title,
+            purchase_estimated_price_base_minor: request.purchase_estimated_price_base_minor,
+            purchase_priority: request.purchase_priority,
+            purchase_planned_date: request.purchase_planned_date,
+            purchase_url: request.purchase_url,
+            purchase_note: request.purchase_note,
+            purchase_status: request.purchase_status,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseDeleteCommand {
+    pub purchase_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PurchaseDeleteCommand {
+    pub fn new(purchase_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { purchase_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseListByProjectCommand {
+    pub project_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PurchaseListByProjectCommand {
+    pub fn new(project_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { project_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseBuyCommand {
+    pub purchase_id: Uuid,
+    pub transaction_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl PurchaseBuyCommand {
+    pub fn new(purchase_id: Uuid, request: PurchaseBuyRequest, auth_user: AuthUser) -> Self {
+        Self { purchase_id, transaction_id: request.transaction_id, auth_user }
+    }
+}
@@ -0,0 +1,259 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    purchase_command::*,
+    purchase_dto::*,
+    purchase_service::{PurchaseService, PurchaseServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{project_id}/purchases", get(get_project_purchases).post(post_project_purchase))
+        .route("/{project_id}/purchases/summary", get(get_project_purchase_summary))
+        .route("/purchases/{purchase_id}", get(get_project_purchase).put(put_project_purchase).delete(delete_project_purchase))
+        .route("/purchases/{purchase_id}/buy", post(post_project_purchase_buy))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/purchases",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Planned purchases of the project by status then priority", body = Vec<PurchaseResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn get_project_purchases(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<PurchaseResponse>>, StatusCode> {
+    let command = PurchaseListByProjectCommand::new(project_id, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let purchases = purchase_service.get_by_project(command).await;
+    match purchases {
+        Ok(purchases) => {
+            match purchases {
+                Some(purchases) => Ok(Json(purchases)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/{project_id}/purchases",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Planned purchase created successfully", body = PurchaseResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid purchase"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn post_project_purchase(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(purchase_create_request): Json<PurchaseCreateRequest>
+) -> Result<Json<PurchaseResponse>, StatusCode> {
+    let command = PurchaseCreateCommand::new(project_id, purchase_create_request, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let purchase = purchase_service.create(command).await;
+    match purchase {
+        Ok(purchase) => {
+            match purchase {
+                Some(purchase) => Ok(Json(purchase)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/purchases/summary",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Totals of the planned purchases by status, with the price variance of the bought ones", body = PurchaseSummaryResponse),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn get_project_purchase_summary(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<PurchaseSummaryResponse>, StatusCode> {
+    let command = PurchaseListByProjectCommand::new(project_id, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let summary = purchase_service.summary(command).await;
+    match summary {
+        Ok(summary) => {
+            match summary {
+                Some(summary) => Ok(Json(summary)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/purchases/{purchase_id}",
+    params(
+        ("purchase_id", description = "purchase identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Planned purchase found successfully", body = PurchaseResponse),
+        (status = StatusCode::NOT_FOUND, description = "Purchase not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn get_project_purchase(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(purchase_id): Path<Uuid>,
+) -> Result<Json<PurchaseResponse>, StatusCode> {
+    let command = PurchaseGetCommand::new(purchase_id, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let purchase = purchase_service.get(command).await;
+    match purchase {
+        Ok(purchase) => {
+            match purchase {
+                Some(purchase) => Ok(Json(purchase)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/purchases/{purchase_id}",
+    params(
+        ("purchase_id", description = "purchase identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Planned purchase updated successfully", body = PurchaseResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid purchase"),
+        (status = StatusCode::NOT_FOUND, description = "Purchase not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn put_project_purchase(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(purchase_id): Path<Uuid>,
+    Json(purchase_update_request): Json<PurchaseUpdateRequest>
+) -> Result<Json<PurchaseResponse>, StatusCode> {
+    let command = PurchaseUpdateCommand::new(purchase_id, purchase_update_request, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let purchase = purchase_service.update(command).await;
+    match purchase {
+        Ok(purchase) => {
+            match purchase {
+                Some(purchase) => Ok(Json(purchase)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/purchases/{purchase_id}",
+    params(
+        ("purchase_id", description = "purchase identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Planned purchase deleted successfully"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn delete_project_purchase(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(purchase_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = PurchaseDeleteCommand::new(purchase_id, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let response = purchase_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/purchases/{purchase_id}/buy",
+    params(
+        ("purchase_id", description = "purchase identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Purchase bought, linked to its transaction with the price variance", body = PurchaseResponse),
+        (status = StatusCode::NOT_FOUND, description = "Purchase or transaction not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Purchase"
+)]
+pub async fn post_project_purchase_buy(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(purchase_id): Path<Uuid>,
+    Json(purchase_buy_request): Json<PurchaseBuyRequest>
+) -> Result<Json<PurchaseResponse>, StatusCode> {
+    let command = PurchaseBuyCommand::new(purchase_id, purchase_buy_request, auth_user);
+    let purchase_service = PurchaseService::from(&state);
+
+    let purchase = purchase_service.buy(command).await;
+    match purchase {
+        Ok(purchase) => {
+            match purchase {
+                Some(purchase) => Ok(Json(purchase)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
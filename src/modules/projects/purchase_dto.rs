@@ -0,0 +1,130 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::projects::purchase_model::{Purchase, PurchaseStatus};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseResponse {
+    pub purchase_id: Uuid,
+    pub project_id: Uuid,
+    pub purchase_title: String,
+    pub purchase_estimated_price_base_minor: i64,
+    pub purchase_priority: i32,
+    pub purchase_planned_date: Option<NaiveDate>,
+    pub purchase_url: Option<String>,
+    pub purchase_note: Option<String>,
+    pub purchase_status: PurchaseStatus,
+
+    pub purchase_transaction_id: Option<Uuid>,
+    pub purchase_actual_price_base_minor: Option<i64>,
+    /// actual - estimated, positive when the item cost more than planned
+    pub purchase_price_variance_base_minor: Option<i64>,
+    /// Variance relative to the estimated price, None when estimated at 0
+    pub purchase_price_variance_percent: Option<Decimal>,
+    pub purchase_bought_at: Option<DateTime<Utc>>,
+
+    pub purchase_created_at: Option<DateTime<Utc>>,
+    pub purchase_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Purchase> for PurchaseResponse {
+    fn from(purchase: &Purchase) -> Self {
+        let variance = purchase.price_variance();
+        Self {
+            purchase_id: bu(purchase.id.clone().unwrap().as_slice()),
+            project_id: bu(purchase.project_id.as_slice()),
+            purchase_title: purchase.title.clone(),
+            purchase_estimated_price_base_minor: purchase.estimated_price_base_minor,
+            purchase_priority: purchase.priority,
+            purchase_planned_date: purchase.planned_date,
+            purchase_url: purchase.url.clone(),
+            purchase_note: purchase.note.clone(),
+            purchase_status: purchase.status,
+            purchase_transaction_id: obu(purchase.transaction_id.as_deref()),
+            purchase_actual_price_base_minor: purchase.actual_price_base_minor,
+            purchase_price_variance_base_minor: variance,
+            purchase_price_variance_percent: variance
+                .filter(|_| purchase.estimated_price_base_minor > 0)
+                .map(|variance| (Decimal::from(variance) * Decimal::from(100) / Decimal::from(purchase.estimated_price_base_minor)).round_dp(2)),
+            purchase_bought_at: purchase.bought_at,
+            purchase_created_at: purchase.created_at,
+            purchase_updated_at: purchase.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseCreateRequest {
+    pub purchase_title: String,
+    pub purchase_estimated_price_base_minor: i64,
+    /// 0 by default, higher first
+    pub purchase_priority: Option<i32>,
+    pub purchase_planned_date: Option<NaiveDate>,
+    pub purchase_url: Option<String>,
+    pub purchase_note: Option<String>,
+    /// Idea by default; bought is only reached through the buy endpoint
+    pub purchase_status: Option<PurchaseStatus>,
+}
+
+/// Setting a bought item back to idea or approved unlinks its transaction and clears its actual price
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseUpdateRequest {
+    pub purchase_title: String,
+    pub purchase_estimated_price_base_minor: i64,
+    pub purchase_priority: i32,
+    pub purchase_planned_date: Option<NaiveDate>,
+    pub purchase_url: Option<String>,
+    pub purchase_note: Option<String>,
+    pub purchase_status: PurchaseStatus,
+}
+
+/// Real transaction of the purchase; it gets attached to the project if it was not already
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseBuyRequest {
+    pub transaction_id: Uuid,
+}
+
+/// Totals of the planned purchases of a project by status
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseSummaryResponse {
+    pub idea_count: i64,
+    pub idea_base_minor: i64,
+    pub approved_count: i64,
+    /// Approved and not bought yet: counted in the project funding needed
+    pub approved_base_minor: i64,
+    pub bought_count: i64,
+    pub bought_estimated_base_minor: i64,
+    pub bought_actual_base_minor: i64,
+    /// actual - estimated over the bought items
+    pub bought_variance_base_minor: i64,
+}
+
+impl PurchaseSummaryResponse {
+    pub fn of(purchases: &[Purchase]) -> Self {
+        let mut summary = Self::default();
+        for purchase in purchases {
+            match purchase.status {
+                PurchaseStatus::Idea => {
+                    summary.idea_count += 1;
+                    summary.idea_base_minor += purchase.estimated_price_base_minor;
+                },
+                PurchaseStatus::Approved => {
+                    summary.approved_count += 1;
+                    summary.approved_base_minor += purchase.estimated_price_base_minor;
+                },
+                PurchaseStatus::Bought => {
+                    summary.bought_count += 1;
+                    summary.bought_estimated_base_minor += purchase.estimated_price_base_minor;
+                    summary.bought_actual_base_minor += purchase.actual_price_base_minor.unwrap_or(0);
+                    summary.bought_variance_base_minor += purchase.price_variance().unwrap_or(0);
+                },
+            }
+        }
+        summary
+    }
+}
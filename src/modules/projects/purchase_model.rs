@@ -0,0 +1,104 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::projects::purchase_command::PurchaseCreateCommand;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::ub;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum PurchaseStatus {
+    Idea,
+    Approved,
+    Bought,
+}
+
+impl PurchaseStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PurchaseStatus::Idea => "idea",
+            PurchaseStatus::Approved => "approved",
+            PurchaseStatus::Bought => "bought",
+        }
+    }
+}
+
+
+/// Planned purchase ("dépense idée") of a project.
+/// Bought items are linked to the real transaction, whose amount becomes `actual_price_base_minor`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Purchase {
+    pub id: Option<Vec<u8>>,
+
+    pub project_id: Vec<u8>,
+    pub title: String,
+    pub estimated_price_base_minor: i64,
+    /// Higher first
+    pub priority: i32,
+    pub planned_date: Option<NaiveDate>,
+    pub url: Option<String>,
+    pub note: Option<String>,
+    /// Only becomes bought through the buy endpoint
+    pub status: PurchaseStatus,
+
+    pub transaction_id: Option<Vec<u8>>,
+    pub actual_price_base_minor: Option<i64>,
+    pub bought_at: Option<DateTime<Utc>>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Purchase {
+    /// actual - estimated, positive when the item cost more than planned
+    pub fn price_variance(&self) -> Option<i64> {
+        self.actual_price_base_minor.map(|actual| actual - self.estimated_price_base_minor)
+    }
+}
+
+impl FromSqlRow for Purchase {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+            title: row.try_get(index_map["title"])?,
+            estimated_price_base_minor: row.try_get(index_map["estimated_price_base_minor"])?,
+            priority: row.try_get(index_map["priority"])?,
+            planned_date: row.try_get(index_map["planned_date"])?,
+            url: row.try_get(index_map["url"])?,
+            note: row.try_get(index_map["note"])?,
+            status: row.try_get(index_map["status"])?,
+            transaction_id: row.try_get(index_map["transaction_id"])?,
+            actual_price_base_minor: row.try_get(index_map["actual_price_base_minor"])?,
+            bought_at: row.try_get(index_map["bought_at"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<PurchaseCreateCommand> for Purchase {
+    fn from(command: PurchaseCreateCommand) -> Self {
+        Self {
+            id: None,
+            project_id: ub(command.project_id),
+            title: command.purchase_title,
+            estimated_price_base_minor: command.purchase_estimated_price_base_minor,
+            priority: command.purchase_priority.unwrap_or(0),
+            planned_date: command.purchase_planned_date,
+            url: command.purchase_url,
+            note: command.purchase_note,
+            status: command.purchase_status.unwrap_or(PurchaseStatus::Idea),
+            transaction_id: None,
+            actual_price_base_minor: None,
+            bought_at: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
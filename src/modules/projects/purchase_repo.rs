@@ -0,0 +1,136 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::purchase_model::{Purchase, PurchaseStatus};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait PurchaseRepositoryInterface {
+
+    async fn get(&self, purchase_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error>;
+
+    async fn create(&self, purchase: Purchase, meta_user: Option<Uuid>) -> Result<Purchase, Error>;
+
+    /// Leaving the bought status clears transaction_id, actual_price_base_minor and bought_at
+    async fn update(&self, purchase: Purchase, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error>;
+
+    async fn delete(&self, purchase_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by status (idea, approved, bought) then priority descending
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Purchase>, Error>;
+
+    /// Marks the purchase bought with the transaction: actual price from its base amount, bought_at from its date.
+    /// The transaction is attached to the purchase project when it has none.
+    async fn buy(&self, purchase_id: Uuid, transaction_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error>;
+
+    /// Undoes `buy`: unlinks the transaction, clears the actual price and bought_at, status back to `status`.
+    /// The transaction itself keeps its project.
+    async fn unbuy(&self, purchase_id: Uuid, status: PurchaseStatus, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct PurchaseRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for PurchaseRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Purchase> for PurchaseRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl PurchaseRepositoryInterface for PurchaseRepository {
+    async fn get(&self, purchase_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(purchase_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_purchase_get_by_id", params).await
+    }
+
+    async fn create(&self, purchase: Purchase, meta_user: Option<Uuid>) -> Result<Purchase, Error> {
+        let params = vec![
+            MySqlParam::from(purchase.project_id),
+            MySqlParam::from(purchase.title),
+            MySqlParam::from(purchase.estimated_price_base_minor),
+            MySqlParam::from(purchase.priority),
+            MySqlParam::from(purchase.planned_date),
+            MySqlParam::from(purchase.url),
+            MySqlParam::from(purchase.note),
+            MySqlParam::from(purchase.status.as_str()),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_purchase_create", params).await
+    }
+
+    async fn update(&self, purchase: Purchase, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error> {
+        let params = vec![
+            MySqlParam::from(purchase.id),
+            MySqlParam::from(purchase.title),
+            MySqlParam::from(purchase.estimated_price_base_minor),
+            MySqlParam::from(purchase.priority),
+            MySqlParam::from(purchase.planned_date),
+            MySqlParam::from(purchase.url),
+            MySqlParam::from(purchase.note),
+            MySqlParam::from(purchase.status.as_str()),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_purchase_update", params).await
+    }
+
+    async fn delete(&self, purchase_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(purchase_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_purchase_delete", params).await
+    }
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Purchase>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_purchase_by_project", params).await
+    }
+
+    async fn buy(&self, purchase_id: Uuid, transaction_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(purchase_id)),
+            MySqlParam::from(ub(transaction_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_purchase_buy", params).await
+    }
+
+    async fn unbuy(&self, purchase_id: Uuid, status: PurchaseStatus, meta_user: Option<Uuid>) -> Result<Option<Purchase>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(purchase_id)),
+            MySqlParam::from(status.as_str()),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_purchase_unbuy", params).await
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+
+use crate::modules::projects::{
+    project_repo::{ProjectRepository, ProjectRepositoryInterface},
+    purchase_command::*,
+    purchase_dto::*,
+    purchase_model::{Purchase, PurchaseStatus},
+    purchase_repo::{PurchaseRepository, PurchaseRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+
+
+#[async_trait]
+pub trait PurchaseServiceInterface {
+
+    async fn get(&self, command: PurchaseGetCommand) -> Result<Option<PurchaseResponse>, Error>;
+
+    /// None when the project does not exist
+    async fn create(&self, command: PurchaseCreateCommand) -> Result<Option<PurchaseResponse>, Error>;
+
+    async fn update(&self, command: PurchaseUpdateCommand) -> Result<Option<PurchaseResponse>, Error>;
+
+    async fn delete(&self, command: PurchaseDeleteCommand) -> Result<(), Error>;
+
+    /// None when the project does not exist
+    async fn get_by_project(&self, command: PurchaseListByProjectCommand) -> Result<Option<Vec<PurchaseResponse>>, Error>;
+
+    /// None when the project does not exist
+    async fn summary(&self, command: PurchaseListByProjectCommand) -> Result<Option<PurchaseSummaryResponse>, Error>;
+
+    /// Links the real transaction and records the price variance
+    async fn buy(&self, command: PurchaseBuyCommand) -> Result<Option<PurchaseResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct PurchaseService {
+    project_repo: ProjectRepository,
+    purchase_repo: PurchaseRepository,
+}
+
+impl From<&AppState> for PurchaseService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            project_repo: ProjectRepository::from(app_state),
+            purchase_repo: PurchaseRepository::from(app_state),
+        }
+    }
+}
+
+impl PurchaseService {
+    fn validate(title: &str, estimated_price_base_minor: i64) -> Result<(), Error> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest("purchase title is required".into()).into());
+        }
+        if estimated_price_base_minor < 0 {
+            return Err(AppError::BadRequest("purchase estimated price cannot be negative".into()).into());
+        }
+        Ok(())
+    }
+
+    async fn project_purchases(&self, command: &PurchaseListByProjectCommand) -> Result<Option<Vec<Purchase>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        match self.project_repo.get(command.project_id, meta_user).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        }
+        match self.purchase_repo.get_by_project(command.project_id, meta_user).await {
+            Ok(purchases) => Ok(Some(purchases)),
+            Err(_) => Err(Error::msg("Error getting project purchases")),
+        }
+    }
+}
+
+#[async_trait]
+impl PurchaseServiceInterface for PurchaseService {
+    async fn get(&self, command: PurchaseGetCommand) -> Result<Option<PurchaseResponse>, Error> {
+        let purchase = self.purchase_repo.get(command.purchase_id, Some(command.auth_user.user_id)).await;
+        match purchase {
+            Ok(purchase) => Ok(purchase.as_ref().map(PurchaseResponse::from)),
+            Err(_) => Err(Error::msg("Error getting purchase")),
+        }
+    }
+
+    async fn create(&self, command: PurchaseCreateCommand) -> Result<Option<PurchaseResponse>, Error> {
+        Self::validate(&command.purchase_title, command.purchase_estimated_price_base_minor)?;
+        if command.purchase_status == Some(PurchaseStatus::Bought) {
+            return Err(AppError::BadRequest("a purchase is bought through the buy endpoint, with its transaction".into()).into());
+        }
+
+        let meta_user = command.auth_user.user_id;
+        match self.project_repo.get(command.project_id, Some(meta_user)).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        }
+
+        let purchase = self.purchase_repo.create(Purchase::from(command), Some(meta_user)).await;
+        match purchase {
+            Ok(purchase) => Ok(Some(PurchaseResponse::from(&purchase))),
+            Err(_) => Err(Error::msg("Error creating purchase")),
+        }
+    }
+
+    async fn update(&self, command: PurchaseUpdateCommand) -> Result<Option<PurchaseResponse>, Error> {
+        Self::validate(&command.purchase_title, command.purchase_estimated_price_base_minor)?;
+
+        let meta_user = command.auth_user.user_id;
+        let purchase = match self.purchase_repo.get(command.purchase_id, Some(meta_user)).await {
+            Ok(Some(purchase)) => purchase,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting purchase")),
+        };
+        if command.purchase_status == PurchaseStatus::Bought && purchase.status != PurchaseStatus::Bought {
+            return Err(AppError::BadRequest("a purchase is bought through the buy endpoint, with its transaction".into()).into());
+        }
+        let purchase = if purchase.status == PurchaseStatus::Bought && command.purchase_status != PurchaseStatus::Bought {
+            match self.purchase_repo.unbuy(command.purchase_id, command.purchase_status, Some(meta_user)).await {
+                Ok(Some(purchase)) => purchase,
+                Ok(None) => return Ok(None),
+                Err(_) => return Err(Error::msg("Error unlinking purchase transaction")),
+            }
+        } else {
+            purchase
+        };
+
+        let purchase_update = Purchase {
+            title: command.purchase_title,
+            estimated_price_base_minor: command.purchase_estimated_price_base_minor,
+            priority: command.purchase_priority,
+            planned_date: command.purchase_planned_date,
+            url: command.purchase_url,
+            note: command.purchase_note,
+            status: command.purchase_status,
+            ..purchase
+        };
+
+        let purchase = self.purchase_repo.update(purchase_update, Some(meta_user)).await;
+        match purchase {
+            Ok(purchase) => Ok(purchase.as_ref().map(PurchaseResponse::from)),
+            Err(_) => Err(Error::msg("Error updating purchase")),
+        }
+    }
+
+    async fn delete(&self, command: PurchaseDeleteCommand) -> Result<(), Error> {
+        let result = self.purchase_repo.delete(command.purchase_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting purchase")),
+        }
+    }
+
+    async fn get_by_project(&self, command: PurchaseListByProjectCommand) -> Result<Option<Vec<PurchaseResponse>>, Error> {
+        let purchases = self.project_purchases(&command).await?;
+        Ok(purchases.map(|purchases| purchases.iter().map(PurchaseResponse::from).collect()))
+    }
+
+    async fn summary(&self, command: PurchaseListByProjectCommand) -> Result<Option<PurchaseSummaryResponse>, Error> {
+        let purchases = self.project_purchases(&command).await?;
+        Ok(purchases.map(|purchases| PurchaseSummaryResponse::of(&purchases)))
+    }
+
+    async fn buy(&self, command: PurchaseBuyCommand) -> Result<Option<PurchaseResponse>, Error> {
+        let purchase = self.purchase_repo.buy(command.purchase_id, command.transaction_id, Some(command.auth_user.user_id)).await;
+        match purchase {
+            Ok(purchase) => Ok(purchase.as_ref().map(PurchaseResponse::from)),
+            Err(_) => Err(Error::msg("Error buying purchase")),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::{
+    recurrence_dto::TaskRecurrenceRequest,
+    recurrence_model::RecurrenceFrequency,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskRecurrenceGetCommand {
+    pub task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskRecurrenceGetCommand {
+    pub fn new(task_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskRecurrenceSetCommand {
+    pub task_id: Uuid,
+
+    pub recurrence_frequency: RecurrenceFrequency,
+    pub recurrence_interval: Option<u16>,
+    pub recurrence_from_completion: Option<bool>,
+    pub recurrence_until_date: Option<NaiveDate>,
+    pub recurrence_expense_amount_minor: Option<i64>,
+    pub recurrence_expense_account_id: Option<Uuid>,
+    pub recurrence_expense_category_id: Option<Uuid>,
+    pub recurrence_expense_payee_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskRecurrenceSetCommand {
+    pub fn new(task_id: Uuid, request: TaskRecurrenceRequest, auth_user: AuthUser) -> Self {
+        Self {
+            task_id,
+            recurrence_frequency: request.recurrence_frequency,
+            recurrence_interval: request.recurrence_interval,
+            recurrence_from_completion: request.recurrence_from_completion,
+            recurrence_until_date: request.recurrence_until_date,
+            recurrence_expense_amount_minor: request.recurrence_expense_amount_minor,
+            recurrence_expense_account_id: request.recurrence_expense_account_id,
+            recurrence_expense_category_id: request.recurrence_expense_category_id,
+            recurrence_expense_payee_id: request.recurrence_expense_payee_id,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskRecurrenceStopCommand {
+    pub task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskRecurrenceStopCommand {
+    pub fn new(task_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskRecurrenceListByProjectCommand {
+    pub project_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskRecurrenceListByProjectCommand {
+    pub fn new(project_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { project_id, auth_user }
+    }
+}
@@ -0,0 +1,158 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::get, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    recurrence_command::*,
+    recurrence_dto::*,
+    recurrence_service::{TaskRecurrenceService, TaskRecurrenceServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{project_id}/recurrences", get(get_project_task_recurrences))
+        .route("/tasks/{task_id}/recurrence", get(get_task_recurrence).put(put_task_recurrence).delete(delete_task_recurrence))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/recurrences",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurring task series of the project with their occurrences", body = Vec<TaskRecurrenceResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task Recurrence"
+)]
+pub async fn get_project_task_recurrences(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskRecurrenceResponse>>, StatusCode> {
+    let command = TaskRecurrenceListByProjectCommand::new(project_id, auth_user);
+    let recurrence_service = TaskRecurrenceService::from(&state);
+
+    let recurrences = recurrence_service.get_by_project(command).await;
+    match recurrences {
+        Ok(recurrences) => {
+            match recurrences {
+                Some(recurrences) => Ok(Json(recurrences)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/tasks/{task_id}/recurrence",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurrence rule of the task series, open occurrence and completion history", body = TaskRecurrenceResponse),
+        (status = StatusCode::NOT_FOUND, description = "Task not found or not recurring"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task Recurrence"
+)]
+pub async fn get_task_recurrence(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<TaskRecurrenceResponse>, StatusCode> {
+    let command = TaskRecurrenceGetCommand::new(task_id, auth_user);
+    let recurrence_service = TaskRecurrenceService::from(&state);
+
+    let recurrence = recurrence_service.get(command).await;
+    match recurrence {
+        Ok(recurrence) => {
+            match recurrence {
+                Some(recurrence) => Ok(Json(recurrence)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/tasks/{task_id}/recurrence",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurrence rule set; completing the task creates the next occurrence", body = TaskRecurrenceResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid rule or task without due date"),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task Recurrence"
+)]
+pub async fn put_task_recurrence(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+    Json(task_recurrence_request): Json<TaskRecurrenceRequest>
+) -> Result<Json<TaskRecurrenceResponse>, StatusCode> {
+    let command = TaskRecurrenceSetCommand::new(task_id, task_recurrence_request, auth_user);
+    let recurrence_service = TaskRecurrenceService::from(&state);
+
+    let recurrence = recurrence_service.set(command).await;
+    match recurrence {
+        Ok(recurrence) => {
+            match recurrence {
+                Some(recurrence) => Ok(Json(recurrence)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/tasks/{task_id}/recurrence",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurrence stopped, occurrences and history kept", body = TaskRecurrenceResponse),
+        (status = StatusCode::NOT_FOUND, description = "Task not found or not recurring"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task Recurrence"
+)]
+pub async fn delete_task_recurrence(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<TaskRecurrenceResponse>, StatusCode> {
+    let command = TaskRecurrenceStopCommand::new(task_id, auth_user);
+    let recurrence_service = TaskRecurrenceService::from(&state);
+
+    let recurrence = recurrence_service.stop(command).await;
+    match recurrence {
+        Ok(recurrence) => {
+            match recurrence {
+                Some(recurrence) => Ok(Json(recurrence)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
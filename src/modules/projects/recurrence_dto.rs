@@ -0,0 +1,78 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    recurrence_model::{RecurrenceFrequency, TaskRecurrence},
+    task_dto::TaskResponse,
+};
+use crate::shared::utils::{bu, obu};
+
+
+/// Recurrence rule with its occurrences: the open one and the completion history
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskRecurrenceResponse {
+    pub recurrence_id: Uuid,
+    pub project_id: Uuid,
+    pub recurrence_frequency: RecurrenceFrequency,
+    pub recurrence_interval: u16,
+    pub recurrence_from_completion: bool,
+    pub recurrence_until_date: Option<NaiveDate>,
+    /// False once stopped: completing the open occurrence no longer creates a next one
+    pub recurrence_active: bool,
+    pub recurrence_expense_amount_minor: Option<i64>,
+    pub recurrence_expense_account_id: Option<Uuid>,
+    pub recurrence_expense_category_id: Option<Uuid>,
+    pub recurrence_expense_payee_id: Option<Uuid>,
+
+    /// Occurrence not done yet, if any
+    pub recurrence_open_task: Option<TaskResponse>,
+    /// Done occurrences, most recent completion first
+    pub recurrence_history: Vec<TaskResponse>,
+
+    pub recurrence_created_at: Option<DateTime<Utc>>,
+    pub recurrence_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&TaskRecurrence> for TaskRecurrenceResponse {
+    fn from(recurrence: &TaskRecurrence) -> Self {
+        Self {
+            recurrence_id: bu(recurrence.id.clone().unwrap().as_slice()),
+            project_id: bu(recurrence.project_id.as_slice()),
+            recurrence_frequency: recurrence.frequency,
+            recurrence_interval: recurrence.interval_count,
+            recurrence_from_completion: recurrence.from_completion,
+            recurrence_until_date: recurrence.until_date,
+            recurrence_active: recurrence.active,
+            recurrence_expense_amount_minor: recurrence.expense_amount_minor,
+            recurrence_expense_account_id: obu(recurrence.expense_account_id.as_deref()),
+            recurrence_expense_category_id: obu(recurrence.expense_category_id.as_deref()),
+            recurrence_expense_payee_id: obu(recurrence.expense_payee_id.as_deref()),
+            recurrence_open_task: None,
+            recurrence_history: Vec::new(),
+            recurrence_created_at: recurrence.created_at,
+            recurrence_updated_at: recurrence.updated_at,
+        }
+    }
+}
+
+/// Replaces the rule of the task series (or starts a series from the task).
+/// The task needs a due date unless the next due dates are counted from completion.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaskRecurrenceRequest {
+    pub recurrence_frequency: RecurrenceFrequency,
+    /// Every N periods, 1 by default
+    pub recurrence_interval: Option<u16>,
+    /// Count the next due date from the completion day instead of the previous due date, false by default
+    pub recurrence_from_completion: Option<bool>,
+    pub recurrence_until_date: Option<NaiveDate>,
+
+    /// Expected expense of each occurrence, positive, in the account currency.
+    /// Spawned as a pending transaction on the project, dated on the occurrence due date.
+    pub recurrence_expense_amount_minor: Option<i64>,
+    /// Required with an expense amount
+    pub recurrence_expense_account_id: Option<Uuid>,
+    pub recurrence_expense_category_id: Option<Uuid>,
+    pub recurrence_expense_payee_id: Option<Uuid>,
+}
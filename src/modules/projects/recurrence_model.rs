@@ -0,0 +1,140 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::projects::recurrence_command::TaskRecurrenceSetCommand;
+use crate::shared::dates::add_months;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::oub;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl RecurrenceFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecurrenceFrequency::Daily => "daily",
+            RecurrenceFrequency::Weekly => "weekly",
+            RecurrenceFrequency::Monthly => "monthly",
+            RecurrenceFrequency::Yearly => "yearly",
+        }
+    }
+}
+
+
+/// Recurrence rule of a series of project tasks.
+/// Every occurrence is a task carrying `recurrence_id`; completing the open one creates the next,
+/// so the done occurrences are the completion history.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TaskRecurrence {
+    pub id: Option<Vec<u8>>,
+
+    pub project_id: Vec<u8>,
+    pub frequency: RecurrenceFrequency,
+    pub interval_count: u16,
+    /// Next due date counted from the completion day instead of the previous due date
+    pub from_completion: bool,
+    /// No occurrence is created past this date
+    pub until_date: Option<NaiveDate>,
+    pub active: bool,
+
+    /// Expected expense of each new occurrence, in the account currency (positive)
+    pub expense_amount_minor: Option<i64>,
+    pub expense_account_id: Option<Vec<u8>>,
+    pub expense_category_id: Option<Vec<u8>>,
+    pub expense_payee_id: Option<Vec<u8>>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl TaskRecurrence {
+    /// Date `periods` periods after `date`
+    fn shift(&self, date: NaiveDate, periods: u32) -> NaiveDate {
+        let count = u32::from(self.interval_count) * periods;
+        match self.frequency {
+            RecurrenceFrequency::Daily => date.checked_add_days(Days::new(count as u64)).unwrap_or(date),
+            RecurrenceFrequency::Weekly => date.checked_add_days(Days::new(count as u64 * 7)).unwrap_or(date),
+            RecurrenceFrequency::Monthly => add_months(date, count as i32),
+            RecurrenceFrequency::Yearly => add_months(date, count as i32 * 12),
+        }
+    }
+
+    /// Due date of the occurrence following one due on `due_date` and completed on `completed_on`.
+    /// Periods missed by a late completion are skipped: the next due date is always after `completed_on`.
+    /// None once the series is stopped or past `until_date`.
+    pub fn next_due(&self, due_date: Option<NaiveDate>, completed_on: NaiveDate) -> Option<NaiveDate> {
+        if !self.active {
+            return None;
+        }
+        let base = match due_date {
+            Some(due_date) if !self.from_completion => due_date,
+            _ => completed_on,
+        };
+        let mut periods = 1;
+        let mut next = self.shift(base, periods);
+        while next <= completed_on && next > base {
+            periods += 1;
+            next = self.shift(base, periods);
+        }
+        if next <= base || self.until_date.is_some_and(|until_date| next > until_date) {
+            return None;
+        }
+        Some(next)
+    }
+
+    pub fn has_expense(&self) -> bool {
+        self.expense_amount_minor.is_some() && self.expense_account_id.is_some()
+    }
+}
+
+impl FromSqlRow for TaskRecurrence {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+            frequency: row.try_get(index_map["frequency"])?,
+            interval_count: row.try_get(index_map["interval_count"])?,
+            from_completion: row.try_get(index_map["from_completion"])?,
+            until_date: row.try_get(index_map["until_date"])?,
+            active: row.try_get(index_map["active"])?,
+            expense_amount_minor: row.try_get(index_map["expense_amount_minor"])?,
+            expense_account_id: row.try_get(index_map["expense_account_id"])?,
+            expense_category_id: row.try_get(index_map["expense_category_id"])?,
+            expense_payee_id: row.try_get(index_map["expense_payee_id"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl TaskRecurrence {
+    /// Rule of the command for a series of the project `project_id`
+    pub fn of_project(command: &TaskRecurrenceSetCommand, project_id: Vec<u8>) -> Self {
+        Self {
+            id: None,
+            project_id,
+            frequency: command.recurrence_frequency,
+            interval_count: command.recurrence_interval.unwrap_or(1),
+            from_completion: command.recurrence_from_completion.unwrap_or(false),
+            until_date: command.recurrence_until_date,
+            active: true,
+            expense_amount_minor: command.recurrence_expense_amount_minor,
+            expense_account_id: oub(command.recurrence_expense_account_id),
+            expense_category_id: oub(command.recurrence_expense_category_id),
+            expense_payee_id: oub(command.recurrence_expense_payee_id),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::recurrence_model::TaskRecurrence;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait TaskRecurrenceRepositoryInterface {
+
+    async fn get(&self, recurrence_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<TaskRecurrence>, Error>;
+
+    async fn create(&self, recurrence: TaskRecurrence, meta_user: Option<Uuid>) -> Result<TaskRecurrence, Error>;
+
+    /// Also used to stop the series (active false); occurrences are never touched
+    async fn update(&self, recurrence: TaskRecurrence, meta_user: Option<Uuid>) -> Result<Option<TaskRecurrence>, Error>;
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TaskRecurrence>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct TaskRecurrenceRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for TaskRecurrenceRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<TaskRecurrence> for TaskRecurrenceRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl TaskRecurrenceRepositoryInterface for TaskRecurrenceRepository {
+    async fn get(&self, recurrence_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<TaskRecurrence>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(recurrence_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_task_recurrence_get_by_id", params).await
+    }
+
+    async fn create(&self, recurrence: TaskRecurrence, meta_user: Option<Uuid>) -> Result<TaskRecurrence, Error> {
+        let params = vec![
+            MySqlParam::from(recurrence.project_id),
+            MySqlParam::from(recurrence.frequency.as_str()),
+            MySqlParam::from(recurrence.interval_count),
+            MySqlParam::from(recurrence.from_completion),
+            MySqlParam::from(recurrence.until_date),
+            MySqlParam::from(recurrence.expense_amount_minor),
+            MySqlParam::from(recurrence.expense_account_id),
+            MySqlParam::from(recurrence.expense_category_id),
+            MySqlParam::from(recurrence.expense_payee_id),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_task_recurrence_create", params).await
+    }
+
+    async fn update(&self, recurrence: TaskRecurrence, meta_user: Option<Uuid>) -> Result<Option<TaskRecurrence>, Error> {
+        let params = vec![
+            MySqlParam::from(recurrence.id),
+            MySqlParam::from(recurrence.frequency.as_str()),
+            MySqlParam::from(recurrence.interval_count),
+            MySqlParam::from(recurrence.from_completion),
+            MySqlParam::from(recurrence.until_date),
+            MySqlParam::from(recurrence.active),
+            MySqlParam::from(recurrence.expense_amount_minor),
+            MySqlParam::from(recurrence.expense_account_id),
+            MySqlParam::from(recurrence.expense_category_id),
+            MySqlParam::from(recurrence.expense_payee_id),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_task_recurrence_update", params).await
+    }
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TaskRecurrence>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_task_recurrence_by_project", params).await
+    }
+}
@@ -0,0 +1,230 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    project_repo::{ProjectRepository, ProjectRepositoryInterface},
+    recurrence_command::*,
+    recurrence_dto::*,
+    recurrence_model::TaskRecurrence,
+    recurrence_repo::{TaskRecurrenceRepository, TaskRecurrenceRepositoryInterface},
+    task_dto::TaskResponse,
+    task_model::{Task, TaskStatus},
+    task_repo::{TaskRepository, TaskRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::bu;
+
+
+#[async_trait]
+pub trait TaskRecurrenceServiceInterface {
+
+    /// None when the task does not exist or is not recurring
+    async fn get(&self, command: TaskRecurrenceGetCommand) -> Result<Option<TaskRecurrenceResponse>, Error>;
+
+    /// Starts a series from the task, or replaces the rule of its series.
+    /// An open occurrence without expected expense gets one when the rule has an expense.
+    async fn set(&self, command: TaskRecurrenceSetCommand) -> Result<Option<TaskRecurrenceResponse>, Error>;
+
+    /// Completing the open occurrence no longer creates a next one; the history is kept
+    async fn stop(&self, command: TaskRecurrenceStopCommand) -> Result<Option<TaskRecurrenceResponse>, Error>;
+
+    /// None when the project does not exist
+    async fn get_by_project(&self, command: TaskRecurrenceListByProjectCommand) -> Result<Option<Vec<TaskRecurrenceResponse>>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct TaskRecurrenceService {
+    project_repo: ProjectRepository,
+    task_repo: TaskRepository,
+    recurrence_repo: TaskRecurrenceRepository,
+}
+
+impl From<&AppState> for TaskRecurrenceService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            project_repo: ProjectRepository::from(app_state),
+            task_repo: TaskRepository::from(app_state),
+            recurrence_repo: TaskRecurrenceRepository::from(app_state),
+        }
+    }
+}
+
+impl TaskRecurrenceService {
+    fn validate(command: &TaskRecurrenceSetCommand, task: &Task) -> Result<(), Error> {
+        if command.recurrence_interval == Some(0) {
+            return Err(AppError::BadRequest("recurrence interval must be at least 1".into()).into());
+        }
+        if task.due_date.is_none() && !command.recurrence_from_completion.unwrap_or(false) {
+            return Err(AppError::BadRequest("a recurring task needs a due date unless counted from completion".into()).into());
+        }
+        if let (Some(until_date), Some(due_date)) = (command.recurrence_until_date, task.due_date)
+            && until_date < due_date {
+            return Err(AppError::BadRequest("recurrence end cannot be before the task due date".into()).into());
+        }
+        if let Some(amount) = command.recurrence_expense_amount_minor {
+            if amount <= 0 {
+                return Err(AppError::BadRequest("expected expense amount must be positive".into()).into());
+            }
+            if command.recurrence_expense_account_id.is_none() {
+                return Err(AppError::BadRequest("expected expense account is required".into()).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Rule with its open occurrence and completion history, taken from the project tasks
+    fn with_occurrences(recurrence: &TaskRecurrence, tasks: &[Task]) -> TaskRecurrenceResponse {
+        let mut occurrences: Vec<&Task> = tasks.iter()
+            .filter(|task| task.recurrence_id == recurrence.id)
+            .collect();
+        occurrences.sort_by_key(|task| Reverse((task.completed_at, task.due_date)));
+
+        let mut response = TaskRecurrenceResponse::from(recurrence);
+        response.recurrence_open_task = occurrences.iter()
+            .find(|task| task.status != TaskStatus::Done)
+            .map(|task| TaskResponse::from(*task));
+        response.recurrence_history = occurrences.iter()
+            .filter(|task| task.status == TaskStatus::Done)
+            .map(|task| TaskResponse::from(*task))
+            .collect();
+        response
+    }
+
+    async fn project_tasks(&self, project_id: &[u8], meta_user: Option<Uuid>) -> Result<Vec<Task>, Error> {
+        match self.task_repo.get_by_project(bu(project_id), meta_user).await {
+            Ok(tasks) => Ok(tasks),
+            Err(_) => Err(Error::msg("Error getting project tasks")),
+        }
+    }
+
+    async fn task_recurrence(&self, task: &Task, meta_user: Option<Uuid>) -> Result<Option<TaskRecurrence>, Error> {
+        let recurrence_id = match task.recurrence_id.as_deref() {
+            Some(recurrence_id) => bu(recurrence_id),
+            None => return Ok(None),
+        };
+        match self.recurrence_repo.get(recurrence_id, meta_user).await {
+            Ok(recurrence) => Ok(recurrence),
+            Err(_) => Err(Error::msg("Error getting task recurrence")),
+        }
+    }
+
+    async fn task(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Task>, Error> {
+        match self.task_repo.get(task_id, meta_user).await {
+            Ok(task) => Ok(task),
+            Err(_) => Err(Error::msg("Error getting task")),
+        }
+    }
+}
+
+#[async_trait]
+impl TaskRecurrenceServiceInterface for TaskRecurrenceService {
+    async fn get(&self, command: TaskRecurrenceGetCommand) -> Result<Option<TaskRecurrenceResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let task = match self.task(command.task_id, meta_user).await? {
+            Some(task) => task,
+            None => return Ok(None),
+        };
+        let recurrence = match self.task_recurrence(&task, meta_user).await? {
+            Some(recurrence) => recurrence,
+            None => return Ok(None),
+        };
+
+        let tasks = self.project_tasks(&task.project_id, meta_user).await?;
+        Ok(Some(Self::with_occurrences(&recurrence, &tasks)))
+    }
+
+    async fn set(&self, command: TaskRecurrenceSetCommand) -> Result<Option<TaskRecurrenceResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let task = match self.task(command.task_id, meta_user).await? {
+            Some(task) => task,
+            None => return Ok(None),
+        };
+        Self::validate(&command, &task)?;
+
+        let recurrence_set = TaskRecurrence::of_project(&command, task.project_id.clone());
+        let recurrence = match self.task_recurrence(&task, meta_user).await? {
+            Some(current) => {
+                let recurrence_update = TaskRecurrence { id: current.id, ..recurrence_set };
+                match self.recurrence_repo.update(recurrence_update, meta_user).await {
+                    Ok(Some(recurrence)) => recurrence,
+                    Ok(None) => return Ok(None),
+                    Err(_) => return Err(Error::msg("Error updating task recurrence")),
+                }
+            },
+            None => {
+                let recurrence = match self.recurrence_repo.create(recurrence_set, meta_user).await {
+                    Ok(recurrence) => recurrence,
+                    Err(_) => return Err(Error::msg("Error creating task recurrence")),
+                };
+                let recurrence_id = bu(recurrence.id.as_deref().unwrap());
+                if self.task_repo.set_recurrence(command.task_id, recurrence_id, meta_user).await.is_err() {
+                    return Err(Error::msg("Error setting task recurrence"));
+                }
+                recurrence
+            },
+        };
+
+        let mut tasks = self.project_tasks(&task.project_id, meta_user).await?;
+        if recurrence.has_expense() {
+            let open: Vec<&Task> = tasks.iter()
+                .filter(|task| task.recurrence_id == recurrence.id && task.status != TaskStatus::Done)
+                .filter(|task| task.expense_transaction_id.is_none())
+                .collect();
+            for task in &open {
+                if self.task_repo.spawn_expense(bu(task.id.as_deref().unwrap()), meta_user).await.is_err() {
+                    return Err(Error::msg("Error creating expected expense"));
+                }
+            }
+            if !open.is_empty() {
+                tasks = self.project_tasks(&task.project_id, meta_user).await?;
+            }
+        }
+
+        Ok(Some(Self::with_occurrences(&recurrence, &tasks)))
+    }
+
+    async fn stop(&self, command: TaskRecurrenceStopCommand) -> Result<Option<TaskRecurrenceResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let task = match self.task(command.task_id, meta_user).await? {
+            Some(task) => task,
+            None => return Ok(None),
+        };
+        let recurrence = match self.task_recurrence(&task, meta_user).await? {
+            Some(recurrence) => recurrence,
+            None => return Ok(None),
+        };
+
+        let recurrence_update = TaskRecurrence { active: false, ..recurrence };
+        let recurrence = match self.recurrence_repo.update(recurrence_update, meta_user).await {
+            Ok(Some(recurrence)) => recurrence,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error stopping task recurrence")),
+        };
+
+        let tasks = self.project_tasks(&task.project_id, meta_user).await?;
+        Ok(Some(Self::with_occurrences(&recurrence, &tasks)))
+    }
+
+    async fn get_by_project(&self, command: TaskRecurrenceListByProjectCommand) -> Result<Option<Vec<TaskRecurrenceResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        match self.project_repo.get(command.project_id, meta_user).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        }
+
+        let recurrences = match self.recurrence_repo.get_by_project(command.project_id, meta_user).await {
+            Ok(recurrences) => recurrences,
+            Err(_) => return Err(Error::msg("Error getting task recurrences")),
+        };
+        let tasks = match self.task_repo.get_by_project(command.project_id, meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        Ok(Some(recurrences.iter().map(|recurrence| Self::with_occurrences(recurrence, &tasks)).collect()))
+    }
+}
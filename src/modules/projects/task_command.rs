@@ -0,0 +1,185 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::{
+    task_dto::{TaskCreateRequest, TaskDependencyCreateRequest, TaskMoveRequest, TaskScheduleQuery, TaskUpdateRequest},
+    task_model::TaskStatus,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskGetCommand {
+    pub task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskGetCommand {
+    pub fn new(task_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskCreateCommand {
+    pub project_id: Uuid,
+    pub task_title: String,
+
+    pub task_status: Option<TaskStatus>,
+    pub task_due_date: Option<NaiveDate>,
+    pub task_parent_task_id: Option<Uuid>,
+    pub task_estimate_minutes: Option<i32>,
+    pub task_assigned_person_id: Option<Uuid>,
+    pub task_location_id: Option<Uuid>,
+    pub task_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskCreateCommand {
+    pub fn new(project_id: Uuid, request: TaskCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            task_title: request.task_title,
+            task_status: request.task_status,
+            task_due_date: request.task_due_date,
+            task_parent_task_id: request.task_parent_task_id,
+            task_estimate_minutes: request.task_estimate_minutes,
+            task_assigned_person_id: request.task_assigned_person_id,
+            task_location_id: request.task_location_id,
+            task_note: request.task_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskUpdateCommand {
+    pub task_id: Uuid,
+
+    pub task_title: String,
+    pub task_due_date: Option<NaiveDate>,
+    pub task_parent_task_id: Option<Uuid>,
+    pub task_estimate_minutes: Option<i32>,
+    pub task_assigned_person_id: Option<Uuid>,
+    pub task_location_id: Option<Uuid>,
+    pub task_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskUpdateCommand {
+    pub fn new(task_id: Uuid, request: TaskUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            task_id,
+            task_title: request.task_title,
+            task_due_date: request.task_due_date,
+            task_parent_task_id: request.task_parent_task_id,
+            task_estimate_minutes: request.task_estimate_minutes,
+            task_assigned_person_id: request.task_assigned_person_id,
+            task_location_id: request.task_location_id,
+            task_note: request.task_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskDeleteCommand {
+    pub task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskDeleteCommand {
+    pub fn new(task_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskListByProjectCommand {
+    pub project_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskListByProjectCommand {
+    pub fn new(project_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { project_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskMoveCommand {
+    pub task_id: Uuid,
+
+    pub task_status: TaskStatus,
+    pub after_task_id: Option<Uuid>,
+    pub before_task_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskMoveCommand {
+    pub fn new(task_id: Uuid, request: TaskMoveRequest, auth_user: AuthUser) -> Self {
+        Self {
+            task_id,
+            task_status: request.task_status,
+            after_task_id: request.after_task_id,
+            before_task_id: request.before_task_id,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskDependencyCreateCommand {
+    pub task_id: Uuid,
+    pub depends_on_task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskDependencyCreateCommand {
+    pub fn new(task_id: Uuid, request: TaskDependencyCreateRequest, auth_user: AuthUser) -> Self {
+        Self { task_id, depends_on_task_id: request.depends_on_task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskDependencyDeleteCommand {
+    pub task_id: Uuid,
+    pub depends_on_task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskDependencyDeleteCommand {
+    pub fn new(task_id: Uuid, depends_on_task_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { task_id, depends_on_task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskScheduleCommand {
+    pub project_id: Uuid,
+
+    pub start_date: Option<NaiveDate>,
+    pub minutes_per_day: Option<i32>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TaskScheduleCommand {
+    pub fn new(project_id: Uuid, query: TaskScheduleQuery, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            start_date: query.start_date,
+            minutes_per_day: query.minutes_per_day,
+            auth_user,
+        }
+    }
+}
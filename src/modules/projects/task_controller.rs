@@ -0,0 +1,494 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{delete, get, post, put}, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    task_command::*,
+    task_dto::*,
+    task_service::{TaskService, TaskServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{project_id}/tasks", get(get_project_tasks).post(post_project_task))
+        .route("/{project_id}/tasks/tree", get(get_project_task_tree))
+        .route("/{project_id}/tasks/unblocked", get(get_project_unblocked_tasks))
+        .route("/{project_id}/tasks/dependencies", get(get_project_task_dependencies))
+        .route("/{project_id}/board", get(get_project_board))
+        .route("/{project_id}/schedule", get(get_project_schedule))
+        .route("/tasks/{task_id}", get(get_project_task).put(put_project_task).delete(delete_project_task))
+        .route("/tasks/{task_id}/move", put(put_project_task_move))
+        .route("/tasks/{task_id}/complete", put(put_project_task_complete))
+        .route("/tasks/{task_id}/dependencies", post(post_project_task_dependency))
+        .route("/tasks/{task_id}/dependencies/{depends_on_task_id}", delete(delete_project_task_dependency))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/tasks",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Tasks of the project, ordered by status then position", body = Vec<TaskResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_tasks(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskResponse>>, StatusCode> {
+    let command = TaskListByProjectCommand::new(project_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let tasks = task_service.get_by_project(command).await;
+    match tasks {
+        Ok(tasks) => Ok(Json(tasks)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/{project_id}/tasks",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task successfully created at the end of its column", body = TaskResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid title, minutes or parent task"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn post_project_task(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(task_create_request): Json<TaskCreateRequest>
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let command = TaskCreateCommand::new(project_id, task_create_request, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let task = task_service.create(command).await;
+    match task {
+        Ok(task) => {
+            match task {
+                Some(task) => Ok(Json(task)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/tasks/tree",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Subtask tree with estimate vs actual minutes rolled up per task and per project", body = TaskTreeResponse),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_task_tree(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<TaskTreeResponse>, StatusCode> {
+    let command = TaskListByProjectCommand::new(project_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let tree = task_service.tree(command).await;
+    match tree {
+        Ok(tree) => {
+            match tree {
+                Some(tree) => Ok(Json(tree)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/board",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Kanban board with todo, doing and done columns", body = TaskBoardResponse),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_board(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<TaskBoardResponse>, StatusCode> {
+    let command = TaskListByProjectCommand::new(project_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let board = task_service.board(command).await;
+    match board {
+        Ok(board) => {
+            match board {
+                Some(board) => Ok(Json(board)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/tasks/{task_id}",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task found successfully", body = TaskResponse),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_task(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let command = TaskGetCommand::new(task_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let task = task_service.get(command).await;
+    match task {
+        Ok(task) => {
+            match task {
+                Some(task) => Ok(Json(task)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/tasks/{task_id}",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task successfully updated", body = TaskResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid title, minutes or parent task"),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn put_project_task(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+    Json(task_update_request): Json<TaskUpdateRequest>
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let command = TaskUpdateCommand::new(task_id, task_update_request, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let task = task_service.update(command).await;
+    match task {
+        Ok(task) => {
+            match task {
+                Some(task) => Ok(Json(task)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/tasks/{task_id}",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task successfully deleted, its subtasks become top-level tasks"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn delete_project_task(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = TaskDeleteCommand::new(task_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let response = task_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/tasks/{task_id}/move",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task moved to the column and position", body = TaskResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Neighbour tasks are not in the target column or in the wrong order"),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn put_project_task_move(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+    Json(task_move_request): Json<TaskMoveRequest>
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let command = TaskMoveCommand::new(task_id, task_move_request, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let task = task_service.move_task(command).await;
+    match task {
+        Ok(task) => {
+            match task {
+                Some(task) => Ok(Json(task)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/tasks/unblocked",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Open tasks whose dependencies are all done", body = Vec<TaskResponse>),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_unblocked_tasks(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskResponse>>, StatusCode> {
+    let command = TaskListByProjectCommand::new(project_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let tasks = task_service.unblocked(command).await;
+    match tasks {
+        Ok(tasks) => {
+            match tasks {
+                Some(tasks) => Ok(Json(tasks)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/tasks/dependencies",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Blocking dependencies between the tasks of the project", body = Vec<TaskDependencyResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_task_dependencies(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskDependencyResponse>>, StatusCode> {
+    let command = TaskListByProjectCommand::new(project_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let dependencies = task_service.dependencies(command).await;
+    match dependencies {
+        Ok(dependencies) => Ok(Json(dependencies)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/{project_id}/schedule",
+    params(
+        ("project_id", description = "project identifier in uuid"),
+        TaskScheduleQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Critical path, slack and earliest finish dates from remaining estimates and dependencies", body = TaskScheduleResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid minutes per day"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn get_project_schedule(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<TaskScheduleQuery>,
+) -> Result<Json<TaskScheduleResponse>, StatusCode> {
+    let command = TaskScheduleCommand::new(project_id, query, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let schedule = task_service.schedule(command).await;
+    match schedule {
+        Ok(schedule) => {
+            match schedule {
+                Some(schedule) => Ok(Json(schedule)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/tasks/{task_id}/complete",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task done, with the tasks it just unblocked", body = TaskCompletionResponse),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn put_project_task_complete(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<TaskCompletionResponse>, StatusCode> {
+    let command = TaskGetCommand::new(task_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let completion = task_service.complete(command).await;
+    match completion {
+        Ok(completion) => {
+            match completion {
+                Some(completion) => Ok(Json(completion)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/tasks/{task_id}/dependencies",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Task now waits for the other task", body = TaskDependencyResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Self dependency, task of another project or cycle"),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn post_project_task_dependency(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+    Json(task_dependency_create_request): Json<TaskDependencyCreateRequest>
+) -> Result<Json<TaskDependencyResponse>, StatusCode> {
+    let command = TaskDependencyCreateCommand::new(task_id, task_dependency_create_request, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let dependency = task_service.add_dependency(command).await;
+    match dependency {
+        Ok(dependency) => {
+            match dependency {
+                Some(dependency) => Ok(Json(dependency)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/tasks/{task_id}/dependencies/{depends_on_task_id}",
+    params(
+        ("task_id", description = "task identifier in uuid"),
+        ("depends_on_task_id", description = "identifier in uuid of the task it depends on")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Dependency successfully removed"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Task"
+)]
+pub async fn delete_project_task_dependency(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((task_id, depends_on_task_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    let command = TaskDependencyDeleteCommand::new(task_id, depends_on_task_id, auth_user);
+    let task_service = TaskService::from(&state);
+
+    let response = task_service.remove_dependency(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
@@ -0,0 +1,236 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::projects::task_model::{Task, TaskDependency, TaskStatus};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskResponse {
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub task_title: String,
+    pub task_status: TaskStatus,
+    pub task_due_date: Option<NaiveDate>,
+    pub task_parent_task_id: Option<Uuid>,
+    pub task_milestone_id: Option<Uuid>,
+    pub task_recurrence_id: Option<Uuid>,
+    /// Pending transaction expected for this occurrence of a recurring task
+    pub task_expense_transaction_id: Option<Uuid>,
+    /// Fractional position inside the kanban column, ascending
+    pub task_order_idx: f64,
+    pub task_estimate_minutes: Option<i32>,
+    /// Sum of the time entries of the task
+    pub task_actual_minutes: Option<i32>,
+    pub task_assigned_person_id: Option<Uuid>,
+    pub task_location_id: Option<Uuid>,
+    pub task_note: Option<String>,
+    pub task_completed_at: Option<DateTime<Utc>>,
+
+    pub task_created_at: Option<DateTime<Utc>>,
+    pub task_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Task> for TaskResponse {
+    fn from(task: &Task) -> Self {
+        Self {
+            task_id: bu(task.id.clone().unwrap().as_slice()),
+            project_id: bu(task.project_id.as_slice()),
+            task_title: task.title.clone(),
+            task_status: task.status,
+            task_due_date: task.due_date,
+            task_parent_task_id: obu(task.parent_task_id.as_deref()),
+            task_milestone_id: obu(task.milestone_id.as_deref()),
+            task_recurrence_id: obu(task.recurrence_id.as_deref()),
+            task_expense_transaction_id: obu(task.expense_transaction_id.as_deref()),
+            task_order_idx: task.order_idx,
+            task_estimate_minutes: task.estimate_minutes,
+            task_actual_minutes: task.actual_minutes,
+            task_assigned_person_id: obu(task.assigned_person_id.as_deref()),
+            task_location_id: obu(task.location_id.as_deref()),
+            task_note: task.note.clone(),
+            task_completed_at: task.completed_at,
+            task_created_at: task.created_at,
+            task_updated_at: task.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaskCreateRequest {
+    pub task_title: String,
+
+    /// Todo by default; the task is appended at the end of its column
+    pub task_status: Option<TaskStatus>,
+    pub task_due_date: Option<NaiveDate>,
+    pub task_parent_task_id: Option<Uuid>,
+    pub task_estimate_minutes: Option<i32>,
+    pub task_assigned_person_id: Option<Uuid>,
+    pub task_location_id: Option<Uuid>,
+    pub task_note: Option<String>,
+}
+
+/// Status and position are not part of the update: they only change through the move endpoint
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaskUpdateRequest {
+    pub task_title: String,
+    pub task_due_date: Option<NaiveDate>,
+    pub task_parent_task_id: Option<Uuid>,
+    pub task_estimate_minutes: Option<i32>,
+    pub task_assigned_person_id: Option<Uuid>,
+    pub task_location_id: Option<Uuid>,
+    pub task_note: Option<String>,
+}
+
+/// Drops the task in `task_status` column between `after_task_id` and `before_task_id`.
+/// Without neighbours the task goes to the end of the column.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaskMoveRequest {
+    pub task_status: TaskStatus,
+    pub after_task_id: Option<Uuid>,
+    pub before_task_id: Option<Uuid>,
+}
+
+/// Estimate vs actual time of a task and all its subtasks
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct TaskTimeRollupResponse {
+    pub estimate_minutes: i64,
+    pub actual_minutes: i64,
+    /// actual - estimate, positive when over the estimate
+    pub variance_minutes: i64,
+    pub task_count: i64,
+    pub done_count: i64,
+}
+
+impl TaskTimeRollupResponse {
+    pub fn of(task: &Task) -> Self {
+        let estimate_minutes = task.estimate_minutes.unwrap_or(0) as i64;
+        let actual_minutes = task.actual_minutes.unwrap_or(0) as i64;
+        Self {
+            estimate_minutes,
+            actual_minutes,
+            variance_minutes: actual_minutes - estimate_minutes,
+            task_count: 1,
+            done_count: (task.status == TaskStatus::Done) as i64,
+        }
+    }
+
+    pub fn add(&mut self, other: &TaskTimeRollupResponse) {
+        self.estimate_minutes += other.estimate_minutes;
+        self.actual_minutes += other.actual_minutes;
+        self.variance_minutes = self.actual_minutes - self.estimate_minutes;
+        self.task_count += other.task_count;
+        self.done_count += other.done_count;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskNodeResponse {
+    pub task: TaskResponse,
+    /// Rolled up over the task itself and its whole subtree
+    pub task_rollup: TaskTimeRollupResponse,
+    #[schema(no_recursion)]
+    pub task_subtasks: Vec<TaskNodeResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskTreeResponse {
+    pub project_id: Uuid,
+    pub project_rollup: TaskTimeRollupResponse,
+    pub tasks: Vec<TaskNodeResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskBoardColumnResponse {
+    pub task_status: TaskStatus,
+    pub column_rollup: TaskTimeRollupResponse,
+    pub tasks: Vec<TaskResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskBoardResponse {
+    pub project_id: Uuid,
+    pub columns: Vec<TaskBoardColumnResponse>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskDependencyResponse {
+    /// Blocked task
+    pub task_id: Uuid,
+    /// Task that must be done first
+    pub depends_on_task_id: Uuid,
+    pub dependency_created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&TaskDependency> for TaskDependencyResponse {
+    fn from(dependency: &TaskDependency) -> Self {
+        Self {
+            task_id: bu(dependency.task_id.as_slice()),
+            depends_on_task_id: bu(dependency.depends_on_task_id.as_slice()),
+            dependency_created_at: dependency.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaskDependencyCreateRequest {
+    pub depends_on_task_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskCompletionResponse {
+    pub task: TaskResponse,
+    /// Tasks waiting on this one whose dependencies are now all done
+    pub unblocked_tasks: Vec<TaskResponse>,
+    /// Next occurrence created when the task is recurring
+    pub next_task: Option<TaskResponse>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TaskScheduleQuery {
+    /// Today by default
+    pub start_date: Option<NaiveDate>,
+    /// Working minutes per day used to turn minutes into dates, 480 by default
+    pub minutes_per_day: Option<i32>,
+}
+
+/// Critical path method over the remaining work: a task starts once all its dependencies finish.
+/// Minutes are counted from `schedule_start_date`; done tasks take no time.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskScheduleItemResponse {
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub task_status: TaskStatus,
+    pub task_due_date: Option<NaiveDate>,
+    /// max(estimate - actual, 0), 0 once done or without estimate
+    pub remaining_minutes: i64,
+    pub earliest_start_minutes: i64,
+    pub earliest_finish_minutes: i64,
+    pub earliest_finish_date: NaiveDate,
+    /// How much the task can slip without delaying the project
+    pub slack_minutes: i64,
+    pub task_critical: bool,
+    /// Earliest finish date is after the due date
+    pub task_late: bool,
+    pub task_blocked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskScheduleResponse {
+    pub project_id: Uuid,
+    pub schedule_start_date: NaiveDate,
+    pub minutes_per_day: i32,
+    /// Length of the critical path
+    pub project_remaining_minutes: i64,
+    pub project_earliest_finish_date: NaiveDate,
+    pub project_due_date: Option<NaiveDate>,
+    pub project_late: bool,
+    /// Open tasks without estimate, counted as zero minutes
+    pub tasks_without_estimate: i64,
+    /// Task ids from first to last
+    pub critical_path: Vec<Uuid>,
+    pub tasks: Vec<TaskScheduleItemResponse>,
+}
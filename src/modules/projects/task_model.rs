@@ -0,0 +1,135 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::projects::task_command::TaskCreateCommand;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum TaskStatus {
+    Todo,
+    Doing,
+    Done,
+}
+
+impl TaskStatus {
+    pub const ALL: [TaskStatus; 3] = [TaskStatus::Todo, TaskStatus::Doing, TaskStatus::Done];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::Doing => "doing",
+            TaskStatus::Done => "done",
+        }
+    }
+}
+
+
+/// Project task row.
+/// `order_idx` is fractional and scoped to the kanban column (project + status):
+/// a move only rewrites the moved task, placed halfway between its new neighbours.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Task {
+    pub id: Option<Vec<u8>>,
+
+    pub project_id: Vec<u8>,
+    pub title: String,
+    pub status: TaskStatus,
+    pub due_date: Option<NaiveDate>,
+    pub parent_task_id: Option<Vec<u8>>,
+    /// Only changes through the milestone attach/detach endpoints
+    pub milestone_id: Option<Vec<u8>>,
+    /// Series of a recurring task, only changes through the recurrence endpoints
+    pub recurrence_id: Option<Vec<u8>>,
+    /// Pending transaction spawned as the expected expense of this occurrence
+    pub expense_transaction_id: Option<Vec<u8>>,
+    pub order_idx: f64,
+
+    pub estimate_minutes: Option<i32>,
+    pub actual_minutes: Option<i32>,
+
+    pub assigned_person_id: Option<Vec<u8>>,
+    pub location_id: Option<Vec<u8>>,
+    pub note: Option<String>,
+    /// Set when the task enters the done column, cleared when it leaves it
+    pub completed_at: Option<DateTime<Utc>>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for Task {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+            title: row.try_get(index_map["title"])?,
+            status: row.try_get(index_map["status"])?,
+            due_date: row.try_get(index_map["due_date"])?,
+            parent_task_id: row.try_get(index_map["parent_task_id"])?,
+            milestone_id: row.try_get(index_map["milestone_id"])?,
+            recurrence_id: row.try_get(index_map["recurrence_id"])?,
+            expense_transaction_id: row.try_get(index_map["expense_transaction_id"])?,
+            order_idx: row.try_get(index_map["order_idx"])?,
+            estimate_minutes: row.try_get(index_map["estimate_minutes"])?,
+            actual_minutes: row.try_get(index_map["actual_minutes"])?,
+            assigned_person_id: row.try_get(index_map["assigned_person_id"])?,
+            location_id: row.try_get(index_map["location_id"])?,
+            note: row.try_get(index_map["note"])?,
+            completed_at: row.try_get(index_map["completed_at"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<TaskCreateCommand> for Task {
+    fn from(command: TaskCreateCommand) -> Self {
+        Self {
+            id: None,
+            project_id: ub(command.project_id),
+            title: command.task_title,
+            status: command.task_status.unwrap_or(TaskStatus::Todo),
+            due_date: command.task_due_date,
+            parent_task_id: oub(command.task_parent_task_id),
+            milestone_id: None,
+            recurrence_id: None,
+            expense_transaction_id: None,
+            order_idx: 0.0,
+            estimate_minutes: command.task_estimate_minutes,
+            actual_minutes: None,
+            assigned_person_id: oub(command.task_assigned_person_id),
+            location_id: oub(command.task_location_id),
+            note: command.task_note,
+            completed_at: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+
+/// `task_id` stays blocked until `depends_on_task_id` is done.
+/// Both tasks belong to the same project and the graph never has cycles.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TaskDependency {
+    pub task_id: Vec<u8>,
+    pub depends_on_task_id: Vec<u8>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for TaskDependency {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            task_id: row.try_get(index_map["task_id"])?,
+            depends_on_task_id: row.try_get(index_map["depends_on_task_id"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+        })
+    }
+}
@@ -0,0 +1,230 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::task_model::{Task, TaskDependency, TaskStatus};
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait TaskRepositoryInterface {
+
+    async fn get(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Task>, Error>;
+
+    async fn create(&self, task: Task, meta_user: Option<Uuid>) -> Result<Task, Error>;
+
+    /// Does not touch status, order_idx nor milestone_id
+    async fn update(&self, task: Task, meta_user: Option<Uuid>) -> Result<Option<Task>, Error>;
+
+    /// Subtasks are kept and become top-level tasks (parent_task_id set to NULL)
+    async fn delete(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Ordered by status then order_idx
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Task>, Error>;
+
+    /// Only writes the moved row
+    async fn move_to(&self, task_id: Uuid, status: TaskStatus, order_idx: f64, meta_user: Option<Uuid>) -> Result<Option<Task>, Error>;
+
+    /// Spreads the column back to evenly spaced order_idx (step 1024), keeping the current order.
+    /// Only needed once repeated moves at the same place exhausted the gap between two tasks.
+    async fn renumber_column(&self, project_id: Uuid, status: TaskStatus, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Sets project_tasks.recurrence_id; the recurrence must belong to the task project
+    async fn set_recurrence(&self, task_id: Uuid, recurrence_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Creates the expected expense of a recurring task from its rule: a pending transaction on the project,
+    /// dated on the task due date (today without one), then stores it in expense_transaction_id
+    async fn spawn_expense(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Task>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct TaskRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for TaskRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<Task> for TaskRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl TaskRepositoryInterface for TaskRepository {
+    async fn get(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Task>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_task_get_by_id", params).await
+    }
+
+    async fn create(&self, task: Task, meta_user: Option<Uuid>) -> Result<Task, Error> {
+        let params = vec![
+            MySqlParam::from(task.project_id),
+            MySqlParam::from(task.title),
+            MySqlParam::from(task.status.as_str()),
+            MySqlParam::from(task.due_date),
+            MySqlParam::from(task.parent_task_id),
+            MySqlParam::from(task.order_idx),
+            MySqlParam::from(task.estimate_minutes),
+            MySqlParam::from(task.actual_minutes),
+            MySqlParam::from(task.assigned_person_id),
+            MySqlParam::from(task.location_id),
+            MySqlParam::from(task.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_task_create", params).await
+    }
+
+    async fn update(&self, task: Task, meta_user: Option<Uuid>) -> Result<Option<Task>, Error> {
+        let params = vec![
+            MySqlParam::from(task.id),
+            MySqlParam::from(task.title),
+            MySqlParam::from(task.due_date),
+            MySqlParam::from(task.parent_task_id),
+            MySqlParam::from(task.estimate_minutes),
+            MySqlParam::from(task.actual_minutes),
+            MySqlParam::from(task.assigned_person_id),
+            MySqlParam::from(task.location_id),
+            MySqlParam::from(task.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_task_update", params).await
+    }
+
+    async fn delete(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_task_delete", params).await
+    }
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<Task>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_task_by_project", params).await
+    }
+
+    async fn move_to(&self, task_id: Uuid, status: TaskStatus, order_idx: f64, meta_user: Option<Uuid>) -> Result<Option<Task>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(status.as_str()),
+            MySqlParam::from(order_idx),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_task_move", params).await
+    }
+
+    async fn renumber_column(&self, project_id: Uuid, status: TaskStatus, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(status.as_str()),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_task_renumber_column", params).await
+    }
+
+    async fn set_recurrence(&self, task_id: Uuid, recurrence_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(ub(recurrence_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_task_set_recurrence", params).await
+    }
+
+    async fn spawn_expense(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<Task>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_task_spawn_expense", params).await
+    }
+}
+
+
+#[async_trait]
+pub trait TaskDependencyRepositoryInterface {
+
+    /// Adding an existing dependency is a no-op
+    async fn create(&self, task_id: Uuid, depends_on_task_id: Uuid, meta_user: Option<Uuid>) -> Result<TaskDependency, Error>;
+
+    async fn delete(&self, task_id: Uuid, depends_on_task_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TaskDependency>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct TaskDependencyRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for TaskDependencyRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<TaskDependency> for TaskDependencyRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl TaskDependencyRepositoryInterface for TaskDependencyRepository {
+    async fn create(&self, task_id: Uuid, depends_on_task_id: Uuid, meta_user: Option<Uuid>) -> Result<TaskDependency, Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(ub(depends_on_task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_task_dependency_create", params).await
+    }
+
+    async fn delete(&self, task_id: Uuid, depends_on_task_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(ub(depends_on_task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_task_dependency_delete", params).await
+    }
+
+    async fn get_by_project(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TaskDependency>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(project_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_task_dependency_by_project", params).await
+    }
+}
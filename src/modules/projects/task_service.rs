@@ -0,0 +1,738 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    project_model::Project,
+    project_repo::{ProjectRepository, ProjectRepositoryInterface},
+    recurrence_repo::{TaskRecurrenceRepository, TaskRecurrenceRepositoryInterface},
+    task_command::*,
+    task_dto::*,
+    task_model::{Task, TaskDependency, TaskStatus},
+    task_repo::{
+        TaskRepository, TaskRepositoryInterface,
+        TaskDependencyRepository, TaskDependencyRepositoryInterface
+    },
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, oub, ub};
+
+
+/// Distance between two consecutive tasks when appended or renumbered
+pub const ORDER_STEP: f64 = 1024.0;
+
+/// Below this gap a midpoint loses precision and the column gets renumbered
+const ORDER_MIN_GAP: f64 = 1e-6;
+
+/// Working day used by the schedule when none is given
+const DEFAULT_MINUTES_PER_DAY: i32 = 480;
+
+
+#[async_trait]
+pub trait TaskServiceInterface {
+
+    async fn get(&self, command: TaskGetCommand) -> Result<Option<TaskResponse>, Error>;
+
+    /// None when the project does not exist
+    async fn create(&self, command: TaskCreateCommand) -> Result<Option<TaskResponse>, Error>;
+
+    async fn update(&self, command: TaskUpdateCommand) -> Result<Option<TaskResponse>, Error>;
+
+    async fn delete(&self, command: TaskDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_project(&self, command: TaskListByProjectCommand) -> Result<Vec<TaskResponse>, Error>;
+
+
+    // --- Views ---
+
+    async fn tree(&self, command: TaskListByProjectCommand) -> Result<Option<TaskTreeResponse>, Error>;
+
+    async fn board(&self, command: TaskListByProjectCommand) -> Result<Option<TaskBoardResponse>, Error>;
+
+
+    // --- Kanban ---
+
+    async fn move_task(&self, command: TaskMoveCommand) -> Result<Option<TaskResponse>, Error>;
+
+
+    // --- Dependencies ---
+
+    async fn dependencies(&self, command: TaskListByProjectCommand) -> Result<Vec<TaskDependencyResponse>, Error>;
+
+    /// None when the task does not exist
+    async fn add_dependency(&self, command: TaskDependencyCreateCommand) -> Result<Option<TaskDependencyResponse>, Error>;
+
+    async fn remove_dependency(&self, command: TaskDependencyDeleteCommand) -> Result<(), Error>;
+
+    /// Open tasks whose dependencies are all done
+    async fn unblocked(&self, command: TaskListByProjectCommand) -> Result<Option<Vec<TaskResponse>>, Error>;
+
+    async fn schedule(&self, command: TaskScheduleCommand) -> Result<Option<TaskScheduleResponse>, Error>;
+
+    /// Moves the task to the end of the done column; a recurring task gets its next occurrence
+    async fn complete(&self, command: TaskGetCommand) -> Result<Option<TaskCompletionResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct TaskService {
+    project_repo: ProjectRepository,
+    task_repo: TaskRepository,
+    dependency_repo: TaskDependencyRepository,
+    recurrence_repo: TaskRecurrenceRepository,
+}
+
+impl From<&AppState> for TaskService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            project_repo: ProjectRepository::from(app_state),
+            task_repo: TaskRepository::from(app_state),
+            dependency_repo: TaskDependencyRepository::from(app_state),
+            recurrence_repo: TaskRecurrenceRepository::from(app_state),
+        }
+    }
+}
+
+impl TaskService {
+    fn validate(title: &str, estimate_minutes: Option<i32>) -> Result<(), Error> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest("task title is required".into()).into());
+        }
+        if estimate_minutes.is_some_and(|minutes| minutes < 0) {
+            return Err(AppError::BadRequest("task estimate cannot be negative".into()).into());
+        }
+        Ok(())
+    }
+
+    /// The parent must belong to the project, and must not be the task itself or one of its subtasks
+    fn validate_parent(tasks: &[Task], task_id: Option<&[u8]>, parent_task_id: Option<&[u8]>) -> Result<(), Error> {
+        let Some(parent_task_id) = parent_task_id else {
+            return Ok(());
+        };
+        let parents: HashMap<&[u8], Option<&[u8]>> = tasks.iter()
+            .filter_map(|task| task.id.as_deref().map(|id| (id, task.parent_task_id.as_deref())))
+            .collect();
+        if !parents.contains_key(parent_task_id) {
+            return Err(AppError::BadRequest("parent task is not part of this project".into()).into());
+        }
+
+        let mut current = Some(parent_task_id);
+        let mut depth = 0;
+        while let Some(id) = current {
+            if Some(id) == task_id || depth > parents.len() {
+                return Err(AppError::BadRequest("a task cannot be nested under itself or one of its subtasks".into()).into());
+            }
+            current = parents.get(id).copied().flatten();
+            depth += 1;
+        }
+        Ok(())
+    }
+
+    /// Tasks of one column in display order
+    fn column<'a>(tasks: &'a [Task], status: TaskStatus, excluded: Option<&[u8]>) -> Vec<&'a Task> {
+        let mut column: Vec<&Task> = tasks.iter()
+            .filter(|task| task.status == status && task.id.as_deref() != excluded)
+            .collect();
+        column.sort_by(|a, b| a.order_idx.total_cmp(&b.order_idx));
+        column
+    }
+
+    /// order_idx for a task dropped between `after` and `before` in `column`.
+    /// Returns None when the gap is too small and the column must be renumbered first.
+    fn slot(column: &[&Task], after: Option<&[u8]>, before: Option<&[u8]>) -> Result<Option<f64>, Error> {
+        let position = |id: &[u8]| column.iter().position(|task| task.id.as_deref() == Some(id))
+            .ok_or_else(|| Error::from(AppError::BadRequest("neighbour task is not in the target column".into())));
+
+        let (lower, upper) = match (after, before) {
+            (Some(after), Some(before)) => {
+                let (after, before) = (position(after)?, position(before)?);
+                if after >= before {
+                    return Err(AppError::BadRequest("after task must come before the before task".into()).into());
+                }
+                (Some(column[after].order_idx), Some(column[before].order_idx))
+            },
+            (Some(after), None) => {
+                let after = position(after)?;
+                (Some(column[after].order_idx), column.get(after + 1).map(|task| task.order_idx))
+            },
+            (None, Some(before)) => {
+                let before = position(before)?;
+                (before.checked_sub(1).map(|index| column[index].order_idx), Some(column[before].order_idx))
+            },
+            (None, None) => (column.last().map(|task| task.order_idx), None),
+        };
+
+        Ok(match (lower, upper) {
+            (Some(lower), Some(upper)) if upper - lower < ORDER_MIN_GAP => None,
+            (Some(lower), Some(upper)) => Some(lower + (upper - lower) / 2.0),
+            (Some(lower), None) => Some(lower + ORDER_STEP),
+            (None, Some(upper)) => Some(upper - ORDER_STEP),
+            (None, None) => Some(ORDER_STEP),
+        })
+    }
+
+    fn node(task: &Task, children: &HashMap<&[u8], Vec<&Task>>) -> TaskNodeResponse {
+        let subtasks: Vec<TaskNodeResponse> = children.get(task.id.as_deref().unwrap_or_default())
+            .map(|subtasks| subtasks.iter().map(|subtask| Self::node(subtask, children)).collect())
+            .unwrap_or_default();
+
+        let mut rollup = TaskTimeRollupResponse::of(task);
+        for subtask in &subtasks {
+            rollup.add(&subtask.task_rollup);
+        }
+
+        TaskNodeResponse {
+            task: TaskResponse::from(task),
+            task_rollup: rollup,
+            task_subtasks: subtasks,
+        }
+    }
+
+    /// Roots are tasks without parent; siblings keep the repository order (status, order_idx)
+    pub fn tree_of(project_id: Uuid, tasks: &[Task]) -> TaskTreeResponse {
+        let mut children: HashMap<&[u8], Vec<&Task>> = HashMap::new();
+        let mut roots = Vec::new();
+        for task in tasks {
+            match task.parent_task_id.as_deref() {
+                Some(parent_task_id) => children.entry(parent_task_id).or_default().push(task),
+                None => roots.push(task),
+            }
+        }
+
+        let nodes: Vec<TaskNodeResponse> = roots.iter().map(|task| Self::node(task, &children)).collect();
+        let mut project_rollup = TaskTimeRollupResponse::default();
+        for node in &nodes {
+            project_rollup.add(&node.task_rollup);
+        }
+
+        TaskTreeResponse { project_id, project_rollup, tasks: nodes }
+    }
+
+    /// Every task appears in its own status column, subtasks included
+    pub fn board_of(project_id: Uuid, tasks: &[Task]) -> TaskBoardResponse {
+        let columns = TaskStatus::ALL.iter().map(|&status| {
+            let column = Self::column(tasks, status, None);
+            let mut column_rollup = TaskTimeRollupResponse::default();
+            for task in &column {
+                column_rollup.add(&TaskTimeRollupResponse::of(task));
+            }
+            TaskBoardColumnResponse {
+                task_status: status,
+                column_rollup,
+                tasks: column.into_iter().map(TaskResponse::from).collect(),
+            }
+        }).collect();
+
+        TaskBoardResponse { project_id, columns }
+    }
+
+    /// Moves the task, then rolls a recurring task over to its next occurrence when it just got done.
+    /// Returns the moved task and the created occurrence.
+    async fn move_and_roll(&self, command: TaskMoveCommand) -> Result<Option<(Task, Option<Task>)>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let task = match self.task_repo.get(command.task_id, meta_user).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+        let project_id = bu(task.project_id.as_slice());
+        let after = oub(command.after_task_id);
+        let before = oub(command.before_task_id);
+
+        let mut renumbered = false;
+        let order_idx = loop {
+            let tasks = match self.task_repo.get_by_project(project_id, meta_user).await {
+                Ok(tasks) => tasks,
+                Err(_) => return Err(Error::msg("Error getting project tasks")),
+            };
+            let column = Self::column(&tasks, command.task_status, task.id.as_deref());
+            match Self::slot(&column, after.as_deref(), before.as_deref())? {
+                Some(order_idx) => break order_idx,
+                None if renumbered => return Err(Error::msg("Error renumbering task column")),
+                None => {
+                    if self.task_repo.renumber_column(project_id, command.task_status, meta_user).await.is_err() {
+                        return Err(Error::msg("Error renumbering task column"));
+                    }
+                    renumbered = true;
+                },
+            }
+        };
+
+        let moved = match self.task_repo.move_to(command.task_id, command.task_status, order_idx, meta_user).await {
+            Ok(Some(moved)) => moved,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error moving task")),
+        };
+
+        let next = if task.status != TaskStatus::Done && moved.status == TaskStatus::Done {
+            self.roll_over(&moved, meta_user).await?
+        } else {
+            None
+        };
+        Ok(Some((moved, next)))
+    }
+
+    /// Creates the next occurrence of a recurring task just completed, with its expected expense.
+    /// Nothing is created when the series is stopped, ended, or already has an open occurrence.
+    async fn roll_over(&self, completed: &Task, meta_user: Option<Uuid>) -> Result<Option<Task>, Error> {
+        let recurrence_id = match completed.recurrence_id.as_deref() {
+            Some(recurrence_id) => bu(recurrence_id),
+            None => return Ok(None),
+        };
+        let recurrence = match self.recurrence_repo.get(recurrence_id, meta_user).await {
+            Ok(Some(recurrence)) => recurrence,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task recurrence")),
+        };
+
+        let completed_on = completed.completed_at.map_or_else(|| Utc::now().date_naive(), |completed_at| completed_at.date_naive());
+        let due_date = match recurrence.next_due(completed.due_date, completed_on) {
+            Some(due_date) => due_date,
+            None => return Ok(None),
+        };
+
+        let tasks = match self.task_repo.get_by_project(bu(completed.project_id.as_slice()), meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        if tasks.iter().any(|task| task.recurrence_id == completed.recurrence_id && task.status != TaskStatus::Done) {
+            return Ok(None);
+        }
+
+        let next_create = Task {
+            id: None,
+            status: TaskStatus::Todo,
+            due_date: Some(due_date),
+            milestone_id: None,
+            recurrence_id: None,
+            expense_transaction_id: None,
+            order_idx: Self::column(&tasks, TaskStatus::Todo, None).last()
+                .map_or(ORDER_STEP, |task| task.order_idx + ORDER_STEP),
+            actual_minutes: None,
+            completed_at: None,
+            created_at: None,
+            updated_at: None,
+            ..completed.clone()
+        };
+        let mut next = match self.task_repo.create(next_create, meta_user).await {
+            Ok(next) => next,
+            Err(_) => return Err(Error::msg("Error creating next task occurrence")),
+        };
+        let next_id = bu(next.id.as_deref().unwrap());
+        if self.task_repo.set_recurrence(next_id, recurrence_id, meta_user).await.is_err() {
+            return Err(Error::msg("Error setting task recurrence"));
+        }
+        next.recurrence_id = completed.recurrence_id.clone();
+
+        if recurrence.has_expense() {
+            match self.task_repo.spawn_expense(next_id, meta_user).await {
+                Ok(Some(spawned)) => next = spawned,
+                Ok(None) => {},
+                Err(_) => return Err(Error::msg("Error creating expected expense")),
+            }
+        }
+        Ok(Some(next))
+    }
+
+    async fn project_tasks(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<(Project, Vec<Task>)>, Error> {
+        let project = match self.project_repo.get(project_id, meta_user).await {
+            Ok(Some(project)) => project,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        };
+        match self.task_repo.get_by_project(project_id, meta_user).await {
+            Ok(tasks) => Ok(Some((project, tasks))),
+            Err(_) => Err(Error::msg("Error getting project tasks")),
+        }
+    }
+
+    async fn project_dependencies(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TaskDependency>, Error> {
+        match self.dependency_repo.get_by_project(project_id, meta_user).await {
+            Ok(dependencies) => Ok(dependencies),
+            Err(_) => Err(Error::msg("Error getting task dependencies")),
+        }
+    }
+
+    /// Open tasks with at least one dependency not done yet
+    fn blocked_of<'a>(tasks: &'a [Task], dependencies: &[TaskDependency]) -> HashSet<&'a [u8]> {
+        let done: HashSet<&[u8]> = tasks.iter()
+            .filter(|task| task.status == TaskStatus::Done)
+            .filter_map(|task| task.id.as_deref())
+            .collect();
+        let waiting: HashSet<&[u8]> = dependencies.iter()
+            .filter(|dependency| !done.contains(dependency.depends_on_task_id.as_slice()))
+            .map(|dependency| dependency.task_id.as_slice())
+            .collect();
+
+        tasks.iter()
+            .filter(|task| task.status != TaskStatus::Done)
+            .filter_map(|task| task.id.as_deref())
+            .filter(|id| waiting.contains(id))
+            .collect()
+    }
+
+    /// Whether `task_id` already depends, directly or not, on `target_id`
+    fn depends_on(dependencies: &[TaskDependency], task_id: &[u8], target_id: &[u8]) -> bool {
+        let mut edges: HashMap<&[u8], Vec<&[u8]>> = HashMap::new();
+        for dependency in dependencies {
+            edges.entry(dependency.task_id.as_slice()).or_default().push(dependency.depends_on_task_id.as_slice());
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![task_id];
+        while let Some(current) = stack.pop() {
+            if current == target_id {
+                return true;
+            }
+            if visited.insert(current) {
+                stack.extend(edges.get(current).into_iter().flatten().copied());
+            }
+        }
+        false
+    }
+
+    /// Critical path method: forward pass for earliest start/finish, backward pass for slack.
+    /// A task with zero slack delays the whole project as soon as it slips.
+    pub fn schedule_of(
+        project: &Project,
+        tasks: &[Task],
+        dependencies: &[TaskDependency],
+        start_date: NaiveDate,
+        minutes_per_day: i32
+    ) -> Result<TaskScheduleResponse, Error> {
+        let index: HashMap<&[u8], usize> = tasks.iter().enumerate()
+            .filter_map(|(i, task)| task.id.as_deref().map(|id| (id, i)))
+            .collect();
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+        for dependency in dependencies {
+            if let (Some(&task), Some(&depends_on)) = (index.get(dependency.task_id.as_slice()), index.get(dependency.depends_on_task_id.as_slice())) {
+                predecessors[task].push(depends_on);
+                successors[depends_on].push(task);
+            }
+        }
+
+        let mut pending: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<usize> = (0..tasks.len()).filter(|&i| pending[i] == 0).collect();
+        let mut order = Vec::with_capacity(tasks.len());
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for &successor in &successors[i] {
+                pending[successor] -= 1;
+                if pending[successor] == 0 {
+                    queue.push_back(successor);
+                }
+            }
+        }
+        if order.len() < tasks.len() {
+            return Err(Error::msg("Error task dependencies contain a cycle"));
+        }
+
+        let remaining: Vec<i64> = tasks.iter().map(|task| match task.status {
+            TaskStatus::Done => 0,
+            _ => (task.estimate_minutes.unwrap_or(0) as i64 - task.actual_minutes.unwrap_or(0) as i64).max(0),
+        }).collect();
+
+        let mut earliest_start = vec![0i64; tasks.len()];
+        let mut earliest_finish = vec![0i64; tasks.len()];
+        for &i in &order {
+            earliest_start[i] = predecessors[i].iter().map(|&p| earliest_finish[p]).max().unwrap_or(0);
+            earliest_finish[i] = earliest_start[i] + remaining[i];
+        }
+        let project_end = earliest_finish.iter().copied().max().unwrap_or(0);
+
+        let mut latest_finish = vec![project_end; tasks.len()];
+        for &i in order.iter().rev() {
+            if let Some(finish) = successors[i].iter().map(|&s| latest_finish[s] - remaining[s]).min() {
+                latest_finish[i] = finish;
+            }
+        }
+        let slack: Vec<i64> = (0..tasks.len()).map(|i| latest_finish[i] - earliest_finish[i]).collect();
+        let critical = |i: usize| tasks[i].status != TaskStatus::Done && slack[i] == 0;
+
+        let mut critical_path = Vec::new();
+        let mut current = (0..tasks.len())
+            .filter(|&i| critical(i) && earliest_finish[i] == project_end && project_end > 0)
+            .min_by_key(|&i| tasks[i].due_date);
+        while let Some(i) = current {
+            critical_path.push(bu(tasks[i].id.as_deref().unwrap()));
+            current = predecessors[i].iter().copied()
+                .find(|&p| critical(p) && earliest_finish[p] == earliest_start[i]);
+        }
+        critical_path.reverse();
+
+        let date_of = |minutes: i64| {
+            let days = if minutes <= 0 { 0 } else { (minutes - 1) / minutes_per_day as i64 };
+            start_date.checked_add_days(Days::new(days as u64)).unwrap_or(start_date)
+        };
+        let blocked = Self::blocked_of(tasks, dependencies);
+
+        let mut items: Vec<TaskScheduleItemResponse> = order.iter().map(|&i| {
+            let task = &tasks[i];
+            let earliest_finish_date = date_of(earliest_finish[i]);
+            TaskScheduleItemResponse {
+                task_id: bu(task.id.as_deref().unwrap()),
+                task_title: task.title.clone(),
+                task_status: task.status,
+                task_due_date: task.due_date,
+                remaining_minutes: remaining[i],
+                earliest_start_minutes: earliest_start[i],
+                earliest_finish_minutes: earliest_finish[i],
+                earliest_finish_date,
+                slack_minutes: slack[i],
+                task_critical: critical(i),
+                task_late: task.status != TaskStatus::Done && task.due_date.is_some_and(|due_date| earliest_finish_date > due_date),
+                task_blocked: task.id.as_deref().is_some_and(|id| blocked.contains(id)),
+            }
+        }).collect();
+        items.sort_by_key(|item| (item.earliest_start_minutes, item.earliest_finish_minutes));
+
+        let project_earliest_finish_date = date_of(project_end);
+        Ok(TaskScheduleResponse {
+            project_id: bu(project.id.as_deref().unwrap()),
+            schedule_start_date: start_date,
+            minutes_per_day,
+            project_remaining_minutes: project_end,
+            project_earliest_finish_date,
+            project_due_date: project.due_date,
+            project_late: project.due_date.is_some_and(|due_date| project_earliest_finish_date > due_date),
+            tasks_without_estimate: tasks.iter()
+                .filter(|task| task.status != TaskStatus::Done && task.estimate_minutes.is_none())
+                .count() as i64,
+            critical_path,
+            tasks: items,
+        })
+    }
+}
+
+#[async_trait]
+impl TaskServiceInterface for TaskService {
+    async fn get(&self, command: TaskGetCommand) -> Result<Option<TaskResponse>, Error> {
+        let task = self.task_repo.get(command.task_id, Some(command.auth_user.user_id)).await;
+        match task {
+            Ok(task) => Ok(task.as_ref().map(TaskResponse::from)),
+            Err(_) => Err(Error::msg("Error getting task")),
+        }
+    }
+
+    async fn create(&self, command: TaskCreateCommand) -> Result<Option<TaskResponse>, Error> {
+        Self::validate(&command.task_title, command.task_estimate_minutes)?;
+
+        let meta_user = command.auth_user.user_id;
+        let tasks = match self.project_tasks(command.project_id, Some(meta_user)).await? {
+            Some((_, tasks)) => tasks,
+            None => return Ok(None),
+        };
+        Self::validate_parent(&tasks, None, oub(command.task_parent_task_id).as_deref())?;
+
+        let mut task_create = Task::from(command);
+        task_create.order_idx = Self::column(&tasks, task_create.status, None).last()
+            .map_or(ORDER_STEP, |task| task.order_idx + ORDER_STEP);
+
+        let task = self.task_repo.create(task_create, Some(meta_user)).await;
+        match task {
+            Ok(task) => Ok(Some(TaskResponse::from(&task))),
+            Err(_) => Err(Error::msg("Error creating task")),
+        }
+    }
+
+    async fn update(&self, command: TaskUpdateCommand) -> Result<Option<TaskResponse>, Error> {
+        Self::validate(&command.task_title, command.task_estimate_minutes)?;
+
+        let meta_user = command.auth_user.user_id;
+        let task = match self.task_repo.get(command.task_id, Some(meta_user)).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+
+        let parent_task_id = oub(command.task_parent_task_id);
+        if parent_task_id.is_some() {
+            let tasks = match self.task_repo.get_by_project(bu(task.project_id.as_slice()), Some(meta_user)).await {
+                Ok(tasks) => tasks,
+                Err(_) => return Err(Error::msg("Error getting project tasks")),
+            };
+            Self::validate_parent(&tasks, task.id.as_deref(), parent_task_id.as_deref())?;
+        }
+
+        let task_update = Task {
+            title: command.task_title,
+            due_date: command.task_due_date,
+            parent_task_id,
+            estimate_minutes: command.task_estimate_minutes,
+            actual_minutes: task.actual_minutes,
+            assigned_person_id: oub(command.task_assigned_person_id),
+            location_id: oub(command.task_location_id),
+            note: command.task_note,
+            ..task
+        };
+
+        let task = self.task_repo.update(task_update, Some(meta_user)).await;
+        match task {
+            Ok(task) => Ok(task.as_ref().map(TaskResponse::from)),
+            Err(_) => Err(Error::msg("Error updating task")),
+        }
+    }
+
+    async fn delete(&self, command: TaskDeleteCommand) -> Result<(), Error> {
+        let result = self.task_repo.delete(command.task_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting task")),
+        }
+    }
+
+    async fn get_by_project(&self, command: TaskListByProjectCommand) -> Result<Vec<TaskResponse>, Error> {
+        let tasks = self.task_repo.get_by_project(command.project_id, Some(command.auth_user.user_id)).await;
+        match tasks {
+            Ok(tasks) => Ok(tasks.iter().map(TaskResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting project tasks")),
+        }
+    }
+
+    async fn tree(&self, command: TaskListByProjectCommand) -> Result<Option<TaskTreeResponse>, Error> {
+        let tasks = self.project_tasks(command.project_id, Some(command.auth_user.user_id)).await?;
+        Ok(tasks.map(|(_, tasks)| Self::tree_of(command.project_id, &tasks)))
+    }
+
+    async fn board(&self, command: TaskListByProjectCommand) -> Result<Option<TaskBoardResponse>, Error> {
+        let tasks = self.project_tasks(command.project_id, Some(command.auth_user.user_id)).await?;
+        Ok(tasks.map(|(_, tasks)| Self::board_of(command.project_id, &tasks)))
+    }
+
+    async fn move_task(&self, command: TaskMoveCommand) -> Result<Option<TaskResponse>, Error> {
+        let moved = self.move_and_roll(command).await?;
+        Ok(moved.map(|(task, _)| TaskResponse::from(&task)))
+    }
+
+    async fn dependencies(&self, command: TaskListByProjectCommand) -> Result<Vec<TaskDependencyResponse>, Error> {
+        let dependencies = self.project_dependencies(command.project_id, Some(command.auth_user.user_id)).await?;
+        Ok(dependencies.iter().map(TaskDependencyResponse::from).collect())
+    }
+
+    async fn add_dependency(&self, command: TaskDependencyCreateCommand) -> Result<Option<TaskDependencyResponse>, Error> {
+        if command.task_id == command.depends_on_task_id {
+            return Err(AppError::BadRequest("a task cannot depend on itself".into()).into());
+        }
+
+        let meta_user = Some(command.auth_user.user_id);
+        let task = match self.task_repo.get(command.task_id, meta_user).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+        let project_id = bu(task.project_id.as_slice());
+        let tasks = match self.task_repo.get_by_project(project_id, meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        let depends_on_task_id = ub(command.depends_on_task_id);
+        if !tasks.iter().any(|task| task.id.as_ref() == Some(&depends_on_task_id)) {
+            return Err(AppError::BadRequest("a task can only depend on a task of the same project".into()).into());
+        }
+
+        let dependencies = self.project_dependencies(project_id, meta_user).await?;
+        if Self::depends_on(&dependencies, &depends_on_task_id, &ub(command.task_id)) {
+            return Err(AppError::BadRequest("this dependency would create a cycle".into()).into());
+        }
+
+        let dependency = self.dependency_repo.create(command.task_id, command.depends_on_task_id, meta_user).await;
+        match dependency {
+            Ok(dependency) => Ok(Some(TaskDependencyResponse::from(&dependency))),
+            Err(_) => Err(Error::msg("Error creating task dependency")),
+        }
+    }
+
+    async fn remove_dependency(&self, command: TaskDependencyDeleteCommand) -> Result<(), Error> {
+        let result = self.dependency_repo.delete(command.task_id, command.depends_on_task_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting task dependency")),
+        }
+    }
+
+    async fn unblocked(&self, command: TaskListByProjectCommand) -> Result<Option<Vec<TaskResponse>>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let tasks = match self.project_tasks(command.project_id, meta_user).await? {
+            Some((_, tasks)) => tasks,
+            None => return Ok(None),
+        };
+        let dependencies = self.project_dependencies(command.project_id, meta_user).await?;
+
+        let blocked = Self::blocked_of(&tasks, &dependencies);
+        Ok(Some(tasks.iter()
+            .filter(|task| task.status != TaskStatus::Done)
+            .filter(|task| task.id.as_deref().is_some_and(|id| !blocked.contains(id)))
+            .map(TaskResponse::from)
+            .collect()))
+    }
+
+    async fn schedule(&self, command: TaskScheduleCommand) -> Result<Option<TaskScheduleResponse>, Error> {
+        let minutes_per_day = command.minutes_per_day.unwrap_or(DEFAULT_MINUTES_PER_DAY);
+        if !(1..=1440).contains(&minutes_per_day) {
+            return Err(AppError::BadRequest("minutes per day must be between 1 and 1440".into()).into());
+        }
+
+        let meta_user = Some(command.auth_user.user_id);
+        let (project, tasks) = match self.project_tasks(command.project_id, meta_user).await? {
+            Some(project_tasks) => project_tasks,
+            None => return Ok(None),
+        };
+        let dependencies = self.project_dependencies(command.project_id, meta_user).await?;
+
+        let start_date = command.start_date.unwrap_or_else(|| Utc::now().date_naive());
+        Self::schedule_of(&project, &tasks, &dependencies, start_date, minutes_per_day).map(Some)
+    }
+
+    async fn complete(&self, command: TaskGetCommand) -> Result<Option<TaskCompletionResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let task = match self.task_repo.get(command.task_id, meta_user).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+        if task.status == TaskStatus::Done {
+            return Ok(Some(TaskCompletionResponse { task: TaskResponse::from(&task), unblocked_tasks: Vec::new(), next_task: None }));
+        }
+
+        let (completed, next) = match self.move_and_roll(TaskMoveCommand {
+            task_id: command.task_id,
+            task_status: TaskStatus::Done,
+            after_task_id: None,
+            before_task_id: None,
+            auth_user: command.auth_user,
+        }).await? {
+            Some(moved) => moved,
+            None => return Ok(None),
+        };
+
+        let project_id = bu(task.project_id.as_slice());
+        let tasks = match self.task_repo.get_by_project(project_id, meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        let dependencies = self.project_dependencies(project_id, meta_user).await?;
+
+        // Every open dependent was blocked by this task until now
+        let task_id = ub(command.task_id);
+        let dependents: HashSet<&[u8]> = dependencies.iter()
+            .filter(|dependency| dependency.depends_on_task_id == task_id)
+            .map(|dependency| dependency.task_id.as_slice())
+            .collect();
+        let blocked = Self::blocked_of(&tasks, &dependencies);
+        let unblocked_tasks = tasks.iter()
+            .filter(|task| task.status != TaskStatus::Done)
+            .filter(|task| task.id.as_deref().is_some_and(|id| dependents.contains(id) && !blocked.contains(id)))
+            .map(TaskResponse::from)
+            .collect();
+
+        Ok(Some(TaskCompletionResponse {
+            task: TaskResponse::from(&completed),
+            unblocked_tasks,
+            next_task: next.as_ref().map(TaskResponse::from),
+        }))
+    }
+}
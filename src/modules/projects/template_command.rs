@@ -0,0 +1,144 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::template_dto::{
+    ProjectCloneRequest, ProjectTemplateCreateRequest, ProjectTemplateInstantiateRequest, ProjectTemplateUpdateRequest
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectTemplateGetCommand {
+    pub template_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectTemplateGetCommand {
+    pub fn new(template_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { template_id, auth_user }
+    }
+}
+
+/// Saves an existing project as a template
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectTemplateCreateCommand {
+    pub project_id: Uuid,
+
+    pub template_name: Option<String>,
+    pub template_description: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectTemplateCreateCommand {
+    pub fn new(project_id: Uuid, request: ProjectTemplateCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            template_name: request.template_name,
+            template_description: request.template_description,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectTemplateUpdateCommand {
+    pub template_id: Uuid,
+
+    pub template_name: String,
+    pub template_description: Option<String>,
+    pub template_priority: i32,
+    pub template_budget_base_minor: i64,
+    pub template_due_offset_days: Option<i32>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectTemplateUpdateCommand {
+    pub fn new(template_id: Uuid, request: ProjectTemplateUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            template_id,
+            template_name: request.template_name,
+            template_description: request.template_description,
+            template_priority: request.template_priority,
+            template_budget_base_minor: request.template_budget_base_minor,
+            template_due_offset_days: request.template_due_offset_days,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectTemplateDeleteCommand {
+    pub template_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectTemplateDeleteCommand {
+    pub fn new(template_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { template_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectTemplateListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectTemplateListByUserCommand {
+    pub fn new(auth_user: AuthUser) -> Self {
+        Self { user_id: auth_user.user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectTemplateInstantiateCommand {
+    pub template_id: Uuid,
+
+    pub project_name: Option<String>,
+    pub project_start_date: NaiveDate,
+    pub project_budget_base_minor: Option<i64>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectTemplateInstantiateCommand {
+    pub fn new(template_id: Uuid, request: ProjectTemplateInstantiateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            template_id,
+            project_name: request.project_name,
+            project_start_date: request.project_start_date,
+            project_budget_base_minor: request.project_budget_base_minor,
+            auth_user,
+        }
+    }
+}
+
+/// Copies a project without saving a template
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectCloneCommand {
+    pub project_id: Uuid,
+
+    pub project_name: Option<String>,
+    pub project_start_date: NaiveDate,
+    pub project_budget_base_minor: Option<i64>,
+
+    pub auth_user: AuthUser,
+}
+
+impl ProjectCloneCommand {
+    pub fn new(project_id: Uuid, request: ProjectCloneRequest, auth_user: AuthUser) -> Self {
+        Self {
+            project_id,
+            project_name: request.project_name,
+            project_start_date: request.project_start_date,
+            project_budget_base_minor: request.project_budget_base_minor,
+            auth_user,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    project_dto::ProjectResponse,
+    template_command::*,
+    template_dto::*,
+    template_service::{ProjectTemplateService, ProjectTemplateServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/templates", get(get_project_templates))
+        .route("/templates/{template_id}", get(get_project_template).put(put_project_template).delete(delete_project_template))
+        .route("/templates/{template_id}/instantiate", post(post_project_template_instantiate))
+        .route("/{project_id}/template", post(post_project_template))
+        .route("/{project_id}/clone", post(post_project_clone))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/templates",
+    responses(
+        (status = StatusCode::OK, description = "Project templates of current user", body = Vec<ProjectTemplateResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn get_project_templates(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<ProjectTemplateResponse>>, StatusCode> {
+    let command = ProjectTemplateListByUserCommand::new(auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let templates = template_service.get_by_user(command).await;
+    match templates {
+        Ok(templates) => Ok(Json(templates)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/templates/{template_id}",
+    params(
+        ("template_id", description = "template identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project template found successfully", body = ProjectTemplateResponse),
+        (status = StatusCode::NOT_FOUND, description = "Project template not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn get_project_template(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(template_id): Path<Uuid>,
+) -> Result<Json<ProjectTemplateResponse>, StatusCode> {
+    let command = ProjectTemplateGetCommand::new(template_id, auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let template = template_service.get(command).await;
+    match template {
+        Ok(template) => {
+            match template {
+                Some(template) => Ok(Json(template)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/templates/{template_id}",
+    params(
+        ("template_id", description = "template identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project template successfully updated", body = ProjectTemplateResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid name or budget"),
+        (status = StatusCode::NOT_FOUND, description = "Project template not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn put_project_template(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(template_id): Path<Uuid>,
+    Json(template_update_request): Json<ProjectTemplateUpdateRequest>
+) -> Result<Json<ProjectTemplateResponse>, StatusCode> {
+    let command = ProjectTemplateUpdateCommand::new(template_id, template_update_request, auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let template = template_service.update(command).await;
+    match template {
+        Ok(template) => {
+            match template {
+                Some(template) => Ok(Json(template)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/templates/{template_id}",
+    params(
+        ("template_id", description = "template identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project template successfully deleted"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn delete_project_template(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(template_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = ProjectTemplateDeleteCommand::new(template_id, auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let response = template_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/templates/{template_id}/instantiate",
+    params(
+        ("template_id", description = "template identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "New planned project with tasks, milestones and dependencies shifted to the start date", body = ProjectResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid budget or inconsistent template content"),
+        (status = StatusCode::NOT_FOUND, description = "Project template not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn post_project_template_instantiate(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(template_id): Path<Uuid>,
+    Json(template_instantiate_request): Json<ProjectTemplateInstantiateRequest>
+) -> Result<Json<ProjectResponse>, StatusCode> {
+    let command = ProjectTemplateInstantiateCommand::new(template_id, template_instantiate_request, auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let project = template_service.instantiate(command).await;
+    match project {
+        Ok(project) => {
+            match project {
+                Some(project) => Ok(Json(project)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/{project_id}/template",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Project saved as a template with its task tree, milestones, relative dates and budget", body = ProjectTemplateResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Empty template name"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn post_project_template(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(template_create_request): Json<ProjectTemplateCreateRequest>
+) -> Result<Json<ProjectTemplateResponse>, StatusCode> {
+    let command = ProjectTemplateCreateCommand::new(project_id, template_create_request, auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let template = template_service.create(command).await;
+    match template {
+        Ok(template) => {
+            match template {
+                Some(template) => Ok(Json(template)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/{project_id}/clone",
+    params(
+        ("project_id", description = "project identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "New planned project copied from this one, dates shifted to the start date", body = ProjectResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid budget"),
+        (status = StatusCode::NOT_FOUND, description = "Project not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Template"
+)]
+pub async fn post_project_clone(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(project_id): Path<Uuid>,
+    Json(project_clone_request): Json<ProjectCloneRequest>
+) -> Result<Json<ProjectResponse>, StatusCode> {
+    let command = ProjectCloneCommand::new(project_id, project_clone_request, auth_user);
+    let template_service = ProjectTemplateService::from(&state);
+
+    let project = template_service.clone_project(command).await;
+    match project {
+        Ok(project) => {
+            match project {
+                Some(project) => Ok(Json(project)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
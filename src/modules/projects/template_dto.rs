@@ -0,0 +1,80 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::projects::template_model::{ProjectTemplate, ProjectTemplateContent};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateResponse {
+    pub template_id: Uuid,
+    pub user_id: Uuid,
+    pub template_name: String,
+    pub template_description: Option<String>,
+    pub template_priority: i32,
+    pub template_budget_base_minor: i64,
+    /// Project due date, in days after its start
+    pub template_due_offset_days: Option<i32>,
+    pub template_source_project_id: Option<Uuid>,
+    pub template_content: ProjectTemplateContent,
+
+    pub template_created_at: Option<DateTime<Utc>>,
+    pub template_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&ProjectTemplate> for ProjectTemplateResponse {
+    fn from(template: &ProjectTemplate) -> Self {
+        Self {
+            template_id: bu(template.id.clone().unwrap().as_slice()),
+            user_id: bu(template.user_id.as_slice()),
+            template_name: template.name.clone(),
+            template_description: template.description.clone(),
+            template_priority: template.priority,
+            template_budget_base_minor: template.budget_base_minor,
+            template_due_offset_days: template.due_offset_days,
+            template_source_project_id: obu(template.source_project_id.as_deref()),
+            template_content: serde_json::from_str(&template.content).unwrap_or_default(),
+            template_created_at: template.created_at,
+            template_updated_at: template.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateCreateRequest {
+    /// Project name by default
+    pub template_name: Option<String>,
+    pub template_description: Option<String>,
+}
+
+/// The content is not editable: save a project again to change tasks and milestones
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateUpdateRequest {
+    pub template_name: String,
+    pub template_description: Option<String>,
+    pub template_priority: i32,
+    pub template_budget_base_minor: i64,
+    pub template_due_offset_days: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateInstantiateRequest {
+    /// Template name by default
+    pub project_name: Option<String>,
+    /// Every date of the template is shifted from this day
+    pub project_start_date: NaiveDate,
+    /// Template budget by default
+    pub project_budget_base_minor: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectCloneRequest {
+    /// Source project name by default
+    pub project_name: Option<String>,
+    /// Every date of the source project is shifted from this day
+    pub project_start_date: NaiveDate,
+    /// Source project budget by default
+    pub project_budget_base_minor: Option<i64>,
+}
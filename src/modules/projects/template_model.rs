@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::shared::db::mysql::FromSqlRow;
+
+
+/// Saved project skeleton. Dates are day offsets from the project start,
+/// so instantiating with another start date shifts everything.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProjectTemplate {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub name: String,
+    pub description: Option<String>,
+    pub priority: i32,
+    pub budget_base_minor: i64,
+    pub due_offset_days: Option<i32>,
+    pub source_project_id: Option<Vec<u8>>,
+    /// ProjectTemplateContent serialized as JSON
+    pub content: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for ProjectTemplate {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            name: row.try_get(index_map["name"])?,
+            description: row.try_get(index_map["description"])?,
+            priority: row.try_get(index_map["priority"])?,
+            budget_base_minor: row.try_get(index_map["budget_base_minor"])?,
+            due_offset_days: row.try_get(index_map["due_offset_days"])?,
+            source_project_id: row.try_get(index_map["source_project_id"])?,
+            content: row.try_get(index_map["content"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateContent {
+    pub milestones: Vec<ProjectTemplateMilestone>,
+    /// Parents always come before their subtasks
+    pub tasks: Vec<ProjectTemplateTask>,
+    pub dependencies: Vec<ProjectTemplateDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateMilestone {
+    pub title: String,
+    pub due_offset_days: Option<i32>,
+    pub budget_base_minor: Option<i64>,
+    pub person_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateTask {
+    pub title: String,
+    /// Index of the parent in `tasks`
+    pub parent: Option<usize>,
+    /// Index of the milestone in `milestones`
+    pub milestone: Option<usize>,
+    pub due_offset_days: Option<i32>,
+    pub estimate_minutes: Option<i32>,
+    pub assigned_person_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+/// Indexes in `tasks`: `task` waits for `depends_on`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTemplateDependency {
+    pub task: usize,
+    pub depends_on: usize,
+}
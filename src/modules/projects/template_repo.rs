@@ -0,0 +1,105 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::template_model::ProjectTemplate;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait ProjectTemplateRepositoryInterface {
+
+    async fn get(&self, template_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<ProjectTemplate>, Error>;
+
+    async fn create(&self, template: ProjectTemplate, meta_user: Option<Uuid>) -> Result<ProjectTemplate, Error>;
+
+    /// Does not touch the content
+    async fn update(&self, template: ProjectTemplate, meta_user: Option<Uuid>) -> Result<Option<ProjectTemplate>, Error>;
+
+    async fn delete(&self, template_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ProjectTemplate>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct ProjectTemplateRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for ProjectTemplateRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<ProjectTemplate> for ProjectTemplateRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ProjectTemplateRepositoryInterface for ProjectTemplateRepository {
+    async fn get(&self, template_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<ProjectTemplate>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(template_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_template_get_by_id", params).await
+    }
+
+    async fn create(&self, template: ProjectTemplate, meta_user: Option<Uuid>) -> Result<ProjectTemplate, Error> {
+        let params = vec![
+            MySqlParam::from(template.user_id),
+            MySqlParam::from(template.name),
+            MySqlParam::from(template.description),
+            MySqlParam::from(template.priority),
+            MySqlParam::from(template.budget_base_minor),
+            MySqlParam::from(template.due_offset_days),
+            MySqlParam::from(template.source_project_id),
+            MySqlParam::from(template.content),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_template_create", params).await
+    }
+
+    async fn update(&self, template: ProjectTemplate, meta_user: Option<Uuid>) -> Result<Option<ProjectTemplate>, Error> {
+        let params = vec![
+            MySqlParam::from(template.id),
+            MySqlParam::from(template.name),
+            MySqlParam::from(template.description),
+            MySqlParam::from(template.priority),
+            MySqlParam::from(template.budget_base_minor),
+            MySqlParam::from(template.due_offset_days),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_template_update", params).await
+    }
+
+    async fn delete(&self, template_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(template_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_template_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<ProjectTemplate>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_template_by_user", params).await
+    }
+}
@@ -0,0 +1,446 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, TimeDelta, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    milestone_model::{Milestone, MilestoneStatus},
+    milestone_repo::{MilestoneRepository, MilestoneRepositoryInterface},
+    project_dto::ProjectResponse,
+    project_model::{Project, ProjectStatus},
+    project_repo::{ProjectRepository, ProjectRepositoryInterface},
+    task_model::{Task, TaskDependency, TaskStatus},
+    task_repo::{
+        TaskRepository, TaskRepositoryInterface,
+        TaskDependencyRepository, TaskDependencyRepositoryInterface
+    },
+    task_service::ORDER_STEP,
+    template_command::*,
+    template_dto::*,
+    template_model::{
+        ProjectTemplate, ProjectTemplateContent, ProjectTemplateDependency,
+        ProjectTemplateMilestone, ProjectTemplateTask
+    },
+    template_repo::{ProjectTemplateRepository, ProjectTemplateRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu, oub, ub};
+
+
+#[async_trait]
+pub trait ProjectTemplateServiceInterface {
+
+    async fn get(&self, command: ProjectTemplateGetCommand) -> Result<Option<ProjectTemplateResponse>, Error>;
+
+    /// None when the project does not exist
+    async fn create(&self, command: ProjectTemplateCreateCommand) -> Result<Option<ProjectTemplateResponse>, Error>;
+
+    async fn update(&self, command: ProjectTemplateUpdateCommand) -> Result<Option<ProjectTemplateResponse>, Error>;
+
+    async fn delete(&self, command: ProjectTemplateDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: ProjectTemplateListByUserCommand) -> Result<Vec<ProjectTemplateResponse>, Error>;
+
+
+    // --- New projects ---
+
+    async fn instantiate(&self, command: ProjectTemplateInstantiateCommand) -> Result<Option<ProjectResponse>, Error>;
+
+    async fn clone_project(&self, command: ProjectCloneCommand) -> Result<Option<ProjectResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct ProjectTemplateService {
+    template_repo: ProjectTemplateRepository,
+    project_repo: ProjectRepository,
+    task_repo: TaskRepository,
+    dependency_repo: TaskDependencyRepository,
+    milestone_repo: MilestoneRepository,
+}
+
+impl From<&AppState> for ProjectTemplateService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            template_repo: ProjectTemplateRepository::from(app_state),
+            project_repo: ProjectRepository::from(app_state),
+            task_repo: TaskRepository::from(app_state),
+            dependency_repo: TaskDependencyRepository::from(app_state),
+            milestone_repo: MilestoneRepository::from(app_state),
+        }
+    }
+}
+
+/// Project skeleton captured from a project or a template, ready to be instantiated
+struct ProjectBlueprint {
+    name: String,
+    description: Option<String>,
+    priority: i32,
+    budget_base_minor: i64,
+    due_offset_days: Option<i32>,
+    content: ProjectTemplateContent,
+}
+
+impl ProjectTemplateService {
+    fn offset(anchor: NaiveDate, date: Option<NaiveDate>) -> Option<i32> {
+        date.map(|date| (date - anchor).num_days() as i32)
+    }
+
+    fn shift(start_date: NaiveDate, offset_days: Option<i32>) -> Option<NaiveDate> {
+        offset_days.map(|days| start_date.checked_add_signed(TimeDelta::days(days as i64)).unwrap_or(start_date))
+    }
+
+    /// Captures tasks, milestones and dependencies with dates relative to the project start
+    /// (or its creation day when it has no start date). Progress is not kept: tasks restart as todo.
+    fn blueprint_of(project: &Project, tasks: &[Task], milestones: &[Milestone], dependencies: &[TaskDependency]) -> ProjectBlueprint {
+        let anchor = project.start_date
+            .or(project.created_at.map(|created_at| created_at.date_naive()))
+            .unwrap_or_else(|| Utc::now().date_naive());
+
+        // Cancelled milestones are not carried over
+        let milestones: Vec<&Milestone> = milestones.iter()
+            .filter(|milestone| milestone.status != MilestoneStatus::Cancelled)
+            .collect();
+        let milestone_index: HashMap<&[u8], usize> = milestones.iter().enumerate()
+            .filter_map(|(i, milestone)| milestone.id.as_deref().map(|id| (id, i)))
+            .collect();
+
+        // Parents before subtasks, siblings in repository order
+        let known: HashMap<&[u8], &Task> = tasks.iter()
+            .filter_map(|task| task.id.as_deref().map(|id| (id, task)))
+            .collect();
+        let mut children: HashMap<&[u8], Vec<&Task>> = HashMap::new();
+        let mut ordered: Vec<&Task> = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            match task.parent_task_id.as_deref().filter(|parent| known.contains_key(parent)) {
+                Some(parent) => children.entry(parent).or_default().push(task),
+                None => ordered.push(task),
+            }
+        }
+        let mut i = 0;
+        while i < ordered.len() {
+            if let Some(subtasks) = ordered[i].id.as_deref().and_then(|id| children.remove(id)) {
+                ordered.extend(subtasks);
+            }
+            i += 1;
+        }
+
+        let task_index: HashMap<&[u8], usize> = ordered.iter().enumerate()
+            .filter_map(|(i, task)| task.id.as_deref().map(|id| (id, i)))
+            .collect();
+
+        let content = ProjectTemplateContent {
+            milestones: milestones.iter()
+                .map(|milestone| ProjectTemplateMilestone {
+                    title: milestone.title.clone(),
+                    due_offset_days: Self::offset(anchor, milestone.due_date),
+                    budget_base_minor: milestone.budget_base_minor,
+                    person_id: obu(milestone.person_id.as_deref()),
+                    location_id: obu(milestone.location_id.as_deref()),
+                    note: milestone.note.clone(),
+                })
+                .collect(),
+            tasks: ordered.iter()
+                .map(|task| ProjectTemplateTask {
+                    title: task.title.clone(),
+                    parent: task.parent_task_id.as_deref().and_then(|parent| task_index.get(parent).copied()),
+                    milestone: task.milestone_id.as_deref().and_then(|milestone| milestone_index.get(milestone).copied()),
+                    due_offset_days: Self::offset(anchor, task.due_date),
+                    estimate_minutes: task.estimate_minutes,
+                    assigned_person_id: obu(task.assigned_person_id.as_deref()),
+                    location_id: obu(task.location_id.as_deref()),
+                    note: task.note.clone(),
+                })
+                .collect(),
+            dependencies: dependencies.iter()
+                .filter_map(|dependency| Some(ProjectTemplateDependency {
+                    task: *task_index.get(dependency.task_id.as_slice())?,
+                    depends_on: *task_index.get(dependency.depends_on_task_id.as_slice())?,
+                }))
+                .collect(),
+        };
+
+        ProjectBlueprint {
+            name: project.name.clone(),
+            description: project.description.clone(),
+            priority: project.priority,
+            budget_base_minor: project.budget_base_minor,
+            due_offset_days: Self::offset(anchor, project.due_date),
+            content,
+        }
+    }
+
+    fn validate_content(content: &ProjectTemplateContent) -> Result<(), Error> {
+        let invalid = content.tasks.iter().enumerate().any(|(i, task)| {
+            task.parent.is_some_and(|parent| parent >= i) || task.milestone.is_some_and(|milestone| milestone >= content.milestones.len())
+        }) || content.dependencies.iter().any(|dependency| {
+            dependency.task >= content.tasks.len() || dependency.depends_on >= content.tasks.len() || dependency.task == dependency.depends_on
+        });
+        if invalid {
+            return Err(AppError::BadRequest("template content is inconsistent".into()).into());
+        }
+        Ok(())
+    }
+
+    async fn project_parts(&self, project_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<ProjectBlueprint>, Error> {
+        let project = match self.project_repo.get(project_id, meta_user).await {
+            Ok(Some(project)) => project,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project")),
+        };
+        let tasks = match self.task_repo.get_by_project(project_id, meta_user).await {
+            Ok(tasks) => tasks,
+            Err(_) => return Err(Error::msg("Error getting project tasks")),
+        };
+        let milestones = match self.milestone_repo.get_by_project(project_id, meta_user).await {
+            Ok(milestones) => milestones,
+            Err(_) => return Err(Error::msg("Error getting milestones")),
+        };
+        let dependencies = match self.dependency_repo.get_by_project(project_id, meta_user).await {
+            Ok(dependencies) => dependencies,
+            Err(_) => return Err(Error::msg("Error getting task dependencies")),
+        };
+        Ok(Some(Self::blueprint_of(&project, &tasks, &milestones, &dependencies)))
+    }
+
+    /// Creates the project then its milestones, tasks and dependencies.
+    /// Stored procedures run one by one, so a failure deletes the half-built project.
+    async fn build(&self, blueprint: ProjectBlueprint, start_date: NaiveDate, user_id: Uuid) -> Result<Project, Error> {
+        Self::validate_content(&blueprint.content)?;
+        let meta_user = Some(user_id);
+
+        let project_create = Project {
+            id: None,
+            user_id: ub(user_id),
+            name: blueprint.name,
+            status: ProjectStatus::Planned,
+            priority: blueprint.priority,
+            start_date: Some(start_date),
+            due_date: Self::shift(start_date, blueprint.due_offset_days),
+            budget_base_minor: blueprint.budget_base_minor,
+            goal_id: None,
+            person_id: None,
+            location_id: None,
+            description: blueprint.description,
+            created_at: None,
+            updated_at: None,
+        };
+        let project = match self.project_repo.create(project_create, meta_user).await {
+            Ok(project) => project,
+            Err(_) => return Err(Error::msg("Error creating project")),
+        };
+        let project_id = bu(project.id.as_deref().unwrap());
+
+        match self.build_content(&project, &blueprint.content, start_date, meta_user).await {
+            Ok(_) => Ok(project),
+            Err(e) => {
+                let _ = self.project_repo.delete(project_id, meta_user).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn build_content(&self, project: &Project, content: &ProjectTemplateContent, start_date: NaiveDate, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let mut milestone_ids = Vec::with_capacity(content.milestones.len());
+        for milestone in &content.milestones {
+            let milestone_create = Milestone {
+                id: None,
+                project_id: project.id.clone().unwrap(),
+                title: milestone.title.clone(),
+                due_date: Self::shift(start_date, milestone.due_offset_days),
+                status: MilestoneStatus::Planned,
+                budget_base_minor: milestone.budget_base_minor,
+                person_id: oub(milestone.person_id),
+                location_id: oub(milestone.location_id),
+                note: milestone.note.clone(),
+                created_at: None,
+                updated_at: None,
+            };
+            match self.milestone_repo.create(milestone_create, meta_user).await {
+                Ok(milestone) => milestone_ids.push(bu(milestone.id.as_deref().unwrap())),
+                Err(_) => return Err(Error::msg("Error creating milestone")),
+            }
+        }
+
+        let mut task_ids: Vec<Uuid> = Vec::with_capacity(content.tasks.len());
+        for (i, task) in content.tasks.iter().enumerate() {
+            let task_create = Task {
+                id: None,
+                project_id: project.id.clone().unwrap(),
+                title: task.title.clone(),
+                status: TaskStatus::Todo,
+                due_date: Self::shift(start_date, task.due_offset_days),
+                parent_task_id: oub(task.parent.map(|parent| task_ids[parent])),
+                milestone_id: None,
+                recurrence_id: None,
+                expense_transaction_id: None,
+                order_idx: (i + 1) as f64 * ORDER_STEP,
+                estimate_minutes: task.estimate_minutes,
+                actual_minutes: None,
+                assigned_person_id: oub(task.assigned_person_id),
+                location_id: oub(task.location_id),
+                note: task.note.clone(),
+                completed_at: None,
+                created_at: None,
+                updated_at: None,
+            };
+            let task_id = match self.task_repo.create(task_create, meta_user).await {
+                Ok(task) => bu(task.id.as_deref().unwrap()),
+                Err(_) => return Err(Error::msg("Error creating task")),
+            };
+            if let Some(milestone) = task.milestone
+                && self.milestone_repo.attach_task(milestone_ids[milestone], task_id, true, meta_user).await.is_err() {
+                return Err(Error::msg("Error attaching task to milestone"));
+            }
+            task_ids.push(task_id);
+        }
+
+        for dependency in &content.dependencies {
+            if self.dependency_repo.create(task_ids[dependency.task], task_ids[dependency.depends_on], meta_user).await.is_err() {
+                return Err(Error::msg("Error creating task dependency"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectTemplateServiceInterface for ProjectTemplateService {
+    async fn get(&self, command: ProjectTemplateGetCommand) -> Result<Option<ProjectTemplateResponse>, Error> {
+        let template = self.template_repo.get(command.template_id, Some(command.auth_user.user_id)).await;
+        match template {
+            Ok(template) => Ok(template.as_ref().map(ProjectTemplateResponse::from)),
+            Err(_) => Err(Error::msg("Error getting project template")),
+        }
+    }
+
+    async fn create(&self, command: ProjectTemplateCreateCommand) -> Result<Option<ProjectTemplateResponse>, Error> {
+        if command.template_name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(AppError::BadRequest("template name cannot be empty".into()).into());
+        }
+
+        let meta_user = command.auth_user.user_id;
+        let blueprint = match self.project_parts(command.project_id, Some(meta_user)).await? {
+            Some(blueprint) => blueprint,
+            None => return Ok(None),
+        };
+
+        let template_create = ProjectTemplate {
+            id: None,
+            user_id: ub(meta_user),
+            name: command.template_name.unwrap_or(blueprint.name),
+            description: command.template_description.or(blueprint.description),
+            priority: blueprint.priority,
+            budget_base_minor: blueprint.budget_base_minor,
+            due_offset_days: blueprint.due_offset_days,
+            source_project_id: Some(ub(command.project_id)),
+            content: serde_json::to_string(&blueprint.content)?,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let template = self.template_repo.create(template_create, Some(meta_user)).await;
+        match template {
+            Ok(template) => Ok(Some(ProjectTemplateResponse::from(&template))),
+            Err(_) => Err(Error::msg("Error creating project template")),
+        }
+    }
+
+    async fn update(&self, command: ProjectTemplateUpdateCommand) -> Result<Option<ProjectTemplateResponse>, Error> {
+        if command.template_name.trim().is_empty() {
+            return Err(AppError::BadRequest("template name is required".into()).into());
+        }
+        if command.template_budget_base_minor < 0 {
+            return Err(AppError::BadRequest("template budget cannot be negative".into()).into());
+        }
+
+        let meta_user = Some(command.auth_user.user_id);
+        let template = match self.template_repo.get(command.template_id, meta_user).await {
+            Ok(Some(template)) => template,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project template")),
+        };
+
+        let template_update = ProjectTemplate {
+            name: command.template_name,
+            description: command.template_description,
+            priority: command.template_priority,
+            budget_base_minor: command.template_budget_base_minor,
+            due_offset_days: command.template_due_offset_days,
+            ..template
+        };
+
+        let template = self.template_repo.update(template_update, meta_user).await;
+        match template {
+            Ok(template) => Ok(template.as_ref().map(ProjectTemplateResponse::from)),
+            Err(_) => Err(Error::msg("Error updating project template")),
+        }
+    }
+
+    async fn delete(&self, command: ProjectTemplateDeleteCommand) -> Result<(), Error> {
+        let result = self.template_repo.delete(command.template_id, Some(command.auth_user.user_id)).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting project template")),
+        }
+    }
+
+    async fn get_by_user(&self, command: ProjectTemplateListByUserCommand) -> Result<Vec<ProjectTemplateResponse>, Error> {
+        let templates = self.template_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await;
+        match templates {
+            Ok(templates) => Ok(templates.iter().map(ProjectTemplateResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting project templates")),
+        }
+    }
+
+    async fn instantiate(&self, command: ProjectTemplateInstantiateCommand) -> Result<Option<ProjectResponse>, Error> {
+        if command.project_budget_base_minor.is_some_and(|budget| budget < 0) {
+            return Err(AppError::BadRequest("project budget cannot be negative".into()).into());
+        }
+
+        let meta_user = command.auth_user.user_id;
+        let template = match self.template_repo.get(command.template_id, Some(meta_user)).await {
+            Ok(Some(template)) => template,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting project template")),
+        };
+        let content: ProjectTemplateContent = match serde_json::from_str(&template.content) {
+            Ok(content) => content,
+            Err(_) => return Err(AppError::BadRequest("template content is not readable".into()).into()),
+        };
+
+        let blueprint = ProjectBlueprint {
+            name: command.project_name.unwrap_or(template.name),
+            description: template.description,
+            priority: template.priority,
+            budget_base_minor: command.project_budget_base_minor.unwrap_or(template.budget_base_minor),
+            due_offset_days: template.due_offset_days,
+            content,
+        };
+        let project = self.build(blueprint, command.project_start_date, meta_user).await?;
+        Ok(Some(ProjectResponse::from(&project)))
+    }
+
+    async fn clone_project(&self, command: ProjectCloneCommand) -> Result<Option<ProjectResponse>, Error> {
+        if command.project_budget_base_minor.is_some_and(|budget| budget < 0) {
+            return Err(AppError::BadRequest("project budget cannot be negative".into()).into());
+        }
+
+        let meta_user = command.auth_user.user_id;
+        let mut blueprint = match self.project_parts(command.project_id, Some(meta_user)).await? {
+            Some(blueprint) => blueprint,
+            None => return Ok(None),
+        };
+        if let Some(name) = command.project_name {
+            blueprint.name = name;
+        }
+        if let Some(budget) = command.project_budget_base_minor {
+            blueprint.budget_base_minor = budget;
+        }
+
+        let project = self.build(blueprint, command.project_start_date, meta_user).await?;
+        Ok(Some(ProjectResponse::from(&project)))
+    }
+}
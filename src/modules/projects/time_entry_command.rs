@@ -0,0 +1,160 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::projects::time_entry_dto::{
+    TimeEntryCreateRequest, TimeEntryUpdateRequest, TimerStartRequest, TimesheetGroupBy, TimesheetQuery
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimeEntryGetCommand {
+    pub time_entry_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimeEntryGetCommand {
+    pub fn new(time_entry_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { time_entry_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimerStartCommand {
+    pub task_id: Uuid,
+
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimerStartCommand {
+    pub fn new(task_id: Uuid, request: TimerStartRequest, auth_user: AuthUser) -> Self {
+        Self {
+            task_id,
+            time_entry_person_id: request.time_entry_person_id,
+            time_entry_note: request.time_entry_note,
+            auth_user,
+        }
+    }
+}
+
+/// Running timer of the current user
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimerCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimerCommand {
+    pub fn new(auth_user: AuthUser) -> Self {
+        Self { user_id: auth_user.user_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimeEntryCreateCommand {
+    pub task_id: Uuid,
+
+    pub time_entry_started_at: DateTime<Utc>,
+    pub time_entry_minutes: i32,
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimeEntryCreateCommand {
+    pub fn new(task_id: Uuid, request: TimeEntryCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            task_id,
+            time_entry_started_at: request.time_entry_started_at,
+            time_entry_minutes: request.time_entry_minutes,
+            time_entry_person_id: request.time_entry_person_id,
+            time_entry_note: request.time_entry_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimeEntryUpdateCommand {
+    pub time_entry_id: Uuid,
+
+    pub time_entry_started_at: DateTime<Utc>,
+    pub time_entry_minutes: i32,
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimeEntryUpdateCommand {
+    pub fn new(time_entry_id: Uuid, request: TimeEntryUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            time_entry_id,
+            time_entry_started_at: request.time_entry_started_at,
+            time_entry_minutes: request.time_entry_minutes,
+            time_entry_person_id: request.time_entry_person_id,
+            time_entry_note: request.time_entry_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimeEntryDeleteCommand {
+    pub time_entry_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimeEntryDeleteCommand {
+    pub fn new(time_entry_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { time_entry_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimeEntryListByTaskCommand {
+    pub task_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimeEntryListByTaskCommand {
+    pub fn new(task_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { task_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimesheetCommand {
+    pub user_id: Uuid,
+
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub group_by: Option<TimesheetGroupBy>,
+    pub project_id: Option<Uuid>,
+    pub person_id: Option<Uuid>,
+
+    pub auth_user: AuthUser,
+}
+
+impl TimesheetCommand {
+    pub fn new(query: TimesheetQuery, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            from: query.from,
+            to: query.to,
+            group_by: query.group_by,
+            project_id: query.project_id,
+            person_id: query.person_id,
+            auth_user,
+        }
+    }
+}
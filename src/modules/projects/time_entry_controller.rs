@@ -0,0 +1,311 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::{get, post}, Json, Router};
+use axum::extract::Query;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    time_entry_command::*,
+    time_entry_dto::*,
+    time_entry_service::{TimeEntryService, TimeEntryServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/timer", get(get_running_timer))
+        .route("/timer/stop", post(post_timer_stop))
+        .route("/timesheet", get(get_timesheet))
+        .route("/tasks/{task_id}/timer", post(post_timer_start))
+        .route("/tasks/{task_id}/time-entries", get(get_task_time_entries).post(post_task_time_entry))
+        .route("/time-entries/{time_entry_id}", get(get_time_entry).put(put_time_entry).delete(delete_time_entry))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/timer",
+    responses(
+        (status = StatusCode::OK, description = "Running timer of current user", body = TimeEntryResponse),
+        (status = StatusCode::NOT_FOUND, description = "No timer running"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn get_running_timer(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<TimeEntryResponse>, StatusCode> {
+    let command = TimerCommand::new(auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let timer = time_entry_service.running_timer(command).await;
+    match timer {
+        Ok(timer) => {
+            match timer {
+                Some(timer) => Ok(Json(timer)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/timer/stop",
+    responses(
+        (status = StatusCode::OK, description = "Timer stopped, task actual minutes updated", body = TimeEntryResponse),
+        (status = StatusCode::NOT_FOUND, description = "No timer running"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn post_timer_stop(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<TimeEntryResponse>, StatusCode> {
+    let command = TimerCommand::new(auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let timer = time_entry_service.stop_timer(command).await;
+    match timer {
+        Ok(timer) => {
+            match timer {
+                Some(timer) => Ok(Json(timer)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/timesheet",
+    params(
+        TimesheetQuery
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Minutes of current user by day, week, project or person", body = TimesheetResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid range"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn get_timesheet(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<TimesheetQuery>,
+) -> Result<Json<TimesheetResponse>, StatusCode> {
+    let command = TimesheetCommand::new(query, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let timesheet = time_entry_service.timesheet(command).await;
+    match timesheet {
+        Ok(timesheet) => Ok(Json(timesheet)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/tasks/{task_id}/timer",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Timer started on the task, the previous running timer is stopped", body = TimeEntryResponse),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn post_timer_start(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+    Json(timer_start_request): Json<TimerStartRequest>
+) -> Result<Json<TimeEntryResponse>, StatusCode> {
+    let command = TimerStartCommand::new(task_id, timer_start_request, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let timer = time_entry_service.start_timer(command).await;
+    match timer {
+        Ok(timer) => {
+            match timer {
+                Some(timer) => Ok(Json(timer)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/tasks/{task_id}/time-entries",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Time entries of the task, most recent first", body = Vec<TimeEntryResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn get_task_time_entries(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TimeEntryResponse>>, StatusCode> {
+    let command = TimeEntryListByTaskCommand::new(task_id, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let entries = time_entry_service.get_by_task(command).await;
+    match entries {
+        Ok(entries) => Ok(Json(entries)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/projects/tasks/{task_id}/time-entries",
+    params(
+        ("task_id", description = "task identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Manual time entry created, task actual minutes updated", body = TimeEntryResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid minutes"),
+        (status = StatusCode::NOT_FOUND, description = "Task not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn post_task_time_entry(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(task_id): Path<Uuid>,
+    Json(time_entry_create_request): Json<TimeEntryCreateRequest>
+) -> Result<Json<TimeEntryResponse>, StatusCode> {
+    let command = TimeEntryCreateCommand::new(task_id, time_entry_create_request, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let entry = time_entry_service.create(command).await;
+    match entry {
+        Ok(entry) => {
+            match entry {
+                Some(entry) => Ok(Json(entry)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/projects/time-entries/{time_entry_id}",
+    params(
+        ("time_entry_id", description = "time entry identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Time entry found successfully", body = TimeEntryResponse),
+        (status = StatusCode::NOT_FOUND, description = "Time entry not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn get_time_entry(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(time_entry_id): Path<Uuid>,
+) -> Result<Json<TimeEntryResponse>, StatusCode> {
+    let command = TimeEntryGetCommand::new(time_entry_id, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let entry = time_entry_service.get(command).await;
+    match entry {
+        Ok(entry) => {
+            match entry {
+                Some(entry) => Ok(Json(entry)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/projects/time-entries/{time_entry_id}",
+    params(
+        ("time_entry_id", description = "time entry identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Time entry updated, task actual minutes updated", body = TimeEntryResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid minutes or timer still running"),
+        (status = StatusCode::NOT_FOUND, description = "Time entry not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn put_time_entry(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(time_entry_id): Path<Uuid>,
+    Json(time_entry_update_request): Json<TimeEntryUpdateRequest>
+) -> Result<Json<TimeEntryResponse>, StatusCode> {
+    let command = TimeEntryUpdateCommand::new(time_entry_id, time_entry_update_request, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let entry = time_entry_service.update(command).await;
+    match entry {
+        Ok(entry) => {
+            match entry {
+                Some(entry) => Ok(Json(entry)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/projects/time-entries/{time_entry_id}",
+    params(
+        ("time_entry_id", description = "time entry identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Time entry deleted, task actual minutes updated"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Project Time"
+)]
+pub async fn delete_time_entry(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(time_entry_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = TimeEntryDeleteCommand::new(time_entry_id, auth_user);
+    let time_entry_service = TimeEntryService::from(&state);
+
+    let response = time_entry_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::modules::projects::time_entry_model::{TimeEntry, TimeEntrySource};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryResponse {
+    pub time_entry_id: Uuid,
+    pub user_id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: Option<String>,
+    pub project_id: Uuid,
+    pub project_name: Option<String>,
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_person_name: Option<String>,
+    pub time_entry_started_at: DateTime<Utc>,
+    /// None while the timer runs
+    pub time_entry_ended_at: Option<DateTime<Utc>>,
+    /// Elapsed minutes so far for a running timer
+    pub time_entry_minutes: i64,
+    pub time_entry_running: bool,
+    pub time_entry_source: TimeEntrySource,
+    pub time_entry_note: Option<String>,
+
+    pub time_entry_created_at: Option<DateTime<Utc>>,
+    pub time_entry_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&TimeEntry> for TimeEntryResponse {
+    fn from(entry: &TimeEntry) -> Self {
+        Self {
+            time_entry_id: bu(entry.id.clone().unwrap().as_slice()),
+            user_id: bu(entry.user_id.as_slice()),
+            task_id: bu(entry.task_id.as_slice()),
+            task_title: entry.task_title.clone(),
+            project_id: bu(entry.project_id.as_slice()),
+            project_name: entry.project_name.clone(),
+            time_entry_person_id: obu(entry.person_id.as_deref()),
+            time_entry_person_name: entry.person_name.clone(),
+            time_entry_started_at: entry.started_at,
+            time_entry_ended_at: entry.ended_at,
+            time_entry_minutes: entry.minutes_at(Utc::now()),
+            time_entry_running: entry.ended_at.is_none(),
+            time_entry_source: entry.source,
+            time_entry_note: entry.note.clone(),
+            time_entry_created_at: entry.created_at,
+            time_entry_updated_at: entry.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimerStartRequest {
+    /// Assigned person of the task by default
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryCreateRequest {
+    pub time_entry_started_at: DateTime<Utc>,
+    pub time_entry_minutes: i32,
+    /// Assigned person of the task by default
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_note: Option<String>,
+}
+
+/// Only finished entries can be edited
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryUpdateRequest {
+    pub time_entry_started_at: DateTime<Utc>,
+    pub time_entry_minutes: i32,
+    pub time_entry_person_id: Option<Uuid>,
+    pub time_entry_note: Option<String>,
+}
+
+
+// --- Timesheets ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimesheetGroupBy {
+    Day,
+    Week,
+    Project,
+    Person,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TimesheetQuery {
+    /// Monday of the current week by default
+    pub from: Option<NaiveDate>,
+    /// Sunday after `from` by default
+    pub to: Option<NaiveDate>,
+    /// Day by default
+    pub group_by: Option<TimesheetGroupBy>,
+    pub project_id: Option<Uuid>,
+    pub person_id: Option<Uuid>,
+}
+
+/// One timesheet line: a day, a week (its Monday), a project or a person.
+/// Days and weeks without time are listed with zero minutes.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimesheetRowResponse {
+    pub row_date: Option<NaiveDate>,
+    pub row_id: Option<Uuid>,
+    pub row_label: Option<String>,
+    pub minutes: i64,
+    pub entry_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimesheetResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub group_by: TimesheetGroupBy,
+    pub total_minutes: i64,
+    pub rows: Vec<TimesheetRowResponse>,
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::shared::db::mysql::FromSqlRow;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum TimeEntrySource {
+    Timer,
+    Manual,
+}
+
+impl TimeEntrySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeEntrySource::Timer => "timer",
+            TimeEntrySource::Manual => "manual",
+        }
+    }
+}
+
+
+/// Time spent on a task. A timer entry without `ended_at` is running;
+/// `minutes` is only set once the entry is finished.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TimeEntry {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub task_id: Vec<u8>,
+    pub project_id: Vec<u8>,
+    pub person_id: Option<Vec<u8>>,
+
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub minutes: Option<i32>,
+    pub source: TimeEntrySource,
+    pub note: Option<String>,
+
+    /// Joined by the procedures for display and timesheets
+    pub task_title: Option<String>,
+    pub project_name: Option<String>,
+    pub person_name: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl TimeEntry {
+    /// Finished minutes, or minutes elapsed so far for a running timer
+    pub fn minutes_at(&self, now: DateTime<Utc>) -> i64 {
+        match (self.minutes, self.ended_at) {
+            (Some(minutes), _) => minutes as i64,
+            (None, Some(ended_at)) => minutes_between(self.started_at, ended_at) as i64,
+            (None, None) => minutes_between(self.started_at, now) as i64,
+        }
+    }
+}
+
+/// Rounded to the nearest minute, never negative
+pub fn minutes_between(started_at: DateTime<Utc>, ended_at: DateTime<Utc>) -> i32 {
+    (((ended_at - started_at).num_seconds() + 30) / 60).max(0) as i32
+}
+
+impl FromSqlRow for TimeEntry {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            task_id: row.try_get(index_map["task_id"])?,
+            project_id: row.try_get(index_map["project_id"])?,
+            person_id: row.try_get(index_map["person_id"])?,
+            started_at: row.try_get(index_map["started_at"])?,
+            ended_at: row.try_get(index_map["ended_at"])?,
+            minutes: row.try_get(index_map["minutes"])?,
+            source: row.try_get(index_map["source"])?,
+            note: row.try_get(index_map["note"])?,
+            task_title: row.try_get(index_map["task_title"])?,
+            project_name: row.try_get(index_map["project_name"])?,
+            person_name: row.try_get(index_map["person_name"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::projects::time_entry_model::TimeEntry;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait TimeEntryRepositoryInterface {
+
+    async fn get(&self, time_entry_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error>;
+
+    /// The timer of the user still running, if any
+    async fn get_running(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error>;
+
+    /// Fails when a second timer would run for the same user
+    async fn create(&self, time_entry: TimeEntry, meta_user: Option<Uuid>) -> Result<TimeEntry, Error>;
+
+    /// Returns None when the entry is missing or already stopped
+    async fn stop(&self, time_entry_id: Uuid, ended_at: DateTime<Utc>, minutes: i32, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error>;
+
+    async fn update(&self, time_entry: TimeEntry, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error>;
+
+    async fn delete(&self, time_entry_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Most recent first
+    async fn get_by_task(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TimeEntry>, Error>;
+
+    /// Entries of the user started between `from` and `to` (both days included)
+    async fn get_by_user_range(
+        &self,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        project_id: Option<Uuid>,
+        person_id: Option<Uuid>,
+        meta_user: Option<Uuid>
+    ) -> Result<Vec<TimeEntry>, Error>;
+
+    /// Sets project_tasks.actual_minutes to the sum of the finished entries of the task
+    async fn sync_task_actual_minutes(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct TimeEntryRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for TimeEntryRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<TimeEntry> for TimeEntryRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepositoryInterface for TimeEntryRepository {
+    async fn get(&self, time_entry_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(time_entry_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_time_entry_get_by_id", params).await
+    }
+
+    async fn get_running(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_time_entry_running_by_user", params).await
+    }
+
+    async fn create(&self, time_entry: TimeEntry, meta_user: Option<Uuid>) -> Result<TimeEntry, Error> {
+        let params = vec![
+            MySqlParam::from(time_entry.user_id),
+            MySqlParam::from(time_entry.task_id),
+            MySqlParam::from(time_entry.project_id),
+            MySqlParam::from(time_entry.person_id),
+            MySqlParam::from(time_entry.started_at),
+            MySqlParam::from(time_entry.ended_at),
+            MySqlParam::from(time_entry.minutes),
+            MySqlParam::from(time_entry.source.as_str()),
+            MySqlParam::from(time_entry.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_project_time_entry_create", params).await
+    }
+
+    async fn stop(&self, time_entry_id: Uuid, ended_at: DateTime<Utc>, minutes: i32, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(time_entry_id)),
+            MySqlParam::from(ended_at),
+            MySqlParam::from(minutes),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_time_entry_stop", params).await
+    }
+
+    async fn update(&self, time_entry: TimeEntry, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error> {
+        let params = vec![
+            MySqlParam::from(time_entry.id),
+            MySqlParam::from(time_entry.person_id),
+            MySqlParam::from(time_entry.started_at),
+            MySqlParam::from(time_entry.ended_at),
+            MySqlParam::from(time_entry.minutes),
+            MySqlParam::from(time_entry.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_project_time_entry_update", params).await
+    }
+
+    async fn delete(&self, time_entry_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(time_entry_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_time_entry_delete", params).await
+    }
+
+    async fn get_by_task(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<TimeEntry>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_time_entry_by_task", params).await
+    }
+
+    async fn get_by_user_range(
+        &self,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        project_id: Option<Uuid>,
+        person_id: Option<Uuid>,
+        meta_user: Option<Uuid>
+    ) -> Result<Vec<TimeEntry>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(from),
+            MySqlParam::from(to),
+            MySqlParam::from(oub(project_id)),
+            MySqlParam::from(oub(person_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_project_time_entry_by_user_range", params).await
+    }
+
+    async fn sync_task_actual_minutes(&self, task_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(task_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_project_task_sync_actual_minutes", params).await
+    }
+}
@@ -0,0 +1,352 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::{Datelike, DateTime, Days, NaiveDate, TimeDelta, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::modules::projects::{
+    task_repo::{TaskRepository, TaskRepositoryInterface},
+    time_entry_command::*,
+    time_entry_dto::*,
+    time_entry_model::{minutes_between, TimeEntry, TimeEntrySource},
+    time_entry_repo::{TimeEntryRepository, TimeEntryRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{bu, obu, oub, ub};
+
+
+/// A manual entry covers at most one day
+const MAX_ENTRY_MINUTES: i32 = 24 * 60;
+
+/// Longest timesheet range, in days
+const MAX_TIMESHEET_DAYS: i64 = 366;
+
+
+#[async_trait]
+pub trait TimeEntryServiceInterface {
+
+    async fn get(&self, command: TimeEntryGetCommand) -> Result<Option<TimeEntryResponse>, Error>;
+
+    /// Manual entry; None when the task does not exist
+    async fn create(&self, command: TimeEntryCreateCommand) -> Result<Option<TimeEntryResponse>, Error>;
+
+    async fn update(&self, command: TimeEntryUpdateCommand) -> Result<Option<TimeEntryResponse>, Error>;
+
+    async fn delete(&self, command: TimeEntryDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_task(&self, command: TimeEntryListByTaskCommand) -> Result<Vec<TimeEntryResponse>, Error>;
+
+
+    // --- Timer ---
+
+    /// Stops the running timer of the user first, if any
+    async fn start_timer(&self, command: TimerStartCommand) -> Result<Option<TimeEntryResponse>, Error>;
+
+    async fn stop_timer(&self, command: TimerCommand) -> Result<Option<TimeEntryResponse>, Error>;
+
+    async fn running_timer(&self, command: TimerCommand) -> Result<Option<TimeEntryResponse>, Error>;
+
+
+    // --- Timesheets ---
+
+    async fn timesheet(&self, command: TimesheetCommand) -> Result<TimesheetResponse, Error>;
+
+}
+
+/// Every change to an entry is followed by a resync of the task actual_minutes,
+/// so the task total always matches its finished entries.
+#[derive(Clone)]
+pub struct TimeEntryService {
+    task_repo: TaskRepository,
+    time_entry_repo: TimeEntryRepository,
+}
+
+impl From<&AppState> for TimeEntryService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            task_repo: TaskRepository::from(app_state),
+            time_entry_repo: TimeEntryRepository::from(app_state),
+        }
+    }
+}
+
+impl TimeEntryService {
+    fn validate_minutes(minutes: i32) -> Result<(), Error> {
+        if !(1..=MAX_ENTRY_MINUTES).contains(&minutes) {
+            return Err(AppError::BadRequest(format!("time entry must last between 1 and {} minutes", MAX_ENTRY_MINUTES)).into());
+        }
+        Ok(())
+    }
+
+    fn ended_at(started_at: DateTime<Utc>, minutes: i32) -> DateTime<Utc> {
+        started_at + TimeDelta::minutes(minutes as i64)
+    }
+
+    fn week_start(date: NaiveDate) -> NaiveDate {
+        date - Days::new(date.weekday().num_days_from_monday() as u64)
+    }
+
+    async fn sync(&self, task_id: &[u8], meta_user: Option<Uuid>) -> Result<(), Error> {
+        match self.time_entry_repo.sync_task_actual_minutes(bu(task_id), meta_user).await {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::msg("Error syncing task actual minutes")),
+        }
+    }
+
+    async fn stop(&self, entry: &TimeEntry, now: DateTime<Utc>, meta_user: Option<Uuid>) -> Result<Option<TimeEntry>, Error> {
+        let minutes = minutes_between(entry.started_at, now);
+        let stopped = match self.time_entry_repo.stop(bu(entry.id.as_deref().unwrap()), now, minutes, meta_user).await {
+            Ok(stopped) => stopped,
+            Err(_) => return Err(Error::msg("Error stopping timer")),
+        };
+        self.sync(&entry.task_id, meta_user).await?;
+        Ok(stopped)
+    }
+
+    /// Groups entries by day or week (every period of the range listed), by project or by person.
+    /// Running timers count the minutes elapsed until `now`.
+    pub fn timesheet_of(entries: &[TimeEntry], from: NaiveDate, to: NaiveDate, group_by: TimesheetGroupBy, now: DateTime<Utc>) -> TimesheetResponse {
+        let rows = match group_by {
+            TimesheetGroupBy::Day | TimesheetGroupBy::Week => {
+                let period_of = |date: NaiveDate| match group_by {
+                    TimesheetGroupBy::Week => Self::week_start(date),
+                    _ => date,
+                };
+                let step = if group_by == TimesheetGroupBy::Week { 7 } else { 1 };
+
+                let mut totals: HashMap<NaiveDate, (i64, i64)> = HashMap::new();
+                for entry in entries {
+                    let total = totals.entry(period_of(entry.started_at.date_naive())).or_default();
+                    total.0 += entry.minutes_at(now);
+                    total.1 += 1;
+                }
+
+                let mut rows = Vec::new();
+                let mut period = period_of(from);
+                while period <= to {
+                    let (minutes, entry_count) = totals.get(&period).copied().unwrap_or_default();
+                    rows.push(TimesheetRowResponse { row_date: Some(period), row_id: None, row_label: None, minutes, entry_count });
+                    period = period + Days::new(step);
+                }
+                rows
+            },
+            TimesheetGroupBy::Project | TimesheetGroupBy::Person => {
+                let mut groups: HashMap<Option<&[u8]>, TimesheetRowResponse> = HashMap::new();
+                for entry in entries {
+                    let (id, label) = match group_by {
+                        TimesheetGroupBy::Project => (Some(entry.project_id.as_slice()), entry.project_name.as_ref()),
+                        _ => (entry.person_id.as_deref(), entry.person_name.as_ref()),
+                    };
+                    let row = groups.entry(id).or_insert_with(|| TimesheetRowResponse {
+                        row_date: None,
+                        row_id: obu(id),
+                        row_label: label.cloned(),
+                        minutes: 0,
+                        entry_count: 0,
+                    });
+                    row.minutes += entry.minutes_at(now);
+                    row.entry_count += 1;
+                }
+
+                let mut rows: Vec<TimesheetRowResponse> = groups.into_values().collect();
+                rows.sort_by(|a, b| b.minutes.cmp(&a.minutes).then_with(|| a.row_label.cmp(&b.row_label)));
+                rows
+            },
+        };
+
+        TimesheetResponse {
+            from,
+            to,
+            group_by,
+            total_minutes: entries.iter().map(|entry| entry.minutes_at(now)).sum(),
+            rows,
+        }
+    }
+}
+
+#[async_trait]
+impl TimeEntryServiceInterface for TimeEntryService {
+    async fn get(&self, command: TimeEntryGetCommand) -> Result<Option<TimeEntryResponse>, Error> {
+        let entry = self.time_entry_repo.get(command.time_entry_id, Some(command.auth_user.user_id)).await;
+        match entry {
+            Ok(entry) => Ok(entry.as_ref().map(TimeEntryResponse::from)),
+            Err(_) => Err(Error::msg("Error getting time entry")),
+        }
+    }
+
+    async fn create(&self, command: TimeEntryCreateCommand) -> Result<Option<TimeEntryResponse>, Error> {
+        Self::validate_minutes(command.time_entry_minutes)?;
+
+        let meta_user = command.auth_user.user_id;
+        let task = match self.task_repo.get(command.task_id, Some(meta_user)).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+
+        let entry_create = TimeEntry {
+            id: None,
+            user_id: ub(meta_user),
+            task_id: ub(command.task_id),
+            project_id: task.project_id,
+            person_id: oub(command.time_entry_person_id).or(task.assigned_person_id),
+            started_at: command.time_entry_started_at,
+            ended_at: Some(Self::ended_at(command.time_entry_started_at, command.time_entry_minutes)),
+            minutes: Some(command.time_entry_minutes),
+            source: TimeEntrySource::Manual,
+            note: command.time_entry_note,
+            task_title: None,
+            project_name: None,
+            person_name: None,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let entry = match self.time_entry_repo.create(entry_create, Some(meta_user)).await {
+            Ok(entry) => entry,
+            Err(_) => return Err(Error::msg("Error creating time entry")),
+        };
+        self.sync(&entry.task_id, Some(meta_user)).await?;
+        Ok(Some(TimeEntryResponse::from(&entry)))
+    }
+
+    async fn update(&self, command: TimeEntryUpdateCommand) -> Result<Option<TimeEntryResponse>, Error> {
+        Self::validate_minutes(command.time_entry_minutes)?;
+
+        let meta_user = Some(command.auth_user.user_id);
+        let entry = match self.time_entry_repo.get(command.time_entry_id, meta_user).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting time entry")),
+        };
+        if entry.ended_at.is_none() {
+            return Err(AppError::BadRequest("stop the timer before editing it".into()).into());
+        }
+
+        let entry_update = TimeEntry {
+            person_id: oub(command.time_entry_person_id),
+            started_at: command.time_entry_started_at,
+            ended_at: Some(Self::ended_at(command.time_entry_started_at, command.time_entry_minutes)),
+            minutes: Some(command.time_entry_minutes),
+            note: command.time_entry_note,
+            ..entry
+        };
+
+        let entry = match self.time_entry_repo.update(entry_update, meta_user).await {
+            Ok(entry) => entry,
+            Err(_) => return Err(Error::msg("Error updating time entry")),
+        };
+        if let Some(entry) = &entry {
+            self.sync(&entry.task_id, meta_user).await?;
+        }
+        Ok(entry.as_ref().map(TimeEntryResponse::from))
+    }
+
+    async fn delete(&self, command: TimeEntryDeleteCommand) -> Result<(), Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let entry = match self.time_entry_repo.get(command.time_entry_id, meta_user).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) => return Ok(()),
+            Err(_) => return Err(Error::msg("Error getting time entry")),
+        };
+
+        if self.time_entry_repo.delete(command.time_entry_id, meta_user).await.is_err() {
+            return Err(Error::msg("Error deleting time entry"));
+        }
+        self.sync(&entry.task_id, meta_user).await
+    }
+
+    async fn get_by_task(&self, command: TimeEntryListByTaskCommand) -> Result<Vec<TimeEntryResponse>, Error> {
+        let entries = self.time_entry_repo.get_by_task(command.task_id, Some(command.auth_user.user_id)).await;
+        match entries {
+            Ok(entries) => Ok(entries.iter().map(TimeEntryResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting time entries")),
+        }
+    }
+
+    async fn start_timer(&self, command: TimerStartCommand) -> Result<Option<TimeEntryResponse>, Error> {
+        let meta_user = command.auth_user.user_id;
+        let task = match self.task_repo.get(command.task_id, Some(meta_user)).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting task")),
+        };
+
+        let now = Utc::now();
+        match self.time_entry_repo.get_running(meta_user, Some(meta_user)).await {
+            Ok(Some(running)) => { self.stop(&running, now, Some(meta_user)).await?; },
+            Ok(None) => {},
+            Err(_) => return Err(Error::msg("Error getting running timer")),
+        }
+
+        let entry_create = TimeEntry {
+            id: None,
+            user_id: ub(meta_user),
+            task_id: ub(command.task_id),
+            project_id: task.project_id,
+            person_id: oub(command.time_entry_person_id).or(task.assigned_person_id),
+            started_at: now,
+            ended_at: None,
+            minutes: None,
+            source: TimeEntrySource::Timer,
+            note: command.time_entry_note,
+            task_title: None,
+            project_name: None,
+            person_name: None,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let entry = self.time_entry_repo.create(entry_create, Some(meta_user)).await;
+        match entry {
+            Ok(entry) => Ok(Some(TimeEntryResponse::from(&entry))),
+            Err(_) => Err(Error::msg("Error starting timer")),
+        }
+    }
+
+    async fn stop_timer(&self, command: TimerCommand) -> Result<Option<TimeEntryResponse>, Error> {
+        let meta_user = Some(command.auth_user.user_id);
+        let running = match self.time_entry_repo.get_running(command.user_id, meta_user).await {
+            Ok(Some(running)) => running,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting running timer")),
+        };
+
+        let stopped = self.stop(&running, Utc::now(), meta_user).await?;
+        Ok(stopped.as_ref().map(TimeEntryResponse::from))
+    }
+
+    async fn running_timer(&self, command: TimerCommand) -> Result<Option<TimeEntryResponse>, Error> {
+        let running = self.time_entry_repo.get_running(command.user_id, Some(command.auth_user.user_id)).await;
+        match running {
+            Ok(running) => Ok(running.as_ref().map(TimeEntryResponse::from)),
+            Err(_) => Err(Error::msg("Error getting running timer")),
+        }
+    }
+
+    async fn timesheet(&self, command: TimesheetCommand) -> Result<TimesheetResponse, Error> {
+        let today = Utc::now().date_naive();
+        let from = command.from.unwrap_or_else(|| Self::week_start(today));
+        let to = command.to.unwrap_or_else(|| from + Days::new(6));
+        if to < from {
+            return Err(AppError::BadRequest("timesheet ends before it starts".into()).into());
+        }
+        if (to - from).num_days() >= MAX_TIMESHEET_DAYS {
+            return Err(AppError::BadRequest(format!("timesheet covers at most {} days", MAX_TIMESHEET_DAYS)).into());
+        }
+
+        let entries = self.time_entry_repo.get_by_user_range(
+            command.user_id,
+            from,
+            to,
+            command.project_id,
+            command.person_id,
+            Some(command.auth_user.user_id)
+        ).await;
+        match entries {
+            Ok(entries) => Ok(Self::timesheet_of(&entries, from, to, command.group_by.unwrap_or(TimesheetGroupBy::Day), Utc::now())),
+            Err(_) => Err(Error::msg("Error getting time entries")),
+        }
+    }
+}
@@ -1,17 +1,47 @@
 use axum::Router;
 
 use crate::modules::{
+    accounts::{forecast_controller, loan_controller, networth_controller, scenario_controller},
+    automations::automation_controller,
+    budgets::budget_controller,
     currencies::currency_controller,
+    goals::{goal_controller, planner_controller},
+    investments::{allocation_controller, asset_controller, instrument_controller, lot_controller, performance_controller, portfolio_controller, trade_controller},
     locations::location_controller,
     people::people_controller,
+    projects::{milestone_controller, project_controller, purchase_controller, recurrence_controller, task_controller, template_controller, time_entry_controller},
+    transactions::recurring_controller,
     users::user::user_controller
 };
 use crate::shared::state::AppState;
 
 pub fn routes() -> Router<AppState> {
     Router::new()
+        .nest("/accounts", networth_controller::routes()
+            .merge(forecast_controller::routes())
+            .merge(scenario_controller::routes())
+            .merge(loan_controller::routes()))
+        .nest("/automations", automation_controller::routes())
+        .nest("/budgets", budget_controller::routes())
         .nest("/currencies", currency_controller::routes())
-        .nest("locations", location_controller::routes())
+        .nest("/goals", goal_controller::routes()
+            .merge(planner_controller::routes()))
+        .nest("/investments", portfolio_controller::routes()
+            .merge(instrument_controller::routes())
+            .merge(trade_controller::routes())
+            .merge(performance_controller::routes())
+            .merge(lot_controller::routes())
+            .merge(allocation_controller::routes())
+            .merge(asset_controller::routes()))
+        .nest("/locations", location_controller::routes())
         .nest("/people", people_controller::routes())
+        .nest("/projects", project_controller::routes()
+            .merge(task_controller::routes())
+            .merge(milestone_controller::routes())
+            .merge(template_controller::routes())
+            .merge(purchase_controller::routes())
+            .merge(recurrence_controller::routes())
+            .merge(time_entry_controller::routes()))
+        .nest("/transactions", recurring_controller::routes())
         .nest("/users", user_controller::routes())
 }
@@ -0,0 +1,6 @@
+pub mod recurring_model;
+pub mod recurring_repo;
+mod recurring_command;
+mod recurring_service;
+pub mod recurring_dto;
+pub mod recurring_controller;
\ No newline at end of file
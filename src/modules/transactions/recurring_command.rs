@@ -0,0 +1,119 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+
+use crate::modules::transactions::{
+    recurring_dto::{RecurringTransactionCreateRequest, RecurringTransactionUpdateRequest},
+    recurring_model::RecurringFrequency,
+};
+use crate::shared::auth::jwt::AuthUser;
+
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurringTransactionGetCommand {
+    pub recurring_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl RecurringTransactionGetCommand {
+    pub fn new(recurring_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { recurring_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurringTransactionCreateCommand {
+    pub user_id: Uuid,
+    pub account_id: Uuid,
+    pub recurring_label: String,
+    pub recurring_amount_minor: i64,
+    pub recurring_category_id: Option<Uuid>,
+    pub recurring_payee_id: Option<Uuid>,
+    pub recurring_frequency: RecurringFrequency,
+    pub recurring_start_date: NaiveDate,
+    pub recurring_end_date: Option<NaiveDate>,
+    pub recurring_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl RecurringTransactionCreateCommand {
+    pub fn new(request: RecurringTransactionCreateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            user_id: auth_user.user_id,
+            account_id: request.account_id,
+            recurring_label: request.recurring_label,
+            recurring_amount_minor: request.recurring_amount_minor,
+            recurring_category_id: request.recurring_category_id,
+            recurring_payee_id: request.recurring_payee_id,
+            recurring_frequency: request.recurring_frequency.unwrap_or(RecurringFrequency::Monthly),
+            recurring_start_date: request.recurring_start_date,
+            recurring_end_date: request.recurring_end_date,
+            recurring_note: request.recurring_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurringTransactionUpdateCommand {
+    pub recurring_id: Uuid,
+    pub account_id: Uuid,
+    pub recurring_label: String,
+    pub recurring_amount_minor: i64,
+    pub recurring_category_id: Option<Uuid>,
+    pub recurring_payee_id: Option<Uuid>,
+    pub recurring_frequency: RecurringFrequency,
+    pub recurring_start_date: NaiveDate,
+    pub recurring_end_date: Option<NaiveDate>,
+    pub recurring_active: bool,
+    pub recurring_note: Option<String>,
+
+    pub auth_user: AuthUser,
+}
+
+impl RecurringTransactionUpdateCommand {
+    pub fn new(recurring_id: Uuid, request: RecurringTransactionUpdateRequest, auth_user: AuthUser) -> Self {
+        Self {
+            recurring_id,
+            account_id: request.account_id,
+            recurring_label: request.recurring_label,
+            recurring_amount_minor: request.recurring_amount_minor,
+            recurring_category_id: request.recurring_category_id,
+            recurring_payee_id: request.recurring_payee_id,
+            recurring_frequency: request.recurring_frequency,
+            recurring_start_date: request.recurring_start_date,
+            recurring_end_date: request.recurring_end_date,
+            recurring_active: request.recurring_active,
+            recurring_note: request.recurring_note,
+            auth_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurringTransactionDeleteCommand {
+    pub recurring_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl RecurringTransactionDeleteCommand {
+    pub fn new(recurring_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { recurring_id, auth_user }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurringTransactionListByUserCommand {
+    pub user_id: Uuid,
+
+    pub auth_user: AuthUser,
+}
+
+impl RecurringTransactionListByUserCommand {
+    pub fn new(user_id: Uuid, auth_user: AuthUser) -> Self {
+        Self { user_id, auth_user }
+    }
+}
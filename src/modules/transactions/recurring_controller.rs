@@ -0,0 +1,168 @@
+use axum::{extract::{Path, State}, http::StatusCode, routing::get, Json, Router};
+use uuid::Uuid;
+
+use crate::modules::transactions::{
+    recurring_command::*,
+    recurring_dto::*,
+    recurring_service::{RecurringTransactionService, RecurringTransactionServiceInterface}
+};
+use crate::shared::{
+    auth::jwt::AuthUser,
+    errors::error_status,
+    state::AppState
+};
+
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/recurring", get(get_recurring_transactions).post(post_recurring_transaction))
+        .route("/recurring/{recurring_id}", get(get_recurring_transaction).put(put_recurring_transaction).delete(delete_recurring_transaction))
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/transactions/recurring",
+    responses(
+        (status = StatusCode::OK, description = "Recurring transactions of current user", body = Vec<RecurringTransactionResponse>),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Transaction Recurring"
+)]
+pub async fn get_recurring_transactions(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<RecurringTransactionResponse>>, StatusCode> {
+    let command = RecurringTransactionListByUserCommand::new(auth_user.user_id, auth_user);
+    let recurring_service = RecurringTransactionService::from(&state);
+
+    let recurrings = recurring_service.get_by_user(command).await;
+    match recurrings {
+        Ok(recurrings) => Ok(Json(recurrings)),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    post,
+    path = "/api/services/transactions/recurring",
+    responses(
+        (status = StatusCode::OK, description = "Recurring transaction created successfully", body = RecurringTransactionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid recurring transaction"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Transaction Recurring"
+)]
+pub async fn post_recurring_transaction(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(recurring_create_request): Json<RecurringTransactionCreateRequest>
+) -> Result<Json<RecurringTransactionResponse>, StatusCode> {
+    let command = RecurringTransactionCreateCommand::new(recurring_create_request, auth_user);
+    let recurring_service = RecurringTransactionService::from(&state);
+
+    let recurring = recurring_service.create(command).await;
+    match recurring {
+        Ok(recurring) => Ok(Json(recurring)),
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    get,
+    path = "/api/services/transactions/recurring/{recurring_id}",
+    params(
+        ("recurring_id", description = "recurring transaction identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurring transaction", body = RecurringTransactionResponse),
+        (status = StatusCode::NOT_FOUND, description = "Recurring transaction not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Transaction Recurring"
+)]
+pub async fn get_recurring_transaction(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(recurring_id): Path<Uuid>,
+) -> Result<Json<RecurringTransactionResponse>, StatusCode> {
+    let command = RecurringTransactionGetCommand::new(recurring_id, auth_user);
+    let recurring_service = RecurringTransactionService::from(&state);
+
+    let recurring = recurring_service.get_by_id(command).await;
+    match recurring {
+        Ok(recurring) => {
+            match recurring {
+                Some(recurring) => Ok(Json(recurring)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+
+#[utoipa::path(
+    put,
+    path = "/api/services/transactions/recurring/{recurring_id}",
+    params(
+        ("recurring_id", description = "recurring transaction identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurring transaction updated successfully", body = RecurringTransactionResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid recurring transaction"),
+        (status = StatusCode::NOT_FOUND, description = "Recurring transaction not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Transaction Recurring"
+)]
+pub async fn put_recurring_transaction(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(recurring_id): Path<Uuid>,
+    Json(recurring_update_request): Json<RecurringTransactionUpdateRequest>
+) -> Result<Json<RecurringTransactionResponse>, StatusCode> {
+    let command = RecurringTransactionUpdateCommand::new(recurring_id, recurring_update_request, auth_user);
+    let recurring_service = RecurringTransactionService::from(&state);
+
+    let recurring = recurring_service.update(command).await;
+    match recurring {
+        Ok(recurring) => {
+            match recurring {
+                Some(recurring) => Ok(Json(recurring)),
+                None => Err(StatusCode::NOT_FOUND)
+            }
+        },
+        Err(e) => Err(error_status(&e))
+    }
+}
+
+
+#[utoipa::path(
+    delete,
+    path = "/api/services/transactions/recurring/{recurring_id}",
+    params(
+        ("recurring_id", description = "recurring transaction identifier in uuid")
+    ),
+    responses(
+        (status = StatusCode::OK, description = "Recurring transaction deleted successfully"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal Server Error"),
+    ),
+    tag = "Transaction Recurring"
+)]
+pub async fn delete_recurring_transaction(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(recurring_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let command = RecurringTransactionDeleteCommand::new(recurring_id, auth_user);
+    let recurring_service = RecurringTransactionService::from(&state);
+
+    let response = recurring_service.delete(command).await;
+    match response {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
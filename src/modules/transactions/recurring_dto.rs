@@ -0,0 +1,79 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::transactions::recurring_model::{RecurringFrequency, RecurringTransaction};
+use crate::shared::utils::{bu, obu};
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RecurringTransactionResponse {
+    pub recurring_id: Uuid,
+    pub user_id: Uuid,
+    pub account_id: Uuid,
+    pub recurring_label: String,
+    /// Signed in the account currency, expenses negative
+    pub recurring_amount_minor: i64,
+    pub recurring_category_id: Option<Uuid>,
+    pub recurring_payee_id: Option<Uuid>,
+    pub recurring_frequency: RecurringFrequency,
+    pub recurring_start_date: NaiveDate,
+    pub recurring_end_date: Option<NaiveDate>,
+    pub recurring_active: bool,
+    pub recurring_note: Option<String>,
+
+    pub recurring_created_at: Option<DateTime<Utc>>,
+    pub recurring_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&RecurringTransaction> for RecurringTransactionResponse {
+    fn from(recurring: &RecurringTransaction) -> Self {
+        Self {
+            recurring_id: bu(recurring.id.clone().unwrap().as_slice()),
+            user_id: bu(recurring.user_id.as_slice()),
+            account_id: bu(recurring.account_id.as_slice()),
+            recurring_label: recurring.label.clone(),
+            recurring_amount_minor: recurring.amount_minor,
+            recurring_category_id: obu(recurring.category_id.as_deref()),
+            recurring_payee_id: obu(recurring.payee_id.as_deref()),
+            recurring_frequency: recurring.frequency,
+            recurring_start_date: recurring.start_date,
+            recurring_end_date: recurring.end_date,
+            recurring_active: recurring.active,
+            recurring_note: recurring.note.clone(),
+            recurring_created_at: recurring.created_at,
+            recurring_updated_at: recurring.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RecurringTransactionCreateRequest {
+    pub account_id: Uuid,
+    pub recurring_label: String,
+    /// Signed in the account currency, expenses negative
+    pub recurring_amount_minor: i64,
+    pub recurring_category_id: Option<Uuid>,
+    pub recurring_payee_id: Option<Uuid>,
+    /// Monthly by default
+    pub recurring_frequency: Option<RecurringFrequency>,
+    /// First occurrence, its day of month is kept by the monthly frequencies
+    pub recurring_start_date: NaiveDate,
+    pub recurring_end_date: Option<NaiveDate>,
+    pub recurring_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RecurringTransactionUpdateRequest {
+    pub account_id: Uuid,
+    pub recurring_label: String,
+    pub recurring_amount_minor: i64,
+    pub recurring_category_id: Option<Uuid>,
+    pub recurring_payee_id: Option<Uuid>,
+    pub recurring_frequency: RecurringFrequency,
+    pub recurring_start_date: NaiveDate,
+    pub recurring_end_date: Option<NaiveDate>,
+    pub recurring_active: bool,
+    pub recurring_note: Option<String>,
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, Error as SqlxError, Row};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::modules::transactions::recurring_command::RecurringTransactionCreateCommand;
+use crate::shared::dates::add_months;
+use crate::shared::db::mysql::FromSqlRow;
+use crate::shared::utils::{oub, ub};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum RecurringFrequency {
+    Weekly,
+    Biweekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl RecurringFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecurringFrequency::Weekly => "weekly",
+            RecurringFrequency::Biweekly => "biweekly",
+            RecurringFrequency::Monthly => "monthly",
+            RecurringFrequency::Quarterly => "quarterly",
+            RecurringFrequency::Yearly => "yearly",
+        }
+    }
+
+    /// Occurrence `n` of a series starting on `start`, counted from the start so month ends do not drift
+    fn nth(&self, start: NaiveDate, n: u32) -> NaiveDate {
+        match self {
+            RecurringFrequency::Weekly => start + Days::new(7 * n as u64),
+            RecurringFrequency::Biweekly => start + Days::new(14 * n as u64),
+            RecurringFrequency::Monthly => add_months(start, n as i32),
+            RecurringFrequency::Quarterly => add_months(start, 3 * n as i32),
+            RecurringFrequency::Yearly => add_months(start, 12 * n as i32),
+        }
+    }
+
+    /// Dates of a series starting on `start` and ending on `end`, from `from` to `to` included
+    pub fn dates(&self, start: NaiveDate, end: Option<NaiveDate>, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        let last = end.map_or(to, |end| end.min(to));
+        let mut dates = Vec::new();
+        if last < from {
+            return dates;
+        }
+        for n in 0.. {
+            let date = self.nth(start, n);
+            if date > last {
+                break;
+            }
+            if date >= from {
+                dates.push(date);
+            }
+        }
+        dates
+    }
+}
+
+
+/// Template of a transaction repeated on a schedule, e.g. rent or salary. Only the forecast reads it,
+/// no transaction is created. `amount_minor` is signed in the account currency, expenses negative.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RecurringTransaction {
+    pub id: Option<Vec<u8>>,
+
+    pub user_id: Vec<u8>,
+    pub account_id: Vec<u8>,
+    pub label: String,
+    pub amount_minor: i64,
+    pub category_id: Option<Vec<u8>>,
+    pub payee_id: Option<Vec<u8>>,
+
+    pub frequency: RecurringFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub active: bool,
+    pub note: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FromSqlRow for RecurringTransaction {
+    fn map_row_to_entity(row: MySqlRow, index_map: &HashMap<String, usize>) -> Result<Self, SqlxError> {
+        Ok(Self {
+            id: row.try_get(index_map["id"])?,
+            user_id: row.try_get(index_map["user_id"])?,
+            account_id: row.try_get(index_map["account_id"])?,
+            label: row.try_get(index_map["label"])?,
+            amount_minor: row.try_get(index_map["amount_minor"])?,
+            category_id: row.try_get(index_map["category_id"])?,
+            payee_id: row.try_get(index_map["payee_id"])?,
+            frequency: row.try_get(index_map["frequency"])?,
+            start_date: row.try_get(index_map["start_date"])?,
+            end_date: row.try_get(index_map["end_date"])?,
+            active: row.try_get(index_map["active"])?,
+            note: row.try_get(index_map["note"])?,
+            created_at: row.try_get(index_map["created_at"])?,
+            updated_at: row.try_get(index_map["updated_at"])?,
+        })
+    }
+}
+
+impl From<RecurringTransactionCreateCommand> for RecurringTransaction {
+    fn from(command: RecurringTransactionCreateCommand) -> Self {
+        Self {
+            id: None,
+            user_id: ub(command.user_id),
+            account_id: ub(command.account_id),
+            label: command.recurring_label,
+            amount_minor: command.recurring_amount_minor,
+            category_id: oub(command.recurring_category_id),
+            payee_id: oub(command.recurring_payee_id),
+            frequency: command.recurring_frequency,
+            start_date: command.recurring_start_date,
+            end_date: command.recurring_end_date,
+            active: true,
+            note: command.recurring_note,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl RecurringTransaction {
+    /// Dates of the occurrences from `from` to `to` included; none while inactive
+    pub fn occurrences(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        if !self.active {
+            return Vec::new();
+        }
+        self.frequency.dates(self.start_date, self.end_date, from, to)
+    }
+}
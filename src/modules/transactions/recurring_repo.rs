@@ -0,0 +1,112 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use uuid::Uuid;
+use sqlx::MySqlPool;
+
+use crate::modules::transactions::recurring_model::RecurringTransaction;
+use crate::shared::db::mysql::{GenericRepository, MySqlParam};
+use crate::shared::crud_repository::CrudRepository;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait RecurringTransactionRepositoryInterface {
+
+    async fn get(&self, recurring_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<RecurringTransaction>, Error>;
+
+    async fn create(&self, recurring: RecurringTransaction, meta_user: Option<Uuid>) -> Result<RecurringTransaction, Error>;
+
+    async fn update(&self, recurring: RecurringTransaction, meta_user: Option<Uuid>) -> Result<Option<RecurringTransaction>, Error>;
+
+    async fn delete(&self, recurring_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error>;
+
+    /// Active and inactive ones, by start date
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<RecurringTransaction>, Error>;
+
+}
+
+
+#[derive(Clone)]
+pub struct RecurringTransactionRepository {
+    pool: MySqlPool,
+}
+
+impl From<&AppState> for RecurringTransactionRepository {
+    fn from(app_state: &AppState) -> Self {
+        Self { pool: app_state.mysql_pool.clone() }
+    }
+}
+
+impl GenericRepository<RecurringTransaction> for RecurringTransactionRepository {
+    fn get_pool(&self) -> &MySqlPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl RecurringTransactionRepositoryInterface for RecurringTransactionRepository {
+    async fn get(&self, recurring_id: Uuid, meta_user: Option<Uuid>) -> Result<Option<RecurringTransaction>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(recurring_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_recurring_transaction_get_by_id", params).await
+    }
+
+    async fn create(&self, recurring: RecurringTransaction, meta_user: Option<Uuid>) -> Result<RecurringTransaction, Error> {
+        let params = vec![
+            MySqlParam::from(recurring.user_id),
+            MySqlParam::from(recurring.account_id),
+            MySqlParam::from(recurring.label),
+            MySqlParam::from(recurring.amount_minor),
+            MySqlParam::from(recurring.category_id),
+            MySqlParam::from(recurring.payee_id),
+            MySqlParam::from(recurring.frequency.as_str()),
+            MySqlParam::from(recurring.start_date),
+            MySqlParam::from(recurring.end_date),
+            MySqlParam::from(recurring.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_one("proc_recurring_transaction_create", params).await
+    }
+
+    async fn update(&self, recurring: RecurringTransaction, meta_user: Option<Uuid>) -> Result<Option<RecurringTransaction>, Error> {
+        let params = vec![
+            MySqlParam::from(recurring.id),
+            MySqlParam::from(recurring.account_id),
+            MySqlParam::from(recurring.label),
+            MySqlParam::from(recurring.amount_minor),
+            MySqlParam::from(recurring.category_id),
+            MySqlParam::from(recurring.payee_id),
+            MySqlParam::from(recurring.frequency.as_str()),
+            MySqlParam::from(recurring.start_date),
+            MySqlParam::from(recurring.end_date),
+            MySqlParam::from(recurring.active),
+            MySqlParam::from(recurring.note),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_optional("proc_recurring_transaction_update", params).await
+    }
+
+    async fn delete(&self, recurring_id: Uuid, meta_user: Option<Uuid>) -> Result<(), Error> {
+        let params = vec![
+            MySqlParam::from(ub(recurring_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure("proc_recurring_transaction_delete", params).await
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, meta_user: Option<Uuid>) -> Result<Vec<RecurringTransaction>, Error> {
+        let params = vec![
+            MySqlParam::from(ub(user_id)),
+            MySqlParam::from(oub(meta_user)),
+        ];
+
+        self.call_procedure_for_list("proc_recurring_transaction_by_user", params).await
+    }
+}
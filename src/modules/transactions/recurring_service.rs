@@ -0,0 +1,115 @@
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::modules::transactions::{
+    recurring_command::*,
+    recurring_dto::*,
+    recurring_model::RecurringTransaction,
+    recurring_repo::{RecurringTransactionRepository, RecurringTransactionRepositoryInterface},
+};
+use crate::shared::errors::AppError;
+use crate::shared::state::AppState;
+use crate::shared::utils::{oub, ub};
+
+
+#[async_trait]
+pub trait RecurringTransactionServiceInterface {
+
+    async fn get_by_id(&self, command: RecurringTransactionGetCommand) -> Result<Option<RecurringTransactionResponse>, Error>;
+
+    async fn create(&self, command: RecurringTransactionCreateCommand) -> Result<RecurringTransactionResponse, Error>;
+
+    async fn update(&self, command: RecurringTransactionUpdateCommand) -> Result<Option<RecurringTransactionResponse>, Error>;
+
+    async fn delete(&self, command: RecurringTransactionDeleteCommand) -> Result<(), Error>;
+
+    async fn get_by_user(&self, command: RecurringTransactionListByUserCommand) -> Result<Vec<RecurringTransactionResponse>, Error>;
+
+}
+
+#[derive(Clone)]
+pub struct RecurringTransactionService {
+    recurring_repo: RecurringTransactionRepository,
+}
+
+impl From<&AppState> for RecurringTransactionService {
+    fn from(app_state: &AppState) -> Self {
+        Self {
+            recurring_repo: RecurringTransactionRepository::from(app_state),
+        }
+    }
+}
+
+impl RecurringTransactionService {
+    fn validate(label: &str, amount_minor: i64, start_date: NaiveDate, end_date: Option<NaiveDate>) -> Result<(), Error> {
+        if label.trim().is_empty() {
+            return Err(AppError::BadRequest("recurring transaction label is required".into()).into());
+        }
+        if amount_minor == 0 {
+            return Err(AppError::BadRequest("recurring transaction amount cannot be zero".into()).into());
+        }
+        if end_date.is_some_and(|end_date| end_date < start_date) {
+            return Err(AppError::BadRequest("end date must be after the start date".into()).into());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RecurringTransactionServiceInterface for RecurringTransactionService {
+    async fn get_by_id(&self, command: RecurringTransactionGetCommand) -> Result<Option<RecurringTransactionResponse>, Error> {
+        match self.recurring_repo.get(command.recurring_id, Some(command.auth_user.user_id)).await {
+            Ok(recurring) => Ok(recurring.as_ref().map(RecurringTransactionResponse::from)),
+            Err(_) => Err(Error::msg("Error getting recurring transaction")),
+        }
+    }
+
+    async fn create(&self, command: RecurringTransactionCreateCommand) -> Result<RecurringTransactionResponse, Error> {
+        Self::validate(&command.recurring_label, command.recurring_amount_minor, command.recurring_start_date, command.recurring_end_date)?;
+        let meta_user = Some(command.auth_user.user_id);
+        match self.recurring_repo.create(RecurringTransaction::from(command), meta_user).await {
+            Ok(recurring) => Ok(RecurringTransactionResponse::from(&recurring)),
+            Err(_) => Err(Error::msg("Error creating recurring transaction")),
+        }
+    }
+
+    async fn update(&self, command: RecurringTransactionUpdateCommand) -> Result<Option<RecurringTransactionResponse>, Error> {
+        Self::validate(&command.recurring_label, command.recurring_amount_minor, command.recurring_start_date, command.recurring_end_date)?;
+        let meta_user = Some(command.auth_user.user_id);
+        let mut recurring = match self.recurring_repo.get(command.recurring_id, meta_user).await {
+            Ok(Some(recurring)) => recurring,
+            Ok(None) => return Ok(None),
+            Err(_) => return Err(Error::msg("Error getting recurring transaction")),
+        };
+        recurring.account_id = ub(command.account_id);
+        recurring.label = command.recurring_label;
+        recurring.amount_minor = command.recurring_amount_minor;
+        recurring.category_id = oub(command.recurring_category_id);
+        recurring.payee_id = oub(command.recurring_payee_id);
+        recurring.frequency = command.recurring_frequency;
+        recurring.start_date = command.recurring_start_date;
+        recurring.end_date = command.recurring_end_date;
+        recurring.active = command.recurring_active;
+        recurring.note = command.recurring_note;
+
+        match self.recurring_repo.update(recurring, meta_user).await {
+            Ok(recurring) => Ok(recurring.as_ref().map(RecurringTransactionResponse::from)),
+            Err(_) => Err(Error::msg("Error updating recurring transaction")),
+        }
+    }
+
+    async fn delete(&self, command: RecurringTransactionDeleteCommand) -> Result<(), Error> {
+        match self.recurring_repo.delete(command.recurring_id, Some(command.auth_user.user_id)).await {
+            Ok(()) => Ok(()),
+            Err(_) => Err(Error::msg("Error deleting recurring transaction")),
+        }
+    }
+
+    async fn get_by_user(&self, command: RecurringTransactionListByUserCommand) -> Result<Vec<RecurringTransactionResponse>, Error> {
+        match self.recurring_repo.get_by_user(command.user_id, Some(command.auth_user.user_id)).await {
+            Ok(recurrings) => Ok(recurrings.iter().map(RecurringTransactionResponse::from).collect()),
+            Err(_) => Err(Error::msg("Error getting recurring transactions")),
+        }
+    }
+}
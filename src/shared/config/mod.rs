@@ -67,6 +67,11 @@ pub struct AppEmailSmtp {
     pub from_name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppPriceConfig {
+    pub files_dir: Option<String>, // local price files, one <SYMBOL>.csv or <ISIN>.csv per instrument
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub is_prod: bool,
@@ -79,6 +84,8 @@ pub struct AppConfig {
 
     pub email_smtp: AppEmailSmtp,
 
+    pub prices: AppPriceConfig,
+
     pub bind_addr: String,
     pub metrics_addr: String,
 }
@@ -208,6 +215,10 @@ impl AppConfig {
             from_name: smtp_from_name,
         };
 
+        let prices = AppPriceConfig {
+            files_dir: get_env("PRICE_FILES_DIR").ok(),
+        };
+
         Ok(AppConfig {
             is_prod,
 
@@ -219,6 +230,8 @@ impl AppConfig {
 
             email_smtp,
 
+            prices,
+
             bind_addr,
             metrics_addr,
         })
@@ -0,0 +1,166 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+
+/// Field quoted when it holds a separator, a quote or a line break, inner quotes doubled
+pub fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One CSV line, CRLF terminated as in RFC 4180
+pub fn row<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut line = fields.iter()
+        .map(|value| field(value.as_ref()))
+        .collect::<Vec<String>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// Records of a CSV document, quoted fields may hold separators, doubled quotes and line breaks.
+/// The separator is `;` when the first line has semicolons but no comma, `,` otherwise.
+/// Blank lines are skipped; each record keeps its 1-based starting line number.
+pub fn parse(content: &str) -> Vec<(usize, Vec<String>)> {
+    let first_line = content.lines().next().unwrap_or_default();
+    let separator = if first_line.contains(';') && !first_line.contains(',') { ';' } else { ',' };
+
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            },
+            '"' if quoted => quoted = false,
+            '"' if value.is_empty() => quoted = true,
+            '\n' if quoted => {
+                value.push(c);
+                line += 1;
+            },
+            '\r' if !quoted => {},
+            '\n' => {
+                record.push(std::mem::take(&mut value));
+                if record.iter().any(|field| !field.trim().is_empty()) {
+                    records.push((start, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                start = line;
+            },
+            c if c == separator && !quoted => record.push(std::mem::take(&mut value)),
+            c => value.push(c),
+        }
+    }
+    record.push(value);
+    if record.iter().any(|field| !field.trim().is_empty()) {
+        records.push((start, record));
+    }
+    records
+}
+
+/// Number with a decimal point or a decimal comma: the last of '.' and ',' is the decimal separator,
+/// the other one separates thousands (1,234.56 and 1.234,56). A separator repeated alone only
+/// separates thousands (1,234,567). Spaces are ignored.
+pub fn decimal(value: &str) -> Option<Decimal> {
+    let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    let Some(index) = value.rfind(['.', ',']) else {
+        return Decimal::from_str(&value).ok();
+    };
+    let (whole, fraction) = (&value[..index], &value[index + 1..]);
+    let (separator, other) = if value[index..].starts_with('.') { ('.', ',') } else { (',', '.') };
+    let value = if whole.contains(separator) && !whole.contains(other) {
+        value.replace(separator, "")
+    } else {
+        format!("{}.{}", whole.replace(['.', ','], ""), fraction)
+    };
+    Decimal::from_str(&value).ok()
+}
+
+/// ISO date (2026-01-31) or day first (31/01/2026)
+pub fn date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%d/%m/%Y"))
+        .ok()
+}
+
+/// Index of the first header column named like one of `names`, ignoring case and spaces
+pub fn column(header: &[String], names: &[&str]) -> Option<usize> {
+    header.iter().position(|title| names.iter().any(|name| title.trim().eq_ignore_ascii_case(name)))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(records: &[(usize, Vec<String>)]) -> Vec<(usize, Vec<&str>)> {
+        records.iter().map(|(line, record)| (*line, record.iter().map(String::as_str).collect())).collect()
+    }
+
+    #[test]
+    fn decimal_reads_the_last_separator_as_the_decimal_one() {
+        assert_eq!(decimal("1234.56"), Some(Decimal::new(123456, 2)));
+        assert_eq!(decimal("1234,56"), Some(Decimal::new(123456, 2)));
+        assert_eq!(decimal("1,234.56"), Some(Decimal::new(123456, 2)));
+        assert_eq!(decimal("1.234,56"), Some(Decimal::new(123456, 2)));
+        assert_eq!(decimal("1.234.567,8"), Some(Decimal::new(12345678, 1)));
+        assert_eq!(decimal("1,234,567"), Some(Decimal::from(1234567)));
+        assert_eq!(decimal(" -1 234,5 "), Some(Decimal::new(-12345, 1)));
+        assert_eq!(decimal("42"), Some(Decimal::from(42)));
+        assert_eq!(decimal("abc"), None);
+        assert_eq!(decimal(""), None);
+    }
+
+    #[test]
+    fn date_reads_iso_and_day_first() {
+        assert_eq!(date("2026-01-31"), NaiveDate::from_ymd_opt(2026, 1, 31));
+        assert_eq!(date(" 31/01/2026 "), NaiveDate::from_ymd_opt(2026, 1, 31));
+        assert_eq!(date("01/31/2026"), None);
+        assert_eq!(date("2026-02-30"), None);
+    }
+
+    #[test]
+    fn parse_keeps_quoted_separators_quotes_and_line_breaks() {
+        let records = parse("name,note\r\n\"Doe, John\",\"said \"\"hi\"\"\"\r\n\"two\nlines\",x\r\n");
+        assert_eq!(fields(&records), vec![
+            (1, vec!["name", "note"]),
+            (2, vec!["Doe, John", "said \"hi\""]),
+            (3, vec!["two\nlines", "x"]),
+        ]);
+    }
+
+    #[test]
+    fn parse_detects_semicolons_and_skips_blank_lines() {
+        let records = parse("date;price\n\n2026-01-31;1.234,56\n ; \n2026-02-28;7\n");
+        assert_eq!(fields(&records), vec![
+            (1, vec!["date", "price"]),
+            (3, vec!["2026-01-31", "1.234,56"]),
+            (5, vec!["2026-02-28", "7"]),
+        ]);
+        assert_eq!(decimal(&records[1].1[1]), Some(Decimal::new(123456, 2)));
+    }
+
+    #[test]
+    fn parse_keeps_commas_with_a_comma_header() {
+        let records = parse("a;b,c\n1;2,3");
+        assert_eq!(fields(&records), vec![(1, vec!["a;b", "c"]), (2, vec!["1;2", "3"])]);
+    }
+
+    #[test]
+    fn row_quotes_what_needs_it() {
+        assert_eq!(row(&["a", "b,c", "say \"x\""]), "a,\"b,c\",\"say \"\"x\"\"\"\r\n");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+
+/// First day of the month containing `date` (budgets are always keyed on it)
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Shift a date by a signed number of months, clamping the day to the end of the target month
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    if months >= 0 {
+        date.checked_add_months(Months::new(months as u32)).unwrap_or(date)
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs())).unwrap_or(date)
+    }
+}
+
+/// Number of whole calendar months from the month of `from` to the month of `to` (negative if `to` is before)
+pub fn months_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    (to.year() - from.year()) * 12 + (to.month() as i32 - from.month() as i32)
+}
+
+/// Day `day` of the month containing `month`, clamped to the last day of that month
+pub fn day_in_month(month: NaiveDate, day: u32) -> NaiveDate {
+    let first = month_start(month);
+    let last = add_months(first, 1).pred_opt().unwrap_or(first);
+    first.with_day(day.clamp(1, last.day())).unwrap_or(last)
+}
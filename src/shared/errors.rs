@@ -45,3 +45,16 @@ impl IntoResponse for AppError {
         (status, Json(ErrorBody { error: msg, detail })).into_response()
     }
 }
+
+
+/// HTTP status for an error coming out of a service.
+/// Services wrap validation failures in `AppError`; anything else is an internal error.
+pub fn error_status(error: &anyhow::Error) -> StatusCode {
+    match error.downcast_ref::<AppError>() {
+        Some(AppError::Unauthorized) => StatusCode::UNAUTHORIZED,
+        Some(AppError::Forbidden) => StatusCode::FORBIDDEN,
+        Some(AppError::NotFound) => StatusCode::NOT_FOUND,
+        Some(AppError::BadRequest(_)) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
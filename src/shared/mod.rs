@@ -13,4 +13,6 @@ pub mod metrics;
 pub mod logging;
 pub mod openapi;
 pub mod utils;
+pub mod dates;
+pub mod csv;
 mod email;
\ No newline at end of file
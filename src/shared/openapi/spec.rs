@@ -7,15 +7,52 @@ use utoipa::{
 };
 
 use crate::modules::{
+    accounts::{
+        forecast_controller, forecast_dto,
+        loan_controller, loan_dto,
+        networth_controller, networth_dto,
+        scenario_controller, scenario_dto
+    },
+    automations::{
+        automation_controller, automation_dto
+    },
+    budgets::{
+        budget_controller, budget_dto
+    },
     currencies::{
         currency_controller, currency_dto
     },
+    goals::{
+        goal_controller, goal_dto,
+        planner_controller, planner_dto
+    },
+    investments::{
+        allocation_controller, allocation_dto,
+        asset_controller, asset_dto,
+        instrument_controller, instrument_dto,
+        lot_controller, lot_dto,
+        performance_controller, performance_dto,
+        portfolio_controller, portfolio_dto,
+        trade_controller, trade_dto
+    },
     locations::{
         location_controller, location_dto
     },
     people::{
         people_controller, people_dto
     },
+    projects::{
+        milestone_controller, milestone_dto,
+        project_controller, project_dto,
+        purchase_controller, purchase_dto,
+        recurrence_controller, recurrence_dto,
+        task_controller, task_dto,
+        template_controller, template_dto,
+        time_entry_controller, time_entry_dto
+    },
+    transactions::{
+        recurring_controller, recurring_dto
+    },
     users::{
         auth::{auth_controller, auth_dto},
         user::{user_controller, user_dto}
@@ -49,9 +86,32 @@ impl Modify for SecurityAddon {
     ),
     tags(
         (name = "Auth", description = "Authentication API endpoints"),
+        (name = "Automation", description = "Automation rules API endpoints"),
+        (name = "Budget", description = "Budget API endpoints"),
+        (name = "Cashflow Forecast", description = "Daily account balance forecast API endpoints"),
+        (name = "Cashflow Scenario", description = "What-if forecast scenario API endpoints"),
         (name = "Currency", description = "Currency API endpoints"),
         (name = "FX", description = "FX API endpoints"),
+        (name = "Goal", description = "Goal API endpoints"),
+        (name = "Goal Planner", description = "Monthly funding planner API endpoints"),
+        (name = "Investment Portfolio", description = "Investment portfolio and position API endpoints"),
+        (name = "Investment Instrument", description = "Investment instrument and price API endpoints"),
+        (name = "Investment Trade", description = "Investment trade API endpoints"),
+        (name = "Investment Performance", description = "Investment performance API endpoints"),
+        (name = "Investment Tax", description = "Investment tax lot and tax report API endpoints"),
+        (name = "Investment Allocation", description = "Investment target allocation and rebalancing API endpoints"),
+        (name = "Investment Asset", description = "Manual asset and monthly valuation API endpoints"),
+        (name = "Loan", description = "Loan amortization schedule and extra payment API endpoints"),
         (name = "Location", description = "Location API endpoints"),
+        (name = "Net Worth", description = "Net worth and monthly net worth history API endpoints"),
+        (name = "Project", description = "Project API endpoints"),
+        (name = "Project Task", description = "Project task API endpoints"),
+        (name = "Project Milestone", description = "Project milestone API endpoints"),
+        (name = "Project Template", description = "Project template API endpoints"),
+        (name = "Project Purchase", description = "Project planned purchase API endpoints"),
+        (name = "Project Task Recurrence", description = "Recurring project task API endpoints"),
+        (name = "Project Time", description = "Project time tracking API endpoints"),
+        (name = "Transaction Recurring", description = "Recurring transaction API endpoints"),
         (name = "User", description = "User Manager API endpoints"),
     ),
     paths(
@@ -59,21 +119,113 @@ impl Modify for SecurityAddon {
         auth_controller::register, auth_controller::login,
         auth_controller::forget_password, auth_controller::reset_password,
 
+        automation_controller::get_automations, automation_controller::post_automation,
+        automation_controller::get_automation, automation_controller::put_automation, automation_controller::delete_automation,
+        automation_controller::post_automations_run, automation_controller::post_automation_run, automation_controller::get_automation_runs,
+
+        budget_controller::get_budgets, budget_controller::post_budget, budget_controller::get_budget,
+        budget_controller::get_envelopes, budget_controller::post_envelope, budget_controller::get_envelope,
+        budget_controller::get_sinking_fund, budget_controller::put_sinking_fund, budget_controller::get_sinking_funds,
+
         currency_controller::get_currencies, currency_controller::post_currency, currency_controller::put_currency,
         currency_controller::get_currency, currency_controller::delete_currency,
     
         currency_controller::get_fx_rates_by_base_code,
         currency_controller::get_fx_rates, currency_controller::post_fx_rate,
         currency_controller::get_fx_rate, currency_controller::put_fx_rate, currency_controller::delete_fx_rate,
+
+        goal_controller::get_goals, goal_controller::post_goal,
+        goal_controller::get_goal, goal_controller::put_goal, goal_controller::delete_goal,
+        goal_controller::get_goal_progress, goal_controller::post_goal_simulation, goal_controller::post_goal_monte_carlo,
+        goal_controller::get_contribution_plans, goal_controller::post_contribution_plan,
+        goal_controller::put_contribution_plan, goal_controller::delete_contribution_plan,
+        goal_controller::get_contributions, goal_controller::post_contribution,
+        goal_controller::post_generate_contributions, goal_controller::put_contribution_done,
+        goal_controller::put_contribution_skip, goal_controller::get_contribution_summary,
+        planner_controller::post_funding_plan, planner_controller::post_funding_plan_accept,
+
+        portfolio_controller::get_portfolios, portfolio_controller::post_portfolio,
+        portfolio_controller::get_portfolio, portfolio_controller::put_portfolio, portfolio_controller::delete_portfolio,
+        portfolio_controller::get_portfolio_positions,
+
+        instrument_controller::get_instruments, instrument_controller::post_instrument,
+        instrument_controller::get_instrument, instrument_controller::put_instrument, instrument_controller::delete_instrument,
+        instrument_controller::get_instrument_prices, instrument_controller::post_instrument_price,
+        instrument_controller::post_instrument_prices_import, instrument_controller::post_prices_import,
+        instrument_controller::post_prices_sync,
+
+        trade_controller::get_portfolio_trades, trade_controller::post_portfolio_trade,
+        trade_controller::get_trade, trade_controller::put_trade, trade_controller::delete_trade,
+
+        performance_controller::get_performance, performance_controller::get_portfolio_performance,
+
+        lot_controller::get_portfolio_lots, lot_controller::get_tax_report, lot_controller::get_tax_report_csv,
+
+        allocation_controller::get_allocation_targets, allocation_controller::put_allocation_targets,
+        allocation_controller::get_rebalancing,
+
+        asset_controller::get_assets, asset_controller::post_asset, asset_controller::get_asset_snapshot,
+        asset_controller::get_asset, asset_controller::put_asset, asset_controller::delete_asset,
+        asset_controller::get_asset_valuations, asset_controller::put_asset_valuation,
+        asset_controller::post_asset_valuations_import, asset_controller::delete_asset_valuation,
     
         location_controller::get_locations, location_controller::post_location,
         location_controller::get_location, location_controller::put_location, location_controller::delete_location,
         location_controller::put_archived,
+
+        networth_controller::get_net_worth, networth_controller::post_net_worth_snapshots,
+        networth_controller::get_net_worth_history,
+        forecast_controller::get_forecast,
+        scenario_controller::get_scenarios, scenario_controller::post_scenario, scenario_controller::post_scenario_compare,
+        scenario_controller::get_scenario, scenario_controller::put_scenario, scenario_controller::delete_scenario,
+        loan_controller::get_loans, loan_controller::post_loan,
+        loan_controller::get_loan, loan_controller::put_loan, loan_controller::delete_loan,
+        loan_controller::get_loan_schedule, loan_controller::post_loan_extra_payments,
     
         people_controller::get_people, people_controller::post_person, 
         people_controller::get_person, people_controller::put_person, people_controller::delete_person, 
         people_controller::put_archived, 
 
+        project_controller::get_projects, project_controller::post_project,
+        project_controller::get_project, project_controller::put_project, project_controller::delete_project,
+        project_controller::put_project_status, project_controller::get_project_status_history,
+        project_controller::get_project_financials,
+
+        task_controller::get_project_tasks, task_controller::post_project_task,
+        task_controller::get_project_task_tree, task_controller::get_project_board,
+        task_controller::get_project_task, task_controller::put_project_task, task_controller::delete_project_task,
+        task_controller::put_project_task_move, task_controller::put_project_task_complete,
+        task_controller::get_project_task_dependencies, task_controller::post_project_task_dependency,
+        task_controller::delete_project_task_dependency,
+        task_controller::get_project_unblocked_tasks, task_controller::get_project_schedule,
+
+        milestone_controller::get_project_milestones, milestone_controller::post_project_milestone,
+        milestone_controller::get_project_milestone, milestone_controller::put_project_milestone, milestone_controller::delete_project_milestone,
+        milestone_controller::get_project_milestone_tasks,
+        milestone_controller::put_project_milestone_task, milestone_controller::delete_project_milestone_task,
+        milestone_controller::put_project_milestone_transaction, milestone_controller::delete_project_milestone_transaction,
+
+        template_controller::get_project_templates, template_controller::post_project_template,
+        template_controller::get_project_template, template_controller::put_project_template, template_controller::delete_project_template,
+        template_controller::post_project_template_instantiate, template_controller::post_project_clone,
+
+        purchase_controller::get_project_purchases, purchase_controller::post_project_purchase,
+        purchase_controller::get_project_purchase_summary, purchase_controller::get_project_purchase,
+        purchase_controller::put_project_purchase, purchase_controller::delete_project_purchase,
+        purchase_controller::post_project_purchase_buy,
+
+        recurrence_controller::get_project_task_recurrences, recurrence_controller::get_task_recurrence,
+        recurrence_controller::put_task_recurrence, recurrence_controller::delete_task_recurrence,
+
+        time_entry_controller::get_running_timer, time_entry_controller::post_timer_start, time_entry_controller::post_timer_stop,
+        time_entry_controller::get_task_time_entries, time_entry_controller::post_task_time_entry,
+        time_entry_controller::get_time_entry, time_entry_controller::put_time_entry, time_entry_controller::delete_time_entry,
+        time_entry_controller::get_timesheet,
+
+        recurring_controller::get_recurring_transactions, recurring_controller::post_recurring_transaction,
+        recurring_controller::get_recurring_transaction, recurring_controller::put_recurring_transaction,
+        recurring_controller::delete_recurring_transaction,
+
         user_controller::get_users, user_controller::post_user,
         user_controller::get_user, user_controller::put_user, user_controller::delete_user,
         user_controller::put_user_currency,
@@ -82,16 +234,99 @@ impl Modify for SecurityAddon {
         schemas(
             auth_dto::LoginRequest, auth_dto::RegisterRequest, auth_dto::ResetPasswordRequest,
 
+            automation_dto::AutomationRuleResponse, automation_dto::AutomationTrigger, automation_dto::AutomationAction,
+            automation_dto::AutomationRuleCreateRequest, automation_dto::AutomationRuleUpdateRequest,
+            automation_dto::AutomationRunRequest, automation_dto::AutomationRunResponse,
+
+            budget_dto::BudgetResponse, budget_dto::BudgetCreateRequest,
+            budget_dto::BudgetEnvelopeResponse, budget_dto::BudgetEnvelopeCreateRequest,
+            budget_dto::SinkingFundResponse, budget_dto::SinkingFundStatus, budget_dto::SinkingFundUpdateRequest,
+
             currency_dto::CurrencyResponse, currency_dto::CurrencyCreateRequest, currency_dto::CurrencyUpdateNameRequest,
             
             currency_dto::FxRateResponse, currency_dto::FxRateCreateRequest, currency_dto::FxRateUpdateRateRequest,
+
+            goal_dto::GoalResponse, goal_dto::GoalProgressResponse, goal_dto::GoalProgressSource, goal_dto::GoalProgressStatus,
+            goal_dto::GoalCreateRequest, goal_dto::GoalUpdateRequest,
+            goal_dto::GoalSimulationRequest, goal_dto::GoalSimulationResponse, goal_dto::GoalSimulationMode, goal_dto::GoalSimulationPoint,
+            goal_dto::GoalMonteCarloRequest, goal_dto::GoalMonteCarloContributionStep, goal_dto::GoalMonteCarloResponse, goal_dto::GoalMonteCarloBand,
+            goal_dto::GoalContributionPlanResponse, goal_dto::GoalContributionPlanCreateRequest, goal_dto::GoalContributionPlanUpdateRequest,
+            goal_dto::GoalContributionResponse, goal_dto::GoalContributionCreateRequest, goal_dto::GoalContributionGenerateRequest,
+            goal_dto::GoalContributionDoneRequest, goal_dto::GoalContributionSkipRequest, goal_dto::GoalContributionSummaryResponse,
+            planner_dto::FundingPlanRequest, planner_dto::FundingPlanResponse, planner_dto::FundingPlanLineResponse,
+            planner_dto::FundingTargetKind, planner_dto::FundingPlanAcceptResponse,
+
+            portfolio_dto::PortfolioResponse, portfolio_dto::PortfolioCreateRequest, portfolio_dto::PortfolioUpdateRequest,
+            portfolio_dto::PositionResponse, portfolio_dto::PortfolioPositionsResponse,
+
+            instrument_dto::InstrumentResponse, instrument_dto::InstrumentCreateRequest, instrument_dto::InstrumentUpdateRequest,
+            instrument_dto::InstrumentPriceResponse, instrument_dto::InstrumentPriceCreateRequest,
+            instrument_dto::PriceImportResponse, instrument_dto::ImportRejectResponse,
+            instrument_dto::PriceSyncResponse, instrument_dto::PriceSyncLineResponse,
+
+            trade_dto::TradeResponse, trade_dto::TradeCreateRequest, trade_dto::TradeUpdateRequest,
+
+            performance_dto::PerformanceResponse, performance_dto::PerformanceLineResponse, performance_dto::BenchmarkResponse,
+
+            lot_dto::PortfolioLotsResponse, lot_dto::LotResponse,
+            lot_dto::TaxReportResponse, lot_dto::RealizedGainResponse, lot_dto::LotMatchResponse,
+            lot_dto::DividendIncomeResponse, lot_dto::TaxReportTotalsResponse,
+
+            allocation_dto::AllocationTargetsResponse, allocation_dto::AllocationTargetResponse,
+            allocation_dto::AllocationTargetSetRequest, allocation_dto::AllocationTargetRequest,
+            allocation_dto::RebalancingResponse, allocation_dto::AllocationLineResponse, allocation_dto::RebalanceAction,
+
+            asset_dto::AssetResponse, asset_dto::AssetCreateRequest, asset_dto::AssetUpdateRequest,
+            asset_dto::AssetValuationResponse, asset_dto::AssetValuationSetRequest, asset_dto::ValuationImportResponse,
+            asset_dto::AssetSnapshotResponse, asset_dto::AssetSnapshotLineResponse,
         
             location_dto::LocationResponse,
             location_dto::LocationCreateRequest, location_dto::LocationUpdateRequest, location_dto::LocationUpdateArchivedRequest,
+
+            networth_dto::NetWorthResponse, networth_dto::NetWorthAccountResponse, networth_dto::NetWorthPortfolioResponse,
+            networth_dto::NetWorthKindResponse, networth_dto::NetWorthSnapshotRequest,
+            networth_dto::NetWorthMonthResponse, networth_dto::NetWorthHistoryResponse,
+            forecast_dto::ForecastResponse, forecast_dto::ForecastAccountResponse, forecast_dto::ForecastDayResponse,
+            forecast_dto::ForecastEventResponse, forecast_dto::ForecastEnvelopeMonthResponse, forecast_dto::ForecastNegativePeriodResponse,
+            scenario_dto::ScenarioResponse, scenario_dto::ScenarioOverlayResponse, scenario_dto::ScenarioOverlayRequest,
+            scenario_dto::ScenarioCreateRequest, scenario_dto::ScenarioUpdateRequest, scenario_dto::ScenarioCompareRequest,
+            scenario_dto::ScenarioComparisonResponse, scenario_dto::ScenarioOutcomeResponse, scenario_dto::ScenarioAccountOutcomeResponse,
+            loan_dto::LoanResponse, loan_dto::LoanRateChangeResponse, loan_dto::LoanRateChangeRequest,
+            loan_dto::LoanCreateRequest, loan_dto::LoanUpdateRequest,
+            loan_dto::LoanScheduleResponse, loan_dto::LoanInstallmentResponse,
+            loan_dto::LoanExtraPaymentRequest, loan_dto::LoanOneOffPaymentRequest, loan_dto::LoanExtraPaymentResponse, loan_dto::LoanPayoffResponse,
         
             people_dto::PeopleResponse,
             people_dto::PeopleCreateRequest, people_dto::PeopleUpdateRequest, people_dto::PeopleUpdateArchivedRequest,
 
+            project_dto::ProjectResponse, project_dto::ProjectCreateRequest, project_dto::ProjectUpdateRequest,
+            project_dto::ProjectStatusUpdateRequest, project_dto::ProjectStatusChangeResponse,
+            project_dto::ProjectFinancialsResponse, project_dto::ProjectSpendingMonthResponse, project_dto::ProjectSpendingBreakdownResponse,
+
+            task_dto::TaskResponse, task_dto::TaskCreateRequest, task_dto::TaskUpdateRequest, task_dto::TaskMoveRequest,
+            task_dto::TaskTimeRollupResponse, task_dto::TaskNodeResponse, task_dto::TaskTreeResponse,
+            task_dto::TaskBoardColumnResponse, task_dto::TaskBoardResponse,
+            task_dto::TaskDependencyResponse, task_dto::TaskDependencyCreateRequest, task_dto::TaskCompletionResponse,
+            task_dto::TaskScheduleItemResponse, task_dto::TaskScheduleResponse,
+
+            milestone_dto::MilestoneResponse, milestone_dto::MilestoneProgressResponse,
+            milestone_dto::MilestoneCreateRequest, milestone_dto::MilestoneUpdateRequest,
+
+            template_dto::ProjectTemplateResponse, template_dto::ProjectTemplateCreateRequest, template_dto::ProjectTemplateUpdateRequest,
+            template_dto::ProjectTemplateInstantiateRequest, template_dto::ProjectCloneRequest,
+
+            purchase_dto::PurchaseResponse, purchase_dto::PurchaseCreateRequest, purchase_dto::PurchaseUpdateRequest,
+            purchase_dto::PurchaseBuyRequest, purchase_dto::PurchaseSummaryResponse,
+
+            recurrence_dto::TaskRecurrenceResponse, recurrence_dto::TaskRecurrenceRequest,
+
+            time_entry_dto::TimeEntryResponse, time_entry_dto::TimerStartRequest,
+            time_entry_dto::TimeEntryCreateRequest, time_entry_dto::TimeEntryUpdateRequest,
+            time_entry_dto::TimesheetResponse, time_entry_dto::TimesheetRowResponse, time_entry_dto::TimesheetGroupBy,
+
+            recurring_dto::RecurringTransactionResponse,
+            recurring_dto::RecurringTransactionCreateRequest, recurring_dto::RecurringTransactionUpdateRequest,
+
             user_dto::UserResponse,
             user_dto::UserCreateRequest, user_dto::UserUpdateNameRequest, user_dto::UserUpdateBaseCurrencyRequest,
         ),